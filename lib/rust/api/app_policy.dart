@@ -0,0 +1,142 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `enforce`, `evaluate`, `kill_target`, `notify`, `policy_target`, `rule_targets`, `validate_rules`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActiveWindow`, `NEXT_ENGINE_ID`, `POLICY_ENGINES`, `PolicyState`, `PolicyStep`, `PolicyTarget`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Starts evaluating `config.rules` against running processes and the active window.
+/// Every action taken is streamed as a `PolicyViolation`. Returns an id for `stop_app_policy`.
+Stream<PolicyViolation>  startAppPolicy({required PolicyConfig config }) => RustLib.instance.api.crateApiAppPolicyStartAppPolicy(config: config);
+
+bool  stopAppPolicy({required BigInt engineId }) => RustLib.instance.api.crateApiAppPolicyStopAppPolicy(engineId: engineId);
+
+            enum PolicyAction {
+                    /// Only report the violation
+log,
+/// Report it and show a desktop notification
+notify,
+/// Notify, then kill the process after the rule's `grace_seconds` unless it exits first.
+/// Leaving a matching window does not cancel the kill.
+warnThenKill,
+/// Kill the process right away
+kill,
+                    ;
+                    
+                }
+
+class PolicyConfig  {
+                final List<PolicyRule> rules;
+/// Time between evaluations, at least one second
+final BigInt intervalMs;
+
+                const PolicyConfig({required this.rules ,required this.intervalMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => rules.hashCode^intervalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PolicyConfig &&
+                runtimeType == other.runtimeType
+                && rules == other.rules&& intervalMs == other.intervalMs;
+        
+            }
+
+/// What the engine did about a violation
+enum PolicyEnforcement {
+                    logged,
+notified,
+/// Notified, the process will be killed at `kill_at_ms`
+warned,
+killed,
+killFailed,
+/// The process exited on its own before it was killed
+exited,
+                    ;
+                    
+                }
+
+/// One blocklist entry. Patterns follow `ProcessFilter`: case-insensitive, glob with
+/// `*` / `?`, substring otherwise. Process patterns match any running process, window
+/// title patterns only the process owning the active window.
+class PolicyRule  {
+                /// Unique id, reported back in violations
+final String id;
+final List<String> namePatterns;
+final List<String> pathPatterns;
+final List<String> windowTitlePatterns;
+final PolicyAction action;
+/// Time between the warning and the kill, only used by `PolicyAction::WarnThenKill`
+final int graceSeconds;
+/// Notification text, defaults to a message naming the rule
+final String? message;
+
+                const PolicyRule({required this.id ,required this.namePatterns ,required this.pathPatterns ,required this.windowTitlePatterns ,required this.action ,required this.graceSeconds ,this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^namePatterns.hashCode^pathPatterns.hashCode^windowTitlePatterns.hashCode^action.hashCode^graceSeconds.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PolicyRule &&
+                runtimeType == other.runtimeType
+                && id == other.id&& namePatterns == other.namePatterns&& pathPatterns == other.pathPatterns&& windowTitlePatterns == other.windowTitlePatterns&& action == other.action&& graceSeconds == other.graceSeconds&& message == other.message;
+        
+            }
+
+class PolicyViolation  {
+                final String ruleId;
+final int pid;
+final String processName;
+final String? exePath;
+/// Set when the rule matched the active window title
+final String? windowTitle;
+final PolicyEnforcement enforcement;
+/// Only set for `Warned`
+final BigInt? killAtMs;
+/// Milliseconds since the Unix epoch
+final BigInt timestampMs;
+
+                const PolicyViolation({required this.ruleId ,required this.pid ,required this.processName ,this.exePath ,this.windowTitle ,required this.enforcement ,this.killAtMs ,required this.timestampMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ruleId.hashCode^pid.hashCode^processName.hashCode^exePath.hashCode^windowTitle.hashCode^enforcement.hashCode^killAtMs.hashCode^timestampMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PolicyViolation &&
+                runtimeType == other.runtimeType
+                && ruleId == other.ruleId&& pid == other.pid&& processName == other.processName&& exePath == other.exePath&& windowTitle == other.windowTitle&& enforcement == other.enforcement&& killAtMs == other.killAtMs&& timestampMs == other.timestampMs;
+        
+            }
+            
\ No newline at end of file
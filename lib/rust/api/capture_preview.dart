@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'frame_analysis.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `capture_preview_frame`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CAPTURE_PREVIEWS`, `NEXT_PREVIEW_ID`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `deref`, `deref`, `fmt`, `initialize`, `initialize`
+
+
+            /// Streams JPEG thumbnails of the primary screen at `fps` frames per second.
+///
+/// Frames go through the same capture backend as `capture_screenshot`, so the
+/// preview shows exactly what would be uploaded. Returns an id for `stop_capture_preview`.
+Stream<PreviewFrame>  startCapturePreview({required double fps , required int maxWidth }) => RustLib.instance.api.crateApiCapturePreviewStartCapturePreview(fps: fps, maxWidth: maxWidth);
+
+bool  stopCapturePreview({required BigInt previewId }) => RustLib.instance.api.crateApiCapturePreviewStopCapturePreview(previewId: previewId);
+
+            /// A downsized frame of what the tracker would capture right now
+class PreviewFrame  {
+                /// JPEG encoded thumbnail
+final Uint8List jpeg;
+final int width;
+final int height;
+/// Capture time in milliseconds since the Unix epoch
+final BigInt capturedAtMs;
+/// Classification of the full-size frame, e.g. to flag black captures during setup
+final FrameClassification classification;
+
+                const PreviewFrame({required this.jpeg ,required this.width ,required this.height ,required this.capturedAtMs ,required this.classification ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => jpeg.hashCode^width.hashCode^height.hashCode^capturedAtMs.hashCode^classification.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PreviewFrame &&
+                runtimeType == other.runtimeType
+                && jpeg == other.jpeg&& width == other.width&& height == other.height&& capturedAtMs == other.capturedAtMs&& classification == other.classification;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,127 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'display_geometry.dart';
+import 'mouse_listener.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add_click`, `add`, `get_heatmap`, `grid`, `heat_colour`, `new`, `record_click`, `render_heatmap`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CLICK_HEATMAPS`, `ClickHeatmap`, `NEXT_HEATMAP_ID`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+
+            /// Creates a heatmap. Clicks reported by a running mouse listener are added automatically,
+/// `add_heatmap_click` can feed events received elsewhere.
+BigInt  createClickHeatmap({required HeatmapConfig config }) => RustLib.instance.api.crateApiClickHeatmapCreateClickHeatmap(config: config);
+
+/// Adds a click event to one heatmap. Returns `false` if it falls outside its scope.
+Future<bool>  addHeatmapClick({required BigInt heatmapId , required MouseEvent event }) => RustLib.instance.api.crateApiClickHeatmapAddHeatmapClick(heatmapId: heatmapId, event: event);
+
+bool  resetClickHeatmap({required BigInt heatmapId }) => RustLib.instance.api.crateApiClickHeatmapResetClickHeatmap(heatmapId: heatmapId);
+
+HeatmapGrid  exportClickHeatmapGrid({required BigInt heatmapId }) => RustLib.instance.api.crateApiClickHeatmapExportClickHeatmapGrid(heatmapId: heatmapId);
+
+/// Renders the heatmap as a `width` x `height` PNG.
+///
+/// With `background_base64` (e.g. a screenshot of the same monitor / window) the heat is
+/// blended over the image at `opacity`, otherwise it is drawn on a transparent background.
+Future<Uint8List>  exportClickHeatmapPng({required BigInt heatmapId , required int width , required int height , String? backgroundBase64 , required double opacity }) => RustLib.instance.api.crateApiClickHeatmapExportClickHeatmapPng(heatmapId: heatmapId, width: width, height: height, backgroundBase64: backgroundBase64, opacity: opacity);
+
+bool  destroyClickHeatmap({required BigInt heatmapId }) => RustLib.instance.api.crateApiClickHeatmapDestroyClickHeatmap(heatmapId: heatmapId);
+
+            class HeatmapConfig  {
+                final HeatmapScope scope;
+/// Grid resolution, clicks are binned relative to the area size so grids from
+/// different resolutions can be aggregated
+final int gridWidth;
+final int gridHeight;
+/// Count right clicks as well as left clicks
+final bool includeRightClicks;
+
+                const HeatmapConfig({required this.scope ,required this.gridWidth ,required this.gridHeight ,required this.includeRightClicks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scope.hashCode^gridWidth.hashCode^gridHeight.hashCode^includeRightClicks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HeatmapConfig &&
+                runtimeType == other.runtimeType
+                && scope == other.scope&& gridWidth == other.gridWidth&& gridHeight == other.gridHeight&& includeRightClicks == other.includeRightClicks;
+        
+            }
+
+/// Raw click counts, row-major
+class HeatmapGrid  {
+                final HeatmapScope scope;
+final int gridWidth;
+final int gridHeight;
+final Uint32List cells;
+final BigInt totalClicks;
+
+                const HeatmapGrid({required this.scope ,required this.gridWidth ,required this.gridHeight ,required this.cells ,required this.totalClicks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scope.hashCode^gridWidth.hashCode^gridHeight.hashCode^cells.hashCode^totalClicks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HeatmapGrid &&
+                runtimeType == other.runtimeType
+                && scope == other.scope&& gridWidth == other.gridWidth&& gridHeight == other.gridHeight&& cells == other.cells&& totalClicks == other.totalClicks;
+        
+            }
+
+/// What the heatmap area covers
+class HeatmapScope  {
+                final HeatmapScopeKind kind;
+/// Monitor or window id, depending on `kind`
+final int id;
+
+                const HeatmapScope({required this.kind ,required this.id ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^id.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HeatmapScope &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& id == other.id;
+        
+            }
+
+enum HeatmapScopeKind {
+                    /// A whole monitor, by `MonitorLocation.monitor_id`
+monitor,
+/// A single window, by `WindowDetails.id`. Only clicks while it is active count.
+window,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `is_secret_flag`, `is_secret_name`, `name_segments`, `redact_arg`, `redact_args`, `redact_command_line`, `redact_credentials`, `redact_extra`, `short_password_flag`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BUILTIN_PATTERNS`, `EXTRA_PATTERNS`, `FLAG_ARG`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `deref`, `deref`, `initialize`, `initialize`, `initialize`
+
+
+            /// Sets additional redaction patterns (regex syntax) on top of the built-in ones,
+/// replacing any set before. A pattern with a group named `secret` only has that
+/// group redacted, e.g. `--db-url (?P<secret>\S+)`; otherwise the whole match is.
+void  setCmdlineRedactionPatterns({required List<String> patterns }) => RustLib.instance.api.crateApiCmdlineRedactionSetCmdlineRedactionPatterns(patterns: patterns);
+
+List<String>  getCmdlineRedactionPatterns() => RustLib.instance.api.crateApiCmdlineRedactionGetCmdlineRedactionPatterns();
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,98 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `changed_tiles`, `decode`, `encode_delta`, `encode`, `force_keyframe`, `get_encoder`, `header`, `new`, `new`, `row_slice_mut`, `row_slice`, `tile_bounds`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DELTA_DECODERS`, `DELTA_ENCODERS`, `DeltaDecoder`, `DeltaEncoder`, `NEXT_CODEC_ID`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `deref`, `deref`, `deref`, `fmt`, `fmt`, `initialize`, `initialize`, `initialize`
+
+
+            BigInt  createDeltaEncoder({required DeltaEncoderConfig config }) => RustLib.instance.api.crateApiDeltaEncodingCreateDeltaEncoder(config: config);
+
+/// Encodes a base64 image (e.g. from `take_full_screenshot`) against the previous frame.
+Future<EncodedFrame>  encodeDeltaFrame({required BigInt encoderId , required String base64Image }) => RustLib.instance.api.crateApiDeltaEncodingEncodeDeltaFrame(encoderId: encoderId, base64Image: base64Image);
+
+/// Captures the primary screen and encodes it against the previous frame.
+Future<EncodedFrame>  captureDeltaFrame({required BigInt encoderId }) => RustLib.instance.api.crateApiDeltaEncodingCaptureDeltaFrame(encoderId: encoderId);
+
+/// Makes the next encoded frame a keyframe, e.g. after an upload failed.
+bool  forceDeltaKeyframe({required BigInt encoderId }) => RustLib.instance.api.crateApiDeltaEncodingForceDeltaKeyframe(encoderId: encoderId);
+
+bool  destroyDeltaEncoder({required BigInt encoderId }) => RustLib.instance.api.crateApiDeltaEncodingDestroyDeltaEncoder(encoderId: encoderId);
+
+BigInt  createDeltaDecoder() => RustLib.instance.api.crateApiDeltaEncodingCreateDeltaDecoder();
+
+/// Applies an encoded frame and returns the rebuilt full image as base64 PNG.
+Future<String>  decodeDeltaFrame({required BigInt decoderId , required List<int> data }) => RustLib.instance.api.crateApiDeltaEncodingDecodeDeltaFrame(decoderId: decoderId, data: data);
+
+bool  destroyDeltaDecoder({required BigInt decoderId }) => RustLib.instance.api.crateApiDeltaEncodingDestroyDeltaDecoder(decoderId: decoderId);
+
+            /// Configuration of a delta encoder
+class DeltaEncoderConfig  {
+                /// Edge length of the square tiles frames are compared in (8-1024)
+final int tileSize;
+/// A full keyframe is emitted every this many frames (0 = only when required)
+final int keyframeInterval;
+/// A keyframe replaces the delta once this share of tiles changed (0.0-1.0)
+final double maxChangedRatio;
+
+                const DeltaEncoderConfig({required this.tileSize ,required this.keyframeInterval ,required this.maxChangedRatio ,});
+
+                static Future<DeltaEncoderConfig>  default_()=>RustLib.instance.api.crateApiDeltaEncodingDeltaEncoderConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => tileSize.hashCode^keyframeInterval.hashCode^maxChangedRatio.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeltaEncoderConfig &&
+                runtimeType == other.runtimeType
+                && tileSize == other.tileSize&& keyframeInterval == other.keyframeInterval&& maxChangedRatio == other.maxChangedRatio;
+        
+            }
+
+/// An encoded frame as produced by the delta encoder.
+///
+/// Layout (little endian): `"PTWD"`, version u8, type u8 (0 = key, 1 = delta),
+/// width u32, height u32, tile size u16, frame index u32, then either the PNG
+/// encoded keyframe or a u32 tile count followed by a deflate stream of
+/// `tile x u16, tile y u16, RGBA pixels` records (edge tiles are clipped).
+class EncodedFrame  {
+                final Uint8List data;
+final bool isKeyframe;
+final int frameIndex;
+final int changedTiles;
+final int totalTiles;
+
+                const EncodedFrame({required this.data ,required this.isKeyframe ,required this.frameIndex ,required this.changedTiles ,required this.totalTiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^isKeyframe.hashCode^frameIndex.hashCode^changedTiles.hashCode^totalTiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EncodedFrame &&
+                runtimeType == other.runtimeType
+                && data == other.data&& isKeyframe == other.isKeyframe&& frameIndex == other.frameIndex&& changedTiles == other.changedTiles&& totalTiles == other.totalTiles;
+        
+            }
+            
\ No newline at end of file
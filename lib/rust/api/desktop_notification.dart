@@ -6,11 +6,13 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `fallback_send_notification`, `send_notification_linux`, `send`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NotificationBuilder`, `NotificationUrgency`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
-/// Sends a desktop notification with the specified title and message.
+            // These functions are ignored because they are not marked as `pub`: `admit`, `capabilities_from_server`, `command_exists`, `deliver`, `ensure_gate_flusher`, `in_quiet_hours`, `is_duplicate`, `is_held_back`, `is_rate_limited`, `kdialog_args`, `new`, `notify_send_args`, `ordered_backends`, `platform_backends`, `probe_backend`, `probe_notification_server`, `record_send`, `rollback_send`, `run_notification_command`, `send_kdialog`, `send_notify_send`, `send_with_backend`, `send_with_fallback`, `send_xmessage`, `send_zenity`, `send`, `stats_for`, `take_deliverable`, `timeout_ms`, `to_notification`, `tool_available`, `urgency_from_level`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BACKEND_PREFERENCE`, `GATE_FLUSHER_RUNNING`, `GatedNotification`, `NOTIFICATION_GATE`, `NotificationBuilder`, `NotificationGate`, `NotificationUrgency`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `deref`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `hash`, `initialize`, `initialize`, `initialize`
+
+
+            /// Sends a desktop notification with the specified title and message.
 ///
 /// # Arguments
 ///
@@ -20,10 +22,13 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 ///
 /// # Returns
 ///
-/// A `Result` indicating success or failure
+/// The backend that showed the notification
 ///
 /// # Cross-platform Compatibility
 ///
+/// The platform API is tried first, then command-line tools (see
+/// `set_notification_backend_preference` to pin or exclude backends):
+///
 /// - Windows: Uses PowerShell or Windows Toast Notifications
 /// - macOS: Uses AppleScript or terminal-notifier
 /// - Linux: Uses notify-send, kdialog, zenity, or xmessage
@@ -31,9 +36,9 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 /// # Example
 ///
 /// ```rust
-/// use crate::api::desktop_notification::send_notification;
+/// use crate::api::desktop_notification::{send_notification, NotificationBackend};
 ///
-/// fn notify_user() -> Result<()> {
+/// fn notify_user() -> Result<NotificationBackend> {
 ///     send_notification(
 ///         "Task Complete".to_string(),
 ///         "Your long-running task has finished successfully!".to_string(),
@@ -41,33 +46,328 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 ///     )
 /// }
 /// ```
-Future<void> sendNotification({
-  required String title,
-  required String message,
-  String? iconPath,
-}) => RustLib.instance.api.crateApiDesktopNotificationSendNotification(
-  title: title,
-  message: message,
-  iconPath: iconPath,
-);
+Future<NotificationBackend>  sendNotification({required String title , required String message , String? iconPath }) => RustLib.instance.api.crateApiDesktopNotificationSendNotification(title: title, message: message, iconPath: iconPath);
+
+void  setNotificationBackendPreference({required NotificationBackendPreference preference }) => RustLib.instance.api.crateApiDesktopNotificationSetNotificationBackendPreference(preference: preference);
+
+NotificationBackendPreference  getNotificationBackendPreference() => RustLib.instance.api.crateApiDesktopNotificationGetNotificationBackendPreference();
+
+/// Checks which notification backends work on this machine and what they support.
+/// On Linux the capabilities come from the server's `GetCapabilities` and
+/// `GetServerInformation`, command-line tools only add what they can pass on.
+Future<List<NotificationBackendProbe>>  probeNotificationBackends() => RustLib.instance.api.crateApiDesktopNotificationProbeNotificationBackends();
 
 /// Adds the current module to the lib.rs file to make it accessible.
 /// This function is purely for documentation and should not be called.
-Future<void> registerModule() =>
-    RustLib.instance.api.crateApiDesktopNotificationRegisterModule();
+Future<void>  registerModule() => RustLib.instance.api.crateApiDesktopNotificationRegisterModule();
 
 /// Advanced notification with additional options - FFI-friendly version
-Future<void> sendNotificationWithOptions({
-  required String title,
-  required String message,
-  String? iconPath,
-  BigInt? timeoutSeconds,
-  int? urgencyLevel,
-}) =>
-    RustLib.instance.api.crateApiDesktopNotificationSendNotificationWithOptions(
-      title: title,
-      message: message,
-      iconPath: iconPath,
-      timeoutSeconds: timeoutSeconds,
-      urgencyLevel: urgencyLevel,
-    );
+Future<NotificationBackend>  sendNotificationWithOptions({required String title , required String message , String? iconPath , BigInt? timeoutSeconds , int? urgencyLevel }) => RustLib.instance.api.crateApiDesktopNotificationSendNotificationWithOptions(title: title, message: message, iconPath: iconPath, timeoutSeconds: timeoutSeconds, urgencyLevel: urgencyLevel);
+
+/// Replaces the gate configuration. Counters and queued notifications are kept,
+/// so this is also how a changed UTC offset for quiet hours is applied.
+void  configureNotificationGate({required NotificationGateConfig config }) => RustLib.instance.api.crateApiDesktopNotificationConfigureNotificationGate(config: config);
+
+void  setDoNotDisturb({required bool enabled }) => RustLib.instance.api.crateApiDesktopNotificationSetDoNotDisturb(enabled: enabled);
+
+/// Sends a notification through the gate. `category` groups notifications for
+/// rate limiting and statistics, e.g. "idle_reminder". Urgency levels as in
+/// `send_notification_with_options`; critical ones ignore quiet hours.
+/// Queued notifications are delivered later without reporting their backend.
+Future<GatedSendResult>  sendGatedNotification({required String category , required String title , required String message , String? iconPath , int? urgencyLevel }) => RustLib.instance.api.crateApiDesktopNotificationSendGatedNotification(category: category, title: title, message: message, iconPath: iconPath, urgencyLevel: urgencyLevel);
+
+NotificationGateStats  getNotificationGateStats() => RustLib.instance.api.crateApiDesktopNotificationGetNotificationGateStats();
+
+void  resetNotificationGateStats() => RustLib.instance.api.crateApiDesktopNotificationResetNotificationGateStats();
+
+            class CategoryGateStats  {
+                final String category;
+final BigInt sent;
+final BigInt deduplicated;
+final BigInt rateLimited;
+final BigInt queued;
+/// Queued notifications dropped because the queue was full
+final BigInt dropped;
+
+                const CategoryGateStats({required this.category ,required this.sent ,required this.deduplicated ,required this.rateLimited ,required this.queued ,required this.dropped ,});
+
+                static Future<CategoryGateStats>  default_()=>RustLib.instance.api.crateApiDesktopNotificationCategoryGateStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => category.hashCode^sent.hashCode^deduplicated.hashCode^rateLimited.hashCode^queued.hashCode^dropped.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CategoryGateStats &&
+                runtimeType == other.runtimeType
+                && category == other.category&& sent == other.sent&& deduplicated == other.deduplicated&& rateLimited == other.rateLimited&& queued == other.queued&& dropped == other.dropped;
+        
+            }
+
+/// What the gate did with a notification
+enum GateDecision {
+                    sent,
+deduplicated,
+rateLimited,
+/// Held back by quiet hours / do-not-disturb, delivered once they end
+queued,
+                    ;
+                    
+                }
+
+/// Result of `send_gated_notification`
+class GatedSendResult  {
+                final GateDecision decision;
+/// Backend that showed the notification, only set when it was sent right away
+final NotificationBackend? backend;
+
+                const GatedSendResult({required this.decision ,this.backend ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => decision.hashCode^backend.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GatedSendResult &&
+                runtimeType == other.runtimeType
+                && decision == other.decision&& backend == other.backend;
+        
+            }
+
+/// A way of showing notifications. `NotifyRust` talks to the platform API
+/// directly, the others shell out to a command-line tool.
+enum NotificationBackend {
+                    notifyRust,
+notifySend,
+kdialog,
+zenity,
+/// Opens a window that blocks until it is clicked away
+xmessage,
+terminalNotifier,
+appleScript,
+powerShellToast,
+powerShellBalloon,
+/// Opens a dialog that blocks until it is clicked away
+messageBox,
+                    ;
+                    
+                }
+
+/// What a backend can show
+class NotificationBackendCapabilities  {
+                /// Buttons reported back through `send_interactive_notification`
+final bool actions;
+final bool bodyMarkup;
+final bool icons;
+/// Notifications stay in a notification center until dismissed
+final bool persistence;
+
+                const NotificationBackendCapabilities({required this.actions ,required this.bodyMarkup ,required this.icons ,required this.persistence ,});
+
+                static Future<NotificationBackendCapabilities>  default_()=>RustLib.instance.api.crateApiDesktopNotificationNotificationBackendCapabilitiesDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => actions.hashCode^bodyMarkup.hashCode^icons.hashCode^persistence.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationBackendCapabilities &&
+                runtimeType == other.runtimeType
+                && actions == other.actions&& bodyMarkup == other.bodyMarkup&& icons == other.icons&& persistence == other.persistence;
+        
+            }
+
+/// Restricts which backends `send_notification` may use
+class NotificationBackendPreference  {
+                /// Use only this backend, ignoring the fallback chain
+final NotificationBackend? pinned;
+/// Never use these backends
+final List<NotificationBackend> excluded;
+
+                const NotificationBackendPreference({this.pinned ,required this.excluded ,});
+
+                static Future<NotificationBackendPreference>  default_()=>RustLib.instance.api.crateApiDesktopNotificationNotificationBackendPreferenceDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => pinned.hashCode^excluded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationBackendPreference &&
+                runtimeType == other.runtimeType
+                && pinned == other.pinned&& excluded == other.excluded;
+        
+            }
+
+class NotificationBackendProbe  {
+                final NotificationBackend backend;
+final bool available;
+/// Excluded or not pinned by the current preference
+final bool disabled;
+final NotificationBackendCapabilities capabilities;
+/// Set for backends going through a freedesktop notification server
+final NotificationServerInfo? server;
+
+                const NotificationBackendProbe({required this.backend ,required this.available ,required this.disabled ,required this.capabilities ,this.server ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => backend.hashCode^available.hashCode^disabled.hashCode^capabilities.hashCode^server.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationBackendProbe &&
+                runtimeType == other.runtimeType
+                && backend == other.backend&& available == other.available&& disabled == other.disabled&& capabilities == other.capabilities&& server == other.server;
+        
+            }
+
+class NotificationGateConfig  {
+                /// Identical notifications (same category, title and message) within this window are dropped
+final BigInt dedupeWindowSeconds;
+/// Notifications allowed per category within `rate_window_seconds`, 0 disables the cap
+final int maxPerCategory;
+final BigInt rateWindowSeconds;
+final QuietHours? quietHours;
+final bool doNotDisturb;
+
+                const NotificationGateConfig({required this.dedupeWindowSeconds ,required this.maxPerCategory ,required this.rateWindowSeconds ,this.quietHours ,required this.doNotDisturb ,});
+
+                static Future<NotificationGateConfig>  default_()=>RustLib.instance.api.crateApiDesktopNotificationNotificationGateConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => dedupeWindowSeconds.hashCode^maxPerCategory.hashCode^rateWindowSeconds.hashCode^quietHours.hashCode^doNotDisturb.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationGateConfig &&
+                runtimeType == other.runtimeType
+                && dedupeWindowSeconds == other.dedupeWindowSeconds&& maxPerCategory == other.maxPerCategory&& rateWindowSeconds == other.rateWindowSeconds&& quietHours == other.quietHours&& doNotDisturb == other.doNotDisturb;
+        
+            }
+
+class NotificationGateStats  {
+                /// Notifications currently waiting for quiet hours / DND to end
+final int pending;
+final List<CategoryGateStats> categories;
+
+                const NotificationGateStats({required this.pending ,required this.categories ,});
+
+                static Future<NotificationGateStats>  default_()=>RustLib.instance.api.crateApiDesktopNotificationNotificationGateStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => pending.hashCode^categories.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationGateStats &&
+                runtimeType == other.runtimeType
+                && pending == other.pending&& categories == other.categories;
+        
+            }
+
+/// Identity of the freedesktop notification server
+class NotificationServerInfo  {
+                final String name;
+final String vendor;
+final String version;
+final String specVersion;
+
+                const NotificationServerInfo({required this.name ,required this.vendor ,required this.version ,required this.specVersion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^vendor.hashCode^version.hashCode^specVersion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationServerInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& vendor == other.vendor&& version == other.version&& specVersion == other.specVersion;
+        
+            }
+
+/// Daily quiet period in local time. `start_minute` > `end_minute` spans midnight.
+class QuietHours  {
+                /// Minutes after local midnight, e.g. 22 * 60
+final int startMinute;
+final int endMinute;
+/// Offset of local time from UTC, e.g. `DateTime.now().timeZoneOffset.inMinutes`.
+/// Not updated by the gate: pass the new offset to `configure_notification_gate`
+/// after a DST switch or time zone change, otherwise quiet hours are shifted.
+final int utcOffsetMinutes;
+
+                const QuietHours({required this.startMinute ,required this.endMinute ,required this.utcOffsetMinutes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startMinute.hashCode^endMinute.hashCode^utcOffsetMinutes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QuietHours &&
+                runtimeType == other.runtimeType
+                && startMinute == other.startMinute&& endMinute == other.endMinute&& utcOffsetMinutes == other.utcOffsetMinutes;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,125 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `bounds_in`, `build_display_geometry`, `cached_display_geometry`, `contains`, `convert_point`, `convert_rect`, `locate_point_in`, `locate_point`, `locate_rect_in`, `locate_rect`, `native_point_to_physical`, `native_rect_to_physical`, `query_display_geometry`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `GEOMETRY_CACHE`, `NativeMonitor`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`
+
+
+            /// Returns the current monitor layout, bypassing the cache.
+List<DisplayGeometry>  getDisplayGeometry() => RustLib.instance.api.crateApiDisplayGeometryGetDisplayGeometry();
+
+/// The space mouse and window coordinates are reported in by the OS before normalisation.
+CoordinateSpace  nativeCoordinateSpace() => RustLib.instance.api.crateApiDisplayGeometryNativeCoordinateSpace();
+
+/// Converts a logical point to physical desktop pixels.
+/// Returns `None` when the point is not on any monitor.
+(double,double)?  logicalToPhysical({required double x , required double y }) => RustLib.instance.api.crateApiDisplayGeometryLogicalToPhysical(x: x, y: y);
+
+/// Converts a point in physical desktop pixels to logical points.
+/// Returns `None` when the point is not on any monitor.
+(double,double)?  physicalToLogical({required double x , required double y }) => RustLib.instance.api.crateApiDisplayGeometryPhysicalToLogical(x: x, y: y);
+
+            /// Coordinate spaces used by the crate.
+///
+/// All coordinates emitted by the crate (`MouseEvent.coords`, `WindowDetails.position`)
+/// are in `Physical` desktop pixels, the same pixels a screenshot is made of. A monitor's
+/// physical size is its logical size multiplied by its scale factor.
+enum CoordinateSpace {
+                    /// Scale-independent points, as used by macOS and by Flutter
+logical,
+/// Device pixels
+physical,
+                    ;
+                    
+                }
+
+/// Bounds of a monitor in both coordinate spaces
+class DisplayGeometry  {
+                /// Same id as `screenshots`' `display_info.id`
+final int id;
+final bool isPrimary;
+final double scaleFactor;
+final DisplayRect logical;
+final DisplayRect physical;
+
+                const DisplayGeometry({required this.id ,required this.isPrimary ,required this.scaleFactor ,required this.logical ,required this.physical ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^isPrimary.hashCode^scaleFactor.hashCode^logical.hashCode^physical.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DisplayGeometry &&
+                runtimeType == other.runtimeType
+                && id == other.id&& isPrimary == other.isPrimary&& scaleFactor == other.scaleFactor&& logical == other.logical&& physical == other.physical;
+        
+            }
+
+class DisplayRect  {
+                final int x;
+final int y;
+final int width;
+final int height;
+
+                const DisplayRect({required this.x ,required this.y ,required this.width ,required this.height ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => x.hashCode^y.hashCode^width.hashCode^height.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DisplayRect &&
+                runtimeType == other.runtimeType
+                && x == other.x&& y == other.y&& width == other.width&& height == other.height;
+        
+            }
+
+/// The monitor a point or window is on, with coordinates relative to its top-left corner
+class MonitorLocation  {
+                /// Same id as `DisplayGeometry.id` and `capture_monitor`
+final int monitorId;
+/// Monitor-relative physical pixels
+final int x;
+final int y;
+
+                const MonitorLocation({required this.monitorId ,required this.x ,required this.y ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => monitorId.hashCode^x.hashCode^y.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MonitorLocation &&
+                runtimeType == other.runtimeType
+                && monitorId == other.monitorId&& x == other.x&& y == other.y;
+        
+            }
+            
\ No newline at end of file
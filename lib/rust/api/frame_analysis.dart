@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `analyze_frame`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Analyses a base64 encoded image, e.g. the output of `take_full_screenshot`.
+FrameAnalysis  analyzeScreenshot({required String base64Image }) => RustLib.instance.api.crateApiFrameAnalysisAnalyzeScreenshot(base64Image: base64Image);
+
+            /// Result of the post-capture image analysis pass
+class FrameAnalysis  {
+                final FrameClassification classification;
+/// Mean luminance (0-255)
+final double meanLuminance;
+/// Luminance variance
+final double luminanceVariance;
+/// Share of sampled pixels falling into the most populated histogram bin (0.0-1.0)
+final double dominantBinRatio;
+/// Share of sampled pixels darker than the "dark" threshold (0.0-1.0)
+final double darkPixelRatio;
+
+                const FrameAnalysis({required this.classification ,required this.meanLuminance ,required this.luminanceVariance ,required this.dominantBinRatio ,required this.darkPixelRatio ,});
+
+                 Future<bool>  isBlank()=>RustLib.instance.api.crateApiFrameAnalysisFrameAnalysisIsBlank(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => classification.hashCode^meanLuminance.hashCode^luminanceVariance.hashCode^dominantBinRatio.hashCode^darkPixelRatio.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FrameAnalysis &&
+                runtimeType == other.runtimeType
+                && classification == other.classification&& meanLuminance == other.meanLuminance&& luminanceVariance == other.luminanceVariance&& dominantBinRatio == other.dominantBinRatio&& darkPixelRatio == other.darkPixelRatio;
+        
+            }
+
+/// Rough classification of a captured frame
+enum FrameClassification {
+                    /// Regular desktop content
+normal,
+/// All black or fully transparent, usually a failed capture
+blank,
+/// A single flat colour that is not black
+uniform,
+/// Almost entirely dark with a small amount of content, e.g. a locked session
+likelyLockScreen,
+                    ;
+                    
+                }
+            
\ No newline at end of file
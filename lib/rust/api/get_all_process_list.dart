@@ -6,64 +6,100 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
-/// Returns a list of all running processes on the system
+            // These functions are ignored because they are not marked as `pub`: `build_node`, `build_process_tree`, `process_info_from`, `refreshed_system`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Returns a list of all running processes on the system
 /// Works on macOS, Linux, and Windows
-Future<List<ProcessInfo>> getAllProcesses() =>
-    RustLib.instance.api.crateApiGetAllProcessListGetAllProcesses();
+Future<List<ProcessInfo>>  getAllProcesses() => RustLib.instance.api.crateApiGetAllProcessListGetAllProcesses();
+
+/// Returns `root_pid` with all of its descendants, `None` if the process does not exist.
+/// Threads (Linux tasks) are left out so only real child processes show up.
+Future<ProcessTreeNode?>  getProcessTree({required int rootPid }) => RustLib.instance.api.crateApiGetAllProcessListGetProcessTree(rootPid: rootPid);
 
 /// Provides platform-specific information about process access
-Future<String> getProcessAccessInfo() =>
-    RustLib.instance.api.crateApiGetAllProcessListGetProcessAccessInfo();
+Future<String>  getProcessAccessInfo() => RustLib.instance.api.crateApiGetAllProcessListGetProcessAccessInfo();
 
 /// Returns a boolean indicating if the function can access process information
 /// This can help diagnose permission issues
-Future<bool> canAccessProcesses() =>
-    RustLib.instance.api.crateApiGetAllProcessListCanAccessProcesses();
+Future<bool>  canAccessProcesses() => RustLib.instance.api.crateApiGetAllProcessListCanAccessProcesses();
 
 /// Finds a process by name (partial match)
-Future<List<ProcessInfo>> findProcessByName({required String name}) =>
-    RustLib.instance.api.crateApiGetAllProcessListFindProcessByName(name: name);
+Future<List<ProcessInfo>>  findProcessByName({required String name }) => RustLib.instance.api.crateApiGetAllProcessListFindProcessByName(name: name);
 
 /// Kills a process by PID
 /// Returns true if successful, false otherwise
-Future<bool> killProcess({required int pid}) =>
-    RustLib.instance.api.crateApiGetAllProcessListKillProcess(pid: pid);
+Future<bool>  killProcess({required int pid }) => RustLib.instance.api.crateApiGetAllProcessListKillProcess(pid: pid);
 
-/// Struct to represent process information
+            /// Struct to represent process information
 /// Compatible with Flutter Rust Bridge
-class ProcessInfo {
-  final int pid;
-  final String name;
-  final List<String> cmd;
-  final BigInt memoryUsage;
-  final double cpuUsage;
-
-  const ProcessInfo({
-    required this.pid,
-    required this.name,
-    required this.cmd,
-    required this.memoryUsage,
-    required this.cpuUsage,
-  });
-
-  @override
-  int get hashCode =>
-      pid.hashCode ^
-      name.hashCode ^
-      cmd.hashCode ^
-      memoryUsage.hashCode ^
-      cpuUsage.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ProcessInfo &&
-          runtimeType == other.runtimeType &&
-          pid == other.pid &&
-          name == other.name &&
-          cmd == other.cmd &&
-          memoryUsage == other.memoryUsage &&
-          cpuUsage == other.cpuUsage;
-}
+class ProcessInfo  {
+                final int pid;
+final String name;
+final List<String> cmd;
+final BigInt memoryUsage;
+final double cpuUsage;
+/// `None` for root processes or when the parent is not visible
+final int? parentPid;
+/// Name of the owning user, `None` if it could not be resolved
+final String? user;
+/// `None` when the executable path is not readable (e.g. other users' processes)
+final String? exePath;
+/// Seconds since the Unix epoch
+final BigInt startTime;
+/// Seconds the process has been running
+final BigInt runTime;
+/// e.g. "Runnable", "Sleeping", "Zombie"
+final String status;
+/// Total bytes read from disk since the process started
+final BigInt diskReadBytes;
+/// Total bytes written to disk since the process started
+final BigInt diskWrittenBytes;
+
+                const ProcessInfo({required this.pid ,required this.name ,required this.cmd ,required this.memoryUsage ,required this.cpuUsage ,this.parentPid ,this.user ,this.exePath ,required this.startTime ,required this.runTime ,required this.status ,required this.diskReadBytes ,required this.diskWrittenBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^name.hashCode^cmd.hashCode^memoryUsage.hashCode^cpuUsage.hashCode^parentPid.hashCode^user.hashCode^exePath.hashCode^startTime.hashCode^runTime.hashCode^status.hashCode^diskReadBytes.hashCode^diskWrittenBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessInfo &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& name == other.name&& cmd == other.cmd&& memoryUsage == other.memoryUsage&& cpuUsage == other.cpuUsage&& parentPid == other.parentPid&& user == other.user&& exePath == other.exePath&& startTime == other.startTime&& runTime == other.runTime&& status == other.status&& diskReadBytes == other.diskReadBytes&& diskWrittenBytes == other.diskWrittenBytes;
+        
+            }
+
+/// A process with its descendants, for rendering the process tree
+class ProcessTreeNode  {
+                final ProcessInfo process;
+final List<ProcessTreeNode> children;
+
+                const ProcessTreeNode({required this.process ,required this.children ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => process.hashCode^children.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessTreeNode &&
+                runtimeType == other.runtimeType
+                && process == other.process&& children == other.children;
+        
+            }
+            
\ No newline at end of file
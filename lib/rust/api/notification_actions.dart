@@ -0,0 +1,86 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `event_from_response`, `event_from_signal`, `show_interactive_notification`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Shows a notification with action buttons and streams the user's response.
+/// Returns the notification id assigned by the notification server.
+///
+/// Needs a freedesktop notification server (Linux / BSD), which reports the
+/// `ActionInvoked` and `NotificationClosed` signals back over D-Bus. Without a
+/// response within ten minutes the notification is closed and `Expired` is reported.
+Stream<NotificationEvent>  sendInteractiveNotification({required String title , required String body , required List<NotificationAction> actions }) => RustLib.instance.api.crateApiNotificationActionsSendInteractiveNotification(title: title, body: body, actions: actions);
+
+            /// A button shown on an interactive notification
+class NotificationAction  {
+                /// Reported back as `action_id` of an `ActionInvoked` event
+final String id;
+final String label;
+
+                const NotificationAction({required this.id ,required this.label ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^label.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationAction &&
+                runtimeType == other.runtimeType
+                && id == other.id&& label == other.label;
+        
+            }
+
+/// How an interactive notification ended. Only the first event is reported,
+/// servers also send a close after an action was invoked.
+class NotificationEvent  {
+                final NotificationEventKind kind;
+/// Id of the invoked action, only set for `ActionInvoked`
+final String? actionId;
+
+                const NotificationEvent({required this.kind ,this.actionId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^actionId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NotificationEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& actionId == other.actionId;
+        
+            }
+
+enum NotificationEventKind {
+                    actionInvoked,
+/// Closed by the user
+dismissed,
+/// Timed out
+expired,
+/// Closed by the app or for a reason the server did not specify
+closed,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `close_persistent`, `show_persistent`, `update_persistent`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PERSISTENT_NOTIFICATIONS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `initialize`
+
+
+            /// Shows a notification that stays until `close_notification`, e.g. a live
+/// "Tracking: Task X — 01:23:45" status. Returns a handle id for updates.
+///
+/// Needs a freedesktop notification server: updates re-send the notification
+/// with its `replaces_id`, so the server swaps it in place.
+Future<BigInt>  showPersistentNotification({required String title , required String body , String? iconPath }) => RustLib.instance.api.crateApiPersistentNotificationShowPersistentNotification(title: title, body: body, iconPath: iconPath);
+
+/// Replaces title and body of a persistent notification in place
+Future<void>  updateNotification({required BigInt handleId , required String title , required String body }) => RustLib.instance.api.crateApiPersistentNotificationUpdateNotification(handleId: handleId, title: title, body: body);
+
+/// Closes a persistent notification and forgets its handle
+Future<void>  closeNotification({required BigInt handleId }) => RustLib.instance.api.crateApiPersistentNotificationCloseNotification(handleId: handleId);
+
+            
+            
\ No newline at end of file
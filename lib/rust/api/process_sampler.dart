@@ -0,0 +1,149 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `push_sample`, `rank_processes`, `record_samples`, `summarize`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PROCESS_HISTORY`, `ProcessHistoryBuffer`, `SAMPLER_RUNNING`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+
+            /// Starts the background process sampler. Returns `false` if it is already running.
+///
+/// Unlike `get_all_processes`, the sampler refreshes the same `System` every
+/// `interval_ms`, so CPU usage is measured over the full interval. Only CPU and
+/// memory are refreshed, which keeps it cheap enough to run all day.
+bool  startProcessSampler({required ProcessSamplerConfig config }) => RustLib.instance.api.crateApiProcessSamplerStartProcessSampler(config: config);
+
+bool  stopProcessSampler() => RustLib.instance.api.crateApiProcessSamplerStopProcessSampler();
+
+bool  isProcessSamplerRunning() => RustLib.instance.api.crateApiProcessSamplerIsProcessSamplerRunning();
+
+/// Returns the recorded samples of a process, `None` if it is not being tracked.
+ProcessHistory?  getProcessHistory({required int pid }) => RustLib.instance.api.crateApiProcessSamplerGetProcessHistory(pid: pid);
+
+/// Returns the `n` processes with the highest average usage over the last `window_seconds`.
+List<ProcessUsageSummary>  topProcesses({required ProcessSortKey by , required int n , required BigInt windowSeconds }) => RustLib.instance.api.crateApiProcessSamplerTopProcesses(by: by, n: n, windowSeconds: windowSeconds);
+
+            class ProcessHistory  {
+                final int pid;
+final String name;
+/// Oldest first
+final List<ProcessSample> samples;
+
+                const ProcessHistory({required this.pid ,required this.name ,required this.samples ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^name.hashCode^samples.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessHistory &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& name == other.name&& samples == other.samples;
+        
+            }
+
+class ProcessSample  {
+                /// Milliseconds since the Unix epoch
+final BigInt timestampMs;
+/// Percentage of one core, can exceed 100 for multi-threaded processes
+final double cpuUsage;
+final BigInt memoryBytes;
+
+                const ProcessSample({required this.timestampMs ,required this.cpuUsage ,required this.memoryBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^cpuUsage.hashCode^memoryBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessSample &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& cpuUsage == other.cpuUsage&& memoryBytes == other.memoryBytes;
+        
+            }
+
+class ProcessSamplerConfig  {
+                /// Time between two refreshes, at least `MINIMUM_CPU_UPDATE_INTERVAL` (200 ms)
+final BigInt intervalMs;
+/// Number of samples kept per process
+final int historyLen;
+
+                const ProcessSamplerConfig({required this.intervalMs ,required this.historyLen ,});
+
+                static Future<ProcessSamplerConfig>  default_()=>RustLib.instance.api.crateApiProcessSamplerProcessSamplerConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => intervalMs.hashCode^historyLen.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessSamplerConfig &&
+                runtimeType == other.runtimeType
+                && intervalMs == other.intervalMs&& historyLen == other.historyLen;
+        
+            }
+
+enum ProcessSortKey {
+                    cpu,
+memory,
+                    ;
+                    
+                }
+
+/// Usage of one process aggregated over a time window
+class ProcessUsageSummary  {
+                final int pid;
+final String name;
+final double averageCpu;
+final double peakCpu;
+final BigInt averageMemoryBytes;
+final BigInt peakMemoryBytes;
+final int sampleCount;
+
+                const ProcessUsageSummary({required this.pid ,required this.name ,required this.averageCpu ,required this.peakCpu ,required this.averageMemoryBytes ,required this.peakMemoryBytes ,required this.sampleCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pid.hashCode^name.hashCode^averageCpu.hashCode^peakCpu.hashCode^averageMemoryBytes.hashCode^peakMemoryBytes.hashCode^sampleCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessUsageSummary &&
+                runtimeType == other.runtimeType
+                && pid == other.pid&& name == other.name&& averageCpu == other.averageCpu&& peakCpu == other.peakCpu&& averageMemoryBytes == other.averageMemoryBytes&& peakMemoryBytes == other.peakMemoryBytes&& sampleCount == other.sampleCount;
+        
+            }
+            
\ No newline at end of file
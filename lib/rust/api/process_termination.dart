@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `descendants`, `is_alive`, `refresh`, `to_sysinfo_signal`, `wait_for_exit`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Asks a process to exit with `options.signal`, waits up to the grace period and
+/// only then kills it. With `kill_tree` the descendants are signalled first and
+/// anything still alive at the end counts against the outcome.
+Future<TerminateOutcome>  terminateProcess({required int pid , required TerminateOptions options }) => RustLib.instance.api.crateApiProcessTerminationTerminateProcess(pid: pid, options: options);
+
+            class TerminateOptions  {
+                final TerminateSignal signal;
+/// Time the process gets to exit after `signal` before it is killed, 0 kills right away
+final BigInt gracePeriodMs;
+/// Also terminate all descendants of the process
+final bool killTree;
+
+                const TerminateOptions({required this.signal ,required this.gracePeriodMs ,required this.killTree ,});
+
+                static Future<TerminateOptions>  default_()=>RustLib.instance.api.crateApiProcessTerminationTerminateOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => signal.hashCode^gracePeriodMs.hashCode^killTree.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TerminateOptions &&
+                runtimeType == other.runtimeType
+                && signal == other.signal&& gracePeriodMs == other.gracePeriodMs&& killTree == other.killTree;
+        
+            }
+
+enum TerminateOutcome {
+                    /// Exited within the grace period
+exited,
+/// Had to be killed after the grace period
+forceKilled,
+notFound,
+/// The signal could not be delivered, usually a process of another user
+permissionDenied,
+/// Still running even after the hard kill
+stillRunning,
+                    ;
+                    
+                }
+
+/// Signal sent first. Windows only supports `Kill`, other signals fall back to it there.
+enum TerminateSignal {
+                    term,
+interrupt,
+hangup,
+quit,
+kill,
+                    ;
+                    
+                }
+            
\ No newline at end of file
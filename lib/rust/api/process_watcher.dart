@@ -0,0 +1,92 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `diff_processes`, `filter_matches`, `pattern_matches`, `tracked_process`, `unix_time_seconds`, `wildcard_match`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NEXT_WATCHER_ID`, `PROCESS_WATCHERS`, `TrackedProcess`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+
+            /// Starts watching for processes matching `filter` and streams start / exit events.
+/// Returns an id for `stop_process_watcher`.
+Stream<ProcessEvent>  startProcessWatcher({required ProcessFilter filter }) => RustLib.instance.api.crateApiProcessWatcherStartProcessWatcher(filter: filter);
+
+bool  stopProcessWatcher({required BigInt watcherId }) => RustLib.instance.api.crateApiProcessWatcherStopProcessWatcher(watcherId: watcherId);
+
+            class ProcessEvent  {
+                final ProcessEventKind kind;
+final int pid;
+final String name;
+final String exePath;
+/// Seconds since the Unix epoch
+final BigInt startTime;
+/// Seconds between start and the moment the exit was noticed, `None` for starts
+final BigInt? runDurationSeconds;
+
+                const ProcessEvent({required this.kind ,required this.pid ,required this.name ,required this.exePath ,required this.startTime ,this.runDurationSeconds ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^pid.hashCode^name.hashCode^exePath.hashCode^startTime.hashCode^runDurationSeconds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& pid == other.pid&& name == other.name&& exePath == other.exePath&& startTime == other.startTime&& runDurationSeconds == other.runDurationSeconds;
+        
+            }
+
+enum ProcessEventKind {
+                    started,
+exited,
+                    ;
+                    
+                }
+
+/// Selects the processes a watcher reports.
+///
+/// Patterns are case-insensitive. Patterns containing `*` or `?` must match the whole
+/// name / path, plain patterns match anywhere (like `find_process_by_name`).
+/// With no patterns at all every process is reported.
+class ProcessFilter  {
+                /// Matched against the process name, e.g. `zoom*` or `slack`
+final List<String> namePatterns;
+/// Matched against the executable path, e.g. `/Applications/*.app/*`
+final List<String> pathPatterns;
+/// Report processes that are already running when the watcher starts as started
+final bool includeExisting;
+
+                const ProcessFilter({required this.namePatterns ,required this.pathPatterns ,required this.includeExisting ,});
+
+                static Future<ProcessFilter>  default_()=>RustLib.instance.api.crateApiProcessWatcherProcessFilterDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => namePatterns.hashCode^pathPatterns.hashCode^includeExisting.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProcessFilter &&
+                runtimeType == other.runtimeType
+                && namePatterns == other.namePatterns&& pathPatterns == other.pathPatterns&& includeExisting == other.includeExisting;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `encoding_deferred`, `next_throttle_factor`, `on_battery_from_sysfs`, `scale`, `throttle_factor`, `throttled_interval`, `wait_for_encoding_budget`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BUDGET_RUNNING`, `BUDGET_STATE`, `BudgetState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `deref`, `deref`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Starts measuring the tracker's own CPU and memory use and throttling it to
+/// `config`. Returns `false` if the budget controller is already running.
+bool  startResourceBudget({required ResourceBudgetConfig config }) => RustLib.instance.api.crateApiResourceBudgetStartResourceBudget(config: config);
+
+/// Stops the budget controller and restores the normal polling intervals
+bool  stopResourceBudget() => RustLib.instance.api.crateApiResourceBudgetStopResourceBudget();
+
+ResourceBudgetStatus  getResourceBudgetStatus() => RustLib.instance.api.crateApiResourceBudgetGetResourceBudgetStatus();
+
+/// Whether the machine currently runs on battery. `false` for desktops and when unknown.
+Future<bool>  isOnBatteryPower() => RustLib.instance.api.crateApiResourceBudgetIsOnBatteryPower();
+
+            class ResourceBudgetConfig  {
+                /// CPU budget in percent of the whole machine (all cores), e.g. 1.0
+final double maxCpuPercent;
+/// Resident memory budget
+final BigInt maxMemoryBytes;
+/// Time between two self measurements
+final BigInt sampleIntervalMs;
+/// Upper bound for slowing down polling because of CPU use
+final double maxThrottleFactor;
+/// Extra slow-down applied on top while running on battery
+final double batteryThrottleFactor;
+
+                const ResourceBudgetConfig({required this.maxCpuPercent ,required this.maxMemoryBytes ,required this.sampleIntervalMs ,required this.maxThrottleFactor ,required this.batteryThrottleFactor ,});
+
+                static Future<ResourceBudgetConfig>  default_()=>RustLib.instance.api.crateApiResourceBudgetResourceBudgetConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxCpuPercent.hashCode^maxMemoryBytes.hashCode^sampleIntervalMs.hashCode^maxThrottleFactor.hashCode^batteryThrottleFactor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ResourceBudgetConfig &&
+                runtimeType == other.runtimeType
+                && maxCpuPercent == other.maxCpuPercent&& maxMemoryBytes == other.maxMemoryBytes&& sampleIntervalMs == other.sampleIntervalMs&& maxThrottleFactor == other.maxThrottleFactor&& batteryThrottleFactor == other.batteryThrottleFactor;
+        
+            }
+
+/// Own resource use and the resulting throttling
+class ResourceBudgetStatus  {
+                final bool running;
+/// Last measured CPU use in percent of the whole machine
+final double cpuPercent;
+final BigInt memoryBytes;
+final bool onBattery;
+/// Multiplier applied to all polling intervals, 1.0 means no throttling
+final double throttleFactor;
+/// Scheduled screenshots wait before encoding while this is set
+final bool encodingDeferred;
+final BigInt inputPollIntervalMs;
+final BigInt windowPollIntervalMs;
+
+                const ResourceBudgetStatus({required this.running ,required this.cpuPercent ,required this.memoryBytes ,required this.onBattery ,required this.throttleFactor ,required this.encodingDeferred ,required this.inputPollIntervalMs ,required this.windowPollIntervalMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => running.hashCode^cpuPercent.hashCode^memoryBytes.hashCode^onBattery.hashCode^throttleFactor.hashCode^encodingDeferred.hashCode^inputPollIntervalMs.hashCode^windowPollIntervalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ResourceBudgetStatus &&
+                runtimeType == other.runtimeType
+                && running == other.running&& cpuPercent == other.cpuPercent&& memoryBytes == other.memoryBytes&& onBattery == other.onBattery&& throttleFactor == other.throttleFactor&& encodingDeferred == other.encodingDeferred&& inputPollIntervalMs == other.inputPollIntervalMs&& windowPollIntervalMs == other.windowPollIntervalMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,105 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'frame_analysis.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'screenshot_watermark.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `capture_screen_frame`, `capture_screen_image`, `decode_base64_image`, `encode_image`, `finish_screenshot`, `prepare_image`, `resize_to_max_width`, `unix_time_ms`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CapturedFrame`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            /// Captures the primary screen and encodes it according to `options`.
+///
+/// Uses the same backend fallback chain as `take_full_screenshot`, so the
+/// result always reflects what the tracker would upload.
+Future<Screenshot>  captureScreenshot({required ScreenshotOptions options }) => RustLib.instance.api.crateApiScreenshotCaptureCaptureScreenshot(options: options);
+
+/// Captures a single monitor, identified by the `monitor_id` reported in
+/// `MouseEvent`, `WindowDetails` and `DisplayGeometry`.
+Future<Screenshot>  captureMonitor({required int monitorId , required ScreenshotOptions options }) => RustLib.instance.api.crateApiScreenshotCaptureCaptureMonitor(monitorId: monitorId, options: options);
+
+/// Captures only the monitor showing the active window, leaving other screens out.
+Future<Screenshot>  captureActiveWindowMonitor({required ScreenshotOptions options }) => RustLib.instance.api.crateApiScreenshotCaptureCaptureActiveWindowMonitor(options: options);
+
+            /// A captured and encoded screenshot
+class Screenshot  {
+                /// Base64 encoded image data, same encoding as `take_full_screenshot`
+final String data;
+final ScreenshotFormat format;
+final int width;
+final int height;
+/// Capture time in milliseconds since the Unix epoch
+final BigInt capturedAtMs;
+/// Blank / lock screen detection for the captured frame
+final FrameAnalysis analysis;
+/// Captured monitor (`display_info.id`), `None` for the primary screen fallback chain
+final int? monitorId;
+
+                const Screenshot({required this.data ,required this.format ,required this.width ,required this.height ,required this.capturedAtMs ,required this.analysis ,this.monitorId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^format.hashCode^width.hashCode^height.hashCode^capturedAtMs.hashCode^analysis.hashCode^monitorId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Screenshot &&
+                runtimeType == other.runtimeType
+                && data == other.data&& format == other.format&& width == other.width&& height == other.height&& capturedAtMs == other.capturedAtMs&& analysis == other.analysis&& monitorId == other.monitorId;
+        
+            }
+
+/// Image encoding used for a captured screenshot
+enum ScreenshotFormat {
+                    png,
+jpeg,
+                    ;
+                    
+                }
+
+/// Options applied to a screenshot between capture and encoding
+class ScreenshotOptions  {
+                final ScreenshotFormat format;
+/// JPEG quality from 1 to 100 (ignored for PNG)
+final int jpegQuality;
+/// Downscale the image to at most this width, keeping the aspect ratio
+final int? maxWidth;
+/// Timestamp / identity strip drawn onto the image before encoding
+final WatermarkOptions? watermark;
+/// Composite the mouse cursor onto the capture
+final bool includeCursor;
+
+                const ScreenshotOptions({required this.format ,required this.jpegQuality ,this.maxWidth ,this.watermark ,required this.includeCursor ,});
+
+                static Future<ScreenshotOptions>  default_()=>RustLib.instance.api.crateApiScreenshotCaptureScreenshotOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => format.hashCode^jpegQuality.hashCode^maxWidth.hashCode^watermark.hashCode^includeCursor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScreenshotOptions &&
+                runtimeType == other.runtimeType
+                && format == other.format&& jpegQuality == other.jpegQuality&& maxWidth == other.maxWidth&& watermark == other.watermark&& includeCursor == other.includeCursor;
+        
+            }
+            
\ No newline at end of file
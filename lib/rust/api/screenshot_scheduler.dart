@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'frame_analysis.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'screenshot_capture.dart';
+import 'screenshot_watermark.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `capture_deferring_encoding`, `idle_for`, `new`, `plan_capture_offsets`, `poll`, `run_scheduler`, `set_scheduler_flags`, `wait_until`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActivityTracker`, `NEXT_SCHEDULER_ID`, `SCREENSHOT_SCHEDULERS`, `SchedulerFlags`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `deref`, `deref`, `fmt`, `fmt`, `initialize`, `initialize`
+
+
+            /// Starts a scheduler that captures `captures_per_interval` screenshots at random
+/// instants within every `interval_seconds` window and sends them through the sink.
+///
+/// Unlike Dart timers the schedule keeps running while the app is in the background.
+/// Returns an id for `pause_screenshot_scheduler`, `resume_screenshot_scheduler`
+/// and `stop_screenshot_scheduler`.
+Stream<Screenshot>  startScreenshotScheduler({required ScreenshotScheduleConfig config }) => RustLib.instance.api.crateApiScreenshotSchedulerStartScreenshotScheduler(config: config);
+
+/// Pauses a scheduler. Capture instants that pass while paused are skipped.
+bool  pauseScreenshotScheduler({required BigInt schedulerId }) => RustLib.instance.api.crateApiScreenshotSchedulerPauseScreenshotScheduler(schedulerId: schedulerId);
+
+/// Resumes a paused scheduler.
+bool  resumeScreenshotScheduler({required BigInt schedulerId }) => RustLib.instance.api.crateApiScreenshotSchedulerResumeScreenshotScheduler(schedulerId: schedulerId);
+
+bool  stopScreenshotScheduler({required BigInt schedulerId }) => RustLib.instance.api.crateApiScreenshotSchedulerStopScreenshotScheduler(schedulerId: schedulerId);
+
+            /// Configuration for the jittered screenshot scheduler
+class ScreenshotScheduleConfig  {
+                /// Length of each scheduling window in seconds
+final BigInt intervalSeconds;
+/// Number of screenshots taken at random instants within each window
+final int capturesPerInterval;
+/// Skip captures once the user has been idle this long (0 disables the check)
+final BigInt idleThresholdSeconds;
+/// Fixed RNG seed for reproducible schedules, `None` seeds from the OS
+final BigInt? seed;
+final ScreenshotOptions options;
+
+                const ScreenshotScheduleConfig({required this.intervalSeconds ,required this.capturesPerInterval ,required this.idleThresholdSeconds ,this.seed ,required this.options ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => intervalSeconds.hashCode^capturesPerInterval.hashCode^idleThresholdSeconds.hashCode^seed.hashCode^options.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScreenshotScheduleConfig &&
+                runtimeType == other.runtimeType
+                && intervalSeconds == other.intervalSeconds&& capturesPerInterval == other.capturesPerInterval&& idleThresholdSeconds == other.idleThresholdSeconds&& seed == other.seed&& options == other.options;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,117 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'frame_analysis.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'screenshot_capture.dart';
+import 'screenshot_watermark.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `parse_spool_file_name`, `write_atomically`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// Captures the primary screen and writes the encoded image into `dir`.
+///
+/// The image is written to a temporary file, synced and then renamed, so readers
+/// never see a partially written screenshot. Only the path crosses FFI, which keeps
+/// large images out of Dart memory while the app is offline.
+Future<SpooledScreenshot>  captureToFile({required String dir , required ScreenshotOptions options }) => RustLib.instance.api.crateApiScreenshotSpoolCaptureToFile(dir: dir, options: options);
+
+/// Lists the screenshots waiting in the spool directory, oldest first.
+Future<List<SpoolEntry>>  listSpooledScreenshots({required String dir }) => RustLib.instance.api.crateApiScreenshotSpoolListSpooledScreenshots(dir: dir);
+
+/// Deletes a spooled screenshot, e.g. after a successful upload.
+/// Returns `false` if the file no longer exists.
+Future<bool>  deleteSpooledScreenshot({required String path }) => RustLib.instance.api.crateApiScreenshotSpoolDeleteSpooledScreenshot(path: path);
+
+/// Deletes the oldest spooled screenshots until the directory uses at most `max_bytes`.
+Future<SpoolQuotaResult>  enforceSpoolQuota({required String dir , required BigInt maxBytes }) => RustLib.instance.api.crateApiScreenshotSpoolEnforceSpoolQuota(dir: dir, maxBytes: maxBytes);
+
+            /// A pending file in the spool directory
+class SpoolEntry  {
+                final String path;
+final BigInt sizeBytes;
+final BigInt capturedAtMs;
+
+                const SpoolEntry({required this.path ,required this.sizeBytes ,required this.capturedAtMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^sizeBytes.hashCode^capturedAtMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SpoolEntry &&
+                runtimeType == other.runtimeType
+                && path == other.path&& sizeBytes == other.sizeBytes&& capturedAtMs == other.capturedAtMs;
+        
+            }
+
+/// Result of a quota enforcement pass
+class SpoolQuotaResult  {
+                /// Files removed, oldest first
+final List<String> deletedPaths;
+final int remainingFiles;
+final BigInt remainingBytes;
+
+                const SpoolQuotaResult({required this.deletedPaths ,required this.remainingFiles ,required this.remainingBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => deletedPaths.hashCode^remainingFiles.hashCode^remainingBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SpoolQuotaResult &&
+                runtimeType == other.runtimeType
+                && deletedPaths == other.deletedPaths&& remainingFiles == other.remainingFiles&& remainingBytes == other.remainingBytes;
+        
+            }
+
+/// A screenshot written to the spool directory
+class SpooledScreenshot  {
+                final String path;
+final ScreenshotFormat format;
+final int width;
+final int height;
+final BigInt sizeBytes;
+/// Capture time in milliseconds since the Unix epoch
+final BigInt capturedAtMs;
+final FrameAnalysis analysis;
+
+                const SpooledScreenshot({required this.path ,required this.format ,required this.width ,required this.height ,required this.sizeBytes ,required this.capturedAtMs ,required this.analysis ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^format.hashCode^width.hashCode^height.hashCode^sizeBytes.hashCode^capturedAtMs.hashCode^analysis.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SpooledScreenshot &&
+                runtimeType == other.runtimeType
+                && path == other.path&& format == other.format&& width == other.width&& height == other.height&& sizeBytes == other.sizeBytes&& capturedAtMs == other.capturedAtMs&& analysis == other.analysis;
+        
+            }
+            
\ No newline at end of file
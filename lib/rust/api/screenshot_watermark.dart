@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_watermark`, `blend_pixel`, `draw_glyph`, `format_utc_timestamp`, `glyph_for`, `watermark_text`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// Options for the timestamp / identity watermark drawn onto screenshots
+class WatermarkOptions  {
+                final WatermarkPosition position;
+/// Opacity of the strip from 0.0 (invisible) to 1.0 (opaque)
+final double opacity;
+/// Pixel scale of the bundled 5x7 font, `None` picks one from the image height.
+/// Clamped to 1..=16.
+final int? scale;
+final bool includeHostname;
+final bool includeUser;
+/// Odoo task id shown in the strip
+final String? taskId;
+/// Free text appended to the strip
+final String? customText;
+
+                const WatermarkOptions({required this.position ,required this.opacity ,this.scale ,required this.includeHostname ,required this.includeUser ,this.taskId ,this.customText ,});
+
+                static Future<WatermarkOptions>  default_()=>RustLib.instance.api.crateApiScreenshotWatermarkWatermarkOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => position.hashCode^opacity.hashCode^scale.hashCode^includeHostname.hashCode^includeUser.hashCode^taskId.hashCode^customText.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WatermarkOptions &&
+                runtimeType == other.runtimeType
+                && position == other.position&& opacity == other.opacity&& scale == other.scale&& includeHostname == other.includeHostname&& includeUser == other.includeUser&& taskId == other.taskId&& customText == other.customText;
+        
+            }
+
+/// Corner of the screenshot the watermark strip is drawn in
+enum WatermarkPosition {
+                    topLeft,
+topRight,
+bottomLeft,
+bottomRight,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,170 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `snapshot`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NEXT_MONITOR_ID`, `SYSTEM_MONITORS`, `SystemCollector`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`
+
+
+            /// Returns the current machine health. Blocks for about 200 ms to measure CPU usage.
+Future<SystemSnapshot>  getSystemSnapshot() => RustLib.instance.api.crateApiSystemMonitorGetSystemSnapshot();
+
+/// Streams a `SystemSnapshot` every `interval_ms` (at least one second).
+/// CPU usage in each snapshot is the average since the previous one.
+/// Returns an id for `stop_system_monitor`.
+Stream<SystemSnapshot>  startSystemMonitor({required BigInt intervalMs }) => RustLib.instance.api.crateApiSystemMonitorStartSystemMonitor(intervalMs: intervalMs);
+
+bool  stopSystemMonitor({required BigInt monitorId }) => RustLib.instance.api.crateApiSystemMonitorStopSystemMonitor(monitorId: monitorId);
+
+            class CpuCoreUsage  {
+                final String name;
+/// Percentage of this core
+final double usage;
+final BigInt frequencyMhz;
+
+                const CpuCoreUsage({required this.name ,required this.usage ,required this.frequencyMhz ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^usage.hashCode^frequencyMhz.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CpuCoreUsage &&
+                runtimeType == other.runtimeType
+                && name == other.name&& usage == other.usage&& frequencyMhz == other.frequencyMhz;
+        
+            }
+
+class DiskUsageInfo  {
+                final String name;
+final String mountPoint;
+final String fileSystem;
+final BigInt totalBytes;
+final BigInt availableBytes;
+final bool isRemovable;
+
+                const DiskUsageInfo({required this.name ,required this.mountPoint ,required this.fileSystem ,required this.totalBytes ,required this.availableBytes ,required this.isRemovable ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^mountPoint.hashCode^fileSystem.hashCode^totalBytes.hashCode^availableBytes.hashCode^isRemovable.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DiskUsageInfo &&
+                runtimeType == other.runtimeType
+                && name == other.name&& mountPoint == other.mountPoint&& fileSystem == other.fileSystem&& totalBytes == other.totalBytes&& availableBytes == other.availableBytes&& isRemovable == other.isRemovable;
+        
+            }
+
+/// Load averages over 1, 5 and 15 minutes, always 0 on Windows
+class LoadAverage  {
+                final double one;
+final double five;
+final double fifteen;
+
+                const LoadAverage({required this.one ,required this.five ,required this.fifteen ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => one.hashCode^five.hashCode^fifteen.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LoadAverage &&
+                runtimeType == other.runtimeType
+                && one == other.one&& five == other.five&& fifteen == other.fifteen;
+        
+            }
+
+class NetworkInterfaceUsage  {
+                final String name;
+/// Bytes received since the interface came up
+final BigInt receivedBytes;
+/// Bytes sent since the interface came up
+final BigInt transmittedBytes;
+
+                const NetworkInterfaceUsage({required this.name ,required this.receivedBytes ,required this.transmittedBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^receivedBytes.hashCode^transmittedBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NetworkInterfaceUsage &&
+                runtimeType == other.runtimeType
+                && name == other.name&& receivedBytes == other.receivedBytes&& transmittedBytes == other.transmittedBytes;
+        
+            }
+
+/// Machine health at one point in time
+class SystemSnapshot  {
+                /// Milliseconds since the Unix epoch
+final BigInt timestampMs;
+/// Average over all cores, in percent
+final double globalCpuUsage;
+final List<CpuCoreUsage> cpuCores;
+final BigInt totalMemoryBytes;
+final BigInt usedMemoryBytes;
+final BigInt availableMemoryBytes;
+final BigInt totalSwapBytes;
+final BigInt usedSwapBytes;
+final LoadAverage loadAverage;
+final BigInt uptimeSeconds;
+/// Seconds since the Unix epoch
+final BigInt bootTime;
+final List<DiskUsageInfo> disks;
+final List<NetworkInterfaceUsage> networks;
+
+                const SystemSnapshot({required this.timestampMs ,required this.globalCpuUsage ,required this.cpuCores ,required this.totalMemoryBytes ,required this.usedMemoryBytes ,required this.availableMemoryBytes ,required this.totalSwapBytes ,required this.usedSwapBytes ,required this.loadAverage ,required this.uptimeSeconds ,required this.bootTime ,required this.disks ,required this.networks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^globalCpuUsage.hashCode^cpuCores.hashCode^totalMemoryBytes.hashCode^usedMemoryBytes.hashCode^availableMemoryBytes.hashCode^totalSwapBytes.hashCode^usedSwapBytes.hashCode^loadAverage.hashCode^uptimeSeconds.hashCode^bootTime.hashCode^disks.hashCode^networks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SystemSnapshot &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& globalCpuUsage == other.globalCpuUsage&& cpuCores == other.cpuCores&& totalMemoryBytes == other.totalMemoryBytes&& usedMemoryBytes == other.usedMemoryBytes&& availableMemoryBytes == other.availableMemoryBytes&& totalSwapBytes == other.totalSwapBytes&& usedSwapBytes == other.usedSwapBytes&& loadAverage == other.loadAverage&& uptimeSeconds == other.uptimeSeconds&& bootTime == other.bootTime&& disks == other.disks&& networks == other.networks;
+        
+            }
+            
\ No newline at end of file
@@ -6,19 +6,27 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Takes a full screenshot of the primary monitor and returns it as a base64 encoded string.
+
+            // These functions are ignored because they are not marked as `pub`: `capture_primary_screen`, `is_blank_capture`
+
+
+            /// Takes a full screenshot of the primary monitor and returns it as a base64 encoded string.
 ///
 /// # Returns
 ///
 /// A `Result` containing the base64 encoded screenshot on success.
-///
+/// 
 /// # Cross-platform Compatibility
-///
+/// 
 /// - Windows: Works natively with multiple enterprise-grade fallback methods
 /// - macOS: Works natively (requires permissions)
 /// - Linux X11: Works natively
 /// - Linux Wayland: Uses XWayland if available, or attempts fallback methods
 ///
+/// Backends that "succeed" with an all-black frame (locked session, missing Wayland
+/// permission, DRM video overlays) are skipped in favour of the next backend. A blank
+/// frame is only returned when no backend produced real content.
+///
 /// # Windows Screenshot Methods (Professional Ordering - NirCmd Priority)
 ///
 /// **Windows Primary Method:**
@@ -50,126 +58,82 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 /// - No side effects (temporary files cleaned up immediately)
 /// - Enterprise-grade reliability
 /// - Cross-Windows version compatibility (7, 8, 10, 11)
-Future<String> takeFullScreenshot() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTakeFullScreenshot();
+Future<String>  takeFullScreenshot() => RustLib.instance.api.crateApiTakeFullScreenshotTakeFullScreenshot();
 
-Future<String> takeScreenshotWithScreenshotsCrate() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrate();
+Future<String>  takeScreenshotWithScreenshotsCrate() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrate();
 
-Future<void> checkLinuxEnvironment() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotCheckLinuxEnvironment();
+Future<void>  checkLinuxEnvironment() => RustLib.instance.api.crateApiTakeFullScreenshotCheckLinuxEnvironment();
 
-Future<String> takeScreenshotLinuxFallback() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotLinuxFallback();
+Future<String>  takeScreenshotLinuxFallback() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotLinuxFallback();
 
 /// Test the primary screenshots crate method (cross-platform)
 /// This is the fastest and most reliable method for all platforms
-Future<String> testScreenshotsCrateMethod() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestScreenshotsCrateMethod();
+Future<String>  testScreenshotsCrateMethod() => RustLib.instance.api.crateApiTakeFullScreenshotTestScreenshotsCrateMethod();
 
 /// Test Linux-specific fallback methods
-Future<String> testLinuxFallbackMethods() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestLinuxFallbackMethods();
+Future<String>  testLinuxFallbackMethods() => RustLib.instance.api.crateApiTakeFullScreenshotTestLinuxFallbackMethods();
 
 /// Test Linux environment checks
 /// - Wayland/X11 detection
 /// - XWayland availability
 /// - Display server compatibility
-Future<void> testLinuxEnvironmentCheck() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestLinuxEnvironmentCheck();
+Future<void>  testLinuxEnvironmentCheck() => RustLib.instance.api.crateApiTakeFullScreenshotTestLinuxEnvironmentCheck();
 
 /// Run all available screenshot methods for current platform
 /// Returns a comprehensive test report
-Future<List<String>> testAllAvailableMethods() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestAllAvailableMethods();
+Future<List<String>>  testAllAvailableMethods() => RustLib.instance.api.crateApiTakeFullScreenshotTestAllAvailableMethods();
+
+Future<void>  checkWindowsEnvironment() => RustLib.instance.api.crateApiTakeFullScreenshotCheckWindowsEnvironment();
 
-Future<void> checkWindowsEnvironment() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotCheckWindowsEnvironment();
+Future<String>  extractBundledNircmd() => RustLib.instance.api.crateApiTakeFullScreenshotExtractBundledNircmd();
 
-Future<String> extractBundledNircmd() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotExtractBundledNircmd();
+Future<bool>  isNircmdAvailable() => RustLib.instance.api.crateApiTakeFullScreenshotIsNircmdAvailable();
 
-Future<bool> isNircmdAvailable() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotIsNircmdAvailable();
+Future<String>  takeScreenshotWindowsCsharp() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsCsharp();
 
-Future<String> takeScreenshotWindowsCsharp() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWindowsCsharp();
+Future<String>  takeScreenshotWindowsDirectshow() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsDirectshow();
 
-Future<String> takeScreenshotWindowsDirectshow() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWindowsDirectshow();
+Future<String>  takeScreenshotWindowsFfmpeg() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsFfmpeg();
 
-Future<String> takeScreenshotWindowsFfmpeg() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWindowsFfmpeg();
+Future<String>  takeScreenshotWindowsMemory() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsMemory();
 
-Future<String> takeScreenshotWindowsMemory() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWindowsMemory();
+Future<String>  takeScreenshotWindowsNircmd() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsNircmd();
 
-Future<String> takeScreenshotWindowsNircmd() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWindowsNircmd();
+Future<String>  takeScreenshotWindowsPowershell() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsPowershell();
 
-Future<String> takeScreenshotWindowsPowershell() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWindowsPowershell();
+Future<String>  takeScreenshotWindowsVbscript() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsVbscript();
 
-Future<String> takeScreenshotWindowsVbscript() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTakeScreenshotWindowsVbscript();
+Future<String>  takeScreenshotWindowsWin32() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsWin32();
 
-Future<String> takeScreenshotWindowsWin32() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsWin32();
+Future<String>  takeScreenshotWindowsWmi() => RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsWmi();
 
-Future<String> takeScreenshotWindowsWmi() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTakeScreenshotWindowsWmi();
+Future<String>  testBundledNircmdExtraction() => RustLib.instance.api.crateApiTakeFullScreenshotTestBundledNircmdExtraction();
 
-Future<String> testBundledNircmdExtraction() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTestBundledNircmdExtraction();
+Future<bool>  testNircmdAvailability() => RustLib.instance.api.crateApiTakeFullScreenshotTestNircmdAvailability();
 
-Future<bool> testNircmdAvailability() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestNircmdAvailability();
+Future<String>  testNircmdCapabilities() => RustLib.instance.api.crateApiTakeFullScreenshotTestNircmdCapabilities();
 
-Future<String> testNircmdCapabilities() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestNircmdCapabilities();
+Future<String>  testNircmdScreenshotSimple() => RustLib.instance.api.crateApiTakeFullScreenshotTestNircmdScreenshotSimple();
 
-Future<String> testNircmdScreenshotSimple() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestNircmdScreenshotSimple();
+Future<void>  testWindowsEnvironmentCheck() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsEnvironmentCheck();
 
-Future<void> testWindowsEnvironmentCheck() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTestWindowsEnvironmentCheck();
+Future<String>  testWindowsMethod1Nircmd() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod1Nircmd();
 
-Future<String> testWindowsMethod1Nircmd() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod1Nircmd();
+Future<String>  testWindowsMethod2Powershell() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod2Powershell();
 
-Future<String> testWindowsMethod2Powershell() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTestWindowsMethod2Powershell();
+Future<String>  testWindowsMethod3Memory() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod3Memory();
 
-Future<String> testWindowsMethod3Memory() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod3Memory();
+Future<String>  testWindowsMethod4Directshow() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod4Directshow();
 
-Future<String> testWindowsMethod4Directshow() =>
-    RustLib.instance.api
-        .crateApiTakeFullScreenshotTestWindowsMethod4Directshow();
+Future<String>  testWindowsMethod5Win32() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod5Win32();
 
-Future<String> testWindowsMethod5Win32() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod5Win32();
+Future<String>  testWindowsMethod6Wmi() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod6Wmi();
 
-Future<String> testWindowsMethod6Wmi() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod6Wmi();
+Future<String>  testWindowsMethod7Ffmpeg() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod7Ffmpeg();
 
-Future<String> testWindowsMethod7Ffmpeg() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod7Ffmpeg();
+Future<String>  testWindowsMethod8Csharp() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod8Csharp();
 
-Future<String> testWindowsMethod8Csharp() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod8Csharp();
+Future<String>  testWindowsMethod9Vbscript() => RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod9Vbscript();
 
-Future<String> testWindowsMethod9Vbscript() =>
-    RustLib.instance.api.crateApiTakeFullScreenshotTestWindowsMethod9Vbscript();
+            
+            
\ No newline at end of file
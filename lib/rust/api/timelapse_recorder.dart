@@ -0,0 +1,114 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `new`, `patch_u32`, `run_recorder`, `set_recording_state`, `write_header`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CountingWriter`, `GifTimelapseWriter`, `MjpegAviWriter`, `NEXT_RECORDER_ID`, `RecorderHandle`, `RecorderShared`, `TIMELAPSE_RECORDERS`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `deref`, `deref`, `eq`, `eq`, `flush`, `fmt`, `fmt`, `fmt`, `fmt`, `initialize`, `initialize`, `write`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `bytes_written`, `finish`, `write_frame`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `bytes_written`, `bytes_written`, `finish`, `finish`, `write_frame`, `write_frame`
+
+
+            /// Starts recording a low frame rate timelapse of the primary screen.
+///
+/// Frames are captured through the regular screenshot path, downsized and appended
+/// to the output file. Returns an id for the pause / resume / stop functions.
+Future<BigInt>  startTimelapseRecording({required TimelapseConfig config }) => RustLib.instance.api.crateApiTimelapseRecorderStartTimelapseRecording(config: config);
+
+bool  pauseTimelapseRecording({required BigInt recorderId }) => RustLib.instance.api.crateApiTimelapseRecorderPauseTimelapseRecording(recorderId: recorderId);
+
+bool  resumeTimelapseRecording({required BigInt recorderId }) => RustLib.instance.api.crateApiTimelapseRecorderResumeTimelapseRecording(recorderId: recorderId);
+
+/// Stops the recorder, finalises the output file and returns the final status.
+Future<TimelapseStatus>  stopTimelapseRecording({required BigInt recorderId }) => RustLib.instance.api.crateApiTimelapseRecorderStopTimelapseRecording(recorderId: recorderId);
+
+TimelapseStatus?  getTimelapseStatus({required BigInt recorderId }) => RustLib.instance.api.crateApiTimelapseRecorderGetTimelapseStatus(recorderId: recorderId);
+
+            class TimelapseConfig  {
+                /// Output file, overwritten if it exists
+final String outputPath;
+final TimelapseFormat format;
+/// Seconds between two sampled frames (e.g. 5-30)
+final BigInt frameIntervalSeconds;
+/// Frames are downsized to at most this width
+final int maxWidth;
+/// Playback speed of the resulting file in frames per second
+final int playbackFps;
+/// JPEG quality for MJPEG frames (ignored for GIF)
+final int jpegQuality;
+/// Recording stops once the output file would exceed this size
+final BigInt maxFileSizeBytes;
+
+                const TimelapseConfig({required this.outputPath ,required this.format ,required this.frameIntervalSeconds ,required this.maxWidth ,required this.playbackFps ,required this.jpegQuality ,required this.maxFileSizeBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => outputPath.hashCode^format.hashCode^frameIntervalSeconds.hashCode^maxWidth.hashCode^playbackFps.hashCode^jpegQuality.hashCode^maxFileSizeBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TimelapseConfig &&
+                runtimeType == other.runtimeType
+                && outputPath == other.outputPath&& format == other.format&& frameIntervalSeconds == other.frameIntervalSeconds&& maxWidth == other.maxWidth&& playbackFps == other.playbackFps&& jpegQuality == other.jpegQuality&& maxFileSizeBytes == other.maxFileSizeBytes;
+        
+            }
+
+/// Container format of the timelapse file
+enum TimelapseFormat {
+                    /// Animated GIF, plays everywhere but is larger
+gif,
+/// Motion JPEG in an AVI container, much smaller for long sessions
+mjpegAvi,
+                    ;
+                    
+                }
+
+enum TimelapseState {
+                    recording,
+paused,
+stopped,
+/// Stopped automatically because `max_file_size_bytes` was reached
+sizeLimitReached,
+failed,
+                    ;
+                    
+                }
+
+class TimelapseStatus  {
+                final TimelapseState state;
+final String outputPath;
+final int framesWritten;
+final BigInt bytesWritten;
+final String? lastError;
+
+                const TimelapseStatus({required this.state ,required this.outputPath ,required this.framesWritten ,required this.bytesWritten ,this.lastError ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => state.hashCode^outputPath.hashCode^framesWritten.hashCode^bytesWritten.hashCode^lastError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TimelapseStatus &&
+                runtimeType == other.runtimeType
+                && state == other.state&& outputPath == other.outputPath&& framesWritten == other.framesWritten&& bytesWritten == other.bytesWritten&& lastError == other.lastError;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'frame_analysis.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'screenshot_capture.dart';
+import 'screenshot_watermark.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `capture_window_image`
+
+
+            /// Captures the contents of a single window, even when other windows cover it.
+///
+/// `window_id` is the id returned by `get_open_windows_info` / `get_active_window_info`.
+/// On X11 the window is redirected through the Composite extension and its offscreen
+/// pixmap is read, so overlapping windows do not show up in the result. The cursor
+/// option is ignored because the cursor is never part of a window pixmap.
+Future<Screenshot>  captureWindowContents({required int windowId , required ScreenshotOptions options }) => RustLib.instance.api.crateApiWindowCaptureCaptureWindowContents(windowId: windowId, options: options);
+
+            
+            
\ No newline at end of file
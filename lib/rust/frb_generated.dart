@@ -4,2587 +4,6359 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/active_window_listener.dart';
+import 'api/app_policy.dart';
+import 'api/capture_preview.dart';
+import 'api/click_heatmap.dart';
+import 'api/cmdline_redaction.dart';
+import 'api/delta_encoding.dart';
 import 'api/desktop_notification.dart';
+import 'api/display_geometry.dart';
+import 'api/frame_analysis.dart';
 import 'api/get_all_process_list.dart';
 import 'api/keyboard_listener.dart';
 import 'api/mouse_listener.dart';
+import 'api/notification_actions.dart';
+import 'api/persistent_notification.dart';
+import 'api/process_sampler.dart';
+import 'api/process_termination.dart';
+import 'api/process_watcher.dart';
+import 'api/resource_budget.dart';
+import 'api/screenshot_capture.dart';
+import 'api/screenshot_scheduler.dart';
+import 'api/screenshot_spool.dart';
+import 'api/screenshot_watermark.dart';
 import 'api/simple.dart';
+import 'api/system_monitor.dart';
 import 'api/take_full_screenshot.dart';
+import 'api/timelapse_recorder.dart';
+import 'api/window_capture.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
+                  RustLib._();
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-    await api.crateApiTakeFullScreenshotInitApp();
-  }
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+await api.crateApiTakeFullScreenshotInitApp();
 
-  @override
-  String get codegenVersion => '2.11.1';
+                  }
 
-  @override
-  int get rustContentHash => 1874832048;
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_pi_task_watch',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-abstract class RustLibApi extends BaseApi {
-  Future<bool> crateApiGetAllProcessListCanAccessProcesses();
+                  @override
+                  int get rustContentHash => -396847468;
 
-  Future<void> crateApiTakeFullScreenshotCheckLinuxEnvironment();
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_pi_task_watch',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<void> crateApiTakeFullScreenshotCheckWindowsEnvironment();
+                abstract class RustLibApi extends BaseApi {
+                  Future<bool> crateApiClickHeatmapAddHeatmapClick({required BigInt heatmapId , required MouseEvent event });
 
-  Future<String> crateApiTakeFullScreenshotExtractBundledNircmd();
+FrameAnalysis crateApiFrameAnalysisAnalyzeScreenshot({required String base64Image });
 
-  Future<List<ProcessInfo>> crateApiGetAllProcessListFindProcessByName({
-    required String name,
-  });
+Future<bool> crateApiGetAllProcessListCanAccessProcesses();
 
-  WindowDetails crateApiActiveWindowListenerGetActiveWindowInfo();
+Future<Screenshot> crateApiScreenshotCaptureCaptureActiveWindowMonitor({required ScreenshotOptions options });
 
-  Future<List<ProcessInfo>> crateApiGetAllProcessListGetAllProcesses();
+Future<EncodedFrame> crateApiDeltaEncodingCaptureDeltaFrame({required BigInt encoderId });
 
-  String crateApiActiveWindowListenerGetCurrentPlatform();
+Future<Screenshot> crateApiScreenshotCaptureCaptureMonitor({required int monitorId , required ScreenshotOptions options });
 
-  List<WindowDetails> crateApiActiveWindowListenerGetOpenWindowsInfo();
+Future<Screenshot> crateApiScreenshotCaptureCaptureScreenshot({required ScreenshotOptions options });
 
-  Future<String> crateApiGetAllProcessListGetProcessAccessInfo();
+Future<SpooledScreenshot> crateApiScreenshotSpoolCaptureToFile({required String dir , required ScreenshotOptions options });
 
-  String crateApiActiveWindowListenerGetWindowIconData({required int windowId});
+Future<Screenshot> crateApiWindowCaptureCaptureWindowContents({required int windowId , required ScreenshotOptions options });
 
-  String crateApiSimpleGreet({required String name});
+Future<CategoryGateStats> crateApiDesktopNotificationCategoryGateStatsDefault();
 
-  Future<void> crateApiSimpleInitApp();
+Future<void> crateApiTakeFullScreenshotCheckLinuxEnvironment();
 
-  Future<void> crateApiTakeFullScreenshotInitApp();
+Future<void> crateApiTakeFullScreenshotCheckWindowsEnvironment();
 
-  Future<bool> crateApiTakeFullScreenshotIsNircmdAvailable();
+Future<void> crateApiPersistentNotificationCloseNotification({required BigInt handleId });
 
-  bool crateApiActiveWindowListenerIsPlatformSupported();
+void crateApiDesktopNotificationConfigureNotificationGate({required NotificationGateConfig config });
 
-  Future<bool> crateApiGetAllProcessListKillProcess({required int pid});
+BigInt crateApiClickHeatmapCreateClickHeatmap({required HeatmapConfig config });
 
-  Future<void> crateApiDesktopNotificationRegisterModule();
+BigInt crateApiDeltaEncodingCreateDeltaDecoder();
 
-  Future<void> crateApiDesktopNotificationSendNotification({
-    required String title,
-    required String message,
-    String? iconPath,
-  });
+BigInt crateApiDeltaEncodingCreateDeltaEncoder({required DeltaEncoderConfig config });
 
-  Future<void> crateApiDesktopNotificationSendNotificationWithOptions({
-    required String title,
-    required String message,
-    String? iconPath,
-    BigInt? timeoutSeconds,
-    int? urgencyLevel,
-  });
+Future<String> crateApiDeltaEncodingDecodeDeltaFrame({required BigInt decoderId , required List<int> data });
 
-  Stream<KeyboardEvent> crateApiKeyboardListenerStartKeyboardListener();
+Future<bool> crateApiScreenshotSpoolDeleteSpooledScreenshot({required String path });
 
-  Stream<MouseEvent> crateApiMouseListenerStartMouseListener();
+Future<DeltaEncoderConfig> crateApiDeltaEncodingDeltaEncoderConfigDefault();
 
-  Stream<WindowDetails> crateApiActiveWindowListenerStartWindowListener();
+bool crateApiClickHeatmapDestroyClickHeatmap({required BigInt heatmapId });
 
-  Stream<WindowDetails> crateApiActiveWindowListenerStartWindowListenerStream();
+bool crateApiDeltaEncodingDestroyDeltaDecoder({required BigInt decoderId });
 
-  bool crateApiActiveWindowListenerStopWindowListener({
-    required BigInt listenerId,
-  });
+bool crateApiDeltaEncodingDestroyDeltaEncoder({required BigInt encoderId });
 
-  Future<String> crateApiTakeFullScreenshotTakeFullScreenshot();
+Future<EncodedFrame> crateApiDeltaEncodingEncodeDeltaFrame({required BigInt encoderId , required String base64Image });
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotLinuxFallback();
+Future<SpoolQuotaResult> crateApiScreenshotSpoolEnforceSpoolQuota({required String dir , required BigInt maxBytes });
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsCsharp();
+HeatmapGrid crateApiClickHeatmapExportClickHeatmapGrid({required BigInt heatmapId });
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsDirectshow();
+Future<Uint8List> crateApiClickHeatmapExportClickHeatmapPng({required BigInt heatmapId , required int width , required int height , String? backgroundBase64 , required double opacity });
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsFfmpeg();
+Future<String> crateApiTakeFullScreenshotExtractBundledNircmd();
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsMemory();
+Future<List<ProcessInfo>> crateApiGetAllProcessListFindProcessByName({required String name });
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsNircmd();
+bool crateApiDeltaEncodingForceDeltaKeyframe({required BigInt encoderId });
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsPowershell();
+Future<bool> crateApiFrameAnalysisFrameAnalysisIsBlank({required FrameAnalysis that });
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsVbscript();
+WindowDetails crateApiActiveWindowListenerGetActiveWindowInfo();
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWin32();
+Future<List<ProcessInfo>> crateApiGetAllProcessListGetAllProcesses();
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWmi();
+List<String> crateApiCmdlineRedactionGetCmdlineRedactionPatterns();
 
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrate();
+String crateApiActiveWindowListenerGetCurrentPlatform();
 
-  Future<List<String>> crateApiTakeFullScreenshotTestAllAvailableMethods();
+List<DisplayGeometry> crateApiDisplayGeometryGetDisplayGeometry();
 
-  Future<String> crateApiTakeFullScreenshotTestBundledNircmdExtraction();
+NotificationBackendPreference crateApiDesktopNotificationGetNotificationBackendPreference();
 
-  Future<void> crateApiTakeFullScreenshotTestLinuxEnvironmentCheck();
+NotificationGateStats crateApiDesktopNotificationGetNotificationGateStats();
 
-  Future<String> crateApiTakeFullScreenshotTestLinuxFallbackMethods();
+List<WindowDetails> crateApiActiveWindowListenerGetOpenWindowsInfo();
 
-  Future<bool> crateApiTakeFullScreenshotTestNircmdAvailability();
+Future<String> crateApiGetAllProcessListGetProcessAccessInfo();
 
-  Future<String> crateApiTakeFullScreenshotTestNircmdCapabilities();
+ProcessHistory? crateApiProcessSamplerGetProcessHistory({required int pid });
 
-  Future<String> crateApiTakeFullScreenshotTestNircmdScreenshotSimple();
+Future<ProcessTreeNode?> crateApiGetAllProcessListGetProcessTree({required int rootPid });
 
-  Future<String> crateApiTakeFullScreenshotTestScreenshotsCrateMethod();
+ResourceBudgetStatus crateApiResourceBudgetGetResourceBudgetStatus();
 
-  Future<void> crateApiTakeFullScreenshotTestWindowsEnvironmentCheck();
+Future<SystemSnapshot> crateApiSystemMonitorGetSystemSnapshot();
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod1Nircmd();
+TimelapseStatus? crateApiTimelapseRecorderGetTimelapseStatus({required BigInt recorderId });
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod2Powershell();
+String crateApiActiveWindowListenerGetWindowIconData({required int windowId });
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod3Memory();
+String crateApiSimpleGreet({required String name });
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod4Directshow();
+Future<void> crateApiSimpleInitApp();
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod5Win32();
+Future<void> crateApiTakeFullScreenshotInitApp();
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod6Wmi();
+Future<bool> crateApiTakeFullScreenshotIsNircmdAvailable();
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod7Ffmpeg();
+Future<bool> crateApiResourceBudgetIsOnBatteryPower();
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod8Csharp();
+bool crateApiActiveWindowListenerIsPlatformSupported();
 
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod9Vbscript();
-}
+bool crateApiProcessSamplerIsProcessSamplerRunning();
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+Future<bool> crateApiGetAllProcessListKillProcess({required int pid });
 
-  @override
-  Future<bool> crateApiGetAllProcessListCanAccessProcesses() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+Future<List<SpoolEntry>> crateApiScreenshotSpoolListSpooledScreenshots({required String dir });
+
+(double,double)? crateApiDisplayGeometryLogicalToPhysical({required double x , required double y });
+
+CoordinateSpace crateApiDisplayGeometryNativeCoordinateSpace();
+
+Future<NotificationBackendCapabilities> crateApiDesktopNotificationNotificationBackendCapabilitiesDefault();
+
+Future<NotificationBackendPreference> crateApiDesktopNotificationNotificationBackendPreferenceDefault();
+
+Future<NotificationGateConfig> crateApiDesktopNotificationNotificationGateConfigDefault();
+
+Future<NotificationGateStats> crateApiDesktopNotificationNotificationGateStatsDefault();
+
+bool crateApiScreenshotSchedulerPauseScreenshotScheduler({required BigInt schedulerId });
+
+bool crateApiTimelapseRecorderPauseTimelapseRecording({required BigInt recorderId });
+
+(double,double)? crateApiDisplayGeometryPhysicalToLogical({required double x , required double y });
+
+Future<List<NotificationBackendProbe>> crateApiDesktopNotificationProbeNotificationBackends();
+
+Future<ProcessFilter> crateApiProcessWatcherProcessFilterDefault();
+
+Future<ProcessSamplerConfig> crateApiProcessSamplerProcessSamplerConfigDefault();
+
+Future<void> crateApiDesktopNotificationRegisterModule();
+
+bool crateApiClickHeatmapResetClickHeatmap({required BigInt heatmapId });
+
+void crateApiDesktopNotificationResetNotificationGateStats();
+
+Future<ResourceBudgetConfig> crateApiResourceBudgetResourceBudgetConfigDefault();
+
+bool crateApiScreenshotSchedulerResumeScreenshotScheduler({required BigInt schedulerId });
+
+bool crateApiTimelapseRecorderResumeTimelapseRecording({required BigInt recorderId });
+
+Future<ScreenshotOptions> crateApiScreenshotCaptureScreenshotOptionsDefault();
+
+Future<GatedSendResult> crateApiDesktopNotificationSendGatedNotification({required String category , required String title , required String message , String? iconPath , int? urgencyLevel });
+
+Stream<NotificationEvent> crateApiNotificationActionsSendInteractiveNotification({required String title , required String body , required List<NotificationAction> actions });
+
+Future<NotificationBackend> crateApiDesktopNotificationSendNotification({required String title , required String message , String? iconPath });
+
+Future<NotificationBackend> crateApiDesktopNotificationSendNotificationWithOptions({required String title , required String message , String? iconPath , BigInt? timeoutSeconds , int? urgencyLevel });
+
+void crateApiCmdlineRedactionSetCmdlineRedactionPatterns({required List<String> patterns });
+
+void crateApiDesktopNotificationSetDoNotDisturb({required bool enabled });
+
+void crateApiDesktopNotificationSetNotificationBackendPreference({required NotificationBackendPreference preference });
+
+Future<BigInt> crateApiPersistentNotificationShowPersistentNotification({required String title , required String body , String? iconPath });
+
+Stream<PolicyViolation> crateApiAppPolicyStartAppPolicy({required PolicyConfig config });
+
+Stream<PreviewFrame> crateApiCapturePreviewStartCapturePreview({required double fps , required int maxWidth });
+
+Stream<KeyboardEvent> crateApiKeyboardListenerStartKeyboardListener();
+
+Stream<MouseEvent> crateApiMouseListenerStartMouseListener();
+
+bool crateApiProcessSamplerStartProcessSampler({required ProcessSamplerConfig config });
+
+Stream<ProcessEvent> crateApiProcessWatcherStartProcessWatcher({required ProcessFilter filter });
+
+bool crateApiResourceBudgetStartResourceBudget({required ResourceBudgetConfig config });
+
+Stream<Screenshot> crateApiScreenshotSchedulerStartScreenshotScheduler({required ScreenshotScheduleConfig config });
+
+Stream<SystemSnapshot> crateApiSystemMonitorStartSystemMonitor({required BigInt intervalMs });
+
+Future<BigInt> crateApiTimelapseRecorderStartTimelapseRecording({required TimelapseConfig config });
+
+Stream<WindowDetails> crateApiActiveWindowListenerStartWindowListener();
+
+Stream<WindowDetails> crateApiActiveWindowListenerStartWindowListenerStream();
+
+bool crateApiAppPolicyStopAppPolicy({required BigInt engineId });
+
+bool crateApiCapturePreviewStopCapturePreview({required BigInt previewId });
+
+bool crateApiProcessSamplerStopProcessSampler();
+
+bool crateApiProcessWatcherStopProcessWatcher({required BigInt watcherId });
+
+bool crateApiResourceBudgetStopResourceBudget();
+
+bool crateApiScreenshotSchedulerStopScreenshotScheduler({required BigInt schedulerId });
+
+bool crateApiSystemMonitorStopSystemMonitor({required BigInt monitorId });
+
+Future<TimelapseStatus> crateApiTimelapseRecorderStopTimelapseRecording({required BigInt recorderId });
+
+bool crateApiActiveWindowListenerStopWindowListener({required BigInt listenerId });
+
+Future<String> crateApiTakeFullScreenshotTakeFullScreenshot();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotLinuxFallback();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsCsharp();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsDirectshow();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsFfmpeg();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsMemory();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsNircmd();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsPowershell();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsVbscript();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWin32();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWmi();
+
+Future<String> crateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrate();
+
+Future<TerminateOptions> crateApiProcessTerminationTerminateOptionsDefault();
+
+Future<TerminateOutcome> crateApiProcessTerminationTerminateProcess({required int pid , required TerminateOptions options });
+
+Future<List<String>> crateApiTakeFullScreenshotTestAllAvailableMethods();
+
+Future<String> crateApiTakeFullScreenshotTestBundledNircmdExtraction();
+
+Future<void> crateApiTakeFullScreenshotTestLinuxEnvironmentCheck();
+
+Future<String> crateApiTakeFullScreenshotTestLinuxFallbackMethods();
+
+Future<bool> crateApiTakeFullScreenshotTestNircmdAvailability();
+
+Future<String> crateApiTakeFullScreenshotTestNircmdCapabilities();
+
+Future<String> crateApiTakeFullScreenshotTestNircmdScreenshotSimple();
+
+Future<String> crateApiTakeFullScreenshotTestScreenshotsCrateMethod();
+
+Future<void> crateApiTakeFullScreenshotTestWindowsEnvironmentCheck();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod1Nircmd();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod2Powershell();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod3Memory();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod4Directshow();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod5Win32();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod6Wmi();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod7Ffmpeg();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod8Csharp();
+
+Future<String> crateApiTakeFullScreenshotTestWindowsMethod9Vbscript();
+
+List<ProcessUsageSummary> crateApiProcessSamplerTopProcesses({required ProcessSortKey by , required int n , required BigInt windowSeconds });
+
+Future<void> crateApiPersistentNotificationUpdateNotification({required BigInt handleId , required String title , required String body });
+
+Future<WatermarkOptions> crateApiScreenshotWatermarkWatermarkOptionsDefault();
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<bool> crateApiClickHeatmapAddHeatmapClick({required BigInt heatmapId , required MouseEvent event })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(heatmapId, serializer);
+sse_encode_box_autoadd_mouse_event(event, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiClickHeatmapAddHeatmapClickConstMeta,
+            argValues: [heatmapId, event],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClickHeatmapAddHeatmapClickConstMeta => const TaskConstMeta(
+            debugName: "add_heatmap_click",
+            argNames: ["heatmapId", "event"],
+        );
+        
+
+@override FrameAnalysis crateApiFrameAnalysisAnalyzeScreenshot({required String base64Image })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(base64Image, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_frame_analysis,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiFrameAnalysisAnalyzeScreenshotConstMeta,
+            argValues: [base64Image],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFrameAnalysisAnalyzeScreenshotConstMeta => const TaskConstMeta(
+            debugName: "analyze_screenshot",
+            argNames: ["base64Image"],
+        );
+        
+
+@override Future<bool> crateApiGetAllProcessListCanAccessProcesses()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetAllProcessListCanAccessProcessesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllProcessListCanAccessProcessesConstMeta =>
-      const TaskConstMeta(debugName: "can_access_processes", argNames: []);
-
-  @override
-  Future<void> crateApiTakeFullScreenshotCheckLinuxEnvironment() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAllProcessListCanAccessProcessesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllProcessListCanAccessProcessesConstMeta => const TaskConstMeta(
+            debugName: "can_access_processes",
+            argNames: [],
+        );
+        
+
+@override Future<Screenshot> crateApiScreenshotCaptureCaptureActiveWindowMonitor({required ScreenshotOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_screenshot_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_screenshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScreenshotCaptureCaptureActiveWindowMonitorConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotCaptureCaptureActiveWindowMonitorConstMeta => const TaskConstMeta(
+            debugName: "capture_active_window_monitor",
+            argNames: ["options"],
+        );
+        
+
+@override Future<EncodedFrame> crateApiDeltaEncodingCaptureDeltaFrame({required BigInt encoderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(encoderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_encoded_frame,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingCaptureDeltaFrameConstMeta,
+            argValues: [encoderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingCaptureDeltaFrameConstMeta => const TaskConstMeta(
+            debugName: "capture_delta_frame",
+            argNames: ["encoderId"],
+        );
+        
+
+@override Future<Screenshot> crateApiScreenshotCaptureCaptureMonitor({required int monitorId , required ScreenshotOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(monitorId, serializer);
+sse_encode_box_autoadd_screenshot_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_screenshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScreenshotCaptureCaptureMonitorConstMeta,
+            argValues: [monitorId, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotCaptureCaptureMonitorConstMeta => const TaskConstMeta(
+            debugName: "capture_monitor",
+            argNames: ["monitorId", "options"],
+        );
+        
+
+@override Future<Screenshot> crateApiScreenshotCaptureCaptureScreenshot({required ScreenshotOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_screenshot_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_screenshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScreenshotCaptureCaptureScreenshotConstMeta,
+            argValues: [options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotCaptureCaptureScreenshotConstMeta => const TaskConstMeta(
+            debugName: "capture_screenshot",
+            argNames: ["options"],
+        );
+        
+
+@override Future<SpooledScreenshot> crateApiScreenshotSpoolCaptureToFile({required String dir , required ScreenshotOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dir, serializer);
+sse_encode_box_autoadd_screenshot_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_spooled_screenshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSpoolCaptureToFileConstMeta,
+            argValues: [dir, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotSpoolCaptureToFileConstMeta => const TaskConstMeta(
+            debugName: "capture_to_file",
+            argNames: ["dir", "options"],
+        );
+        
+
+@override Future<Screenshot> crateApiWindowCaptureCaptureWindowContents({required int windowId , required ScreenshotOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(windowId, serializer);
+sse_encode_box_autoadd_screenshot_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_screenshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiWindowCaptureCaptureWindowContentsConstMeta,
+            argValues: [windowId, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWindowCaptureCaptureWindowContentsConstMeta => const TaskConstMeta(
+            debugName: "capture_window_contents",
+            argNames: ["windowId", "options"],
+        );
+        
+
+@override Future<CategoryGateStats> crateApiDesktopNotificationCategoryGateStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_category_gate_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationCategoryGateStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationCategoryGateStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "category_gate_stats_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTakeFullScreenshotCheckLinuxEnvironment()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotCheckLinuxEnvironmentConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotCheckLinuxEnvironmentConstMeta => const TaskConstMeta(
+            debugName: "check_linux_environment",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTakeFullScreenshotCheckWindowsEnvironment()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotCheckLinuxEnvironmentConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTakeFullScreenshotCheckLinuxEnvironmentConstMeta =>
-      const TaskConstMeta(debugName: "check_linux_environment", argNames: []);
-
-  @override
-  Future<void> crateApiTakeFullScreenshotCheckWindowsEnvironment() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotCheckWindowsEnvironmentConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotCheckWindowsEnvironmentConstMeta => const TaskConstMeta(
+            debugName: "check_windows_environment",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiPersistentNotificationCloseNotification({required BigInt handleId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handleId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotCheckWindowsEnvironmentConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotCheckWindowsEnvironmentConstMeta =>
-      const TaskConstMeta(debugName: "check_windows_environment", argNames: []);
-
-  @override
-  Future<String> crateApiTakeFullScreenshotExtractBundledNircmd() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPersistentNotificationCloseNotificationConstMeta,
+            argValues: [handleId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPersistentNotificationCloseNotificationConstMeta => const TaskConstMeta(
+            debugName: "close_notification",
+            argNames: ["handleId"],
+        );
+        
+
+@override void crateApiDesktopNotificationConfigureNotificationGate({required NotificationGateConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_notification_gate_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationConfigureNotificationGateConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationConfigureNotificationGateConstMeta => const TaskConstMeta(
+            debugName: "configure_notification_gate",
+            argNames: ["config"],
+        );
+        
+
+@override BigInt crateApiClickHeatmapCreateClickHeatmap({required HeatmapConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_heatmap_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiClickHeatmapCreateClickHeatmapConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClickHeatmapCreateClickHeatmapConstMeta => const TaskConstMeta(
+            debugName: "create_click_heatmap",
+            argNames: ["config"],
+        );
+        
+
+@override BigInt crateApiDeltaEncodingCreateDeltaDecoder()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingCreateDeltaDecoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingCreateDeltaDecoderConstMeta => const TaskConstMeta(
+            debugName: "create_delta_decoder",
+            argNames: [],
+        );
+        
+
+@override BigInt crateApiDeltaEncodingCreateDeltaEncoder({required DeltaEncoderConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_delta_encoder_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingCreateDeltaEncoderConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingCreateDeltaEncoderConstMeta => const TaskConstMeta(
+            debugName: "create_delta_encoder",
+            argNames: ["config"],
+        );
+        
+
+@override Future<String> crateApiDeltaEncodingDecodeDeltaFrame({required BigInt decoderId , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(decoderId, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingDecodeDeltaFrameConstMeta,
+            argValues: [decoderId, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingDecodeDeltaFrameConstMeta => const TaskConstMeta(
+            debugName: "decode_delta_frame",
+            argNames: ["decoderId", "data"],
+        );
+        
+
+@override Future<bool> crateApiScreenshotSpoolDeleteSpooledScreenshot({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSpoolDeleteSpooledScreenshotConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotSpoolDeleteSpooledScreenshotConstMeta => const TaskConstMeta(
+            debugName: "delete_spooled_screenshot",
+            argNames: ["path"],
+        );
+        
+
+@override Future<DeltaEncoderConfig> crateApiDeltaEncodingDeltaEncoderConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_delta_encoder_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingDeltaEncoderConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingDeltaEncoderConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "delta_encoder_config_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiClickHeatmapDestroyClickHeatmap({required BigInt heatmapId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(heatmapId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiClickHeatmapDestroyClickHeatmapConstMeta,
+            argValues: [heatmapId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClickHeatmapDestroyClickHeatmapConstMeta => const TaskConstMeta(
+            debugName: "destroy_click_heatmap",
+            argNames: ["heatmapId"],
+        );
+        
+
+@override bool crateApiDeltaEncodingDestroyDeltaDecoder({required BigInt decoderId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(decoderId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingDestroyDeltaDecoderConstMeta,
+            argValues: [decoderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingDestroyDeltaDecoderConstMeta => const TaskConstMeta(
+            debugName: "destroy_delta_decoder",
+            argNames: ["decoderId"],
+        );
+        
+
+@override bool crateApiDeltaEncodingDestroyDeltaEncoder({required BigInt encoderId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(encoderId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingDestroyDeltaEncoderConstMeta,
+            argValues: [encoderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingDestroyDeltaEncoderConstMeta => const TaskConstMeta(
+            debugName: "destroy_delta_encoder",
+            argNames: ["encoderId"],
+        );
+        
+
+@override Future<EncodedFrame> crateApiDeltaEncodingEncodeDeltaFrame({required BigInt encoderId , required String base64Image })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(encoderId, serializer);
+sse_encode_String(base64Image, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_encoded_frame,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingEncodeDeltaFrameConstMeta,
+            argValues: [encoderId, base64Image],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingEncodeDeltaFrameConstMeta => const TaskConstMeta(
+            debugName: "encode_delta_frame",
+            argNames: ["encoderId", "base64Image"],
+        );
+        
+
+@override Future<SpoolQuotaResult> crateApiScreenshotSpoolEnforceSpoolQuota({required String dir , required BigInt maxBytes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dir, serializer);
+sse_encode_u_64(maxBytes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_spool_quota_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSpoolEnforceSpoolQuotaConstMeta,
+            argValues: [dir, maxBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotSpoolEnforceSpoolQuotaConstMeta => const TaskConstMeta(
+            debugName: "enforce_spool_quota",
+            argNames: ["dir", "maxBytes"],
+        );
+        
+
+@override HeatmapGrid crateApiClickHeatmapExportClickHeatmapGrid({required BigInt heatmapId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(heatmapId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_heatmap_grid,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiClickHeatmapExportClickHeatmapGridConstMeta,
+            argValues: [heatmapId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClickHeatmapExportClickHeatmapGridConstMeta => const TaskConstMeta(
+            debugName: "export_click_heatmap_grid",
+            argNames: ["heatmapId"],
+        );
+        
+
+@override Future<Uint8List> crateApiClickHeatmapExportClickHeatmapPng({required BigInt heatmapId , required int width , required int height , String? backgroundBase64 , required double opacity })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(heatmapId, serializer);
+sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_opt_String(backgroundBase64, serializer);
+sse_encode_f_32(opacity, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiClickHeatmapExportClickHeatmapPngConstMeta,
+            argValues: [heatmapId, width, height, backgroundBase64, opacity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClickHeatmapExportClickHeatmapPngConstMeta => const TaskConstMeta(
+            debugName: "export_click_heatmap_png",
+            argNames: ["heatmapId", "width", "height", "backgroundBase64", "opacity"],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotExtractBundledNircmd()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotExtractBundledNircmdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTakeFullScreenshotExtractBundledNircmdConstMeta =>
-      const TaskConstMeta(debugName: "extract_bundled_nircmd", argNames: []);
-
-  @override
-  Future<List<ProcessInfo>> crateApiGetAllProcessListFindProcessByName({
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotExtractBundledNircmdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotExtractBundledNircmdConstMeta => const TaskConstMeta(
+            debugName: "extract_bundled_nircmd",
+            argNames: [],
+        );
+        
+
+@override Future<List<ProcessInfo>> crateApiGetAllProcessListFindProcessByName({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_process_info,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetAllProcessListFindProcessByNameConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllProcessListFindProcessByNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "find_process_by_name",
-        argNames: ["name"],
-      );
-
-  @override
-  WindowDetails crateApiActiveWindowListenerGetActiveWindowInfo() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAllProcessListFindProcessByNameConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllProcessListFindProcessByNameConstMeta => const TaskConstMeta(
+            debugName: "find_process_by_name",
+            argNames: ["name"],
+        );
+        
+
+@override bool crateApiDeltaEncodingForceDeltaKeyframe({required BigInt encoderId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(encoderId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDeltaEncodingForceDeltaKeyframeConstMeta,
+            argValues: [encoderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeltaEncodingForceDeltaKeyframeConstMeta => const TaskConstMeta(
+            debugName: "force_delta_keyframe",
+            argNames: ["encoderId"],
+        );
+        
+
+@override Future<bool> crateApiFrameAnalysisFrameAnalysisIsBlank({required FrameAnalysis that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_frame_analysis(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiFrameAnalysisFrameAnalysisIsBlankConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFrameAnalysisFrameAnalysisIsBlankConstMeta => const TaskConstMeta(
+            debugName: "frame_analysis_is_blank",
+            argNames: ["that"],
+        );
+        
+
+@override WindowDetails crateApiActiveWindowListenerGetActiveWindowInfo()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_window_details,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiActiveWindowListenerGetActiveWindowInfoConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiActiveWindowListenerGetActiveWindowInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_active_window_info", argNames: []);
-
-  @override
-  Future<List<ProcessInfo>> crateApiGetAllProcessListGetAllProcesses() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerGetActiveWindowInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerGetActiveWindowInfoConstMeta => const TaskConstMeta(
+            debugName: "get_active_window_info",
+            argNames: [],
+        );
+        
+
+@override Future<List<ProcessInfo>> crateApiGetAllProcessListGetAllProcesses()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_process_info,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetAllProcessListGetAllProcessesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllProcessListGetAllProcessesConstMeta =>
-      const TaskConstMeta(debugName: "get_all_processes", argNames: []);
-
-  @override
-  String crateApiActiveWindowListenerGetCurrentPlatform() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAllProcessListGetAllProcessesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllProcessListGetAllProcessesConstMeta => const TaskConstMeta(
+            debugName: "get_all_processes",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiCmdlineRedactionGetCmdlineRedactionPatterns()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCmdlineRedactionGetCmdlineRedactionPatternsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCmdlineRedactionGetCmdlineRedactionPatternsConstMeta => const TaskConstMeta(
+            debugName: "get_cmdline_redaction_patterns",
+            argNames: [],
+        );
+        
+
+@override String crateApiActiveWindowListenerGetCurrentPlatform()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiActiveWindowListenerGetCurrentPlatformConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiActiveWindowListenerGetCurrentPlatformConstMeta =>
-      const TaskConstMeta(debugName: "get_current_platform", argNames: []);
-
-  @override
-  List<WindowDetails> crateApiActiveWindowListenerGetOpenWindowsInfo() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerGetCurrentPlatformConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerGetCurrentPlatformConstMeta => const TaskConstMeta(
+            debugName: "get_current_platform",
+            argNames: [],
+        );
+        
+
+@override List<DisplayGeometry> crateApiDisplayGeometryGetDisplayGeometry()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_display_geometry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDisplayGeometryGetDisplayGeometryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDisplayGeometryGetDisplayGeometryConstMeta => const TaskConstMeta(
+            debugName: "get_display_geometry",
+            argNames: [],
+        );
+        
+
+@override NotificationBackendPreference crateApiDesktopNotificationGetNotificationBackendPreference()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_backend_preference,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationGetNotificationBackendPreferenceConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationGetNotificationBackendPreferenceConstMeta => const TaskConstMeta(
+            debugName: "get_notification_backend_preference",
+            argNames: [],
+        );
+        
+
+@override NotificationGateStats crateApiDesktopNotificationGetNotificationGateStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_gate_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationGetNotificationGateStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationGetNotificationGateStatsConstMeta => const TaskConstMeta(
+            debugName: "get_notification_gate_stats",
+            argNames: [],
+        );
+        
+
+@override List<WindowDetails> crateApiActiveWindowListenerGetOpenWindowsInfo()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_window_details,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiActiveWindowListenerGetOpenWindowsInfoConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiActiveWindowListenerGetOpenWindowsInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_open_windows_info", argNames: []);
-
-  @override
-  Future<String> crateApiGetAllProcessListGetProcessAccessInfo() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerGetOpenWindowsInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerGetOpenWindowsInfoConstMeta => const TaskConstMeta(
+            debugName: "get_open_windows_info",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiGetAllProcessListGetProcessAccessInfo()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetAllProcessListGetProcessAccessInfoConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllProcessListGetProcessAccessInfoConstMeta =>
-      const TaskConstMeta(debugName: "get_process_access_info", argNames: []);
-
-  @override
-  String crateApiActiveWindowListenerGetWindowIconData({
-    required int windowId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(windowId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAllProcessListGetProcessAccessInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllProcessListGetProcessAccessInfoConstMeta => const TaskConstMeta(
+            debugName: "get_process_access_info",
+            argNames: [],
+        );
+        
+
+@override ProcessHistory? crateApiProcessSamplerGetProcessHistory({required int pid })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(pid, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_process_history,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessSamplerGetProcessHistoryConstMeta,
+            argValues: [pid],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessSamplerGetProcessHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_process_history",
+            argNames: ["pid"],
+        );
+        
+
+@override Future<ProcessTreeNode?> crateApiGetAllProcessListGetProcessTree({required int rootPid })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(rootPid, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_process_tree_node,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetAllProcessListGetProcessTreeConstMeta,
+            argValues: [rootPid],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllProcessListGetProcessTreeConstMeta => const TaskConstMeta(
+            debugName: "get_process_tree",
+            argNames: ["rootPid"],
+        );
+        
+
+@override ResourceBudgetStatus crateApiResourceBudgetGetResourceBudgetStatus()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resource_budget_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiResourceBudgetGetResourceBudgetStatusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResourceBudgetGetResourceBudgetStatusConstMeta => const TaskConstMeta(
+            debugName: "get_resource_budget_status",
+            argNames: [],
+        );
+        
+
+@override Future<SystemSnapshot> crateApiSystemMonitorGetSystemSnapshot()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_system_snapshot,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemMonitorGetSystemSnapshotConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemMonitorGetSystemSnapshotConstMeta => const TaskConstMeta(
+            debugName: "get_system_snapshot",
+            argNames: [],
+        );
+        
+
+@override TimelapseStatus? crateApiTimelapseRecorderGetTimelapseStatus({required BigInt recorderId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(recorderId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_timelapse_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTimelapseRecorderGetTimelapseStatusConstMeta,
+            argValues: [recorderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTimelapseRecorderGetTimelapseStatusConstMeta => const TaskConstMeta(
+            debugName: "get_timelapse_status",
+            argNames: ["recorderId"],
+        );
+        
+
+@override String crateApiActiveWindowListenerGetWindowIconData({required int windowId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(windowId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiActiveWindowListenerGetWindowIconDataConstMeta,
-        argValues: [windowId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiActiveWindowListenerGetWindowIconDataConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_window_icon_data",
-        argNames: ["windowId"],
-      );
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerGetWindowIconDataConstMeta,
+            argValues: [windowId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerGetWindowIconDataConstMeta => const TaskConstMeta(
+            debugName: "get_window_icon_data",
+            argNames: ["windowId"],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<void> crateApiTakeFullScreenshotInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTakeFullScreenshotInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTakeFullScreenshotInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<bool> crateApiTakeFullScreenshotIsNircmdAvailable() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiTakeFullScreenshotIsNircmdAvailable()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotIsNircmdAvailableConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTakeFullScreenshotIsNircmdAvailableConstMeta =>
-      const TaskConstMeta(debugName: "is_nircmd_available", argNames: []);
-
-  @override
-  bool crateApiActiveWindowListenerIsPlatformSupported() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotIsNircmdAvailableConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotIsNircmdAvailableConstMeta => const TaskConstMeta(
+            debugName: "is_nircmd_available",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiResourceBudgetIsOnBatteryPower()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiActiveWindowListenerIsPlatformSupportedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiActiveWindowListenerIsPlatformSupportedConstMeta =>
-      const TaskConstMeta(debugName: "is_platform_supported", argNames: []);
-
-  @override
-  Future<bool> crateApiGetAllProcessListKillProcess({required int pid}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(pid, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiResourceBudgetIsOnBatteryPowerConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResourceBudgetIsOnBatteryPowerConstMeta => const TaskConstMeta(
+            debugName: "is_on_battery_power",
+            argNames: [],
+        );
+        
+
+@override bool crateApiActiveWindowListenerIsPlatformSupported()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetAllProcessListKillProcessConstMeta,
-        argValues: [pid],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAllProcessListKillProcessConstMeta =>
-      const TaskConstMeta(debugName: "kill_process", argNames: ["pid"]);
-
-  @override
-  Future<void> crateApiDesktopNotificationRegisterModule() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 18,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerIsPlatformSupportedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerIsPlatformSupportedConstMeta => const TaskConstMeta(
+            debugName: "is_platform_supported",
+            argNames: [],
+        );
+        
+
+@override bool crateApiProcessSamplerIsProcessSamplerRunning()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessSamplerIsProcessSamplerRunningConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessSamplerIsProcessSamplerRunningConstMeta => const TaskConstMeta(
+            debugName: "is_process_sampler_running",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiGetAllProcessListKillProcess({required int pid })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(pid, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetAllProcessListKillProcessConstMeta,
+            argValues: [pid],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllProcessListKillProcessConstMeta => const TaskConstMeta(
+            debugName: "kill_process",
+            argNames: ["pid"],
+        );
+        
+
+@override Future<List<SpoolEntry>> crateApiScreenshotSpoolListSpooledScreenshots({required String dir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_spool_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSpoolListSpooledScreenshotsConstMeta,
+            argValues: [dir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotSpoolListSpooledScreenshotsConstMeta => const TaskConstMeta(
+            debugName: "list_spooled_screenshots",
+            argNames: ["dir"],
+        );
+        
+
+@override (double,double)? crateApiDisplayGeometryLogicalToPhysical({required double x , required double y })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(x, serializer);
+sse_encode_f_64(y, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_record_f_64_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDisplayGeometryLogicalToPhysicalConstMeta,
+            argValues: [x, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDisplayGeometryLogicalToPhysicalConstMeta => const TaskConstMeta(
+            debugName: "logical_to_physical",
+            argNames: ["x", "y"],
+        );
+        
+
+@override CoordinateSpace crateApiDisplayGeometryNativeCoordinateSpace()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_coordinate_space,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDisplayGeometryNativeCoordinateSpaceConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDisplayGeometryNativeCoordinateSpaceConstMeta => const TaskConstMeta(
+            debugName: "native_coordinate_space",
+            argNames: [],
+        );
+        
+
+@override Future<NotificationBackendCapabilities> crateApiDesktopNotificationNotificationBackendCapabilitiesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_backend_capabilities,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationNotificationBackendCapabilitiesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationNotificationBackendCapabilitiesDefaultConstMeta => const TaskConstMeta(
+            debugName: "notification_backend_capabilities_default",
+            argNames: [],
+        );
+        
+
+@override Future<NotificationBackendPreference> crateApiDesktopNotificationNotificationBackendPreferenceDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_backend_preference,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationNotificationBackendPreferenceDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationNotificationBackendPreferenceDefaultConstMeta => const TaskConstMeta(
+            debugName: "notification_backend_preference_default",
+            argNames: [],
+        );
+        
+
+@override Future<NotificationGateConfig> crateApiDesktopNotificationNotificationGateConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_gate_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationNotificationGateConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationNotificationGateConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "notification_gate_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<NotificationGateStats> crateApiDesktopNotificationNotificationGateStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_gate_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationNotificationGateStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationNotificationGateStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "notification_gate_stats_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiScreenshotSchedulerPauseScreenshotScheduler({required BigInt schedulerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(schedulerId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSchedulerPauseScreenshotSchedulerConstMeta,
+            argValues: [schedulerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotSchedulerPauseScreenshotSchedulerConstMeta => const TaskConstMeta(
+            debugName: "pause_screenshot_scheduler",
+            argNames: ["schedulerId"],
+        );
+        
+
+@override bool crateApiTimelapseRecorderPauseTimelapseRecording({required BigInt recorderId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(recorderId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTimelapseRecorderPauseTimelapseRecordingConstMeta,
+            argValues: [recorderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTimelapseRecorderPauseTimelapseRecordingConstMeta => const TaskConstMeta(
+            debugName: "pause_timelapse_recording",
+            argNames: ["recorderId"],
+        );
+        
+
+@override (double,double)? crateApiDisplayGeometryPhysicalToLogical({required double x , required double y })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(x, serializer);
+sse_encode_f_64(y, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_record_f_64_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDisplayGeometryPhysicalToLogicalConstMeta,
+            argValues: [x, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDisplayGeometryPhysicalToLogicalConstMeta => const TaskConstMeta(
+            debugName: "physical_to_logical",
+            argNames: ["x", "y"],
+        );
+        
+
+@override Future<List<NotificationBackendProbe>> crateApiDesktopNotificationProbeNotificationBackends()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_notification_backend_probe,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationProbeNotificationBackendsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationProbeNotificationBackendsConstMeta => const TaskConstMeta(
+            debugName: "probe_notification_backends",
+            argNames: [],
+        );
+        
+
+@override Future<ProcessFilter> crateApiProcessWatcherProcessFilterDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_process_filter,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessWatcherProcessFilterDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessWatcherProcessFilterDefaultConstMeta => const TaskConstMeta(
+            debugName: "process_filter_default",
+            argNames: [],
+        );
+        
+
+@override Future<ProcessSamplerConfig> crateApiProcessSamplerProcessSamplerConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_process_sampler_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessSamplerProcessSamplerConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessSamplerProcessSamplerConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "process_sampler_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiDesktopNotificationRegisterModule()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDesktopNotificationRegisterModuleConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDesktopNotificationRegisterModuleConstMeta =>
-      const TaskConstMeta(debugName: "register_module", argNames: []);
-
-  @override
-  Future<void> crateApiDesktopNotificationSendNotification({
-    required String title,
-    required String message,
-    String? iconPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          sse_encode_String(message, serializer);
-          sse_encode_opt_String(iconPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 19,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationRegisterModuleConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationRegisterModuleConstMeta => const TaskConstMeta(
+            debugName: "register_module",
+            argNames: [],
+        );
+        
+
+@override bool crateApiClickHeatmapResetClickHeatmap({required BigInt heatmapId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(heatmapId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiClickHeatmapResetClickHeatmapConstMeta,
+            argValues: [heatmapId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiClickHeatmapResetClickHeatmapConstMeta => const TaskConstMeta(
+            debugName: "reset_click_heatmap",
+            argNames: ["heatmapId"],
+        );
+        
+
+@override void crateApiDesktopNotificationResetNotificationGateStats()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationResetNotificationGateStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationResetNotificationGateStatsConstMeta => const TaskConstMeta(
+            debugName: "reset_notification_gate_stats",
+            argNames: [],
+        );
+        
+
+@override Future<ResourceBudgetConfig> crateApiResourceBudgetResourceBudgetConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resource_budget_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiResourceBudgetResourceBudgetConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResourceBudgetResourceBudgetConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "resource_budget_config_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiScreenshotSchedulerResumeScreenshotScheduler({required BigInt schedulerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(schedulerId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSchedulerResumeScreenshotSchedulerConstMeta,
+            argValues: [schedulerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotSchedulerResumeScreenshotSchedulerConstMeta => const TaskConstMeta(
+            debugName: "resume_screenshot_scheduler",
+            argNames: ["schedulerId"],
+        );
+        
+
+@override bool crateApiTimelapseRecorderResumeTimelapseRecording({required BigInt recorderId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(recorderId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTimelapseRecorderResumeTimelapseRecordingConstMeta,
+            argValues: [recorderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTimelapseRecorderResumeTimelapseRecordingConstMeta => const TaskConstMeta(
+            debugName: "resume_timelapse_recording",
+            argNames: ["recorderId"],
+        );
+        
+
+@override Future<ScreenshotOptions> crateApiScreenshotCaptureScreenshotOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_screenshot_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScreenshotCaptureScreenshotOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotCaptureScreenshotOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "screenshot_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<GatedSendResult> crateApiDesktopNotificationSendGatedNotification({required String category , required String title , required String message , String? iconPath , int? urgencyLevel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(category, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(message, serializer);
+sse_encode_opt_String(iconPath, serializer);
+sse_encode_opt_box_autoadd_i_32(urgencyLevel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gated_send_result,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiDesktopNotificationSendNotificationConstMeta,
-        argValues: [title, message, iconPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDesktopNotificationSendNotificationConstMeta =>
-      const TaskConstMeta(
-        debugName: "send_notification",
-        argNames: ["title", "message", "iconPath"],
-      );
-
-  @override
-  Future<void> crateApiDesktopNotificationSendNotificationWithOptions({
-    required String title,
-    required String message,
-    String? iconPath,
-    BigInt? timeoutSeconds,
-    int? urgencyLevel,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          sse_encode_String(message, serializer);
-          sse_encode_opt_String(iconPath, serializer);
-          sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
-          sse_encode_opt_box_autoadd_i_32(urgencyLevel, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 20,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationSendGatedNotificationConstMeta,
+            argValues: [category, title, message, iconPath, urgencyLevel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationSendGatedNotificationConstMeta => const TaskConstMeta(
+            debugName: "send_gated_notification",
+            argNames: ["category", "title", "message", "iconPath", "urgencyLevel"],
+        );
+        
+
+@override Stream<NotificationEvent> crateApiNotificationActionsSendInteractiveNotification({required String title , required String body , required List<NotificationAction> actions })  { 
+            final sink = RustStreamSink<NotificationEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(title, serializer);
+sse_encode_String(body, serializer);
+sse_encode_list_notification_action(actions, serializer);
+sse_encode_StreamSink_notification_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiNotificationActionsSendInteractiveNotificationConstMeta,
+            argValues: [title, body, actions, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiNotificationActionsSendInteractiveNotificationConstMeta => const TaskConstMeta(
+            debugName: "send_interactive_notification",
+            argNames: ["title", "body", "actions", "sink"],
+        );
+        
+
+@override Future<NotificationBackend> crateApiDesktopNotificationSendNotification({required String title , required String message , String? iconPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(title, serializer);
+sse_encode_String(message, serializer);
+sse_encode_opt_String(iconPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_backend,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationSendNotificationConstMeta,
+            argValues: [title, message, iconPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationSendNotificationConstMeta => const TaskConstMeta(
+            debugName: "send_notification",
+            argNames: ["title", "message", "iconPath"],
+        );
+        
+
+@override Future<NotificationBackend> crateApiDesktopNotificationSendNotificationWithOptions({required String title , required String message , String? iconPath , BigInt? timeoutSeconds , int? urgencyLevel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(title, serializer);
+sse_encode_String(message, serializer);
+sse_encode_opt_String(iconPath, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutSeconds, serializer);
+sse_encode_opt_box_autoadd_i_32(urgencyLevel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_notification_backend,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationSendNotificationWithOptionsConstMeta,
+            argValues: [title, message, iconPath, timeoutSeconds, urgencyLevel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationSendNotificationWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "send_notification_with_options",
+            argNames: ["title", "message", "iconPath", "timeoutSeconds", "urgencyLevel"],
+        );
+        
+
+@override void crateApiCmdlineRedactionSetCmdlineRedactionPatterns({required List<String> patterns })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(patterns, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCmdlineRedactionSetCmdlineRedactionPatternsConstMeta,
+            argValues: [patterns],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCmdlineRedactionSetCmdlineRedactionPatternsConstMeta => const TaskConstMeta(
+            debugName: "set_cmdline_redaction_patterns",
+            argNames: ["patterns"],
+        );
+        
+
+@override void crateApiDesktopNotificationSetDoNotDisturb({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationSetDoNotDisturbConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationSetDoNotDisturbConstMeta => const TaskConstMeta(
+            debugName: "set_do_not_disturb",
+            argNames: ["enabled"],
+        );
+        
+
+@override void crateApiDesktopNotificationSetNotificationBackendPreference({required NotificationBackendPreference preference })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_notification_backend_preference(preference, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiDesktopNotificationSetNotificationBackendPreferenceConstMeta,
+            argValues: [preference],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDesktopNotificationSetNotificationBackendPreferenceConstMeta => const TaskConstMeta(
+            debugName: "set_notification_backend_preference",
+            argNames: ["preference"],
+        );
+        
+
+@override Future<BigInt> crateApiPersistentNotificationShowPersistentNotification({required String title , required String body , String? iconPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(title, serializer);
+sse_encode_String(body, serializer);
+sse_encode_opt_String(iconPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiDesktopNotificationSendNotificationWithOptionsConstMeta,
-        argValues: [title, message, iconPath, timeoutSeconds, urgencyLevel],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiDesktopNotificationSendNotificationWithOptionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "send_notification_with_options",
-        argNames: [
-          "title",
-          "message",
-          "iconPath",
-          "timeoutSeconds",
-          "urgencyLevel",
-        ],
-      );
-
-  @override
-  Stream<KeyboardEvent> crateApiKeyboardListenerStartKeyboardListener() {
-    final sink = RustStreamSink<KeyboardEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_keyboard_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 21,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_String,
-          ),
-          constMeta: kCrateApiKeyboardListenerStartKeyboardListenerConstMeta,
-          argValues: [sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiKeyboardListenerStartKeyboardListenerConstMeta =>
-      const TaskConstMeta(
-        debugName: "start_keyboard_listener",
-        argNames: ["sink"],
-      );
-
-  @override
-  Stream<MouseEvent> crateApiMouseListenerStartMouseListener() {
-    final sink = RustStreamSink<MouseEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_mouse_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 22,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_String,
-          ),
-          constMeta: kCrateApiMouseListenerStartMouseListenerConstMeta,
-          argValues: [sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiMouseListenerStartMouseListenerConstMeta =>
-      const TaskConstMeta(
-        debugName: "start_mouse_listener",
-        argNames: ["sink"],
-      );
-
-  @override
-  Stream<WindowDetails> crateApiActiveWindowListenerStartWindowListener() {
-    final sink = RustStreamSink<WindowDetails>();
-    handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_StreamSink_window_details_Sse(sink, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiPersistentNotificationShowPersistentNotificationConstMeta,
+            argValues: [title, body, iconPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPersistentNotificationShowPersistentNotificationConstMeta => const TaskConstMeta(
+            debugName: "show_persistent_notification",
+            argNames: ["title", "body", "iconPath"],
+        );
+        
+
+@override Stream<PolicyViolation> crateApiAppPolicyStartAppPolicy({required PolicyConfig config })  { 
+            final sink = RustStreamSink<PolicyViolation>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_policy_config(config, serializer);
+sse_encode_StreamSink_policy_violation_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAppPolicyStartAppPolicyConstMeta,
+            argValues: [config, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiAppPolicyStartAppPolicyConstMeta => const TaskConstMeta(
+            debugName: "start_app_policy",
+            argNames: ["config", "sink"],
+        );
+        
+
+@override Stream<PreviewFrame> crateApiCapturePreviewStartCapturePreview({required double fps , required int maxWidth })  { 
+            final sink = RustStreamSink<PreviewFrame>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(fps, serializer);
+sse_encode_u_32(maxWidth, serializer);
+sse_encode_StreamSink_preview_frame_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCapturePreviewStartCapturePreviewConstMeta,
+            argValues: [fps, maxWidth, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiCapturePreviewStartCapturePreviewConstMeta => const TaskConstMeta(
+            debugName: "start_capture_preview",
+            argNames: ["fps", "maxWidth", "sink"],
+        );
+        
+
+@override Stream<KeyboardEvent> crateApiKeyboardListenerStartKeyboardListener()  { 
+            final sink = RustStreamSink<KeyboardEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_keyboard_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiKeyboardListenerStartKeyboardListenerConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiKeyboardListenerStartKeyboardListenerConstMeta => const TaskConstMeta(
+            debugName: "start_keyboard_listener",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<MouseEvent> crateApiMouseListenerStartMouseListener()  { 
+            final sink = RustStreamSink<MouseEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_mouse_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMouseListenerStartMouseListenerConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiMouseListenerStartMouseListenerConstMeta => const TaskConstMeta(
+            debugName: "start_mouse_listener",
+            argNames: ["sink"],
+        );
+        
+
+@override bool crateApiProcessSamplerStartProcessSampler({required ProcessSamplerConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_process_sampler_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiActiveWindowListenerStartWindowListenerConstMeta,
-        argValues: [sink],
-        apiImpl: this,
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiActiveWindowListenerStartWindowListenerConstMeta =>
-      const TaskConstMeta(
-        debugName: "start_window_listener",
-        argNames: ["sink"],
-      );
-
-  @override
-  Stream<WindowDetails>
-  crateApiActiveWindowListenerStartWindowListenerStream() {
-    final sink = RustStreamSink<WindowDetails>();
-    handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_StreamSink_window_details_Sse(sink, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProcessSamplerStartProcessSamplerConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessSamplerStartProcessSamplerConstMeta => const TaskConstMeta(
+            debugName: "start_process_sampler",
+            argNames: ["config"],
+        );
+        
+
+@override Stream<ProcessEvent> crateApiProcessWatcherStartProcessWatcher({required ProcessFilter filter })  { 
+            final sink = RustStreamSink<ProcessEvent>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_process_filter(filter, serializer);
+sse_encode_StreamSink_process_event_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProcessWatcherStartProcessWatcherConstMeta,
+            argValues: [filter, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiProcessWatcherStartProcessWatcherConstMeta => const TaskConstMeta(
+            debugName: "start_process_watcher",
+            argNames: ["filter", "sink"],
+        );
+        
+
+@override bool crateApiResourceBudgetStartResourceBudget({required ResourceBudgetConfig config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_resource_budget_config(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiActiveWindowListenerStartWindowListenerStreamConstMeta,
-        argValues: [sink],
-        apiImpl: this,
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta
-  get kCrateApiActiveWindowListenerStartWindowListenerStreamConstMeta =>
-      const TaskConstMeta(
-        debugName: "start_window_listener_stream",
-        argNames: ["sink"],
-      );
-
-  @override
-  bool crateApiActiveWindowListenerStopWindowListener({
-    required BigInt listenerId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(listenerId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiResourceBudgetStartResourceBudgetConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResourceBudgetStartResourceBudgetConstMeta => const TaskConstMeta(
+            debugName: "start_resource_budget",
+            argNames: ["config"],
+        );
+        
+
+@override Stream<Screenshot> crateApiScreenshotSchedulerStartScreenshotScheduler({required ScreenshotScheduleConfig config })  { 
+            final sink = RustStreamSink<Screenshot>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_screenshot_schedule_config(config, serializer);
+sse_encode_StreamSink_screenshot_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSchedulerStartScreenshotSchedulerConstMeta,
+            argValues: [config, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiScreenshotSchedulerStartScreenshotSchedulerConstMeta => const TaskConstMeta(
+            debugName: "start_screenshot_scheduler",
+            argNames: ["config", "sink"],
+        );
+        
+
+@override Stream<SystemSnapshot> crateApiSystemMonitorStartSystemMonitor({required BigInt intervalMs })  { 
+            final sink = RustStreamSink<SystemSnapshot>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(intervalMs, serializer);
+sse_encode_StreamSink_system_snapshot_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSystemMonitorStartSystemMonitorConstMeta,
+            argValues: [intervalMs, sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSystemMonitorStartSystemMonitorConstMeta => const TaskConstMeta(
+            debugName: "start_system_monitor",
+            argNames: ["intervalMs", "sink"],
+        );
+        
+
+@override Future<BigInt> crateApiTimelapseRecorderStartTimelapseRecording({required TimelapseConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_timelapse_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTimelapseRecorderStartTimelapseRecordingConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTimelapseRecorderStartTimelapseRecordingConstMeta => const TaskConstMeta(
+            debugName: "start_timelapse_recording",
+            argNames: ["config"],
+        );
+        
+
+@override Stream<WindowDetails> crateApiActiveWindowListenerStartWindowListener()  { 
+            final sink = RustStreamSink<WindowDetails>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_window_details_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerStartWindowListenerConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerStartWindowListenerConstMeta => const TaskConstMeta(
+            debugName: "start_window_listener",
+            argNames: ["sink"],
+        );
+        
+
+@override Stream<WindowDetails> crateApiActiveWindowListenerStartWindowListenerStream()  { 
+            final sink = RustStreamSink<WindowDetails>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_window_details_Sse(sink, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerStartWindowListenerStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        ));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerStartWindowListenerStreamConstMeta => const TaskConstMeta(
+            debugName: "start_window_listener_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override bool crateApiAppPolicyStopAppPolicy({required BigInt engineId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(engineId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAppPolicyStopAppPolicyConstMeta,
+            argValues: [engineId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAppPolicyStopAppPolicyConstMeta => const TaskConstMeta(
+            debugName: "stop_app_policy",
+            argNames: ["engineId"],
+        );
+        
+
+@override bool crateApiCapturePreviewStopCapturePreview({required BigInt previewId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(previewId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCapturePreviewStopCapturePreviewConstMeta,
+            argValues: [previewId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCapturePreviewStopCapturePreviewConstMeta => const TaskConstMeta(
+            debugName: "stop_capture_preview",
+            argNames: ["previewId"],
+        );
+        
+
+@override bool crateApiProcessSamplerStopProcessSampler()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiActiveWindowListenerStopWindowListenerConstMeta,
-        argValues: [listenerId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiActiveWindowListenerStopWindowListenerConstMeta =>
-      const TaskConstMeta(
-        debugName: "stop_window_listener",
-        argNames: ["listenerId"],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeFullScreenshot() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiProcessSamplerStopProcessSamplerConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessSamplerStopProcessSamplerConstMeta => const TaskConstMeta(
+            debugName: "stop_process_sampler",
+            argNames: [],
+        );
+        
+
+@override bool crateApiProcessWatcherStopProcessWatcher({required BigInt watcherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(watcherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessWatcherStopProcessWatcherConstMeta,
+            argValues: [watcherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessWatcherStopProcessWatcherConstMeta => const TaskConstMeta(
+            debugName: "stop_process_watcher",
+            argNames: ["watcherId"],
+        );
+        
+
+@override bool crateApiResourceBudgetStopResourceBudget()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiResourceBudgetStopResourceBudgetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiResourceBudgetStopResourceBudgetConstMeta => const TaskConstMeta(
+            debugName: "stop_resource_budget",
+            argNames: [],
+        );
+        
+
+@override bool crateApiScreenshotSchedulerStopScreenshotScheduler({required BigInt schedulerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(schedulerId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScreenshotSchedulerStopScreenshotSchedulerConstMeta,
+            argValues: [schedulerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotSchedulerStopScreenshotSchedulerConstMeta => const TaskConstMeta(
+            debugName: "stop_screenshot_scheduler",
+            argNames: ["schedulerId"],
+        );
+        
+
+@override bool crateApiSystemMonitorStopSystemMonitor({required BigInt monitorId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(monitorId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSystemMonitorStopSystemMonitorConstMeta,
+            argValues: [monitorId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSystemMonitorStopSystemMonitorConstMeta => const TaskConstMeta(
+            debugName: "stop_system_monitor",
+            argNames: ["monitorId"],
+        );
+        
+
+@override Future<TimelapseStatus> crateApiTimelapseRecorderStopTimelapseRecording({required BigInt recorderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(recorderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_timelapse_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiTimelapseRecorderStopTimelapseRecordingConstMeta,
+            argValues: [recorderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTimelapseRecorderStopTimelapseRecordingConstMeta => const TaskConstMeta(
+            debugName: "stop_timelapse_recording",
+            argNames: ["recorderId"],
+        );
+        
+
+@override bool crateApiActiveWindowListenerStopWindowListener({required BigInt listenerId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(listenerId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiActiveWindowListenerStopWindowListenerConstMeta,
+            argValues: [listenerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiActiveWindowListenerStopWindowListenerConstMeta => const TaskConstMeta(
+            debugName: "stop_window_listener",
+            argNames: ["listenerId"],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeFullScreenshot()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTakeFullScreenshotConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTakeFullScreenshotTakeFullScreenshotConstMeta =>
-      const TaskConstMeta(debugName: "take_full_screenshot", argNames: []);
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotLinuxFallback() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeFullScreenshotConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeFullScreenshotConstMeta => const TaskConstMeta(
+            debugName: "take_full_screenshot",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotLinuxFallback()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotLinuxFallbackConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotLinuxFallbackConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_linux_fallback",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsCsharp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotLinuxFallbackConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotLinuxFallbackConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_linux_fallback",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsCsharp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsCsharpConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsCsharpConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_csharp",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsDirectshow() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsCsharpConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsCsharpConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_csharp",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsDirectshow()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsDirectshowConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsDirectshowConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_directshow",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsFfmpeg() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsDirectshowConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsDirectshowConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_directshow",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsFfmpeg()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsFfmpegConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsFfmpegConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_ffmpeg",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsMemory() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsFfmpegConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsFfmpegConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_ffmpeg",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsMemory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsMemoryConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsMemoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_memory",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsNircmd() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 32,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsMemoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsMemoryConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_memory",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsNircmd()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsNircmdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsNircmdConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_nircmd",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsPowershell() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsNircmdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsNircmdConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_nircmd",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsPowershell()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsPowershellConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsPowershellConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_powershell",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsVbscript() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsPowershellConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsPowershellConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_powershell",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsVbscript()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsVbscriptConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsVbscriptConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_vbscript",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWin32() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsVbscriptConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsVbscriptConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_vbscript",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWin32()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWindowsWin32ConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsWin32ConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_win32",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWmi() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 36,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsWin32ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsWin32ConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_win32",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWindowsWmi()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsWmiConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWindowsWmiConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_windows_wmi",
-        argNames: [],
-      );
-
-  @override
-  Future<String>
-  crateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrate() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWindowsWmiConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWindowsWmiConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_windows_wmi",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrate()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrateConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrateConstMeta =>
-      const TaskConstMeta(
-        debugName: "take_screenshot_with_screenshots_crate",
-        argNames: [],
-      );
-
-  @override
-  Future<List<String>> crateApiTakeFullScreenshotTestAllAvailableMethods() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 38,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrateConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTakeScreenshotWithScreenshotsCrateConstMeta => const TaskConstMeta(
+            debugName: "take_screenshot_with_screenshots_crate",
+            argNames: [],
+        );
+        
+
+@override Future<TerminateOptions> crateApiProcessTerminationTerminateOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_terminate_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessTerminationTerminateOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessTerminationTerminateOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "terminate_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<TerminateOutcome> crateApiProcessTerminationTerminateProcess({required int pid , required TerminateOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(pid, serializer);
+sse_encode_box_autoadd_terminate_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_terminate_outcome,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessTerminationTerminateProcessConstMeta,
+            argValues: [pid, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessTerminationTerminateProcessConstMeta => const TaskConstMeta(
+            debugName: "terminate_process",
+            argNames: ["pid", "options"],
+        );
+        
+
+@override Future<List<String>> crateApiTakeFullScreenshotTestAllAvailableMethods()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestAllAvailableMethodsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestAllAvailableMethodsConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_all_available_methods",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestBundledNircmdExtraction() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestAllAvailableMethodsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestAllAvailableMethodsConstMeta => const TaskConstMeta(
+            debugName: "test_all_available_methods",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestBundledNircmdExtraction()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestBundledNircmdExtractionConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestBundledNircmdExtractionConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_bundled_nircmd_extraction",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiTakeFullScreenshotTestLinuxEnvironmentCheck() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 40,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestBundledNircmdExtractionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestBundledNircmdExtractionConstMeta => const TaskConstMeta(
+            debugName: "test_bundled_nircmd_extraction",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTakeFullScreenshotTestLinuxEnvironmentCheck()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestLinuxEnvironmentCheckConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestLinuxEnvironmentCheckConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_linux_environment_check",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestLinuxFallbackMethods() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 41,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestLinuxEnvironmentCheckConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestLinuxEnvironmentCheckConstMeta => const TaskConstMeta(
+            debugName: "test_linux_environment_check",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestLinuxFallbackMethods()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestLinuxFallbackMethodsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestLinuxFallbackMethodsConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_linux_fallback_methods",
-        argNames: [],
-      );
-
-  @override
-  Future<bool> crateApiTakeFullScreenshotTestNircmdAvailability() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 42,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestLinuxFallbackMethodsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestLinuxFallbackMethodsConstMeta => const TaskConstMeta(
+            debugName: "test_linux_fallback_methods",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiTakeFullScreenshotTestNircmdAvailability()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestNircmdAvailabilityConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestNircmdAvailabilityConstMeta =>
-      const TaskConstMeta(debugName: "test_nircmd_availability", argNames: []);
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestNircmdCapabilities() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestNircmdAvailabilityConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestNircmdAvailabilityConstMeta => const TaskConstMeta(
+            debugName: "test_nircmd_availability",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestNircmdCapabilities()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestNircmdCapabilitiesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestNircmdCapabilitiesConstMeta =>
-      const TaskConstMeta(debugName: "test_nircmd_capabilities", argNames: []);
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestNircmdScreenshotSimple() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestNircmdCapabilitiesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestNircmdCapabilitiesConstMeta => const TaskConstMeta(
+            debugName: "test_nircmd_capabilities",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestNircmdScreenshotSimple()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestNircmdScreenshotSimpleConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestNircmdScreenshotSimpleConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_nircmd_screenshot_simple",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestScreenshotsCrateMethod() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestNircmdScreenshotSimpleConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestNircmdScreenshotSimpleConstMeta => const TaskConstMeta(
+            debugName: "test_nircmd_screenshot_simple",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestScreenshotsCrateMethod()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestScreenshotsCrateMethodConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestScreenshotsCrateMethodConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_screenshots_crate_method",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiTakeFullScreenshotTestWindowsEnvironmentCheck() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestScreenshotsCrateMethodConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestScreenshotsCrateMethodConstMeta => const TaskConstMeta(
+            debugName: "test_screenshots_crate_method",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiTakeFullScreenshotTestWindowsEnvironmentCheck()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestWindowsEnvironmentCheckConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsEnvironmentCheckConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_environment_check",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod1Nircmd() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsEnvironmentCheckConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsEnvironmentCheckConstMeta => const TaskConstMeta(
+            debugName: "test_windows_environment_check",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod1Nircmd()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod1NircmdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod1NircmdConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_1_nircmd",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod2Powershell() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 48,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod1NircmdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod1NircmdConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_1_nircmd",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod2Powershell()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestWindowsMethod2PowershellConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod2PowershellConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_2_powershell",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod3Memory() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 49,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod2PowershellConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod2PowershellConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_2_powershell",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod3Memory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod3MemoryConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod3MemoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_3_memory",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod4Directshow() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 50,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod3MemoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod3MemoryConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_3_memory",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod4Directshow()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestWindowsMethod4DirectshowConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod4DirectshowConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_4_directshow",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod5Win32() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod4DirectshowConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod4DirectshowConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_4_directshow",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod5Win32()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod5Win32ConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod5Win32ConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_5_win32",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod6Wmi() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 52,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod5Win32ConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod5Win32ConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_5_win32",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod6Wmi()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod6WmiConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod6WmiConstMeta =>
-      const TaskConstMeta(debugName: "test_windows_method_6_wmi", argNames: []);
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod7Ffmpeg() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 53,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod6WmiConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod6WmiConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_6_wmi",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod7Ffmpeg()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod7FfmpegConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod7FfmpegConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_7_ffmpeg",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod8Csharp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 54,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod7FfmpegConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod7FfmpegConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_7_ffmpeg",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod8Csharp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod8CsharpConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod8CsharpConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_8_csharp",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiTakeFullScreenshotTestWindowsMethod9Vbscript() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 55,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod8CsharpConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod8CsharpConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_8_csharp",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiTakeFullScreenshotTestWindowsMethod9Vbscript()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-        constMeta:
-            kCrateApiTakeFullScreenshotTestWindowsMethod9VbscriptConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiTakeFullScreenshotTestWindowsMethod9VbscriptConstMeta =>
-      const TaskConstMeta(
-        debugName: "test_windows_method_9_vbscript",
-        argNames: [],
-      );
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  RustStreamSink<KeyboardEvent> dco_decode_StreamSink_keyboard_event_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<MouseEvent> dco_decode_StreamSink_mouse_event_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  RustStreamSink<WindowDetails> dco_decode_StreamSink_window_details_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  KeyboardEvent dco_decode_keyboard_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return KeyboardEvent(
-      key: dco_decode_String(arr[0]),
-      isKeyPress: dco_decode_bool(arr[1]),
-    );
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<ProcessInfo> dco_decode_list_process_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_process_info).toList();
-  }
-
-  @protected
-  List<WindowDetails> dco_decode_list_window_details(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_window_details).toList();
-  }
-
-  @protected
-  MouseEvent dco_decode_mouse_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return MouseEvent(
-      button: dco_decode_String(arr[0]),
-      isButtonPress: dco_decode_bool(arr[1]),
-      coords: dco_decode_record_i_32_i_32(arr[2]),
-      isLeftClick: dco_decode_bool(arr[3]),
-      isRightClick: dco_decode_bool(arr[4]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  ProcessInfo dco_decode_process_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ProcessInfo(
-      pid: dco_decode_u_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      cmd: dco_decode_list_String(arr[2]),
-      memoryUsage: dco_decode_u_64(arr[3]),
-      cpuUsage: dco_decode_f_32(arr[4]),
-    );
-  }
-
-  @protected
-  (int, int) dco_decode_record_i_32_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_i_32(arr[0]), dco_decode_i_32(arr[1]));
-  }
-
-  @protected
-  (int, int, int, int) dco_decode_record_i_32_i_32_i_32_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4) {
-      throw Exception('Expected 4 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_i_32(arr[0]),
-      dco_decode_i_32(arr[1]),
-      dco_decode_i_32(arr[2]),
-      dco_decode_i_32(arr[3]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  WindowDetails dco_decode_window_details(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return WindowDetails(
-      id: dco_decode_u_32(arr[0]),
-      title: dco_decode_String(arr[1]),
-      position: dco_decode_record_i_32_i_32_i_32_i_32(arr[2]),
-      isFullScreen: dco_decode_bool(arr[3]),
-      processName: dco_decode_String(arr[4]),
-      processPath: dco_decode_String(arr[5]),
-      processId: dco_decode_u_32(arr[6]),
-      os: dco_decode_String(arr[7]),
-    );
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  RustStreamSink<KeyboardEvent> sse_decode_StreamSink_keyboard_event_Sse(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<MouseEvent> sse_decode_StreamSink_mouse_event_Sse(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  RustStreamSink<WindowDetails> sse_decode_StreamSink_window_details_Sse(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  KeyboardEvent sse_decode_keyboard_event(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_key = sse_decode_String(deserializer);
-    var var_isKeyPress = sse_decode_bool(deserializer);
-    return KeyboardEvent(key: var_key, isKeyPress: var_isKeyPress);
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ProcessInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_process_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<WindowDetails> sse_decode_list_window_details(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WindowDetails>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_window_details(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  MouseEvent sse_decode_mouse_event(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_button = sse_decode_String(deserializer);
-    var var_isButtonPress = sse_decode_bool(deserializer);
-    var var_coords = sse_decode_record_i_32_i_32(deserializer);
-    var var_isLeftClick = sse_decode_bool(deserializer);
-    var var_isRightClick = sse_decode_bool(deserializer);
-    return MouseEvent(
-      button: var_button,
-      isButtonPress: var_isButtonPress,
-      coords: var_coords,
-      isLeftClick: var_isLeftClick,
-      isRightClick: var_isRightClick,
-    );
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ProcessInfo sse_decode_process_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pid = sse_decode_u_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_cmd = sse_decode_list_String(deserializer);
-    var var_memoryUsage = sse_decode_u_64(deserializer);
-    var var_cpuUsage = sse_decode_f_32(deserializer);
-    return ProcessInfo(
-      pid: var_pid,
-      name: var_name,
-      cmd: var_cmd,
-      memoryUsage: var_memoryUsage,
-      cpuUsage: var_cpuUsage,
-    );
-  }
-
-  @protected
-  (int, int) sse_decode_record_i_32_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_i_32(deserializer);
-    var var_field1 = sse_decode_i_32(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, int, int, int) sse_decode_record_i_32_i_32_i_32_i_32(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_i_32(deserializer);
-    var var_field1 = sse_decode_i_32(deserializer);
-    var var_field2 = sse_decode_i_32(deserializer);
-    var var_field3 = sse_decode_i_32(deserializer);
-    return (var_field0, var_field1, var_field2, var_field3);
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  WindowDetails sse_decode_window_details(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_u_32(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_position = sse_decode_record_i_32_i_32_i_32_i_32(deserializer);
-    var var_isFullScreen = sse_decode_bool(deserializer);
-    var var_processName = sse_decode_String(deserializer);
-    var var_processPath = sse_decode_String(deserializer);
-    var var_processId = sse_decode_u_32(deserializer);
-    var var_os = sse_decode_String(deserializer);
-    return WindowDetails(
-      id: var_id,
-      title: var_title,
-      position: var_position,
-      isFullScreen: var_isFullScreen,
-      processName: var_processName,
-      processPath: var_processPath,
-      processId: var_processId,
-      os: var_os,
-    );
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void sse_encode_StreamSink_keyboard_event_Sse(
-    RustStreamSink<KeyboardEvent> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_keyboard_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_StreamSink_mouse_event_Sse(
-    RustStreamSink<MouseEvent> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_mouse_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_StreamSink_window_details_Sse(
-    RustStreamSink<WindowDetails> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_window_details,
+        )
+        ,
+            constMeta: kCrateApiTakeFullScreenshotTestWindowsMethod9VbscriptConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeFullScreenshotTestWindowsMethod9VbscriptConstMeta => const TaskConstMeta(
+            debugName: "test_windows_method_9_vbscript",
+            argNames: [],
+        );
+        
+
+@override List<ProcessUsageSummary> crateApiProcessSamplerTopProcesses({required ProcessSortKey by , required int n , required BigInt windowSeconds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_process_sort_key(by, serializer);
+sse_encode_u_32(n, serializer);
+sse_encode_u_64(windowSeconds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_process_usage_summary,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProcessSamplerTopProcessesConstMeta,
+            argValues: [by, n, windowSeconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProcessSamplerTopProcessesConstMeta => const TaskConstMeta(
+            debugName: "top_processes",
+            argNames: ["by", "n", "windowSeconds"],
+        );
+        
+
+@override Future<void> crateApiPersistentNotificationUpdateNotification({required BigInt handleId , required String title , required String body })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handleId, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(body, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_keyboard_event(KeyboardEvent self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.key, serializer);
-    sse_encode_bool(self.isKeyPress, serializer);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_process_info(
-    List<ProcessInfo> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_process_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_window_details(
-    List<WindowDetails> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_window_details(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_mouse_event(MouseEvent self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.button, serializer);
-    sse_encode_bool(self.isButtonPress, serializer);
-    sse_encode_record_i_32_i_32(self.coords, serializer);
-    sse_encode_bool(self.isLeftClick, serializer);
-    sse_encode_bool(self.isRightClick, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_process_info(ProcessInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.pid, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_list_String(self.cmd, serializer);
-    sse_encode_u_64(self.memoryUsage, serializer);
-    sse_encode_f_32(self.cpuUsage, serializer);
-  }
-
-  @protected
-  void sse_encode_record_i_32_i_32((int, int) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.$1, serializer);
-    sse_encode_i_32(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_i_32_i_32_i_32_i_32(
-    (int, int, int, int) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.$1, serializer);
-    sse_encode_i_32(self.$2, serializer);
-    sse_encode_i_32(self.$3, serializer);
-    sse_encode_i_32(self.$4, serializer);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_window_details(WindowDetails self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_record_i_32_i_32_i_32_i_32(self.position, serializer);
-    sse_encode_bool(self.isFullScreen, serializer);
-    sse_encode_String(self.processName, serializer);
-    sse_encode_String(self.processPath, serializer);
-    sse_encode_u_32(self.processId, serializer);
-    sse_encode_String(self.os, serializer);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiPersistentNotificationUpdateNotificationConstMeta,
+            argValues: [handleId, title, body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPersistentNotificationUpdateNotificationConstMeta => const TaskConstMeta(
+            debugName: "update_notification",
+            argNames: ["handleId", "title", "body"],
+        );
+        
+
+@override Future<WatermarkOptions> crateApiScreenshotWatermarkWatermarkOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_watermark_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScreenshotWatermarkWatermarkOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScreenshotWatermarkWatermarkOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "watermark_options_default",
+            argNames: [],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<KeyboardEvent> dco_decode_StreamSink_keyboard_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<MouseEvent> dco_decode_StreamSink_mouse_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<NotificationEvent> dco_decode_StreamSink_notification_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<PolicyViolation> dco_decode_StreamSink_policy_violation_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<PreviewFrame> dco_decode_StreamSink_preview_frame_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ProcessEvent> dco_decode_StreamSink_process_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<Screenshot> dco_decode_StreamSink_screenshot_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<SystemSnapshot> dco_decode_StreamSink_system_snapshot_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<WindowDetails> dco_decode_StreamSink_window_details_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected DeltaEncoderConfig dco_decode_box_autoadd_delta_encoder_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_delta_encoder_config(raw); }
+
+@protected FrameAnalysis dco_decode_box_autoadd_frame_analysis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_frame_analysis(raw); }
+
+@protected HeatmapConfig dco_decode_box_autoadd_heatmap_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_heatmap_config(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected MonitorLocation dco_decode_box_autoadd_monitor_location(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_monitor_location(raw); }
+
+@protected MouseEvent dco_decode_box_autoadd_mouse_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_mouse_event(raw); }
+
+@protected NotificationBackend dco_decode_box_autoadd_notification_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_notification_backend(raw); }
+
+@protected NotificationBackendPreference dco_decode_box_autoadd_notification_backend_preference(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_notification_backend_preference(raw); }
+
+@protected NotificationGateConfig dco_decode_box_autoadd_notification_gate_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_notification_gate_config(raw); }
+
+@protected NotificationServerInfo dco_decode_box_autoadd_notification_server_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_notification_server_info(raw); }
+
+@protected PolicyConfig dco_decode_box_autoadd_policy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_policy_config(raw); }
+
+@protected ProcessFilter dco_decode_box_autoadd_process_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_process_filter(raw); }
+
+@protected ProcessHistory dco_decode_box_autoadd_process_history(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_process_history(raw); }
+
+@protected ProcessSamplerConfig dco_decode_box_autoadd_process_sampler_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_process_sampler_config(raw); }
+
+@protected ProcessTreeNode dco_decode_box_autoadd_process_tree_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_process_tree_node(raw); }
+
+@protected QuietHours dco_decode_box_autoadd_quiet_hours(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_quiet_hours(raw); }
+
+@protected (double,double) dco_decode_box_autoadd_record_f_64_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as (double,double); }
+
+@protected ResourceBudgetConfig dco_decode_box_autoadd_resource_budget_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_resource_budget_config(raw); }
+
+@protected ScreenshotOptions dco_decode_box_autoadd_screenshot_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_screenshot_options(raw); }
+
+@protected ScreenshotScheduleConfig dco_decode_box_autoadd_screenshot_schedule_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_screenshot_schedule_config(raw); }
+
+@protected TerminateOptions dco_decode_box_autoadd_terminate_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_terminate_options(raw); }
+
+@protected TimelapseConfig dco_decode_box_autoadd_timelapse_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_timelapse_config(raw); }
+
+@protected TimelapseStatus dco_decode_box_autoadd_timelapse_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_timelapse_status(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected WatermarkOptions dco_decode_box_autoadd_watermark_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_watermark_options(raw); }
+
+@protected CategoryGateStats dco_decode_category_gate_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CategoryGateStats(category: dco_decode_String(arr[0]),
+sent: dco_decode_u_64(arr[1]),
+deduplicated: dco_decode_u_64(arr[2]),
+rateLimited: dco_decode_u_64(arr[3]),
+queued: dco_decode_u_64(arr[4]),
+dropped: dco_decode_u_64(arr[5]),); }
+
+@protected CoordinateSpace dco_decode_coordinate_space(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CoordinateSpace.values[raw as int]; }
+
+@protected CpuCoreUsage dco_decode_cpu_core_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CpuCoreUsage(name: dco_decode_String(arr[0]),
+usage: dco_decode_f_32(arr[1]),
+frequencyMhz: dco_decode_u_64(arr[2]),); }
+
+@protected DeltaEncoderConfig dco_decode_delta_encoder_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DeltaEncoderConfig(tileSize: dco_decode_u_32(arr[0]),
+keyframeInterval: dco_decode_u_32(arr[1]),
+maxChangedRatio: dco_decode_f_32(arr[2]),); }
+
+@protected DiskUsageInfo dco_decode_disk_usage_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return DiskUsageInfo(name: dco_decode_String(arr[0]),
+mountPoint: dco_decode_String(arr[1]),
+fileSystem: dco_decode_String(arr[2]),
+totalBytes: dco_decode_u_64(arr[3]),
+availableBytes: dco_decode_u_64(arr[4]),
+isRemovable: dco_decode_bool(arr[5]),); }
+
+@protected DisplayGeometry dco_decode_display_geometry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DisplayGeometry(id: dco_decode_u_32(arr[0]),
+isPrimary: dco_decode_bool(arr[1]),
+scaleFactor: dco_decode_f_32(arr[2]),
+logical: dco_decode_display_rect(arr[3]),
+physical: dco_decode_display_rect(arr[4]),); }
+
+@protected DisplayRect dco_decode_display_rect(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DisplayRect(x: dco_decode_i_32(arr[0]),
+y: dco_decode_i_32(arr[1]),
+width: dco_decode_u_32(arr[2]),
+height: dco_decode_u_32(arr[3]),); }
+
+@protected EncodedFrame dco_decode_encoded_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return EncodedFrame(data: dco_decode_list_prim_u_8_strict(arr[0]),
+isKeyframe: dco_decode_bool(arr[1]),
+frameIndex: dco_decode_u_32(arr[2]),
+changedTiles: dco_decode_u_32(arr[3]),
+totalTiles: dco_decode_u_32(arr[4]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FrameAnalysis dco_decode_frame_analysis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FrameAnalysis(classification: dco_decode_frame_classification(arr[0]),
+meanLuminance: dco_decode_f_32(arr[1]),
+luminanceVariance: dco_decode_f_32(arr[2]),
+dominantBinRatio: dco_decode_f_32(arr[3]),
+darkPixelRatio: dco_decode_f_32(arr[4]),); }
+
+@protected FrameClassification dco_decode_frame_classification(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FrameClassification.values[raw as int]; }
+
+@protected GateDecision dco_decode_gate_decision(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GateDecision.values[raw as int]; }
+
+@protected GatedSendResult dco_decode_gated_send_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return GatedSendResult(decision: dco_decode_gate_decision(arr[0]),
+backend: dco_decode_opt_box_autoadd_notification_backend(arr[1]),); }
+
+@protected HeatmapConfig dco_decode_heatmap_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return HeatmapConfig(scope: dco_decode_heatmap_scope(arr[0]),
+gridWidth: dco_decode_u_32(arr[1]),
+gridHeight: dco_decode_u_32(arr[2]),
+includeRightClicks: dco_decode_bool(arr[3]),); }
+
+@protected HeatmapGrid dco_decode_heatmap_grid(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return HeatmapGrid(scope: dco_decode_heatmap_scope(arr[0]),
+gridWidth: dco_decode_u_32(arr[1]),
+gridHeight: dco_decode_u_32(arr[2]),
+cells: dco_decode_list_prim_u_32_strict(arr[3]),
+totalClicks: dco_decode_u_64(arr[4]),); }
+
+@protected HeatmapScope dco_decode_heatmap_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HeatmapScope(kind: dco_decode_heatmap_scope_kind(arr[0]),
+id: dco_decode_u_32(arr[1]),); }
+
+@protected HeatmapScopeKind dco_decode_heatmap_scope_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return HeatmapScopeKind.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected KeyboardEvent dco_decode_keyboard_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return KeyboardEvent(key: dco_decode_String(arr[0]),
+isKeyPress: dco_decode_bool(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<CategoryGateStats> dco_decode_list_category_gate_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_category_gate_stats).toList(); }
+
+@protected List<CpuCoreUsage> dco_decode_list_cpu_core_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cpu_core_usage).toList(); }
+
+@protected List<DiskUsageInfo> dco_decode_list_disk_usage_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_disk_usage_info).toList(); }
+
+@protected List<DisplayGeometry> dco_decode_list_display_geometry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_display_geometry).toList(); }
+
+@protected List<NetworkInterfaceUsage> dco_decode_list_network_interface_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_network_interface_usage).toList(); }
+
+@protected List<NotificationAction> dco_decode_list_notification_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_notification_action).toList(); }
+
+@protected List<NotificationBackend> dco_decode_list_notification_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_notification_backend).toList(); }
+
+@protected List<NotificationBackendProbe> dco_decode_list_notification_backend_probe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_notification_backend_probe).toList(); }
+
+@protected List<PolicyRule> dco_decode_list_policy_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_policy_rule).toList(); }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<ProcessInfo> dco_decode_list_process_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_process_info).toList(); }
+
+@protected List<ProcessSample> dco_decode_list_process_sample(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_process_sample).toList(); }
+
+@protected List<ProcessTreeNode> dco_decode_list_process_tree_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_process_tree_node).toList(); }
+
+@protected List<ProcessUsageSummary> dco_decode_list_process_usage_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_process_usage_summary).toList(); }
+
+@protected List<SpoolEntry> dco_decode_list_spool_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_spool_entry).toList(); }
+
+@protected List<WindowDetails> dco_decode_list_window_details(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_window_details).toList(); }
+
+@protected LoadAverage dco_decode_load_average(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LoadAverage(one: dco_decode_f_64(arr[0]),
+five: dco_decode_f_64(arr[1]),
+fifteen: dco_decode_f_64(arr[2]),); }
+
+@protected MonitorLocation dco_decode_monitor_location(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MonitorLocation(monitorId: dco_decode_u_32(arr[0]),
+x: dco_decode_i_32(arr[1]),
+y: dco_decode_i_32(arr[2]),); }
+
+@protected MouseEvent dco_decode_mouse_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MouseEvent(button: dco_decode_String(arr[0]),
+isButtonPress: dco_decode_bool(arr[1]),
+coords: dco_decode_record_i_32_i_32(arr[2]),
+monitor: dco_decode_opt_box_autoadd_monitor_location(arr[3]),
+isLeftClick: dco_decode_bool(arr[4]),
+isRightClick: dco_decode_bool(arr[5]),); }
+
+@protected NetworkInterfaceUsage dco_decode_network_interface_usage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return NetworkInterfaceUsage(name: dco_decode_String(arr[0]),
+receivedBytes: dco_decode_u_64(arr[1]),
+transmittedBytes: dco_decode_u_64(arr[2]),); }
+
+@protected NotificationAction dco_decode_notification_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return NotificationAction(id: dco_decode_String(arr[0]),
+label: dco_decode_String(arr[1]),); }
+
+@protected NotificationBackend dco_decode_notification_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NotificationBackend.values[raw as int]; }
+
+@protected NotificationBackendCapabilities dco_decode_notification_backend_capabilities(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return NotificationBackendCapabilities(actions: dco_decode_bool(arr[0]),
+bodyMarkup: dco_decode_bool(arr[1]),
+icons: dco_decode_bool(arr[2]),
+persistence: dco_decode_bool(arr[3]),); }
+
+@protected NotificationBackendPreference dco_decode_notification_backend_preference(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return NotificationBackendPreference(pinned: dco_decode_opt_box_autoadd_notification_backend(arr[0]),
+excluded: dco_decode_list_notification_backend(arr[1]),); }
+
+@protected NotificationBackendProbe dco_decode_notification_backend_probe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return NotificationBackendProbe(backend: dco_decode_notification_backend(arr[0]),
+available: dco_decode_bool(arr[1]),
+disabled: dco_decode_bool(arr[2]),
+capabilities: dco_decode_notification_backend_capabilities(arr[3]),
+server: dco_decode_opt_box_autoadd_notification_server_info(arr[4]),); }
+
+@protected NotificationEvent dco_decode_notification_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return NotificationEvent(kind: dco_decode_notification_event_kind(arr[0]),
+actionId: dco_decode_opt_String(arr[1]),); }
+
+@protected NotificationEventKind dco_decode_notification_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NotificationEventKind.values[raw as int]; }
+
+@protected NotificationGateConfig dco_decode_notification_gate_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return NotificationGateConfig(dedupeWindowSeconds: dco_decode_u_64(arr[0]),
+maxPerCategory: dco_decode_u_32(arr[1]),
+rateWindowSeconds: dco_decode_u_64(arr[2]),
+quietHours: dco_decode_opt_box_autoadd_quiet_hours(arr[3]),
+doNotDisturb: dco_decode_bool(arr[4]),); }
+
+@protected NotificationGateStats dco_decode_notification_gate_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return NotificationGateStats(pending: dco_decode_u_32(arr[0]),
+categories: dco_decode_list_category_gate_stats(arr[1]),); }
+
+@protected NotificationServerInfo dco_decode_notification_server_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return NotificationServerInfo(name: dco_decode_String(arr[0]),
+vendor: dco_decode_String(arr[1]),
+version: dco_decode_String(arr[2]),
+specVersion: dco_decode_String(arr[3]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected MonitorLocation? dco_decode_opt_box_autoadd_monitor_location(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_monitor_location(raw); }
+
+@protected NotificationBackend? dco_decode_opt_box_autoadd_notification_backend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_notification_backend(raw); }
+
+@protected NotificationServerInfo? dco_decode_opt_box_autoadd_notification_server_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_notification_server_info(raw); }
+
+@protected ProcessHistory? dco_decode_opt_box_autoadd_process_history(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_process_history(raw); }
+
+@protected ProcessTreeNode? dco_decode_opt_box_autoadd_process_tree_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_process_tree_node(raw); }
+
+@protected QuietHours? dco_decode_opt_box_autoadd_quiet_hours(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_quiet_hours(raw); }
+
+@protected (double,double)? dco_decode_opt_box_autoadd_record_f_64_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_record_f_64_f_64(raw); }
+
+@protected TimelapseStatus? dco_decode_opt_box_autoadd_timelapse_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_timelapse_status(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected WatermarkOptions? dco_decode_opt_box_autoadd_watermark_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_watermark_options(raw); }
+
+@protected PolicyAction dco_decode_policy_action(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PolicyAction.values[raw as int]; }
+
+@protected PolicyConfig dco_decode_policy_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PolicyConfig(rules: dco_decode_list_policy_rule(arr[0]),
+intervalMs: dco_decode_u_64(arr[1]),); }
+
+@protected PolicyEnforcement dco_decode_policy_enforcement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PolicyEnforcement.values[raw as int]; }
+
+@protected PolicyRule dco_decode_policy_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PolicyRule(id: dco_decode_String(arr[0]),
+namePatterns: dco_decode_list_String(arr[1]),
+pathPatterns: dco_decode_list_String(arr[2]),
+windowTitlePatterns: dco_decode_list_String(arr[3]),
+action: dco_decode_policy_action(arr[4]),
+graceSeconds: dco_decode_u_32(arr[5]),
+message: dco_decode_opt_String(arr[6]),); }
+
+@protected PolicyViolation dco_decode_policy_violation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return PolicyViolation(ruleId: dco_decode_String(arr[0]),
+pid: dco_decode_u_32(arr[1]),
+processName: dco_decode_String(arr[2]),
+exePath: dco_decode_opt_String(arr[3]),
+windowTitle: dco_decode_opt_String(arr[4]),
+enforcement: dco_decode_policy_enforcement(arr[5]),
+killAtMs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+timestampMs: dco_decode_u_64(arr[7]),); }
+
+@protected PreviewFrame dco_decode_preview_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PreviewFrame(jpeg: dco_decode_list_prim_u_8_strict(arr[0]),
+width: dco_decode_u_32(arr[1]),
+height: dco_decode_u_32(arr[2]),
+capturedAtMs: dco_decode_u_64(arr[3]),
+classification: dco_decode_frame_classification(arr[4]),); }
+
+@protected ProcessEvent dco_decode_process_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ProcessEvent(kind: dco_decode_process_event_kind(arr[0]),
+pid: dco_decode_u_32(arr[1]),
+name: dco_decode_String(arr[2]),
+exePath: dco_decode_String(arr[3]),
+startTime: dco_decode_u_64(arr[4]),
+runDurationSeconds: dco_decode_opt_box_autoadd_u_64(arr[5]),); }
+
+@protected ProcessEventKind dco_decode_process_event_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProcessEventKind.values[raw as int]; }
+
+@protected ProcessFilter dco_decode_process_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ProcessFilter(namePatterns: dco_decode_list_String(arr[0]),
+pathPatterns: dco_decode_list_String(arr[1]),
+includeExisting: dco_decode_bool(arr[2]),); }
+
+@protected ProcessHistory dco_decode_process_history(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ProcessHistory(pid: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+samples: dco_decode_list_process_sample(arr[2]),); }
+
+@protected ProcessInfo dco_decode_process_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return ProcessInfo(pid: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+cmd: dco_decode_list_String(arr[2]),
+memoryUsage: dco_decode_u_64(arr[3]),
+cpuUsage: dco_decode_f_32(arr[4]),
+parentPid: dco_decode_opt_box_autoadd_u_32(arr[5]),
+user: dco_decode_opt_String(arr[6]),
+exePath: dco_decode_opt_String(arr[7]),
+startTime: dco_decode_u_64(arr[8]),
+runTime: dco_decode_u_64(arr[9]),
+status: dco_decode_String(arr[10]),
+diskReadBytes: dco_decode_u_64(arr[11]),
+diskWrittenBytes: dco_decode_u_64(arr[12]),); }
+
+@protected ProcessSample dco_decode_process_sample(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ProcessSample(timestampMs: dco_decode_u_64(arr[0]),
+cpuUsage: dco_decode_f_32(arr[1]),
+memoryBytes: dco_decode_u_64(arr[2]),); }
+
+@protected ProcessSamplerConfig dco_decode_process_sampler_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ProcessSamplerConfig(intervalMs: dco_decode_u_64(arr[0]),
+historyLen: dco_decode_u_32(arr[1]),); }
+
+@protected ProcessSortKey dco_decode_process_sort_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProcessSortKey.values[raw as int]; }
+
+@protected ProcessTreeNode dco_decode_process_tree_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ProcessTreeNode(process: dco_decode_process_info(arr[0]),
+children: dco_decode_list_process_tree_node(arr[1]),); }
+
+@protected ProcessUsageSummary dco_decode_process_usage_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ProcessUsageSummary(pid: dco_decode_u_32(arr[0]),
+name: dco_decode_String(arr[1]),
+averageCpu: dco_decode_f_32(arr[2]),
+peakCpu: dco_decode_f_32(arr[3]),
+averageMemoryBytes: dco_decode_u_64(arr[4]),
+peakMemoryBytes: dco_decode_u_64(arr[5]),
+sampleCount: dco_decode_u_32(arr[6]),); }
+
+@protected QuietHours dco_decode_quiet_hours(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return QuietHours(startMinute: dco_decode_u_32(arr[0]),
+endMinute: dco_decode_u_32(arr[1]),
+utcOffsetMinutes: dco_decode_i_32(arr[2]),); }
+
+@protected (double,double) dco_decode_record_f_64_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_f_64(arr[0]),dco_decode_f_64(arr[1]),); }
+
+@protected (int,int) dco_decode_record_i_32_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_i_32(arr[0]),dco_decode_i_32(arr[1]),); }
+
+@protected (int,int,int,int) dco_decode_record_i_32_i_32_i_32_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 4) {
+                throw Exception('Expected 4 elements, got ${arr.length}');
+            }
+            return (dco_decode_i_32(arr[0]),dco_decode_i_32(arr[1]),dco_decode_i_32(arr[2]),dco_decode_i_32(arr[3]),); }
+
+@protected ResourceBudgetConfig dco_decode_resource_budget_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ResourceBudgetConfig(maxCpuPercent: dco_decode_f_32(arr[0]),
+maxMemoryBytes: dco_decode_u_64(arr[1]),
+sampleIntervalMs: dco_decode_u_64(arr[2]),
+maxThrottleFactor: dco_decode_f_64(arr[3]),
+batteryThrottleFactor: dco_decode_f_64(arr[4]),); }
+
+@protected ResourceBudgetStatus dco_decode_resource_budget_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ResourceBudgetStatus(running: dco_decode_bool(arr[0]),
+cpuPercent: dco_decode_f_32(arr[1]),
+memoryBytes: dco_decode_u_64(arr[2]),
+onBattery: dco_decode_bool(arr[3]),
+throttleFactor: dco_decode_f_64(arr[4]),
+encodingDeferred: dco_decode_bool(arr[5]),
+inputPollIntervalMs: dco_decode_u_64(arr[6]),
+windowPollIntervalMs: dco_decode_u_64(arr[7]),); }
+
+@protected Screenshot dco_decode_screenshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return Screenshot(data: dco_decode_String(arr[0]),
+format: dco_decode_screenshot_format(arr[1]),
+width: dco_decode_u_32(arr[2]),
+height: dco_decode_u_32(arr[3]),
+capturedAtMs: dco_decode_u_64(arr[4]),
+analysis: dco_decode_frame_analysis(arr[5]),
+monitorId: dco_decode_opt_box_autoadd_u_32(arr[6]),); }
+
+@protected ScreenshotFormat dco_decode_screenshot_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScreenshotFormat.values[raw as int]; }
+
+@protected ScreenshotOptions dco_decode_screenshot_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ScreenshotOptions(format: dco_decode_screenshot_format(arr[0]),
+jpegQuality: dco_decode_u_8(arr[1]),
+maxWidth: dco_decode_opt_box_autoadd_u_32(arr[2]),
+watermark: dco_decode_opt_box_autoadd_watermark_options(arr[3]),
+includeCursor: dco_decode_bool(arr[4]),); }
+
+@protected ScreenshotScheduleConfig dco_decode_screenshot_schedule_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ScreenshotScheduleConfig(intervalSeconds: dco_decode_u_64(arr[0]),
+capturesPerInterval: dco_decode_u_32(arr[1]),
+idleThresholdSeconds: dco_decode_u_64(arr[2]),
+seed: dco_decode_opt_box_autoadd_u_64(arr[3]),
+options: dco_decode_screenshot_options(arr[4]),); }
+
+@protected SpoolEntry dco_decode_spool_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SpoolEntry(path: dco_decode_String(arr[0]),
+sizeBytes: dco_decode_u_64(arr[1]),
+capturedAtMs: dco_decode_u_64(arr[2]),); }
+
+@protected SpoolQuotaResult dco_decode_spool_quota_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SpoolQuotaResult(deletedPaths: dco_decode_list_String(arr[0]),
+remainingFiles: dco_decode_u_32(arr[1]),
+remainingBytes: dco_decode_u_64(arr[2]),); }
+
+@protected SpooledScreenshot dco_decode_spooled_screenshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SpooledScreenshot(path: dco_decode_String(arr[0]),
+format: dco_decode_screenshot_format(arr[1]),
+width: dco_decode_u_32(arr[2]),
+height: dco_decode_u_32(arr[3]),
+sizeBytes: dco_decode_u_64(arr[4]),
+capturedAtMs: dco_decode_u_64(arr[5]),
+analysis: dco_decode_frame_analysis(arr[6]),); }
+
+@protected SystemSnapshot dco_decode_system_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return SystemSnapshot(timestampMs: dco_decode_u_64(arr[0]),
+globalCpuUsage: dco_decode_f_32(arr[1]),
+cpuCores: dco_decode_list_cpu_core_usage(arr[2]),
+totalMemoryBytes: dco_decode_u_64(arr[3]),
+usedMemoryBytes: dco_decode_u_64(arr[4]),
+availableMemoryBytes: dco_decode_u_64(arr[5]),
+totalSwapBytes: dco_decode_u_64(arr[6]),
+usedSwapBytes: dco_decode_u_64(arr[7]),
+loadAverage: dco_decode_load_average(arr[8]),
+uptimeSeconds: dco_decode_u_64(arr[9]),
+bootTime: dco_decode_u_64(arr[10]),
+disks: dco_decode_list_disk_usage_info(arr[11]),
+networks: dco_decode_list_network_interface_usage(arr[12]),); }
+
+@protected TerminateOptions dco_decode_terminate_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TerminateOptions(signal: dco_decode_terminate_signal(arr[0]),
+gracePeriodMs: dco_decode_u_64(arr[1]),
+killTree: dco_decode_bool(arr[2]),); }
+
+@protected TerminateOutcome dco_decode_terminate_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TerminateOutcome.values[raw as int]; }
+
+@protected TerminateSignal dco_decode_terminate_signal(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TerminateSignal.values[raw as int]; }
+
+@protected TimelapseConfig dco_decode_timelapse_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return TimelapseConfig(outputPath: dco_decode_String(arr[0]),
+format: dco_decode_timelapse_format(arr[1]),
+frameIntervalSeconds: dco_decode_u_64(arr[2]),
+maxWidth: dco_decode_u_32(arr[3]),
+playbackFps: dco_decode_u_32(arr[4]),
+jpegQuality: dco_decode_u_8(arr[5]),
+maxFileSizeBytes: dco_decode_u_64(arr[6]),); }
+
+@protected TimelapseFormat dco_decode_timelapse_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimelapseFormat.values[raw as int]; }
+
+@protected TimelapseState dco_decode_timelapse_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimelapseState.values[raw as int]; }
+
+@protected TimelapseStatus dco_decode_timelapse_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return TimelapseStatus(state: dco_decode_timelapse_state(arr[0]),
+outputPath: dco_decode_String(arr[1]),
+framesWritten: dco_decode_u_32(arr[2]),
+bytesWritten: dco_decode_u_64(arr[3]),
+lastError: dco_decode_opt_String(arr[4]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected WatermarkOptions dco_decode_watermark_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return WatermarkOptions(position: dco_decode_watermark_position(arr[0]),
+opacity: dco_decode_f_32(arr[1]),
+scale: dco_decode_opt_box_autoadd_u_32(arr[2]),
+includeHostname: dco_decode_bool(arr[3]),
+includeUser: dco_decode_bool(arr[4]),
+taskId: dco_decode_opt_String(arr[5]),
+customText: dco_decode_opt_String(arr[6]),); }
+
+@protected WatermarkPosition dco_decode_watermark_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return WatermarkPosition.values[raw as int]; }
+
+@protected WindowDetails dco_decode_window_details(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return WindowDetails(id: dco_decode_u_32(arr[0]),
+title: dco_decode_String(arr[1]),
+position: dco_decode_record_i_32_i_32_i_32_i_32(arr[2]),
+monitor: dco_decode_opt_box_autoadd_monitor_location(arr[3]),
+isFullScreen: dco_decode_bool(arr[4]),
+processName: dco_decode_String(arr[5]),
+processPath: dco_decode_String(arr[6]),
+processId: dco_decode_u_32(arr[7]),
+os: dco_decode_String(arr[8]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<KeyboardEvent> sse_decode_StreamSink_keyboard_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<MouseEvent> sse_decode_StreamSink_mouse_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<NotificationEvent> sse_decode_StreamSink_notification_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<PolicyViolation> sse_decode_StreamSink_policy_violation_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<PreviewFrame> sse_decode_StreamSink_preview_frame_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ProcessEvent> sse_decode_StreamSink_process_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<Screenshot> sse_decode_StreamSink_screenshot_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<SystemSnapshot> sse_decode_StreamSink_system_snapshot_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<WindowDetails> sse_decode_StreamSink_window_details_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected DeltaEncoderConfig sse_decode_box_autoadd_delta_encoder_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_delta_encoder_config(deserializer)); }
+
+@protected FrameAnalysis sse_decode_box_autoadd_frame_analysis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_frame_analysis(deserializer)); }
+
+@protected HeatmapConfig sse_decode_box_autoadd_heatmap_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_heatmap_config(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected MonitorLocation sse_decode_box_autoadd_monitor_location(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_monitor_location(deserializer)); }
+
+@protected MouseEvent sse_decode_box_autoadd_mouse_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_mouse_event(deserializer)); }
+
+@protected NotificationBackend sse_decode_box_autoadd_notification_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_notification_backend(deserializer)); }
+
+@protected NotificationBackendPreference sse_decode_box_autoadd_notification_backend_preference(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_notification_backend_preference(deserializer)); }
+
+@protected NotificationGateConfig sse_decode_box_autoadd_notification_gate_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_notification_gate_config(deserializer)); }
+
+@protected NotificationServerInfo sse_decode_box_autoadd_notification_server_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_notification_server_info(deserializer)); }
+
+@protected PolicyConfig sse_decode_box_autoadd_policy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_policy_config(deserializer)); }
+
+@protected ProcessFilter sse_decode_box_autoadd_process_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_process_filter(deserializer)); }
+
+@protected ProcessHistory sse_decode_box_autoadd_process_history(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_process_history(deserializer)); }
+
+@protected ProcessSamplerConfig sse_decode_box_autoadd_process_sampler_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_process_sampler_config(deserializer)); }
+
+@protected ProcessTreeNode sse_decode_box_autoadd_process_tree_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_process_tree_node(deserializer)); }
+
+@protected QuietHours sse_decode_box_autoadd_quiet_hours(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_quiet_hours(deserializer)); }
+
+@protected (double,double) sse_decode_box_autoadd_record_f_64_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_record_f_64_f_64(deserializer)); }
+
+@protected ResourceBudgetConfig sse_decode_box_autoadd_resource_budget_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_resource_budget_config(deserializer)); }
+
+@protected ScreenshotOptions sse_decode_box_autoadd_screenshot_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_screenshot_options(deserializer)); }
+
+@protected ScreenshotScheduleConfig sse_decode_box_autoadd_screenshot_schedule_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_screenshot_schedule_config(deserializer)); }
+
+@protected TerminateOptions sse_decode_box_autoadd_terminate_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_terminate_options(deserializer)); }
+
+@protected TimelapseConfig sse_decode_box_autoadd_timelapse_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_timelapse_config(deserializer)); }
+
+@protected TimelapseStatus sse_decode_box_autoadd_timelapse_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_timelapse_status(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected WatermarkOptions sse_decode_box_autoadd_watermark_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_watermark_options(deserializer)); }
+
+@protected CategoryGateStats sse_decode_category_gate_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_category = sse_decode_String(deserializer);
+var var_sent = sse_decode_u_64(deserializer);
+var var_deduplicated = sse_decode_u_64(deserializer);
+var var_rateLimited = sse_decode_u_64(deserializer);
+var var_queued = sse_decode_u_64(deserializer);
+var var_dropped = sse_decode_u_64(deserializer);
+return CategoryGateStats(category: var_category, sent: var_sent, deduplicated: var_deduplicated, rateLimited: var_rateLimited, queued: var_queued, dropped: var_dropped); }
+
+@protected CoordinateSpace sse_decode_coordinate_space(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CoordinateSpace.values[inner]; }
+
+@protected CpuCoreUsage sse_decode_cpu_core_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_usage = sse_decode_f_32(deserializer);
+var var_frequencyMhz = sse_decode_u_64(deserializer);
+return CpuCoreUsage(name: var_name, usage: var_usage, frequencyMhz: var_frequencyMhz); }
+
+@protected DeltaEncoderConfig sse_decode_delta_encoder_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_tileSize = sse_decode_u_32(deserializer);
+var var_keyframeInterval = sse_decode_u_32(deserializer);
+var var_maxChangedRatio = sse_decode_f_32(deserializer);
+return DeltaEncoderConfig(tileSize: var_tileSize, keyframeInterval: var_keyframeInterval, maxChangedRatio: var_maxChangedRatio); }
+
+@protected DiskUsageInfo sse_decode_disk_usage_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_mountPoint = sse_decode_String(deserializer);
+var var_fileSystem = sse_decode_String(deserializer);
+var var_totalBytes = sse_decode_u_64(deserializer);
+var var_availableBytes = sse_decode_u_64(deserializer);
+var var_isRemovable = sse_decode_bool(deserializer);
+return DiskUsageInfo(name: var_name, mountPoint: var_mountPoint, fileSystem: var_fileSystem, totalBytes: var_totalBytes, availableBytes: var_availableBytes, isRemovable: var_isRemovable); }
+
+@protected DisplayGeometry sse_decode_display_geometry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_isPrimary = sse_decode_bool(deserializer);
+var var_scaleFactor = sse_decode_f_32(deserializer);
+var var_logical = sse_decode_display_rect(deserializer);
+var var_physical = sse_decode_display_rect(deserializer);
+return DisplayGeometry(id: var_id, isPrimary: var_isPrimary, scaleFactor: var_scaleFactor, logical: var_logical, physical: var_physical); }
+
+@protected DisplayRect sse_decode_display_rect(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_x = sse_decode_i_32(deserializer);
+var var_y = sse_decode_i_32(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+return DisplayRect(x: var_x, y: var_y, width: var_width, height: var_height); }
+
+@protected EncodedFrame sse_decode_encoded_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_isKeyframe = sse_decode_bool(deserializer);
+var var_frameIndex = sse_decode_u_32(deserializer);
+var var_changedTiles = sse_decode_u_32(deserializer);
+var var_totalTiles = sse_decode_u_32(deserializer);
+return EncodedFrame(data: var_data, isKeyframe: var_isKeyframe, frameIndex: var_frameIndex, changedTiles: var_changedTiles, totalTiles: var_totalTiles); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FrameAnalysis sse_decode_frame_analysis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_classification = sse_decode_frame_classification(deserializer);
+var var_meanLuminance = sse_decode_f_32(deserializer);
+var var_luminanceVariance = sse_decode_f_32(deserializer);
+var var_dominantBinRatio = sse_decode_f_32(deserializer);
+var var_darkPixelRatio = sse_decode_f_32(deserializer);
+return FrameAnalysis(classification: var_classification, meanLuminance: var_meanLuminance, luminanceVariance: var_luminanceVariance, dominantBinRatio: var_dominantBinRatio, darkPixelRatio: var_darkPixelRatio); }
+
+@protected FrameClassification sse_decode_frame_classification(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FrameClassification.values[inner]; }
+
+@protected GateDecision sse_decode_gate_decision(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return GateDecision.values[inner]; }
+
+@protected GatedSendResult sse_decode_gated_send_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_decision = sse_decode_gate_decision(deserializer);
+var var_backend = sse_decode_opt_box_autoadd_notification_backend(deserializer);
+return GatedSendResult(decision: var_decision, backend: var_backend); }
+
+@protected HeatmapConfig sse_decode_heatmap_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scope = sse_decode_heatmap_scope(deserializer);
+var var_gridWidth = sse_decode_u_32(deserializer);
+var var_gridHeight = sse_decode_u_32(deserializer);
+var var_includeRightClicks = sse_decode_bool(deserializer);
+return HeatmapConfig(scope: var_scope, gridWidth: var_gridWidth, gridHeight: var_gridHeight, includeRightClicks: var_includeRightClicks); }
+
+@protected HeatmapGrid sse_decode_heatmap_grid(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scope = sse_decode_heatmap_scope(deserializer);
+var var_gridWidth = sse_decode_u_32(deserializer);
+var var_gridHeight = sse_decode_u_32(deserializer);
+var var_cells = sse_decode_list_prim_u_32_strict(deserializer);
+var var_totalClicks = sse_decode_u_64(deserializer);
+return HeatmapGrid(scope: var_scope, gridWidth: var_gridWidth, gridHeight: var_gridHeight, cells: var_cells, totalClicks: var_totalClicks); }
+
+@protected HeatmapScope sse_decode_heatmap_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_heatmap_scope_kind(deserializer);
+var var_id = sse_decode_u_32(deserializer);
+return HeatmapScope(kind: var_kind, id: var_id); }
+
+@protected HeatmapScopeKind sse_decode_heatmap_scope_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return HeatmapScopeKind.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected KeyboardEvent sse_decode_keyboard_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_isKeyPress = sse_decode_bool(deserializer);
+return KeyboardEvent(key: var_key, isKeyPress: var_isKeyPress); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CategoryGateStats> sse_decode_list_category_gate_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CategoryGateStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_category_gate_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CpuCoreUsage> sse_decode_list_cpu_core_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CpuCoreUsage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cpu_core_usage(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DiskUsageInfo> sse_decode_list_disk_usage_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DiskUsageInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_disk_usage_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DisplayGeometry> sse_decode_list_display_geometry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DisplayGeometry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_display_geometry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NetworkInterfaceUsage> sse_decode_list_network_interface_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NetworkInterfaceUsage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_network_interface_usage(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NotificationAction> sse_decode_list_notification_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NotificationAction>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_notification_action(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NotificationBackend> sse_decode_list_notification_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NotificationBackend>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_notification_backend(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NotificationBackendProbe> sse_decode_list_notification_backend_probe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NotificationBackendProbe>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_notification_backend_probe(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PolicyRule> sse_decode_list_policy_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PolicyRule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_policy_rule(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProcessInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_process_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProcessSample> sse_decode_list_process_sample(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProcessSample>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_process_sample(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProcessTreeNode> sse_decode_list_process_tree_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProcessTreeNode>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_process_tree_node(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProcessUsageSummary> sse_decode_list_process_usage_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProcessUsageSummary>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_process_usage_summary(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SpoolEntry> sse_decode_list_spool_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SpoolEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_spool_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<WindowDetails> sse_decode_list_window_details(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <WindowDetails>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_window_details(deserializer)); }
+        return ans_;
+         }
+
+@protected LoadAverage sse_decode_load_average(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_one = sse_decode_f_64(deserializer);
+var var_five = sse_decode_f_64(deserializer);
+var var_fifteen = sse_decode_f_64(deserializer);
+return LoadAverage(one: var_one, five: var_five, fifteen: var_fifteen); }
+
+@protected MonitorLocation sse_decode_monitor_location(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_monitorId = sse_decode_u_32(deserializer);
+var var_x = sse_decode_i_32(deserializer);
+var var_y = sse_decode_i_32(deserializer);
+return MonitorLocation(monitorId: var_monitorId, x: var_x, y: var_y); }
+
+@protected MouseEvent sse_decode_mouse_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_button = sse_decode_String(deserializer);
+var var_isButtonPress = sse_decode_bool(deserializer);
+var var_coords = sse_decode_record_i_32_i_32(deserializer);
+var var_monitor = sse_decode_opt_box_autoadd_monitor_location(deserializer);
+var var_isLeftClick = sse_decode_bool(deserializer);
+var var_isRightClick = sse_decode_bool(deserializer);
+return MouseEvent(button: var_button, isButtonPress: var_isButtonPress, coords: var_coords, monitor: var_monitor, isLeftClick: var_isLeftClick, isRightClick: var_isRightClick); }
+
+@protected NetworkInterfaceUsage sse_decode_network_interface_usage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_receivedBytes = sse_decode_u_64(deserializer);
+var var_transmittedBytes = sse_decode_u_64(deserializer);
+return NetworkInterfaceUsage(name: var_name, receivedBytes: var_receivedBytes, transmittedBytes: var_transmittedBytes); }
+
+@protected NotificationAction sse_decode_notification_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_label = sse_decode_String(deserializer);
+return NotificationAction(id: var_id, label: var_label); }
+
+@protected NotificationBackend sse_decode_notification_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NotificationBackend.values[inner]; }
+
+@protected NotificationBackendCapabilities sse_decode_notification_backend_capabilities(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_actions = sse_decode_bool(deserializer);
+var var_bodyMarkup = sse_decode_bool(deserializer);
+var var_icons = sse_decode_bool(deserializer);
+var var_persistence = sse_decode_bool(deserializer);
+return NotificationBackendCapabilities(actions: var_actions, bodyMarkup: var_bodyMarkup, icons: var_icons, persistence: var_persistence); }
+
+@protected NotificationBackendPreference sse_decode_notification_backend_preference(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pinned = sse_decode_opt_box_autoadd_notification_backend(deserializer);
+var var_excluded = sse_decode_list_notification_backend(deserializer);
+return NotificationBackendPreference(pinned: var_pinned, excluded: var_excluded); }
+
+@protected NotificationBackendProbe sse_decode_notification_backend_probe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_backend = sse_decode_notification_backend(deserializer);
+var var_available = sse_decode_bool(deserializer);
+var var_disabled = sse_decode_bool(deserializer);
+var var_capabilities = sse_decode_notification_backend_capabilities(deserializer);
+var var_server = sse_decode_opt_box_autoadd_notification_server_info(deserializer);
+return NotificationBackendProbe(backend: var_backend, available: var_available, disabled: var_disabled, capabilities: var_capabilities, server: var_server); }
+
+@protected NotificationEvent sse_decode_notification_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_notification_event_kind(deserializer);
+var var_actionId = sse_decode_opt_String(deserializer);
+return NotificationEvent(kind: var_kind, actionId: var_actionId); }
+
+@protected NotificationEventKind sse_decode_notification_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NotificationEventKind.values[inner]; }
+
+@protected NotificationGateConfig sse_decode_notification_gate_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dedupeWindowSeconds = sse_decode_u_64(deserializer);
+var var_maxPerCategory = sse_decode_u_32(deserializer);
+var var_rateWindowSeconds = sse_decode_u_64(deserializer);
+var var_quietHours = sse_decode_opt_box_autoadd_quiet_hours(deserializer);
+var var_doNotDisturb = sse_decode_bool(deserializer);
+return NotificationGateConfig(dedupeWindowSeconds: var_dedupeWindowSeconds, maxPerCategory: var_maxPerCategory, rateWindowSeconds: var_rateWindowSeconds, quietHours: var_quietHours, doNotDisturb: var_doNotDisturb); }
+
+@protected NotificationGateStats sse_decode_notification_gate_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pending = sse_decode_u_32(deserializer);
+var var_categories = sse_decode_list_category_gate_stats(deserializer);
+return NotificationGateStats(pending: var_pending, categories: var_categories); }
+
+@protected NotificationServerInfo sse_decode_notification_server_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_vendor = sse_decode_String(deserializer);
+var var_version = sse_decode_String(deserializer);
+var var_specVersion = sse_decode_String(deserializer);
+return NotificationServerInfo(name: var_name, vendor: var_vendor, version: var_version, specVersion: var_specVersion); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected MonitorLocation? sse_decode_opt_box_autoadd_monitor_location(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_monitor_location(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NotificationBackend? sse_decode_opt_box_autoadd_notification_backend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_notification_backend(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NotificationServerInfo? sse_decode_opt_box_autoadd_notification_server_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_notification_server_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProcessHistory? sse_decode_opt_box_autoadd_process_history(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_process_history(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProcessTreeNode? sse_decode_opt_box_autoadd_process_tree_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_process_tree_node(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected QuietHours? sse_decode_opt_box_autoadd_quiet_hours(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_quiet_hours(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected (double,double)? sse_decode_opt_box_autoadd_record_f_64_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_record_f_64_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TimelapseStatus? sse_decode_opt_box_autoadd_timelapse_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_timelapse_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WatermarkOptions? sse_decode_opt_box_autoadd_watermark_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_watermark_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PolicyAction sse_decode_policy_action(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PolicyAction.values[inner]; }
+
+@protected PolicyConfig sse_decode_policy_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rules = sse_decode_list_policy_rule(deserializer);
+var var_intervalMs = sse_decode_u_64(deserializer);
+return PolicyConfig(rules: var_rules, intervalMs: var_intervalMs); }
+
+@protected PolicyEnforcement sse_decode_policy_enforcement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PolicyEnforcement.values[inner]; }
+
+@protected PolicyRule sse_decode_policy_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_namePatterns = sse_decode_list_String(deserializer);
+var var_pathPatterns = sse_decode_list_String(deserializer);
+var var_windowTitlePatterns = sse_decode_list_String(deserializer);
+var var_action = sse_decode_policy_action(deserializer);
+var var_graceSeconds = sse_decode_u_32(deserializer);
+var var_message = sse_decode_opt_String(deserializer);
+return PolicyRule(id: var_id, namePatterns: var_namePatterns, pathPatterns: var_pathPatterns, windowTitlePatterns: var_windowTitlePatterns, action: var_action, graceSeconds: var_graceSeconds, message: var_message); }
+
+@protected PolicyViolation sse_decode_policy_violation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ruleId = sse_decode_String(deserializer);
+var var_pid = sse_decode_u_32(deserializer);
+var var_processName = sse_decode_String(deserializer);
+var var_exePath = sse_decode_opt_String(deserializer);
+var var_windowTitle = sse_decode_opt_String(deserializer);
+var var_enforcement = sse_decode_policy_enforcement(deserializer);
+var var_killAtMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+return PolicyViolation(ruleId: var_ruleId, pid: var_pid, processName: var_processName, exePath: var_exePath, windowTitle: var_windowTitle, enforcement: var_enforcement, killAtMs: var_killAtMs, timestampMs: var_timestampMs); }
+
+@protected PreviewFrame sse_decode_preview_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_jpeg = sse_decode_list_prim_u_8_strict(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_capturedAtMs = sse_decode_u_64(deserializer);
+var var_classification = sse_decode_frame_classification(deserializer);
+return PreviewFrame(jpeg: var_jpeg, width: var_width, height: var_height, capturedAtMs: var_capturedAtMs, classification: var_classification); }
+
+@protected ProcessEvent sse_decode_process_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_process_event_kind(deserializer);
+var var_pid = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_exePath = sse_decode_String(deserializer);
+var var_startTime = sse_decode_u_64(deserializer);
+var var_runDurationSeconds = sse_decode_opt_box_autoadd_u_64(deserializer);
+return ProcessEvent(kind: var_kind, pid: var_pid, name: var_name, exePath: var_exePath, startTime: var_startTime, runDurationSeconds: var_runDurationSeconds); }
+
+@protected ProcessEventKind sse_decode_process_event_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProcessEventKind.values[inner]; }
+
+@protected ProcessFilter sse_decode_process_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_namePatterns = sse_decode_list_String(deserializer);
+var var_pathPatterns = sse_decode_list_String(deserializer);
+var var_includeExisting = sse_decode_bool(deserializer);
+return ProcessFilter(namePatterns: var_namePatterns, pathPatterns: var_pathPatterns, includeExisting: var_includeExisting); }
+
+@protected ProcessHistory sse_decode_process_history(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_samples = sse_decode_list_process_sample(deserializer);
+return ProcessHistory(pid: var_pid, name: var_name, samples: var_samples); }
+
+@protected ProcessInfo sse_decode_process_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_cmd = sse_decode_list_String(deserializer);
+var var_memoryUsage = sse_decode_u_64(deserializer);
+var var_cpuUsage = sse_decode_f_32(deserializer);
+var var_parentPid = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_user = sse_decode_opt_String(deserializer);
+var var_exePath = sse_decode_opt_String(deserializer);
+var var_startTime = sse_decode_u_64(deserializer);
+var var_runTime = sse_decode_u_64(deserializer);
+var var_status = sse_decode_String(deserializer);
+var var_diskReadBytes = sse_decode_u_64(deserializer);
+var var_diskWrittenBytes = sse_decode_u_64(deserializer);
+return ProcessInfo(pid: var_pid, name: var_name, cmd: var_cmd, memoryUsage: var_memoryUsage, cpuUsage: var_cpuUsage, parentPid: var_parentPid, user: var_user, exePath: var_exePath, startTime: var_startTime, runTime: var_runTime, status: var_status, diskReadBytes: var_diskReadBytes, diskWrittenBytes: var_diskWrittenBytes); }
+
+@protected ProcessSample sse_decode_process_sample(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_cpuUsage = sse_decode_f_32(deserializer);
+var var_memoryBytes = sse_decode_u_64(deserializer);
+return ProcessSample(timestampMs: var_timestampMs, cpuUsage: var_cpuUsage, memoryBytes: var_memoryBytes); }
+
+@protected ProcessSamplerConfig sse_decode_process_sampler_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_intervalMs = sse_decode_u_64(deserializer);
+var var_historyLen = sse_decode_u_32(deserializer);
+return ProcessSamplerConfig(intervalMs: var_intervalMs, historyLen: var_historyLen); }
+
+@protected ProcessSortKey sse_decode_process_sort_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProcessSortKey.values[inner]; }
+
+@protected ProcessTreeNode sse_decode_process_tree_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_process = sse_decode_process_info(deserializer);
+var var_children = sse_decode_list_process_tree_node(deserializer);
+return ProcessTreeNode(process: var_process, children: var_children); }
+
+@protected ProcessUsageSummary sse_decode_process_usage_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pid = sse_decode_u_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_averageCpu = sse_decode_f_32(deserializer);
+var var_peakCpu = sse_decode_f_32(deserializer);
+var var_averageMemoryBytes = sse_decode_u_64(deserializer);
+var var_peakMemoryBytes = sse_decode_u_64(deserializer);
+var var_sampleCount = sse_decode_u_32(deserializer);
+return ProcessUsageSummary(pid: var_pid, name: var_name, averageCpu: var_averageCpu, peakCpu: var_peakCpu, averageMemoryBytes: var_averageMemoryBytes, peakMemoryBytes: var_peakMemoryBytes, sampleCount: var_sampleCount); }
+
+@protected QuietHours sse_decode_quiet_hours(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startMinute = sse_decode_u_32(deserializer);
+var var_endMinute = sse_decode_u_32(deserializer);
+var var_utcOffsetMinutes = sse_decode_i_32(deserializer);
+return QuietHours(startMinute: var_startMinute, endMinute: var_endMinute, utcOffsetMinutes: var_utcOffsetMinutes); }
+
+@protected (double,double) sse_decode_record_f_64_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_f_64(deserializer);
+var var_field1 = sse_decode_f_64(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,int) sse_decode_record_i_32_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_i_32(deserializer);
+var var_field1 = sse_decode_i_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,int,int,int) sse_decode_record_i_32_i_32_i_32_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_i_32(deserializer);
+var var_field1 = sse_decode_i_32(deserializer);
+var var_field2 = sse_decode_i_32(deserializer);
+var var_field3 = sse_decode_i_32(deserializer);
+return (var_field0, var_field1, var_field2, var_field3); }
+
+@protected ResourceBudgetConfig sse_decode_resource_budget_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxCpuPercent = sse_decode_f_32(deserializer);
+var var_maxMemoryBytes = sse_decode_u_64(deserializer);
+var var_sampleIntervalMs = sse_decode_u_64(deserializer);
+var var_maxThrottleFactor = sse_decode_f_64(deserializer);
+var var_batteryThrottleFactor = sse_decode_f_64(deserializer);
+return ResourceBudgetConfig(maxCpuPercent: var_maxCpuPercent, maxMemoryBytes: var_maxMemoryBytes, sampleIntervalMs: var_sampleIntervalMs, maxThrottleFactor: var_maxThrottleFactor, batteryThrottleFactor: var_batteryThrottleFactor); }
+
+@protected ResourceBudgetStatus sse_decode_resource_budget_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_running = sse_decode_bool(deserializer);
+var var_cpuPercent = sse_decode_f_32(deserializer);
+var var_memoryBytes = sse_decode_u_64(deserializer);
+var var_onBattery = sse_decode_bool(deserializer);
+var var_throttleFactor = sse_decode_f_64(deserializer);
+var var_encodingDeferred = sse_decode_bool(deserializer);
+var var_inputPollIntervalMs = sse_decode_u_64(deserializer);
+var var_windowPollIntervalMs = sse_decode_u_64(deserializer);
+return ResourceBudgetStatus(running: var_running, cpuPercent: var_cpuPercent, memoryBytes: var_memoryBytes, onBattery: var_onBattery, throttleFactor: var_throttleFactor, encodingDeferred: var_encodingDeferred, inputPollIntervalMs: var_inputPollIntervalMs, windowPollIntervalMs: var_windowPollIntervalMs); }
+
+@protected Screenshot sse_decode_screenshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_String(deserializer);
+var var_format = sse_decode_screenshot_format(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_capturedAtMs = sse_decode_u_64(deserializer);
+var var_analysis = sse_decode_frame_analysis(deserializer);
+var var_monitorId = sse_decode_opt_box_autoadd_u_32(deserializer);
+return Screenshot(data: var_data, format: var_format, width: var_width, height: var_height, capturedAtMs: var_capturedAtMs, analysis: var_analysis, monitorId: var_monitorId); }
+
+@protected ScreenshotFormat sse_decode_screenshot_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ScreenshotFormat.values[inner]; }
+
+@protected ScreenshotOptions sse_decode_screenshot_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_format = sse_decode_screenshot_format(deserializer);
+var var_jpegQuality = sse_decode_u_8(deserializer);
+var var_maxWidth = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_watermark = sse_decode_opt_box_autoadd_watermark_options(deserializer);
+var var_includeCursor = sse_decode_bool(deserializer);
+return ScreenshotOptions(format: var_format, jpegQuality: var_jpegQuality, maxWidth: var_maxWidth, watermark: var_watermark, includeCursor: var_includeCursor); }
+
+@protected ScreenshotScheduleConfig sse_decode_screenshot_schedule_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_intervalSeconds = sse_decode_u_64(deserializer);
+var var_capturesPerInterval = sse_decode_u_32(deserializer);
+var var_idleThresholdSeconds = sse_decode_u_64(deserializer);
+var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_options = sse_decode_screenshot_options(deserializer);
+return ScreenshotScheduleConfig(intervalSeconds: var_intervalSeconds, capturesPerInterval: var_capturesPerInterval, idleThresholdSeconds: var_idleThresholdSeconds, seed: var_seed, options: var_options); }
+
+@protected SpoolEntry sse_decode_spool_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_sizeBytes = sse_decode_u_64(deserializer);
+var var_capturedAtMs = sse_decode_u_64(deserializer);
+return SpoolEntry(path: var_path, sizeBytes: var_sizeBytes, capturedAtMs: var_capturedAtMs); }
+
+@protected SpoolQuotaResult sse_decode_spool_quota_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_deletedPaths = sse_decode_list_String(deserializer);
+var var_remainingFiles = sse_decode_u_32(deserializer);
+var var_remainingBytes = sse_decode_u_64(deserializer);
+return SpoolQuotaResult(deletedPaths: var_deletedPaths, remainingFiles: var_remainingFiles, remainingBytes: var_remainingBytes); }
+
+@protected SpooledScreenshot sse_decode_spooled_screenshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_format = sse_decode_screenshot_format(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_sizeBytes = sse_decode_u_64(deserializer);
+var var_capturedAtMs = sse_decode_u_64(deserializer);
+var var_analysis = sse_decode_frame_analysis(deserializer);
+return SpooledScreenshot(path: var_path, format: var_format, width: var_width, height: var_height, sizeBytes: var_sizeBytes, capturedAtMs: var_capturedAtMs, analysis: var_analysis); }
+
+@protected SystemSnapshot sse_decode_system_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_globalCpuUsage = sse_decode_f_32(deserializer);
+var var_cpuCores = sse_decode_list_cpu_core_usage(deserializer);
+var var_totalMemoryBytes = sse_decode_u_64(deserializer);
+var var_usedMemoryBytes = sse_decode_u_64(deserializer);
+var var_availableMemoryBytes = sse_decode_u_64(deserializer);
+var var_totalSwapBytes = sse_decode_u_64(deserializer);
+var var_usedSwapBytes = sse_decode_u_64(deserializer);
+var var_loadAverage = sse_decode_load_average(deserializer);
+var var_uptimeSeconds = sse_decode_u_64(deserializer);
+var var_bootTime = sse_decode_u_64(deserializer);
+var var_disks = sse_decode_list_disk_usage_info(deserializer);
+var var_networks = sse_decode_list_network_interface_usage(deserializer);
+return SystemSnapshot(timestampMs: var_timestampMs, globalCpuUsage: var_globalCpuUsage, cpuCores: var_cpuCores, totalMemoryBytes: var_totalMemoryBytes, usedMemoryBytes: var_usedMemoryBytes, availableMemoryBytes: var_availableMemoryBytes, totalSwapBytes: var_totalSwapBytes, usedSwapBytes: var_usedSwapBytes, loadAverage: var_loadAverage, uptimeSeconds: var_uptimeSeconds, bootTime: var_bootTime, disks: var_disks, networks: var_networks); }
+
+@protected TerminateOptions sse_decode_terminate_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_signal = sse_decode_terminate_signal(deserializer);
+var var_gracePeriodMs = sse_decode_u_64(deserializer);
+var var_killTree = sse_decode_bool(deserializer);
+return TerminateOptions(signal: var_signal, gracePeriodMs: var_gracePeriodMs, killTree: var_killTree); }
+
+@protected TerminateOutcome sse_decode_terminate_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TerminateOutcome.values[inner]; }
+
+@protected TerminateSignal sse_decode_terminate_signal(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TerminateSignal.values[inner]; }
+
+@protected TimelapseConfig sse_decode_timelapse_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_outputPath = sse_decode_String(deserializer);
+var var_format = sse_decode_timelapse_format(deserializer);
+var var_frameIntervalSeconds = sse_decode_u_64(deserializer);
+var var_maxWidth = sse_decode_u_32(deserializer);
+var var_playbackFps = sse_decode_u_32(deserializer);
+var var_jpegQuality = sse_decode_u_8(deserializer);
+var var_maxFileSizeBytes = sse_decode_u_64(deserializer);
+return TimelapseConfig(outputPath: var_outputPath, format: var_format, frameIntervalSeconds: var_frameIntervalSeconds, maxWidth: var_maxWidth, playbackFps: var_playbackFps, jpegQuality: var_jpegQuality, maxFileSizeBytes: var_maxFileSizeBytes); }
+
+@protected TimelapseFormat sse_decode_timelapse_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TimelapseFormat.values[inner]; }
+
+@protected TimelapseState sse_decode_timelapse_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TimelapseState.values[inner]; }
+
+@protected TimelapseStatus sse_decode_timelapse_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_timelapse_state(deserializer);
+var var_outputPath = sse_decode_String(deserializer);
+var var_framesWritten = sse_decode_u_32(deserializer);
+var var_bytesWritten = sse_decode_u_64(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return TimelapseStatus(state: var_state, outputPath: var_outputPath, framesWritten: var_framesWritten, bytesWritten: var_bytesWritten, lastError: var_lastError); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected WatermarkOptions sse_decode_watermark_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_position = sse_decode_watermark_position(deserializer);
+var var_opacity = sse_decode_f_32(deserializer);
+var var_scale = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_includeHostname = sse_decode_bool(deserializer);
+var var_includeUser = sse_decode_bool(deserializer);
+var var_taskId = sse_decode_opt_String(deserializer);
+var var_customText = sse_decode_opt_String(deserializer);
+return WatermarkOptions(position: var_position, opacity: var_opacity, scale: var_scale, includeHostname: var_includeHostname, includeUser: var_includeUser, taskId: var_taskId, customText: var_customText); }
+
+@protected WatermarkPosition sse_decode_watermark_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return WatermarkPosition.values[inner]; }
+
+@protected WindowDetails sse_decode_window_details(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_32(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_position = sse_decode_record_i_32_i_32_i_32_i_32(deserializer);
+var var_monitor = sse_decode_opt_box_autoadd_monitor_location(deserializer);
+var var_isFullScreen = sse_decode_bool(deserializer);
+var var_processName = sse_decode_String(deserializer);
+var var_processPath = sse_decode_String(deserializer);
+var var_processId = sse_decode_u_32(deserializer);
+var var_os = sse_decode_String(deserializer);
+return WindowDetails(id: var_id, title: var_title, position: var_position, monitor: var_monitor, isFullScreen: var_isFullScreen, processName: var_processName, processPath: var_processPath, processId: var_processId, os: var_os); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_keyboard_event_Sse(RustStreamSink<KeyboardEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_keyboard_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_mouse_event_Sse(RustStreamSink<MouseEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_mouse_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_notification_event_Sse(RustStreamSink<NotificationEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_notification_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_policy_violation_Sse(RustStreamSink<PolicyViolation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_policy_violation,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_preview_frame_Sse(RustStreamSink<PreviewFrame> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_preview_frame,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_process_event_Sse(RustStreamSink<ProcessEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_process_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_screenshot_Sse(RustStreamSink<Screenshot> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_screenshot,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_system_snapshot_Sse(RustStreamSink<SystemSnapshot> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_system_snapshot,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_window_details_Sse(RustStreamSink<WindowDetails> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_window_details,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_delta_encoder_config(DeltaEncoderConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_delta_encoder_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_frame_analysis(FrameAnalysis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_frame_analysis(self, serializer); }
+
+@protected void sse_encode_box_autoadd_heatmap_config(HeatmapConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_heatmap_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_monitor_location(MonitorLocation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_monitor_location(self, serializer); }
+
+@protected void sse_encode_box_autoadd_mouse_event(MouseEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_mouse_event(self, serializer); }
+
+@protected void sse_encode_box_autoadd_notification_backend(NotificationBackend self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_notification_backend(self, serializer); }
+
+@protected void sse_encode_box_autoadd_notification_backend_preference(NotificationBackendPreference self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_notification_backend_preference(self, serializer); }
+
+@protected void sse_encode_box_autoadd_notification_gate_config(NotificationGateConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_notification_gate_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_notification_server_info(NotificationServerInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_notification_server_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_policy_config(PolicyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_policy_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_process_filter(ProcessFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_process_history(ProcessHistory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_history(self, serializer); }
+
+@protected void sse_encode_box_autoadd_process_sampler_config(ProcessSamplerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_sampler_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_process_tree_node(ProcessTreeNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_tree_node(self, serializer); }
+
+@protected void sse_encode_box_autoadd_quiet_hours(QuietHours self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_quiet_hours(self, serializer); }
+
+@protected void sse_encode_box_autoadd_record_f_64_f_64((double,double) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_f_64_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_resource_budget_config(ResourceBudgetConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_resource_budget_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_screenshot_options(ScreenshotOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_screenshot_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_screenshot_schedule_config(ScreenshotScheduleConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_screenshot_schedule_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_terminate_options(TerminateOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_terminate_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_timelapse_config(TimelapseConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_timelapse_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_timelapse_status(TimelapseStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_timelapse_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_watermark_options(WatermarkOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_watermark_options(self, serializer); }
+
+@protected void sse_encode_category_gate_stats(CategoryGateStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.category, serializer);
+sse_encode_u_64(self.sent, serializer);
+sse_encode_u_64(self.deduplicated, serializer);
+sse_encode_u_64(self.rateLimited, serializer);
+sse_encode_u_64(self.queued, serializer);
+sse_encode_u_64(self.dropped, serializer);
+ }
+
+@protected void sse_encode_coordinate_space(CoordinateSpace self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_cpu_core_usage(CpuCoreUsage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_f_32(self.usage, serializer);
+sse_encode_u_64(self.frequencyMhz, serializer);
+ }
+
+@protected void sse_encode_delta_encoder_config(DeltaEncoderConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.tileSize, serializer);
+sse_encode_u_32(self.keyframeInterval, serializer);
+sse_encode_f_32(self.maxChangedRatio, serializer);
+ }
+
+@protected void sse_encode_disk_usage_info(DiskUsageInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.mountPoint, serializer);
+sse_encode_String(self.fileSystem, serializer);
+sse_encode_u_64(self.totalBytes, serializer);
+sse_encode_u_64(self.availableBytes, serializer);
+sse_encode_bool(self.isRemovable, serializer);
+ }
+
+@protected void sse_encode_display_geometry(DisplayGeometry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_bool(self.isPrimary, serializer);
+sse_encode_f_32(self.scaleFactor, serializer);
+sse_encode_display_rect(self.logical, serializer);
+sse_encode_display_rect(self.physical, serializer);
+ }
+
+@protected void sse_encode_display_rect(DisplayRect self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.x, serializer);
+sse_encode_i_32(self.y, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+ }
+
+@protected void sse_encode_encoded_frame(EncodedFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_bool(self.isKeyframe, serializer);
+sse_encode_u_32(self.frameIndex, serializer);
+sse_encode_u_32(self.changedTiles, serializer);
+sse_encode_u_32(self.totalTiles, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_frame_analysis(FrameAnalysis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_frame_classification(self.classification, serializer);
+sse_encode_f_32(self.meanLuminance, serializer);
+sse_encode_f_32(self.luminanceVariance, serializer);
+sse_encode_f_32(self.dominantBinRatio, serializer);
+sse_encode_f_32(self.darkPixelRatio, serializer);
+ }
+
+@protected void sse_encode_frame_classification(FrameClassification self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_gate_decision(GateDecision self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_gated_send_result(GatedSendResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_gate_decision(self.decision, serializer);
+sse_encode_opt_box_autoadd_notification_backend(self.backend, serializer);
+ }
+
+@protected void sse_encode_heatmap_config(HeatmapConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_heatmap_scope(self.scope, serializer);
+sse_encode_u_32(self.gridWidth, serializer);
+sse_encode_u_32(self.gridHeight, serializer);
+sse_encode_bool(self.includeRightClicks, serializer);
+ }
+
+@protected void sse_encode_heatmap_grid(HeatmapGrid self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_heatmap_scope(self.scope, serializer);
+sse_encode_u_32(self.gridWidth, serializer);
+sse_encode_u_32(self.gridHeight, serializer);
+sse_encode_list_prim_u_32_strict(self.cells, serializer);
+sse_encode_u_64(self.totalClicks, serializer);
+ }
+
+@protected void sse_encode_heatmap_scope(HeatmapScope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_heatmap_scope_kind(self.kind, serializer);
+sse_encode_u_32(self.id, serializer);
+ }
+
+@protected void sse_encode_heatmap_scope_kind(HeatmapScopeKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_keyboard_event(KeyboardEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_bool(self.isKeyPress, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_category_gate_stats(List<CategoryGateStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_category_gate_stats(item, serializer); } }
+
+@protected void sse_encode_list_cpu_core_usage(List<CpuCoreUsage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cpu_core_usage(item, serializer); } }
+
+@protected void sse_encode_list_disk_usage_info(List<DiskUsageInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_disk_usage_info(item, serializer); } }
+
+@protected void sse_encode_list_display_geometry(List<DisplayGeometry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_display_geometry(item, serializer); } }
+
+@protected void sse_encode_list_network_interface_usage(List<NetworkInterfaceUsage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_network_interface_usage(item, serializer); } }
+
+@protected void sse_encode_list_notification_action(List<NotificationAction> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_notification_action(item, serializer); } }
+
+@protected void sse_encode_list_notification_backend(List<NotificationBackend> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_notification_backend(item, serializer); } }
+
+@protected void sse_encode_list_notification_backend_probe(List<NotificationBackendProbe> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_notification_backend_probe(item, serializer); } }
+
+@protected void sse_encode_list_policy_rule(List<PolicyRule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_policy_rule(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_process_info(List<ProcessInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_process_info(item, serializer); } }
+
+@protected void sse_encode_list_process_sample(List<ProcessSample> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_process_sample(item, serializer); } }
+
+@protected void sse_encode_list_process_tree_node(List<ProcessTreeNode> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_process_tree_node(item, serializer); } }
+
+@protected void sse_encode_list_process_usage_summary(List<ProcessUsageSummary> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_process_usage_summary(item, serializer); } }
+
+@protected void sse_encode_list_spool_entry(List<SpoolEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_spool_entry(item, serializer); } }
+
+@protected void sse_encode_list_window_details(List<WindowDetails> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_window_details(item, serializer); } }
+
+@protected void sse_encode_load_average(LoadAverage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.one, serializer);
+sse_encode_f_64(self.five, serializer);
+sse_encode_f_64(self.fifteen, serializer);
+ }
+
+@protected void sse_encode_monitor_location(MonitorLocation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.monitorId, serializer);
+sse_encode_i_32(self.x, serializer);
+sse_encode_i_32(self.y, serializer);
+ }
+
+@protected void sse_encode_mouse_event(MouseEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.button, serializer);
+sse_encode_bool(self.isButtonPress, serializer);
+sse_encode_record_i_32_i_32(self.coords, serializer);
+sse_encode_opt_box_autoadd_monitor_location(self.monitor, serializer);
+sse_encode_bool(self.isLeftClick, serializer);
+sse_encode_bool(self.isRightClick, serializer);
+ }
+
+@protected void sse_encode_network_interface_usage(NetworkInterfaceUsage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.receivedBytes, serializer);
+sse_encode_u_64(self.transmittedBytes, serializer);
+ }
+
+@protected void sse_encode_notification_action(NotificationAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.label, serializer);
+ }
+
+@protected void sse_encode_notification_backend(NotificationBackend self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_notification_backend_capabilities(NotificationBackendCapabilities self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.actions, serializer);
+sse_encode_bool(self.bodyMarkup, serializer);
+sse_encode_bool(self.icons, serializer);
+sse_encode_bool(self.persistence, serializer);
+ }
+
+@protected void sse_encode_notification_backend_preference(NotificationBackendPreference self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_notification_backend(self.pinned, serializer);
+sse_encode_list_notification_backend(self.excluded, serializer);
+ }
+
+@protected void sse_encode_notification_backend_probe(NotificationBackendProbe self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_notification_backend(self.backend, serializer);
+sse_encode_bool(self.available, serializer);
+sse_encode_bool(self.disabled, serializer);
+sse_encode_notification_backend_capabilities(self.capabilities, serializer);
+sse_encode_opt_box_autoadd_notification_server_info(self.server, serializer);
+ }
+
+@protected void sse_encode_notification_event(NotificationEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_notification_event_kind(self.kind, serializer);
+sse_encode_opt_String(self.actionId, serializer);
+ }
+
+@protected void sse_encode_notification_event_kind(NotificationEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_notification_gate_config(NotificationGateConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.dedupeWindowSeconds, serializer);
+sse_encode_u_32(self.maxPerCategory, serializer);
+sse_encode_u_64(self.rateWindowSeconds, serializer);
+sse_encode_opt_box_autoadd_quiet_hours(self.quietHours, serializer);
+sse_encode_bool(self.doNotDisturb, serializer);
+ }
+
+@protected void sse_encode_notification_gate_stats(NotificationGateStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pending, serializer);
+sse_encode_list_category_gate_stats(self.categories, serializer);
+ }
+
+@protected void sse_encode_notification_server_info(NotificationServerInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.vendor, serializer);
+sse_encode_String(self.version, serializer);
+sse_encode_String(self.specVersion, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_monitor_location(MonitorLocation? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_monitor_location(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_notification_backend(NotificationBackend? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_notification_backend(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_notification_server_info(NotificationServerInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_notification_server_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_process_history(ProcessHistory? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_process_history(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_process_tree_node(ProcessTreeNode? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_process_tree_node(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_quiet_hours(QuietHours? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_quiet_hours(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_record_f_64_f_64((double,double)? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_record_f_64_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_timelapse_status(TimelapseStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_timelapse_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_watermark_options(WatermarkOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_watermark_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_policy_action(PolicyAction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_policy_config(PolicyConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_policy_rule(self.rules, serializer);
+sse_encode_u_64(self.intervalMs, serializer);
+ }
+
+@protected void sse_encode_policy_enforcement(PolicyEnforcement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_policy_rule(PolicyRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_list_String(self.namePatterns, serializer);
+sse_encode_list_String(self.pathPatterns, serializer);
+sse_encode_list_String(self.windowTitlePatterns, serializer);
+sse_encode_policy_action(self.action, serializer);
+sse_encode_u_32(self.graceSeconds, serializer);
+sse_encode_opt_String(self.message, serializer);
+ }
+
+@protected void sse_encode_policy_violation(PolicyViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.ruleId, serializer);
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.processName, serializer);
+sse_encode_opt_String(self.exePath, serializer);
+sse_encode_opt_String(self.windowTitle, serializer);
+sse_encode_policy_enforcement(self.enforcement, serializer);
+sse_encode_opt_box_autoadd_u_64(self.killAtMs, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+ }
+
+@protected void sse_encode_preview_frame(PreviewFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.jpeg, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_u_64(self.capturedAtMs, serializer);
+sse_encode_frame_classification(self.classification, serializer);
+ }
+
+@protected void sse_encode_process_event(ProcessEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_event_kind(self.kind, serializer);
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.exePath, serializer);
+sse_encode_u_64(self.startTime, serializer);
+sse_encode_opt_box_autoadd_u_64(self.runDurationSeconds, serializer);
+ }
+
+@protected void sse_encode_process_event_kind(ProcessEventKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_process_filter(ProcessFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.namePatterns, serializer);
+sse_encode_list_String(self.pathPatterns, serializer);
+sse_encode_bool(self.includeExisting, serializer);
+ }
+
+@protected void sse_encode_process_history(ProcessHistory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_list_process_sample(self.samples, serializer);
+ }
+
+@protected void sse_encode_process_info(ProcessInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_list_String(self.cmd, serializer);
+sse_encode_u_64(self.memoryUsage, serializer);
+sse_encode_f_32(self.cpuUsage, serializer);
+sse_encode_opt_box_autoadd_u_32(self.parentPid, serializer);
+sse_encode_opt_String(self.user, serializer);
+sse_encode_opt_String(self.exePath, serializer);
+sse_encode_u_64(self.startTime, serializer);
+sse_encode_u_64(self.runTime, serializer);
+sse_encode_String(self.status, serializer);
+sse_encode_u_64(self.diskReadBytes, serializer);
+sse_encode_u_64(self.diskWrittenBytes, serializer);
+ }
+
+@protected void sse_encode_process_sample(ProcessSample self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_f_32(self.cpuUsage, serializer);
+sse_encode_u_64(self.memoryBytes, serializer);
+ }
+
+@protected void sse_encode_process_sampler_config(ProcessSamplerConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.intervalMs, serializer);
+sse_encode_u_32(self.historyLen, serializer);
+ }
+
+@protected void sse_encode_process_sort_key(ProcessSortKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_process_tree_node(ProcessTreeNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_process_info(self.process, serializer);
+sse_encode_list_process_tree_node(self.children, serializer);
+ }
+
+@protected void sse_encode_process_usage_summary(ProcessUsageSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.pid, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_f_32(self.averageCpu, serializer);
+sse_encode_f_32(self.peakCpu, serializer);
+sse_encode_u_64(self.averageMemoryBytes, serializer);
+sse_encode_u_64(self.peakMemoryBytes, serializer);
+sse_encode_u_32(self.sampleCount, serializer);
+ }
+
+@protected void sse_encode_quiet_hours(QuietHours self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.startMinute, serializer);
+sse_encode_u_32(self.endMinute, serializer);
+sse_encode_i_32(self.utcOffsetMinutes, serializer);
+ }
+
+@protected void sse_encode_record_f_64_f_64((double,double) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.$1, serializer);
+sse_encode_f_64(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_i_32_i_32((int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.$1, serializer);
+sse_encode_i_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_i_32_i_32_i_32_i_32((int,int,int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.$1, serializer);
+sse_encode_i_32(self.$2, serializer);
+sse_encode_i_32(self.$3, serializer);
+sse_encode_i_32(self.$4, serializer);
+ }
+
+@protected void sse_encode_resource_budget_config(ResourceBudgetConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.maxCpuPercent, serializer);
+sse_encode_u_64(self.maxMemoryBytes, serializer);
+sse_encode_u_64(self.sampleIntervalMs, serializer);
+sse_encode_f_64(self.maxThrottleFactor, serializer);
+sse_encode_f_64(self.batteryThrottleFactor, serializer);
+ }
+
+@protected void sse_encode_resource_budget_status(ResourceBudgetStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.running, serializer);
+sse_encode_f_32(self.cpuPercent, serializer);
+sse_encode_u_64(self.memoryBytes, serializer);
+sse_encode_bool(self.onBattery, serializer);
+sse_encode_f_64(self.throttleFactor, serializer);
+sse_encode_bool(self.encodingDeferred, serializer);
+sse_encode_u_64(self.inputPollIntervalMs, serializer);
+sse_encode_u_64(self.windowPollIntervalMs, serializer);
+ }
+
+@protected void sse_encode_screenshot(Screenshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.data, serializer);
+sse_encode_screenshot_format(self.format, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_u_64(self.capturedAtMs, serializer);
+sse_encode_frame_analysis(self.analysis, serializer);
+sse_encode_opt_box_autoadd_u_32(self.monitorId, serializer);
+ }
+
+@protected void sse_encode_screenshot_format(ScreenshotFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_screenshot_options(ScreenshotOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_screenshot_format(self.format, serializer);
+sse_encode_u_8(self.jpegQuality, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxWidth, serializer);
+sse_encode_opt_box_autoadd_watermark_options(self.watermark, serializer);
+sse_encode_bool(self.includeCursor, serializer);
+ }
+
+@protected void sse_encode_screenshot_schedule_config(ScreenshotScheduleConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.intervalSeconds, serializer);
+sse_encode_u_32(self.capturesPerInterval, serializer);
+sse_encode_u_64(self.idleThresholdSeconds, serializer);
+sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+sse_encode_screenshot_options(self.options, serializer);
+ }
+
+@protected void sse_encode_spool_entry(SpoolEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.sizeBytes, serializer);
+sse_encode_u_64(self.capturedAtMs, serializer);
+ }
+
+@protected void sse_encode_spool_quota_result(SpoolQuotaResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.deletedPaths, serializer);
+sse_encode_u_32(self.remainingFiles, serializer);
+sse_encode_u_64(self.remainingBytes, serializer);
+ }
+
+@protected void sse_encode_spooled_screenshot(SpooledScreenshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_screenshot_format(self.format, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_u_64(self.sizeBytes, serializer);
+sse_encode_u_64(self.capturedAtMs, serializer);
+sse_encode_frame_analysis(self.analysis, serializer);
+ }
+
+@protected void sse_encode_system_snapshot(SystemSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_f_32(self.globalCpuUsage, serializer);
+sse_encode_list_cpu_core_usage(self.cpuCores, serializer);
+sse_encode_u_64(self.totalMemoryBytes, serializer);
+sse_encode_u_64(self.usedMemoryBytes, serializer);
+sse_encode_u_64(self.availableMemoryBytes, serializer);
+sse_encode_u_64(self.totalSwapBytes, serializer);
+sse_encode_u_64(self.usedSwapBytes, serializer);
+sse_encode_load_average(self.loadAverage, serializer);
+sse_encode_u_64(self.uptimeSeconds, serializer);
+sse_encode_u_64(self.bootTime, serializer);
+sse_encode_list_disk_usage_info(self.disks, serializer);
+sse_encode_list_network_interface_usage(self.networks, serializer);
+ }
+
+@protected void sse_encode_terminate_options(TerminateOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_terminate_signal(self.signal, serializer);
+sse_encode_u_64(self.gracePeriodMs, serializer);
+sse_encode_bool(self.killTree, serializer);
+ }
+
+@protected void sse_encode_terminate_outcome(TerminateOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_terminate_signal(TerminateSignal self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_timelapse_config(TimelapseConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.outputPath, serializer);
+sse_encode_timelapse_format(self.format, serializer);
+sse_encode_u_64(self.frameIntervalSeconds, serializer);
+sse_encode_u_32(self.maxWidth, serializer);
+sse_encode_u_32(self.playbackFps, serializer);
+sse_encode_u_8(self.jpegQuality, serializer);
+sse_encode_u_64(self.maxFileSizeBytes, serializer);
+ }
+
+@protected void sse_encode_timelapse_format(TimelapseFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_timelapse_state(TimelapseState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_timelapse_status(TimelapseStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_timelapse_state(self.state, serializer);
+sse_encode_String(self.outputPath, serializer);
+sse_encode_u_32(self.framesWritten, serializer);
+sse_encode_u_64(self.bytesWritten, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_watermark_options(WatermarkOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_watermark_position(self.position, serializer);
+sse_encode_f_32(self.opacity, serializer);
+sse_encode_opt_box_autoadd_u_32(self.scale, serializer);
+sse_encode_bool(self.includeHostname, serializer);
+sse_encode_bool(self.includeUser, serializer);
+sse_encode_opt_String(self.taskId, serializer);
+sse_encode_opt_String(self.customText, serializer);
+ }
+
+@protected void sse_encode_watermark_position(WatermarkPosition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_window_details(WindowDetails self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_record_i_32_i_32_i_32_i_32(self.position, serializer);
+sse_encode_opt_box_autoadd_monitor_location(self.monitor, serializer);
+sse_encode_bool(self.isFullScreen, serializer);
+sse_encode_String(self.processName, serializer);
+sse_encode_String(self.processPath, serializer);
+sse_encode_u_32(self.processId, serializer);
+sse_encode_String(self.os, serializer);
+ }
+                }
+                
@@ -4,319 +4,958 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/active_window_listener.dart';
+import 'api/app_policy.dart';
+import 'api/capture_preview.dart';
+import 'api/click_heatmap.dart';
+import 'api/cmdline_redaction.dart';
+import 'api/delta_encoding.dart';
 import 'api/desktop_notification.dart';
+import 'api/display_geometry.dart';
+import 'api/frame_analysis.dart';
 import 'api/get_all_process_list.dart';
 import 'api/keyboard_listener.dart';
 import 'api/mouse_listener.dart';
+import 'api/notification_actions.dart';
+import 'api/persistent_notification.dart';
+import 'api/process_sampler.dart';
+import 'api/process_termination.dart';
+import 'api/process_watcher.dart';
+import 'api/resource_budget.dart';
+import 'api/screenshot_capture.dart';
+import 'api/screenshot_scheduler.dart';
+import 'api/screenshot_spool.dart';
+import 'api/screenshot_watermark.dart';
 import 'api/simple.dart';
+import 'api/system_monitor.dart';
 import 'api/take_full_screenshot.dart';
+import 'api/timelapse_recorder.dart';
+import 'api/window_capture.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  RustStreamSink<KeyboardEvent> dco_decode_StreamSink_keyboard_event_Sse(
-    dynamic raw,
-  );
 
-  @protected
-  RustStreamSink<MouseEvent> dco_decode_StreamSink_mouse_event_Sse(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  RustStreamSink<WindowDetails> dco_decode_StreamSink_window_details_Sse(
-    dynamic raw,
-  );
+                  
 
-  @protected
-  String dco_decode_String(dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected RustStreamSink<KeyboardEvent> dco_decode_StreamSink_keyboard_event_Sse(dynamic raw);
 
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw);
+@protected RustStreamSink<MouseEvent> dco_decode_StreamSink_mouse_event_Sse(dynamic raw);
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+@protected RustStreamSink<NotificationEvent> dco_decode_StreamSink_notification_event_Sse(dynamic raw);
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
+@protected RustStreamSink<PolicyViolation> dco_decode_StreamSink_policy_violation_Sse(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected RustStreamSink<PreviewFrame> dco_decode_StreamSink_preview_frame_Sse(dynamic raw);
 
-  @protected
-  KeyboardEvent dco_decode_keyboard_event(dynamic raw);
+@protected RustStreamSink<ProcessEvent> dco_decode_StreamSink_process_event_Sse(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected RustStreamSink<Screenshot> dco_decode_StreamSink_screenshot_Sse(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected RustStreamSink<SystemSnapshot> dco_decode_StreamSink_system_snapshot_Sse(dynamic raw);
 
-  @protected
-  List<ProcessInfo> dco_decode_list_process_info(dynamic raw);
+@protected RustStreamSink<WindowDetails> dco_decode_StreamSink_window_details_Sse(dynamic raw);
 
-  @protected
-  List<WindowDetails> dco_decode_list_window_details(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  MouseEvent dco_decode_mouse_event(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected DeltaEncoderConfig dco_decode_box_autoadd_delta_encoder_config(dynamic raw);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+@protected FrameAnalysis dco_decode_box_autoadd_frame_analysis(dynamic raw);
 
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+@protected HeatmapConfig dco_decode_box_autoadd_heatmap_config(dynamic raw);
 
-  @protected
-  ProcessInfo dco_decode_process_info(dynamic raw);
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
-  @protected
-  (int, int) dco_decode_record_i_32_i_32(dynamic raw);
+@protected MonitorLocation dco_decode_box_autoadd_monitor_location(dynamic raw);
 
-  @protected
-  (int, int, int, int) dco_decode_record_i_32_i_32_i_32_i_32(dynamic raw);
+@protected MouseEvent dco_decode_box_autoadd_mouse_event(dynamic raw);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected NotificationBackend dco_decode_box_autoadd_notification_backend(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected NotificationBackendPreference dco_decode_box_autoadd_notification_backend_preference(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected NotificationGateConfig dco_decode_box_autoadd_notification_gate_config(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected NotificationServerInfo dco_decode_box_autoadd_notification_server_info(dynamic raw);
 
-  @protected
-  WindowDetails dco_decode_window_details(dynamic raw);
+@protected PolicyConfig dco_decode_box_autoadd_policy_config(dynamic raw);
 
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+@protected ProcessFilter dco_decode_box_autoadd_process_filter(dynamic raw);
 
-  @protected
-  RustStreamSink<KeyboardEvent> sse_decode_StreamSink_keyboard_event_Sse(
-    SseDeserializer deserializer,
-  );
+@protected ProcessHistory dco_decode_box_autoadd_process_history(dynamic raw);
 
-  @protected
-  RustStreamSink<MouseEvent> sse_decode_StreamSink_mouse_event_Sse(
-    SseDeserializer deserializer,
-  );
+@protected ProcessSamplerConfig dco_decode_box_autoadd_process_sampler_config(dynamic raw);
 
-  @protected
-  RustStreamSink<WindowDetails> sse_decode_StreamSink_window_details_Sse(
-    SseDeserializer deserializer,
-  );
+@protected ProcessTreeNode dco_decode_box_autoadd_process_tree_node(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected QuietHours dco_decode_box_autoadd_quiet_hours(dynamic raw);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected (double,double) dco_decode_box_autoadd_record_f_64_f_64(dynamic raw);
 
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+@protected ResourceBudgetConfig dco_decode_box_autoadd_resource_budget_config(dynamic raw);
 
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+@protected ScreenshotOptions dco_decode_box_autoadd_screenshot_options(dynamic raw);
 
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+@protected ScreenshotScheduleConfig dco_decode_box_autoadd_screenshot_schedule_config(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected TerminateOptions dco_decode_box_autoadd_terminate_options(dynamic raw);
 
-  @protected
-  KeyboardEvent sse_decode_keyboard_event(SseDeserializer deserializer);
+@protected TimelapseConfig dco_decode_box_autoadd_timelapse_config(dynamic raw);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected TimelapseStatus dco_decode_box_autoadd_timelapse_status(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  List<WindowDetails> sse_decode_list_window_details(
-    SseDeserializer deserializer,
-  );
+@protected WatermarkOptions dco_decode_box_autoadd_watermark_options(dynamic raw);
 
-  @protected
-  MouseEvent sse_decode_mouse_event(SseDeserializer deserializer);
+@protected CategoryGateStats dco_decode_category_gate_stats(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected CoordinateSpace dco_decode_coordinate_space(dynamic raw);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+@protected CpuCoreUsage dco_decode_cpu_core_usage(dynamic raw);
 
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+@protected DeltaEncoderConfig dco_decode_delta_encoder_config(dynamic raw);
 
-  @protected
-  ProcessInfo sse_decode_process_info(SseDeserializer deserializer);
+@protected DiskUsageInfo dco_decode_disk_usage_info(dynamic raw);
 
-  @protected
-  (int, int) sse_decode_record_i_32_i_32(SseDeserializer deserializer);
+@protected DisplayGeometry dco_decode_display_geometry(dynamic raw);
 
-  @protected
-  (int, int, int, int) sse_decode_record_i_32_i_32_i_32_i_32(
-    SseDeserializer deserializer,
-  );
+@protected DisplayRect dco_decode_display_rect(dynamic raw);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected EncodedFrame dco_decode_encoded_frame(dynamic raw);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected double dco_decode_f_32(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected FrameAnalysis dco_decode_frame_analysis(dynamic raw);
 
-  @protected
-  WindowDetails sse_decode_window_details(SseDeserializer deserializer);
+@protected FrameClassification dco_decode_frame_classification(dynamic raw);
 
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+@protected GateDecision dco_decode_gate_decision(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_keyboard_event_Sse(
-    RustStreamSink<KeyboardEvent> self,
-    SseSerializer serializer,
-  );
+@protected GatedSendResult dco_decode_gated_send_result(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_mouse_event_Sse(
-    RustStreamSink<MouseEvent> self,
-    SseSerializer serializer,
-  );
+@protected HeatmapConfig dco_decode_heatmap_config(dynamic raw);
 
-  @protected
-  void sse_encode_StreamSink_window_details_Sse(
-    RustStreamSink<WindowDetails> self,
-    SseSerializer serializer,
-  );
+@protected HeatmapGrid dco_decode_heatmap_grid(dynamic raw);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected HeatmapScope dco_decode_heatmap_scope(dynamic raw);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected HeatmapScopeKind dco_decode_heatmap_scope_kind(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+@protected KeyboardEvent dco_decode_keyboard_event(dynamic raw);
 
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected List<CategoryGateStats> dco_decode_list_category_gate_stats(dynamic raw);
 
-  @protected
-  void sse_encode_keyboard_event(KeyboardEvent self, SseSerializer serializer);
+@protected List<CpuCoreUsage> dco_decode_list_cpu_core_usage(dynamic raw);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected List<DiskUsageInfo> dco_decode_list_disk_usage_info(dynamic raw);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected List<DisplayGeometry> dco_decode_list_display_geometry(dynamic raw);
 
-  @protected
-  void sse_encode_list_process_info(
-    List<ProcessInfo> self,
-    SseSerializer serializer,
-  );
+@protected List<NetworkInterfaceUsage> dco_decode_list_network_interface_usage(dynamic raw);
 
-  @protected
-  void sse_encode_list_window_details(
-    List<WindowDetails> self,
-    SseSerializer serializer,
-  );
+@protected List<NotificationAction> dco_decode_list_notification_action(dynamic raw);
 
-  @protected
-  void sse_encode_mouse_event(MouseEvent self, SseSerializer serializer);
+@protected List<NotificationBackend> dco_decode_list_notification_backend(dynamic raw);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected List<NotificationBackendProbe> dco_decode_list_notification_backend_probe(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+@protected List<PolicyRule> dco_decode_list_policy_rule(dynamic raw);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
 
-  @protected
-  void sse_encode_process_info(ProcessInfo self, SseSerializer serializer);
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
-  @protected
-  void sse_encode_record_i_32_i_32((int, int) self, SseSerializer serializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  void sse_encode_record_i_32_i_32_i_32_i_32(
-    (int, int, int, int) self,
-    SseSerializer serializer,
-  );
+@protected List<ProcessInfo> dco_decode_list_process_info(dynamic raw);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected List<ProcessSample> dco_decode_list_process_sample(dynamic raw);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected List<ProcessTreeNode> dco_decode_list_process_tree_node(dynamic raw);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected List<ProcessUsageSummary> dco_decode_list_process_usage_summary(dynamic raw);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected List<SpoolEntry> dco_decode_list_spool_entry(dynamic raw);
+
+@protected List<WindowDetails> dco_decode_list_window_details(dynamic raw);
+
+@protected LoadAverage dco_decode_load_average(dynamic raw);
+
+@protected MonitorLocation dco_decode_monitor_location(dynamic raw);
+
+@protected MouseEvent dco_decode_mouse_event(dynamic raw);
+
+@protected NetworkInterfaceUsage dco_decode_network_interface_usage(dynamic raw);
+
+@protected NotificationAction dco_decode_notification_action(dynamic raw);
+
+@protected NotificationBackend dco_decode_notification_backend(dynamic raw);
+
+@protected NotificationBackendCapabilities dco_decode_notification_backend_capabilities(dynamic raw);
+
+@protected NotificationBackendPreference dco_decode_notification_backend_preference(dynamic raw);
+
+@protected NotificationBackendProbe dco_decode_notification_backend_probe(dynamic raw);
+
+@protected NotificationEvent dco_decode_notification_event(dynamic raw);
+
+@protected NotificationEventKind dco_decode_notification_event_kind(dynamic raw);
+
+@protected NotificationGateConfig dco_decode_notification_gate_config(dynamic raw);
+
+@protected NotificationGateStats dco_decode_notification_gate_stats(dynamic raw);
+
+@protected NotificationServerInfo dco_decode_notification_server_info(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected MonitorLocation? dco_decode_opt_box_autoadd_monitor_location(dynamic raw);
+
+@protected NotificationBackend? dco_decode_opt_box_autoadd_notification_backend(dynamic raw);
+
+@protected NotificationServerInfo? dco_decode_opt_box_autoadd_notification_server_info(dynamic raw);
+
+@protected ProcessHistory? dco_decode_opt_box_autoadd_process_history(dynamic raw);
+
+@protected ProcessTreeNode? dco_decode_opt_box_autoadd_process_tree_node(dynamic raw);
+
+@protected QuietHours? dco_decode_opt_box_autoadd_quiet_hours(dynamic raw);
+
+@protected (double,double)? dco_decode_opt_box_autoadd_record_f_64_f_64(dynamic raw);
+
+@protected TimelapseStatus? dco_decode_opt_box_autoadd_timelapse_status(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected WatermarkOptions? dco_decode_opt_box_autoadd_watermark_options(dynamic raw);
+
+@protected PolicyAction dco_decode_policy_action(dynamic raw);
+
+@protected PolicyConfig dco_decode_policy_config(dynamic raw);
+
+@protected PolicyEnforcement dco_decode_policy_enforcement(dynamic raw);
+
+@protected PolicyRule dco_decode_policy_rule(dynamic raw);
+
+@protected PolicyViolation dco_decode_policy_violation(dynamic raw);
+
+@protected PreviewFrame dco_decode_preview_frame(dynamic raw);
+
+@protected ProcessEvent dco_decode_process_event(dynamic raw);
+
+@protected ProcessEventKind dco_decode_process_event_kind(dynamic raw);
+
+@protected ProcessFilter dco_decode_process_filter(dynamic raw);
+
+@protected ProcessHistory dco_decode_process_history(dynamic raw);
+
+@protected ProcessInfo dco_decode_process_info(dynamic raw);
+
+@protected ProcessSample dco_decode_process_sample(dynamic raw);
+
+@protected ProcessSamplerConfig dco_decode_process_sampler_config(dynamic raw);
+
+@protected ProcessSortKey dco_decode_process_sort_key(dynamic raw);
+
+@protected ProcessTreeNode dco_decode_process_tree_node(dynamic raw);
+
+@protected ProcessUsageSummary dco_decode_process_usage_summary(dynamic raw);
+
+@protected QuietHours dco_decode_quiet_hours(dynamic raw);
+
+@protected (double,double) dco_decode_record_f_64_f_64(dynamic raw);
+
+@protected (int,int) dco_decode_record_i_32_i_32(dynamic raw);
+
+@protected (int,int,int,int) dco_decode_record_i_32_i_32_i_32_i_32(dynamic raw);
+
+@protected ResourceBudgetConfig dco_decode_resource_budget_config(dynamic raw);
+
+@protected ResourceBudgetStatus dco_decode_resource_budget_status(dynamic raw);
+
+@protected Screenshot dco_decode_screenshot(dynamic raw);
+
+@protected ScreenshotFormat dco_decode_screenshot_format(dynamic raw);
+
+@protected ScreenshotOptions dco_decode_screenshot_options(dynamic raw);
+
+@protected ScreenshotScheduleConfig dco_decode_screenshot_schedule_config(dynamic raw);
+
+@protected SpoolEntry dco_decode_spool_entry(dynamic raw);
+
+@protected SpoolQuotaResult dco_decode_spool_quota_result(dynamic raw);
+
+@protected SpooledScreenshot dco_decode_spooled_screenshot(dynamic raw);
+
+@protected SystemSnapshot dco_decode_system_snapshot(dynamic raw);
+
+@protected TerminateOptions dco_decode_terminate_options(dynamic raw);
+
+@protected TerminateOutcome dco_decode_terminate_outcome(dynamic raw);
+
+@protected TerminateSignal dco_decode_terminate_signal(dynamic raw);
+
+@protected TimelapseConfig dco_decode_timelapse_config(dynamic raw);
+
+@protected TimelapseFormat dco_decode_timelapse_format(dynamic raw);
+
+@protected TimelapseState dco_decode_timelapse_state(dynamic raw);
+
+@protected TimelapseStatus dco_decode_timelapse_status(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected WatermarkOptions dco_decode_watermark_options(dynamic raw);
+
+@protected WatermarkPosition dco_decode_watermark_position(dynamic raw);
+
+@protected WindowDetails dco_decode_window_details(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<KeyboardEvent> sse_decode_StreamSink_keyboard_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<MouseEvent> sse_decode_StreamSink_mouse_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<NotificationEvent> sse_decode_StreamSink_notification_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<PolicyViolation> sse_decode_StreamSink_policy_violation_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<PreviewFrame> sse_decode_StreamSink_preview_frame_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ProcessEvent> sse_decode_StreamSink_process_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<Screenshot> sse_decode_StreamSink_screenshot_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<SystemSnapshot> sse_decode_StreamSink_system_snapshot_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<WindowDetails> sse_decode_StreamSink_window_details_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected DeltaEncoderConfig sse_decode_box_autoadd_delta_encoder_config(SseDeserializer deserializer);
+
+@protected FrameAnalysis sse_decode_box_autoadd_frame_analysis(SseDeserializer deserializer);
+
+@protected HeatmapConfig sse_decode_box_autoadd_heatmap_config(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected MonitorLocation sse_decode_box_autoadd_monitor_location(SseDeserializer deserializer);
+
+@protected MouseEvent sse_decode_box_autoadd_mouse_event(SseDeserializer deserializer);
+
+@protected NotificationBackend sse_decode_box_autoadd_notification_backend(SseDeserializer deserializer);
+
+@protected NotificationBackendPreference sse_decode_box_autoadd_notification_backend_preference(SseDeserializer deserializer);
+
+@protected NotificationGateConfig sse_decode_box_autoadd_notification_gate_config(SseDeserializer deserializer);
+
+@protected NotificationServerInfo sse_decode_box_autoadd_notification_server_info(SseDeserializer deserializer);
+
+@protected PolicyConfig sse_decode_box_autoadd_policy_config(SseDeserializer deserializer);
+
+@protected ProcessFilter sse_decode_box_autoadd_process_filter(SseDeserializer deserializer);
+
+@protected ProcessHistory sse_decode_box_autoadd_process_history(SseDeserializer deserializer);
+
+@protected ProcessSamplerConfig sse_decode_box_autoadd_process_sampler_config(SseDeserializer deserializer);
+
+@protected ProcessTreeNode sse_decode_box_autoadd_process_tree_node(SseDeserializer deserializer);
+
+@protected QuietHours sse_decode_box_autoadd_quiet_hours(SseDeserializer deserializer);
+
+@protected (double,double) sse_decode_box_autoadd_record_f_64_f_64(SseDeserializer deserializer);
+
+@protected ResourceBudgetConfig sse_decode_box_autoadd_resource_budget_config(SseDeserializer deserializer);
+
+@protected ScreenshotOptions sse_decode_box_autoadd_screenshot_options(SseDeserializer deserializer);
+
+@protected ScreenshotScheduleConfig sse_decode_box_autoadd_screenshot_schedule_config(SseDeserializer deserializer);
+
+@protected TerminateOptions sse_decode_box_autoadd_terminate_options(SseDeserializer deserializer);
+
+@protected TimelapseConfig sse_decode_box_autoadd_timelapse_config(SseDeserializer deserializer);
+
+@protected TimelapseStatus sse_decode_box_autoadd_timelapse_status(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected WatermarkOptions sse_decode_box_autoadd_watermark_options(SseDeserializer deserializer);
+
+@protected CategoryGateStats sse_decode_category_gate_stats(SseDeserializer deserializer);
+
+@protected CoordinateSpace sse_decode_coordinate_space(SseDeserializer deserializer);
+
+@protected CpuCoreUsage sse_decode_cpu_core_usage(SseDeserializer deserializer);
+
+@protected DeltaEncoderConfig sse_decode_delta_encoder_config(SseDeserializer deserializer);
+
+@protected DiskUsageInfo sse_decode_disk_usage_info(SseDeserializer deserializer);
+
+@protected DisplayGeometry sse_decode_display_geometry(SseDeserializer deserializer);
+
+@protected DisplayRect sse_decode_display_rect(SseDeserializer deserializer);
+
+@protected EncodedFrame sse_decode_encoded_frame(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FrameAnalysis sse_decode_frame_analysis(SseDeserializer deserializer);
+
+@protected FrameClassification sse_decode_frame_classification(SseDeserializer deserializer);
+
+@protected GateDecision sse_decode_gate_decision(SseDeserializer deserializer);
+
+@protected GatedSendResult sse_decode_gated_send_result(SseDeserializer deserializer);
+
+@protected HeatmapConfig sse_decode_heatmap_config(SseDeserializer deserializer);
+
+@protected HeatmapGrid sse_decode_heatmap_grid(SseDeserializer deserializer);
+
+@protected HeatmapScope sse_decode_heatmap_scope(SseDeserializer deserializer);
+
+@protected HeatmapScopeKind sse_decode_heatmap_scope_kind(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected KeyboardEvent sse_decode_keyboard_event(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<CategoryGateStats> sse_decode_list_category_gate_stats(SseDeserializer deserializer);
+
+@protected List<CpuCoreUsage> sse_decode_list_cpu_core_usage(SseDeserializer deserializer);
+
+@protected List<DiskUsageInfo> sse_decode_list_disk_usage_info(SseDeserializer deserializer);
+
+@protected List<DisplayGeometry> sse_decode_list_display_geometry(SseDeserializer deserializer);
+
+@protected List<NetworkInterfaceUsage> sse_decode_list_network_interface_usage(SseDeserializer deserializer);
+
+@protected List<NotificationAction> sse_decode_list_notification_action(SseDeserializer deserializer);
+
+@protected List<NotificationBackend> sse_decode_list_notification_backend(SseDeserializer deserializer);
+
+@protected List<NotificationBackendProbe> sse_decode_list_notification_backend_probe(SseDeserializer deserializer);
+
+@protected List<PolicyRule> sse_decode_list_policy_rule(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<ProcessInfo> sse_decode_list_process_info(SseDeserializer deserializer);
+
+@protected List<ProcessSample> sse_decode_list_process_sample(SseDeserializer deserializer);
+
+@protected List<ProcessTreeNode> sse_decode_list_process_tree_node(SseDeserializer deserializer);
+
+@protected List<ProcessUsageSummary> sse_decode_list_process_usage_summary(SseDeserializer deserializer);
+
+@protected List<SpoolEntry> sse_decode_list_spool_entry(SseDeserializer deserializer);
+
+@protected List<WindowDetails> sse_decode_list_window_details(SseDeserializer deserializer);
+
+@protected LoadAverage sse_decode_load_average(SseDeserializer deserializer);
+
+@protected MonitorLocation sse_decode_monitor_location(SseDeserializer deserializer);
+
+@protected MouseEvent sse_decode_mouse_event(SseDeserializer deserializer);
+
+@protected NetworkInterfaceUsage sse_decode_network_interface_usage(SseDeserializer deserializer);
+
+@protected NotificationAction sse_decode_notification_action(SseDeserializer deserializer);
+
+@protected NotificationBackend sse_decode_notification_backend(SseDeserializer deserializer);
+
+@protected NotificationBackendCapabilities sse_decode_notification_backend_capabilities(SseDeserializer deserializer);
+
+@protected NotificationBackendPreference sse_decode_notification_backend_preference(SseDeserializer deserializer);
+
+@protected NotificationBackendProbe sse_decode_notification_backend_probe(SseDeserializer deserializer);
+
+@protected NotificationEvent sse_decode_notification_event(SseDeserializer deserializer);
+
+@protected NotificationEventKind sse_decode_notification_event_kind(SseDeserializer deserializer);
+
+@protected NotificationGateConfig sse_decode_notification_gate_config(SseDeserializer deserializer);
+
+@protected NotificationGateStats sse_decode_notification_gate_stats(SseDeserializer deserializer);
+
+@protected NotificationServerInfo sse_decode_notification_server_info(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected MonitorLocation? sse_decode_opt_box_autoadd_monitor_location(SseDeserializer deserializer);
+
+@protected NotificationBackend? sse_decode_opt_box_autoadd_notification_backend(SseDeserializer deserializer);
+
+@protected NotificationServerInfo? sse_decode_opt_box_autoadd_notification_server_info(SseDeserializer deserializer);
+
+@protected ProcessHistory? sse_decode_opt_box_autoadd_process_history(SseDeserializer deserializer);
+
+@protected ProcessTreeNode? sse_decode_opt_box_autoadd_process_tree_node(SseDeserializer deserializer);
+
+@protected QuietHours? sse_decode_opt_box_autoadd_quiet_hours(SseDeserializer deserializer);
+
+@protected (double,double)? sse_decode_opt_box_autoadd_record_f_64_f_64(SseDeserializer deserializer);
+
+@protected TimelapseStatus? sse_decode_opt_box_autoadd_timelapse_status(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected WatermarkOptions? sse_decode_opt_box_autoadd_watermark_options(SseDeserializer deserializer);
+
+@protected PolicyAction sse_decode_policy_action(SseDeserializer deserializer);
+
+@protected PolicyConfig sse_decode_policy_config(SseDeserializer deserializer);
+
+@protected PolicyEnforcement sse_decode_policy_enforcement(SseDeserializer deserializer);
+
+@protected PolicyRule sse_decode_policy_rule(SseDeserializer deserializer);
+
+@protected PolicyViolation sse_decode_policy_violation(SseDeserializer deserializer);
+
+@protected PreviewFrame sse_decode_preview_frame(SseDeserializer deserializer);
+
+@protected ProcessEvent sse_decode_process_event(SseDeserializer deserializer);
+
+@protected ProcessEventKind sse_decode_process_event_kind(SseDeserializer deserializer);
+
+@protected ProcessFilter sse_decode_process_filter(SseDeserializer deserializer);
+
+@protected ProcessHistory sse_decode_process_history(SseDeserializer deserializer);
+
+@protected ProcessInfo sse_decode_process_info(SseDeserializer deserializer);
+
+@protected ProcessSample sse_decode_process_sample(SseDeserializer deserializer);
+
+@protected ProcessSamplerConfig sse_decode_process_sampler_config(SseDeserializer deserializer);
+
+@protected ProcessSortKey sse_decode_process_sort_key(SseDeserializer deserializer);
+
+@protected ProcessTreeNode sse_decode_process_tree_node(SseDeserializer deserializer);
+
+@protected ProcessUsageSummary sse_decode_process_usage_summary(SseDeserializer deserializer);
+
+@protected QuietHours sse_decode_quiet_hours(SseDeserializer deserializer);
+
+@protected (double,double) sse_decode_record_f_64_f_64(SseDeserializer deserializer);
+
+@protected (int,int) sse_decode_record_i_32_i_32(SseDeserializer deserializer);
+
+@protected (int,int,int,int) sse_decode_record_i_32_i_32_i_32_i_32(SseDeserializer deserializer);
+
+@protected ResourceBudgetConfig sse_decode_resource_budget_config(SseDeserializer deserializer);
+
+@protected ResourceBudgetStatus sse_decode_resource_budget_status(SseDeserializer deserializer);
+
+@protected Screenshot sse_decode_screenshot(SseDeserializer deserializer);
+
+@protected ScreenshotFormat sse_decode_screenshot_format(SseDeserializer deserializer);
+
+@protected ScreenshotOptions sse_decode_screenshot_options(SseDeserializer deserializer);
+
+@protected ScreenshotScheduleConfig sse_decode_screenshot_schedule_config(SseDeserializer deserializer);
+
+@protected SpoolEntry sse_decode_spool_entry(SseDeserializer deserializer);
+
+@protected SpoolQuotaResult sse_decode_spool_quota_result(SseDeserializer deserializer);
+
+@protected SpooledScreenshot sse_decode_spooled_screenshot(SseDeserializer deserializer);
+
+@protected SystemSnapshot sse_decode_system_snapshot(SseDeserializer deserializer);
+
+@protected TerminateOptions sse_decode_terminate_options(SseDeserializer deserializer);
+
+@protected TerminateOutcome sse_decode_terminate_outcome(SseDeserializer deserializer);
+
+@protected TerminateSignal sse_decode_terminate_signal(SseDeserializer deserializer);
+
+@protected TimelapseConfig sse_decode_timelapse_config(SseDeserializer deserializer);
+
+@protected TimelapseFormat sse_decode_timelapse_format(SseDeserializer deserializer);
+
+@protected TimelapseState sse_decode_timelapse_state(SseDeserializer deserializer);
+
+@protected TimelapseStatus sse_decode_timelapse_status(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected WatermarkOptions sse_decode_watermark_options(SseDeserializer deserializer);
+
+@protected WatermarkPosition sse_decode_watermark_position(SseDeserializer deserializer);
+
+@protected WindowDetails sse_decode_window_details(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_keyboard_event_Sse(RustStreamSink<KeyboardEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_mouse_event_Sse(RustStreamSink<MouseEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_notification_event_Sse(RustStreamSink<NotificationEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_policy_violation_Sse(RustStreamSink<PolicyViolation> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_preview_frame_Sse(RustStreamSink<PreviewFrame> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_process_event_Sse(RustStreamSink<ProcessEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_screenshot_Sse(RustStreamSink<Screenshot> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_system_snapshot_Sse(RustStreamSink<SystemSnapshot> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_window_details_Sse(RustStreamSink<WindowDetails> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_delta_encoder_config(DeltaEncoderConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_frame_analysis(FrameAnalysis self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_heatmap_config(HeatmapConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_monitor_location(MonitorLocation self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_mouse_event(MouseEvent self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_notification_backend(NotificationBackend self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_notification_backend_preference(NotificationBackendPreference self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_notification_gate_config(NotificationGateConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_notification_server_info(NotificationServerInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_policy_config(PolicyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_process_filter(ProcessFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_process_history(ProcessHistory self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_process_sampler_config(ProcessSamplerConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_process_tree_node(ProcessTreeNode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_quiet_hours(QuietHours self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_record_f_64_f_64((double,double) self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_resource_budget_config(ResourceBudgetConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_screenshot_options(ScreenshotOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_screenshot_schedule_config(ScreenshotScheduleConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_terminate_options(TerminateOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_timelapse_config(TimelapseConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_timelapse_status(TimelapseStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_watermark_options(WatermarkOptions self, SseSerializer serializer);
+
+@protected void sse_encode_category_gate_stats(CategoryGateStats self, SseSerializer serializer);
+
+@protected void sse_encode_coordinate_space(CoordinateSpace self, SseSerializer serializer);
+
+@protected void sse_encode_cpu_core_usage(CpuCoreUsage self, SseSerializer serializer);
+
+@protected void sse_encode_delta_encoder_config(DeltaEncoderConfig self, SseSerializer serializer);
+
+@protected void sse_encode_disk_usage_info(DiskUsageInfo self, SseSerializer serializer);
+
+@protected void sse_encode_display_geometry(DisplayGeometry self, SseSerializer serializer);
+
+@protected void sse_encode_display_rect(DisplayRect self, SseSerializer serializer);
+
+@protected void sse_encode_encoded_frame(EncodedFrame self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_frame_analysis(FrameAnalysis self, SseSerializer serializer);
+
+@protected void sse_encode_frame_classification(FrameClassification self, SseSerializer serializer);
+
+@protected void sse_encode_gate_decision(GateDecision self, SseSerializer serializer);
+
+@protected void sse_encode_gated_send_result(GatedSendResult self, SseSerializer serializer);
+
+@protected void sse_encode_heatmap_config(HeatmapConfig self, SseSerializer serializer);
+
+@protected void sse_encode_heatmap_grid(HeatmapGrid self, SseSerializer serializer);
+
+@protected void sse_encode_heatmap_scope(HeatmapScope self, SseSerializer serializer);
+
+@protected void sse_encode_heatmap_scope_kind(HeatmapScopeKind self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_keyboard_event(KeyboardEvent self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_category_gate_stats(List<CategoryGateStats> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cpu_core_usage(List<CpuCoreUsage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_disk_usage_info(List<DiskUsageInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_display_geometry(List<DisplayGeometry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_network_interface_usage(List<NetworkInterfaceUsage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_notification_action(List<NotificationAction> self, SseSerializer serializer);
+
+@protected void sse_encode_list_notification_backend(List<NotificationBackend> self, SseSerializer serializer);
+
+@protected void sse_encode_list_notification_backend_probe(List<NotificationBackendProbe> self, SseSerializer serializer);
+
+@protected void sse_encode_list_policy_rule(List<PolicyRule> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_process_info(List<ProcessInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_process_sample(List<ProcessSample> self, SseSerializer serializer);
+
+@protected void sse_encode_list_process_tree_node(List<ProcessTreeNode> self, SseSerializer serializer);
+
+@protected void sse_encode_list_process_usage_summary(List<ProcessUsageSummary> self, SseSerializer serializer);
+
+@protected void sse_encode_list_spool_entry(List<SpoolEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_window_details(List<WindowDetails> self, SseSerializer serializer);
+
+@protected void sse_encode_load_average(LoadAverage self, SseSerializer serializer);
+
+@protected void sse_encode_monitor_location(MonitorLocation self, SseSerializer serializer);
+
+@protected void sse_encode_mouse_event(MouseEvent self, SseSerializer serializer);
+
+@protected void sse_encode_network_interface_usage(NetworkInterfaceUsage self, SseSerializer serializer);
+
+@protected void sse_encode_notification_action(NotificationAction self, SseSerializer serializer);
+
+@protected void sse_encode_notification_backend(NotificationBackend self, SseSerializer serializer);
+
+@protected void sse_encode_notification_backend_capabilities(NotificationBackendCapabilities self, SseSerializer serializer);
+
+@protected void sse_encode_notification_backend_preference(NotificationBackendPreference self, SseSerializer serializer);
+
+@protected void sse_encode_notification_backend_probe(NotificationBackendProbe self, SseSerializer serializer);
+
+@protected void sse_encode_notification_event(NotificationEvent self, SseSerializer serializer);
+
+@protected void sse_encode_notification_event_kind(NotificationEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_notification_gate_config(NotificationGateConfig self, SseSerializer serializer);
+
+@protected void sse_encode_notification_gate_stats(NotificationGateStats self, SseSerializer serializer);
+
+@protected void sse_encode_notification_server_info(NotificationServerInfo self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_monitor_location(MonitorLocation? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_notification_backend(NotificationBackend? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_notification_server_info(NotificationServerInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_process_history(ProcessHistory? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_process_tree_node(ProcessTreeNode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_quiet_hours(QuietHours? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_record_f_64_f_64((double,double)? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_timelapse_status(TimelapseStatus? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_watermark_options(WatermarkOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_policy_action(PolicyAction self, SseSerializer serializer);
+
+@protected void sse_encode_policy_config(PolicyConfig self, SseSerializer serializer);
+
+@protected void sse_encode_policy_enforcement(PolicyEnforcement self, SseSerializer serializer);
+
+@protected void sse_encode_policy_rule(PolicyRule self, SseSerializer serializer);
+
+@protected void sse_encode_policy_violation(PolicyViolation self, SseSerializer serializer);
+
+@protected void sse_encode_preview_frame(PreviewFrame self, SseSerializer serializer);
+
+@protected void sse_encode_process_event(ProcessEvent self, SseSerializer serializer);
+
+@protected void sse_encode_process_event_kind(ProcessEventKind self, SseSerializer serializer);
+
+@protected void sse_encode_process_filter(ProcessFilter self, SseSerializer serializer);
+
+@protected void sse_encode_process_history(ProcessHistory self, SseSerializer serializer);
+
+@protected void sse_encode_process_info(ProcessInfo self, SseSerializer serializer);
+
+@protected void sse_encode_process_sample(ProcessSample self, SseSerializer serializer);
+
+@protected void sse_encode_process_sampler_config(ProcessSamplerConfig self, SseSerializer serializer);
+
+@protected void sse_encode_process_sort_key(ProcessSortKey self, SseSerializer serializer);
+
+@protected void sse_encode_process_tree_node(ProcessTreeNode self, SseSerializer serializer);
+
+@protected void sse_encode_process_usage_summary(ProcessUsageSummary self, SseSerializer serializer);
+
+@protected void sse_encode_quiet_hours(QuietHours self, SseSerializer serializer);
+
+@protected void sse_encode_record_f_64_f_64((double,double) self, SseSerializer serializer);
+
+@protected void sse_encode_record_i_32_i_32((int,int) self, SseSerializer serializer);
+
+@protected void sse_encode_record_i_32_i_32_i_32_i_32((int,int,int,int) self, SseSerializer serializer);
+
+@protected void sse_encode_resource_budget_config(ResourceBudgetConfig self, SseSerializer serializer);
+
+@protected void sse_encode_resource_budget_status(ResourceBudgetStatus self, SseSerializer serializer);
+
+@protected void sse_encode_screenshot(Screenshot self, SseSerializer serializer);
+
+@protected void sse_encode_screenshot_format(ScreenshotFormat self, SseSerializer serializer);
+
+@protected void sse_encode_screenshot_options(ScreenshotOptions self, SseSerializer serializer);
+
+@protected void sse_encode_screenshot_schedule_config(ScreenshotScheduleConfig self, SseSerializer serializer);
+
+@protected void sse_encode_spool_entry(SpoolEntry self, SseSerializer serializer);
+
+@protected void sse_encode_spool_quota_result(SpoolQuotaResult self, SseSerializer serializer);
+
+@protected void sse_encode_spooled_screenshot(SpooledScreenshot self, SseSerializer serializer);
+
+@protected void sse_encode_system_snapshot(SystemSnapshot self, SseSerializer serializer);
+
+@protected void sse_encode_terminate_options(TerminateOptions self, SseSerializer serializer);
+
+@protected void sse_encode_terminate_outcome(TerminateOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_terminate_signal(TerminateSignal self, SseSerializer serializer);
+
+@protected void sse_encode_timelapse_config(TimelapseConfig self, SseSerializer serializer);
+
+@protected void sse_encode_timelapse_format(TimelapseFormat self, SseSerializer serializer);
+
+@protected void sse_encode_timelapse_state(TimelapseState self, SseSerializer serializer);
+
+@protected void sse_encode_timelapse_status(TimelapseStatus self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_watermark_options(WatermarkOptions self, SseSerializer serializer);
+
+@protected void sse_encode_watermark_position(WatermarkPosition self, SseSerializer serializer);
+
+@protected void sse_encode_window_details(WindowDetails self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_window_details(WindowDetails self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
-}
+            
+        }
+        
\ No newline at end of file
@@ -1,5 +1,7 @@
 use x_win::{get_active_window, get_open_windows, XWinError, WindowInfo, get_window_icon};
 use flutter_rust_bridge::frb;
+use crate::api::display_geometry::{locate_rect, native_rect_to_physical, MonitorLocation};
+use crate::api::resource_budget::throttled_interval;
 use crate::frb_generated::StreamSink;
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -17,7 +19,8 @@ lazy_static::lazy_static! {
 pub struct WindowDetails {
     pub id: u32,
     pub title: String,
-    pub position: (i32, i32, i32, i32), // x, y, width, height
+    pub position: (i32, i32, i32, i32), // x, y, width, height in physical desktop pixels
+    pub monitor: Option<MonitorLocation>, // Monitor showing most of the window, top-left relative to it
     pub is_full_screen: bool,
     pub process_name: String,
     pub process_path: String,
@@ -26,15 +29,18 @@ pub struct WindowDetails {
 }
 
 fn convert_window_info(window: &WindowInfo) -> WindowDetails {
+    let position = native_rect_to_physical((
+        window.position.x,
+        window.position.y,
+        window.position.width,
+        window.position.height,
+    ));
+
     WindowDetails {
         id: window.id,
         title: window.title.clone(),
-        position: (
-            window.position.x,
-            window.position.y,
-            window.position.width,
-            window.position.height,
-        ),
+        position,
+        monitor: locate_rect(position),
         is_full_screen: window.position.is_full_screen,
         process_name: window.info.name.clone(),
         process_path: window.info.path.clone(),
@@ -114,6 +120,17 @@ pub fn get_current_platform() -> String {
     }
 }
 
+/// Use a different polling frequency based on platform
+pub(crate) fn window_polling_interval() -> Duration {
+    if cfg!(target_os = "windows") {
+        Duration::from_millis(300) // Windows is generally faster
+    } else if cfg!(target_os = "macos") {
+        Duration::from_millis(500) // Default for macOS
+    } else {
+        Duration::from_millis(700) // Slower for Linux and others to reduce overhead
+    }
+}
+
 #[frb(sync)]
 pub fn start_window_listener_stream(sink: StreamSink<WindowDetails>) -> u64 {
     let running = Arc::new(Mutex::new(true));
@@ -128,15 +145,6 @@ pub fn start_window_listener_stream(sink: StreamSink<WindowDetails>) -> u64 {
     thread::spawn(move || {
         let mut last_window_id: u32 = 0;
         
-        // Use a different polling frequency based on platform
-        let polling_interval = if cfg!(target_os = "windows") {
-            Duration::from_millis(300) // Windows is generally faster
-        } else if cfg!(target_os = "macos") {
-            Duration::from_millis(500) // Default for macOS
-        } else {
-            Duration::from_millis(700) // Slower for Linux and others to reduce overhead
-        };
-        
         while *running_clone.lock().unwrap() {
             match get_active_window() {
                 Ok(window) => {
@@ -151,7 +159,7 @@ pub fn start_window_listener_stream(sink: StreamSink<WindowDetails>) -> u64 {
                     // If errors persist on specific platforms, we could add platform-specific error handling here
                 }
             }
-            thread::sleep(polling_interval);
+            thread::sleep(throttled_interval(window_polling_interval()));
         }
     });
     
@@ -0,0 +1,474 @@
+use crate::api::active_window_listener::get_active_window_info;
+use crate::api::desktop_notification::send_notification;
+use crate::api::get_all_process_list::{process_info_from, ProcessInfo};
+use crate::api::process_termination::{terminate_process, TerminateOptions, TerminateOutcome};
+use crate::api::process_watcher::pattern_matches;
+use crate::api::screenshot_capture::unix_time_ms;
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind, Users};
+
+// Store running policy engines with unique IDs
+lazy_static::lazy_static! {
+    static ref POLICY_ENGINES: Mutex<HashMap<u64, Arc<Mutex<bool>>>> = Mutex::new(HashMap::new());
+    static ref NEXT_ENGINE_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+/// Shortest allowed time between two evaluations
+const MIN_EVALUATION_INTERVAL_MS: u64 = 1_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyAction {
+    /// Only report the violation
+    Log,
+    /// Report it and show a desktop notification
+    Notify,
+    /// Notify, then kill the process after the rule's `grace_seconds` unless it exits first.
+    /// Leaving a matching window does not cancel the kill.
+    WarnThenKill,
+    /// Kill the process right away
+    Kill,
+}
+
+/// One blocklist entry. Patterns follow `ProcessFilter`: case-insensitive, glob with
+/// `*` / `?`, substring otherwise. Process patterns match any running process, window
+/// title patterns only the process owning the active window.
+#[derive(Debug, Clone)]
+pub struct PolicyRule {
+    /// Unique id, reported back in violations
+    pub id: String,
+    pub name_patterns: Vec<String>,
+    pub path_patterns: Vec<String>,
+    pub window_title_patterns: Vec<String>,
+    pub action: PolicyAction,
+    /// Time between the warning and the kill, only used by `PolicyAction::WarnThenKill`
+    pub grace_seconds: u32,
+    /// Notification text, defaults to a message naming the rule
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyConfig {
+    pub rules: Vec<PolicyRule>,
+    /// Time between evaluations, at least one second
+    pub interval_ms: u64,
+}
+
+/// What the engine did about a violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyEnforcement {
+    Logged,
+    Notified,
+    /// Notified, the process will be killed at `kill_at_ms`
+    Warned,
+    Killed,
+    KillFailed,
+    /// The process exited on its own before it was killed
+    Exited,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyViolation {
+    pub rule_id: String,
+    pub pid: u32,
+    pub process_name: String,
+    pub exe_path: Option<String>,
+    /// Set when the rule matched the active window title
+    pub window_title: Option<String>,
+    pub enforcement: PolicyEnforcement,
+    /// Only set for `Warned`
+    pub kill_at_ms: Option<u64>,
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+}
+
+/// A process currently violating a rule
+#[derive(Debug, Clone, PartialEq)]
+struct PolicyTarget {
+    pid: u32,
+    start_time: u64,
+    name: String,
+    exe_path: Option<String>,
+    window_title: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PolicyStep {
+    Log,
+    Notify,
+    Warn { kill_at_ms: u64 },
+    Kill,
+}
+
+/// Violations that were already acted on, so each one is reported once.
+/// The start time is part of the key so a reused PID counts as a new process.
+#[frb(ignore)]
+#[derive(Default)]
+struct PolicyState {
+    /// Pending kill deadline for `WarnThenKill`, `None` once nothing is left to do
+    active: HashMap<(String, u32, u64), Option<u64>>,
+}
+
+/// The active window as seen by the rules
+struct ActiveWindow {
+    title: String,
+    process_id: u32,
+}
+
+/// Starts evaluating `config.rules` against running processes and the active window.
+/// Every action taken is streamed as a `PolicyViolation`. Returns an id for `stop_app_policy`.
+#[frb(sync)]
+pub fn start_app_policy(config: PolicyConfig, sink: StreamSink<PolicyViolation>) -> Result<u64, String> {
+    validate_rules(&config.rules)?;
+
+    let running = Arc::new(Mutex::new(true));
+    let running_clone = Arc::clone(&running);
+
+    let engine_id = NEXT_ENGINE_ID.fetch_add(1, Ordering::SeqCst);
+    POLICY_ENGINES.lock().unwrap().insert(engine_id, running);
+
+    let interval = Duration::from_millis(config.interval_ms.max(MIN_EVALUATION_INTERVAL_MS));
+
+    thread::spawn(move || {
+        println!("[POLICY] Engine {} started with {} rule(s)", engine_id, config.rules.len());
+        let mut system = System::new();
+        let users = Users::new_with_refreshed_list();
+        let refresh_kind = ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_user(UpdateKind::OnlyIfNotSet)
+            .without_tasks();
+        let mut state = PolicyState::default();
+        let own_pid = std::process::id();
+
+        while *running_clone.lock().unwrap() {
+            system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+            let processes: Vec<ProcessInfo> = system
+                .processes()
+                .iter()
+                .filter(|(pid, _)| pid.as_u32() != own_pid)
+                .map(|(pid, process)| process_info_from(*pid, process, &users))
+                .collect();
+            let active_window = get_active_window_info().ok().map(|window| ActiveWindow {
+                title: window.title,
+                process_id: window.process_id,
+            });
+
+            let steps = evaluate(&config.rules, &processes, active_window.as_ref(), &mut state, unix_time_ms());
+            for (rule, target, step) in steps {
+                let violation = enforce(rule, target, step);
+                if sink.add(violation).is_err() {
+                    // Dart side stopped listening
+                    println!("[POLICY] Stream closed, stopping engine {}", engine_id);
+                    POLICY_ENGINES.lock().unwrap().remove(&engine_id);
+                    return;
+                }
+            }
+
+            thread::sleep(interval);
+        }
+
+        println!("[POLICY] Engine {} stopped", engine_id);
+    });
+
+    Ok(engine_id)
+}
+
+#[frb(sync)]
+pub fn stop_app_policy(engine_id: u64) -> bool {
+    let mut engines = POLICY_ENGINES.lock().unwrap();
+
+    if let Some(running) = engines.remove(&engine_id) {
+        if let Ok(mut guard) = running.lock() {
+            *guard = false;
+        }
+        true
+    } else {
+        false
+    }
+}
+
+fn validate_rules(rules: &[PolicyRule]) -> Result<(), String> {
+    let mut ids = HashSet::new();
+    for rule in rules {
+        if rule.id.trim().is_empty() {
+            return Err("Policy rule ids must not be empty".to_string());
+        }
+        if !ids.insert(rule.id.as_str()) {
+            return Err(format!("Duplicate policy rule id '{}'", rule.id));
+        }
+        let patterns = || rule.name_patterns.iter().chain(&rule.path_patterns).chain(&rule.window_title_patterns);
+        if patterns().next().is_none() {
+            return Err(format!("Policy rule '{}' has no patterns", rule.id));
+        }
+        if patterns().any(|p| p.trim().is_empty()) {
+            return Err(format!("Policy rule '{}' has an empty pattern", rule.id));
+        }
+    }
+    Ok(())
+}
+
+fn rule_targets(rule: &PolicyRule, processes: &[ProcessInfo], active_window: Option<&ActiveWindow>) -> Vec<PolicyTarget> {
+    processes
+        .iter()
+        .filter_map(|process| {
+            let window_title = active_window
+                .filter(|window| window.process_id == process.pid)
+                .filter(|window| rule.window_title_patterns.iter().any(|p| pattern_matches(p, &window.title)))
+                .map(|window| window.title.clone());
+            let exe_path = process.exe_path.as_deref().unwrap_or("");
+            let process_matches = rule.name_patterns.iter().any(|p| pattern_matches(p, &process.name))
+                || (!exe_path.is_empty() && rule.path_patterns.iter().any(|p| pattern_matches(p, exe_path)));
+
+            (process_matches || window_title.is_some()).then(|| policy_target(process, window_title))
+        })
+        .collect()
+}
+
+fn policy_target(process: &ProcessInfo, window_title: Option<String>) -> PolicyTarget {
+    PolicyTarget {
+        pid: process.pid,
+        start_time: process.start_time,
+        name: process.name.clone(),
+        exe_path: process.exe_path.clone(),
+        window_title,
+    }
+}
+
+/// Decides what to do for every violation, given what was already done before
+fn evaluate<'a>(
+    rules: &'a [PolicyRule],
+    processes: &[ProcessInfo],
+    active_window: Option<&ActiveWindow>,
+    state: &mut PolicyState,
+    now_ms: u64,
+) -> Vec<(&'a PolicyRule, PolicyTarget, PolicyStep)> {
+    let mut steps = Vec::new();
+    let mut seen = HashSet::new();
+
+    for rule in rules {
+        for target in rule_targets(rule, processes, active_window) {
+            let key = (rule.id.clone(), target.pid, target.start_time);
+            seen.insert(key.clone());
+
+            let step = match state.active.get_mut(&key) {
+                None => {
+                    let (step, deadline) = match rule.action {
+                        PolicyAction::Log => (PolicyStep::Log, None),
+                        PolicyAction::Notify => (PolicyStep::Notify, None),
+                        PolicyAction::Kill => (PolicyStep::Kill, None),
+                        PolicyAction::WarnThenKill => {
+                            let kill_at_ms = now_ms + rule.grace_seconds as u64 * 1000;
+                            (PolicyStep::Warn { kill_at_ms }, Some(kill_at_ms))
+                        }
+                    };
+                    state.active.insert(key, deadline);
+                    step
+                }
+                Some(deadline) if deadline.is_some_and(|kill_at| now_ms >= kill_at) => {
+                    *deadline = None;
+                    PolicyStep::Kill
+                }
+                Some(_) => continue,
+            };
+            steps.push((rule, target, step));
+        }
+    }
+
+    // A violation that ended (process exited, window left) starts over next time. A pending
+    // kill stays while the process runs, so switching away from a window does not escape it.
+    let mut expired = Vec::new();
+    state.active.retain(|key, deadline| {
+        if seen.contains(key) {
+            return true;
+        }
+        let Some(kill_at) = *deadline else {
+            return false;
+        };
+        let Some(process) = processes.iter().find(|p| p.pid == key.1 && p.start_time == key.2) else {
+            return false;
+        };
+        if now_ms >= kill_at {
+            *deadline = None;
+            expired.push((key.0.clone(), process));
+        }
+        true
+    });
+    for (rule_id, process) in expired {
+        if let Some(rule) = rules.iter().find(|rule| rule.id == rule_id) {
+            steps.push((rule, policy_target(process, None), PolicyStep::Kill));
+        }
+    }
+    steps
+}
+
+/// Carries out one step and logs it
+fn enforce(rule: &PolicyRule, target: PolicyTarget, step: PolicyStep) -> PolicyViolation {
+    let message = rule
+        .message
+        .clone()
+        .unwrap_or_else(|| format!("{} is not allowed by policy '{}'", target.name, rule.id));
+
+    let (enforcement, kill_at_ms) = match step {
+        PolicyStep::Log => (PolicyEnforcement::Logged, None),
+        PolicyStep::Notify => {
+            notify(&message);
+            (PolicyEnforcement::Notified, None)
+        }
+        PolicyStep::Warn { kill_at_ms } => {
+            let seconds = kill_at_ms.saturating_sub(unix_time_ms()).div_ceil(1000);
+            notify(&format!("{}. It will be closed in {} seconds.", message, seconds));
+            (PolicyEnforcement::Warned, Some(kill_at_ms))
+        }
+        PolicyStep::Kill => (kill_target(&target), None),
+    };
+
+    println!(
+        "[POLICY] Rule '{}': {:?} {} (pid {})",
+        rule.id, enforcement, target.name, target.pid
+    );
+
+    PolicyViolation {
+        rule_id: rule.id.clone(),
+        pid: target.pid,
+        process_name: target.name,
+        exe_path: target.exe_path,
+        window_title: target.window_title,
+        enforcement,
+        kill_at_ms,
+        timestamp_ms: unix_time_ms(),
+    }
+}
+
+/// Terminates the target, but only if its PID still belongs to the same process.
+/// During a grace period the process may exit and the PID be handed to another one.
+fn kill_target(target: &PolicyTarget) -> PolicyEnforcement {
+    let mut system = System::new();
+    let pid = Pid::from_u32(target.pid);
+    system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), true, ProcessRefreshKind::nothing());
+    let same_process = system
+        .process(pid)
+        .is_some_and(|process| process.start_time() == target.start_time && process.name().to_string_lossy() == target.name);
+    if !same_process {
+        return PolicyEnforcement::Exited;
+    }
+
+    match terminate_process(target.pid, TerminateOptions::default()) {
+        TerminateOutcome::Exited | TerminateOutcome::ForceKilled => PolicyEnforcement::Killed,
+        TerminateOutcome::NotFound => PolicyEnforcement::Exited,
+        TerminateOutcome::PermissionDenied | TerminateOutcome::StillRunning => PolicyEnforcement::KillFailed,
+    }
+}
+
+fn notify(message: &str) {
+    if let Err(e) = send_notification("Application policy".to_string(), message.to_string(), None) {
+        println!("[POLICY] Failed to send notification: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, names: &[&str], titles: &[&str], action: PolicyAction) -> PolicyRule {
+        PolicyRule {
+            id: id.to_string(),
+            name_patterns: names.iter().map(|s| s.to_string()).collect(),
+            path_patterns: Vec::new(),
+            window_title_patterns: titles.iter().map(|s| s.to_string()).collect(),
+            action,
+            grace_seconds: 30,
+            message: None,
+        }
+    }
+
+    fn process(pid: u32, name: &str) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: name.to_string(),
+            cmd: Vec::new(),
+            memory_usage: 0,
+            cpu_usage: 0.0,
+            parent_pid: None,
+            user: None,
+            exe_path: None,
+            start_time: 100,
+            run_time: 0,
+            status: String::new(),
+            disk_read_bytes: 0,
+            disk_written_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn test_rules_are_validated() {
+        assert!(validate_rules(&[rule("games", &["steam"], &[], PolicyAction::Log)]).is_ok());
+        assert!(validate_rules(&[rule("", &["steam"], &[], PolicyAction::Log)]).is_err());
+        assert!(validate_rules(&[rule("empty", &[], &[], PolicyAction::Log)]).is_err());
+        let duplicate = rule("games", &["steam"], &[], PolicyAction::Log);
+        assert!(validate_rules(&[duplicate.clone(), duplicate]).is_err());
+    }
+
+    #[test]
+    fn test_each_violation_is_acted_on_once() {
+        let rules = vec![
+            rule("torrent", &["qbittorrent"], &[], PolicyAction::Notify),
+            rule("youtube", &[], &["*youtube*"], PolicyAction::Log),
+        ];
+        let processes = vec![process(10, "qbittorrent"), process(20, "firefox")];
+        let window = ActiveWindow { title: "Cats - YouTube".to_string(), process_id: 20 };
+        let mut state = PolicyState::default();
+
+        let steps = evaluate(&rules, &processes, Some(&window), &mut state, 0);
+        let summary: Vec<(&str, u32, PolicyStep)> = steps.iter().map(|(r, t, s)| (r.id.as_str(), t.pid, *s)).collect();
+        assert_eq!(summary, vec![("torrent", 10, PolicyStep::Notify), ("youtube", 20, PolicyStep::Log)]);
+        assert_eq!(steps[1].1.window_title.as_deref(), Some("Cats - YouTube"));
+
+        assert!(evaluate(&rules, &processes, Some(&window), &mut state, 1_000).is_empty());
+
+        // Leaving the window ends the violation, coming back reports it again
+        assert!(evaluate(&rules, &processes, None, &mut state, 2_000).is_empty());
+        assert_eq!(evaluate(&rules, &processes, Some(&window), &mut state, 3_000).len(), 1);
+    }
+
+    #[test]
+    fn test_warn_then_kill_waits_for_grace_period() {
+        let rules = vec![rule("miner", &["xmrig"], &[], PolicyAction::WarnThenKill)];
+        let processes = vec![process(42, "xmrig")];
+        let mut state = PolicyState::default();
+
+        let steps = evaluate(&rules, &processes, None, &mut state, 1_000);
+        assert_eq!(steps[0].2, PolicyStep::Warn { kill_at_ms: 31_000 });
+        assert!(evaluate(&rules, &processes, None, &mut state, 30_999).is_empty());
+        assert_eq!(evaluate(&rules, &processes, None, &mut state, 31_000)[0].2, PolicyStep::Kill);
+        assert!(evaluate(&rules, &processes, None, &mut state, 40_000).is_empty());
+    }
+
+    #[test]
+    fn test_leaving_the_window_does_not_cancel_a_pending_kill() {
+        let rules = vec![rule("youtube", &[], &["*youtube*"], PolicyAction::WarnThenKill)];
+        let processes = vec![process(20, "firefox")];
+        let window = ActiveWindow { title: "Cats - YouTube".to_string(), process_id: 20 };
+        let mut state = PolicyState::default();
+
+        let steps = evaluate(&rules, &processes, Some(&window), &mut state, 1_000);
+        assert_eq!(steps[0].2, PolicyStep::Warn { kill_at_ms: 31_000 });
+
+        // Switching away and back keeps the original deadline
+        assert!(evaluate(&rules, &processes, None, &mut state, 10_000).is_empty());
+        assert!(evaluate(&rules, &processes, Some(&window), &mut state, 20_000).is_empty());
+
+        let steps = evaluate(&rules, &processes, None, &mut state, 31_000);
+        assert_eq!(steps.len(), 1);
+        assert_eq!((steps[0].1.pid, steps[0].2), (20, PolicyStep::Kill));
+
+        // Acted on once, then forgotten when the process exits
+        assert!(evaluate(&rules, &processes, Some(&window), &mut state, 32_000).is_empty());
+        assert!(evaluate(&rules, &[], None, &mut state, 40_000).is_empty());
+        assert!(state.active.is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::api::frame_analysis::{analyze_frame, FrameClassification};
+use crate::api::resource_budget::wait_for_encoding_budget;
+use crate::api::screenshot_capture::{capture_screen_image, encode_image, resize_to_max_width, unix_time_ms, ScreenshotFormat};
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Store running previews with unique IDs
+lazy_static::lazy_static! {
+    static ref CAPTURE_PREVIEWS: Mutex<HashMap<u64, Arc<Mutex<bool>>>> = Mutex::new(HashMap::new());
+    static ref NEXT_PREVIEW_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+/// Upper bound on the preview rate, full-screen captures are expensive
+const MAX_PREVIEW_FPS: f64 = 5.0;
+
+/// JPEG quality used for preview thumbnails
+const PREVIEW_JPEG_QUALITY: u8 = 70;
+
+/// Longest a preview frame waits for the resource budget, a stale preview is worse than a slow one
+const MAX_PREVIEW_ENCODING_DEFERRAL: Duration = Duration::from_secs(2);
+
+/// A downsized frame of what the tracker would capture right now
+#[derive(Debug, Clone)]
+pub struct PreviewFrame {
+    /// JPEG encoded thumbnail
+    pub jpeg: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// Capture time in milliseconds since the Unix epoch
+    pub captured_at_ms: u64,
+    /// Classification of the full-size frame, e.g. to flag black captures during setup
+    pub classification: FrameClassification,
+}
+
+/// Streams JPEG thumbnails of the primary screen at `fps` frames per second.
+///
+/// Frames go through the same capture backend as `capture_screenshot`, so the
+/// preview shows exactly what would be uploaded. Returns an id for `stop_capture_preview`.
+#[frb(sync)]
+pub fn start_capture_preview(fps: f64, max_width: u32, sink: StreamSink<PreviewFrame>) -> Result<u64, String> {
+    if !(fps > 0.0) {
+        return Err("Preview frame rate must be greater than zero".to_string());
+    }
+    let frame_interval = Duration::from_secs_f64(1.0 / fps.min(MAX_PREVIEW_FPS));
+
+    let running = Arc::new(Mutex::new(true));
+    let running_clone = Arc::clone(&running);
+
+    let preview_id = NEXT_PREVIEW_ID.fetch_add(1, Ordering::SeqCst);
+    CAPTURE_PREVIEWS.lock().unwrap().insert(preview_id, running);
+
+    thread::spawn(move || {
+        println!("[PREVIEW] Preview {} started at {:?} per frame", preview_id, frame_interval);
+
+        while *running_clone.lock().unwrap() {
+            let started = Instant::now();
+
+            match capture_preview_frame(max_width) {
+                Ok(frame) => {
+                    if sink.add(frame).is_err() {
+                        // Dart side stopped listening
+                        println!("[PREVIEW] Stream closed, stopping preview {}", preview_id);
+                        break;
+                    }
+                }
+                Err(e) => println!("[PREVIEW] Preview capture failed: {}", e),
+            }
+
+            // Capture time counts towards the frame interval
+            if let Some(remaining) = frame_interval.checked_sub(started.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+
+        CAPTURE_PREVIEWS.lock().unwrap().remove(&preview_id);
+        println!("[PREVIEW] Preview {} stopped", preview_id);
+    });
+
+    Ok(preview_id)
+}
+
+#[frb(sync)]
+pub fn stop_capture_preview(preview_id: u64) -> bool {
+    let mut previews = CAPTURE_PREVIEWS.lock().unwrap();
+
+    if let Some(running) = previews.remove(&preview_id) {
+        if let Ok(mut guard) = running.lock() {
+            *guard = false;
+        }
+        true
+    } else {
+        false
+    }
+}
+
+fn capture_preview_frame(max_width: u32) -> anyhow::Result<PreviewFrame> {
+    let captured_at_ms = unix_time_ms();
+    let image = capture_screen_image()?;
+    wait_for_encoding_budget(MAX_PREVIEW_ENCODING_DEFERRAL);
+    let classification = analyze_frame(&image).classification;
+    let thumbnail = resize_to_max_width(image, max_width);
+    let jpeg = encode_image(&thumbnail, ScreenshotFormat::Jpeg, PREVIEW_JPEG_QUALITY)?;
+
+    Ok(PreviewFrame {
+        jpeg,
+        width: thumbnail.width(),
+        height: thumbnail.height(),
+        captured_at_ms,
+        classification,
+    })
+}
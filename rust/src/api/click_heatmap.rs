@@ -0,0 +1,330 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::frb;
+use image::{imageops::FilterType, ImageBuffer, Luma, Rgba, RgbaImage};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::api::active_window_listener::get_active_window_info;
+use crate::api::display_geometry::cached_display_geometry;
+use crate::api::mouse_listener::MouseEvent;
+use crate::api::screenshot_capture::{decode_base64_image, encode_image, ScreenshotFormat};
+
+// Store heatmaps with unique IDs
+lazy_static::lazy_static! {
+    static ref CLICK_HEATMAPS: Mutex<HashMap<u64, Arc<Mutex<ClickHeatmap>>>> = Mutex::new(HashMap::new());
+    static ref NEXT_HEATMAP_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatmapScopeKind {
+    /// A whole monitor, by `MonitorLocation.monitor_id`
+    Monitor,
+    /// A single window, by `WindowDetails.id`. Only clicks while it is active count.
+    Window,
+}
+
+/// What the heatmap area covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeatmapScope {
+    pub kind: HeatmapScopeKind,
+    /// Monitor or window id, depending on `kind`
+    pub id: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct HeatmapConfig {
+    pub scope: HeatmapScope,
+    /// Grid resolution, clicks are binned relative to the area size so grids from
+    /// different resolutions can be aggregated
+    pub grid_width: u32,
+    pub grid_height: u32,
+    /// Count right clicks as well as left clicks
+    pub include_right_clicks: bool,
+}
+
+/// Raw click counts, row-major
+#[derive(Debug, Clone)]
+pub struct HeatmapGrid {
+    pub scope: HeatmapScope,
+    pub grid_width: u32,
+    pub grid_height: u32,
+    pub cells: Vec<u32>,
+    pub total_clicks: u64,
+}
+
+struct ClickHeatmap {
+    config: HeatmapConfig,
+    cells: Vec<u32>,
+    total_clicks: u64,
+}
+
+impl ClickHeatmap {
+    fn new(config: HeatmapConfig) -> Self {
+        let cells = vec![0; (config.grid_width * config.grid_height) as usize];
+        ClickHeatmap { config, cells, total_clicks: 0 }
+    }
+
+    /// Adds a click at `(x, y)` within an area of `area_size` pixels
+    fn add(&mut self, (x, y): (i32, i32), area_size: (u32, u32)) -> bool {
+        let (area_width, area_height) = area_size;
+        if x < 0 || y < 0 || area_width == 0 || area_height == 0 || x as u32 >= area_width || y as u32 >= area_height {
+            return false;
+        }
+        let column = (x as u64 * self.config.grid_width as u64 / area_width as u64) as u32;
+        let row = (y as u64 * self.config.grid_height as u64 / area_height as u64) as u32;
+        let index = (row * self.config.grid_width + column) as usize;
+        self.cells[index] = self.cells[index].saturating_add(1);
+        self.total_clicks += 1;
+        true
+    }
+
+    fn grid(&self) -> HeatmapGrid {
+        HeatmapGrid {
+            scope: self.config.scope,
+            grid_width: self.config.grid_width,
+            grid_height: self.config.grid_height,
+            cells: self.cells.clone(),
+            total_clicks: self.total_clicks,
+        }
+    }
+}
+
+/// Creates a heatmap. Clicks reported by a running mouse listener are added automatically,
+/// `add_heatmap_click` can feed events received elsewhere.
+#[frb(sync)]
+pub fn create_click_heatmap(config: HeatmapConfig) -> Result<u64, String> {
+    if config.grid_width == 0 || config.grid_height == 0 || config.grid_width > 1024 || config.grid_height > 1024 {
+        return Err("Grid size must be between 1 and 1024 cells per side".to_string());
+    }
+
+    let heatmap_id = NEXT_HEATMAP_ID.fetch_add(1, Ordering::SeqCst);
+    CLICK_HEATMAPS
+        .lock()
+        .unwrap()
+        .insert(heatmap_id, Arc::new(Mutex::new(ClickHeatmap::new(config))));
+    Ok(heatmap_id)
+}
+
+/// Adds a click event to one heatmap. Returns `false` if it falls outside its scope.
+pub fn add_heatmap_click(heatmap_id: u64, event: MouseEvent) -> bool {
+    match get_heatmap(heatmap_id) {
+        Ok(heatmap) => {
+            let mut active_window = None;
+            add_click(&mut heatmap.lock().unwrap(), &event, &mut active_window)
+        }
+        Err(_) => false,
+    }
+}
+
+#[frb(sync)]
+pub fn reset_click_heatmap(heatmap_id: u64) -> bool {
+    match get_heatmap(heatmap_id) {
+        Ok(heatmap) => {
+            let mut heatmap = heatmap.lock().unwrap();
+            heatmap.cells.iter_mut().for_each(|cell| *cell = 0);
+            heatmap.total_clicks = 0;
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[frb(sync)]
+pub fn export_click_heatmap_grid(heatmap_id: u64) -> Result<HeatmapGrid, String> {
+    get_heatmap(heatmap_id)
+        .map(|heatmap| heatmap.lock().unwrap().grid())
+        .map_err(|e| e.to_string())
+}
+
+/// Renders the heatmap as a `width` x `height` PNG.
+///
+/// With `background_base64` (e.g. a screenshot of the same monitor / window) the heat is
+/// blended over the image at `opacity`, otherwise it is drawn on a transparent background.
+pub fn export_click_heatmap_png(
+    heatmap_id: u64,
+    width: u32,
+    height: u32,
+    background_base64: Option<String>,
+    opacity: f32,
+) -> Result<Vec<u8>> {
+    if width == 0 || height == 0 {
+        return Err(anyhow!("Output size must not be empty"));
+    }
+    let grid = get_heatmap(heatmap_id)?.lock().unwrap().grid();
+    let background = match background_base64 {
+        Some(encoded) => Some(decode_base64_image(&encoded)?),
+        None => None,
+    };
+
+    let image = render_heatmap(&grid, width, height, background, opacity);
+    encode_image(&image, ScreenshotFormat::Png, 0)
+}
+
+#[frb(sync)]
+pub fn destroy_click_heatmap(heatmap_id: u64) -> bool {
+    CLICK_HEATMAPS.lock().unwrap().remove(&heatmap_id).is_some()
+}
+
+/// Feeds a click reported by the mouse listener into every heatmap in scope
+pub(crate) fn record_click(event: &MouseEvent) {
+    let heatmaps: Vec<Arc<Mutex<ClickHeatmap>>> = CLICK_HEATMAPS.lock().unwrap().values().cloned().collect();
+    // Looked up at most once per click, and only if a window heatmap needs it
+    let mut active_window = None;
+    for heatmap in heatmaps {
+        add_click(&mut heatmap.lock().unwrap(), event, &mut active_window);
+    }
+}
+
+fn get_heatmap(heatmap_id: u64) -> Result<Arc<Mutex<ClickHeatmap>>> {
+    CLICK_HEATMAPS
+        .lock()
+        .unwrap()
+        .get(&heatmap_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Heatmap {} not found", heatmap_id))
+}
+
+/// Id and position of the active window, `None` if it could not be determined
+type ActiveWindow = Option<(u32, (i32, i32, i32, i32))>;
+
+/// Bins a press event into the heatmap if it lies within its scope.
+/// `active_window` caches the window lookup across heatmaps.
+fn add_click(heatmap: &mut ClickHeatmap, event: &MouseEvent, active_window: &mut Option<ActiveWindow>) -> bool {
+    let counted = event.is_left_click || (heatmap.config.include_right_clicks && event.is_right_click);
+    if !event.is_button_press || !counted {
+        return false;
+    }
+
+    let scope = heatmap.config.scope;
+    match scope.kind {
+        HeatmapScopeKind::Monitor => {
+            let monitor_id = scope.id;
+            let location = match event.monitor {
+                Some(location) if location.monitor_id == monitor_id => location,
+                _ => return false,
+            };
+            let display = match cached_display_geometry().into_iter().find(|d| d.id == monitor_id) {
+                Some(display) => display,
+                None => return false,
+            };
+            heatmap.add((location.x, location.y), (display.physical.width, display.physical.height))
+        }
+        HeatmapScopeKind::Window => {
+            let window_id = scope.id;
+            let window = active_window.get_or_insert_with(|| get_active_window_info().ok().map(|w| (w.id, w.position)));
+            match window {
+                Some((id, (x, y, width, height))) if *id == window_id => heatmap.add(
+                    (event.coords.0 - *x, event.coords.1 - *y),
+                    ((*width).max(0) as u32, (*height).max(0) as u32),
+                ),
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Draws the grid as a blurred, colour-mapped density image
+fn render_heatmap(grid: &HeatmapGrid, width: u32, height: u32, background: Option<RgbaImage>, opacity: f32) -> RgbaImage {
+    let density: ImageBuffer<Luma<f32>, Vec<f32>> =
+        ImageBuffer::from_fn(grid.grid_width, grid.grid_height, |x, y| {
+            Luma([grid.cells[(y * grid.grid_width + x) as usize] as f32])
+        });
+    // Spread single clicks over neighbouring cells so hot spots read as areas
+    let density = image::imageops::blur(&density, 1.0);
+    let density = image::imageops::resize(&density, width, height, FilterType::Triangle);
+    let max = density.pixels().map(|p| p.0[0]).fold(0.0f32, f32::max);
+
+    let mut output = match background {
+        Some(background) => image::imageops::resize(&background, width, height, FilterType::Triangle),
+        None => RgbaImage::new(width, height),
+    };
+    if max <= 0.0 {
+        return output;
+    }
+
+    let opacity = opacity.clamp(0.0, 1.0);
+    for (x, y, pixel) in output.enumerate_pixels_mut() {
+        let intensity = (density.get_pixel(x, y).0[0] / max).clamp(0.0, 1.0);
+        if intensity < 0.02 {
+            continue;
+        }
+        let Rgba([r, g, b, _]) = heat_colour(intensity);
+        // Cold areas fade out so the background stays readable
+        let alpha = opacity * intensity.sqrt();
+        let blend = |under: u8, over: u8| (under as f32 * (1.0 - alpha) + over as f32 * alpha).round() as u8;
+        pixel.0 = [
+            blend(pixel.0[0], r),
+            blend(pixel.0[1], g),
+            blend(pixel.0[2], b),
+            pixel.0[3].max((alpha * 255.0).round() as u8),
+        ];
+    }
+    output
+}
+
+/// Blue -> cyan -> green -> yellow -> red colour ramp
+fn heat_colour(intensity: f32) -> Rgba<u8> {
+    const STOPS: [(f32, [f32; 3]); 5] = [
+        (0.0, [0.0, 0.0, 255.0]),
+        (0.25, [0.0, 255.0, 255.0]),
+        (0.5, [0.0, 255.0, 0.0]),
+        (0.75, [255.0, 255.0, 0.0]),
+        (1.0, [255.0, 0.0, 0.0]),
+    ];
+
+    let intensity = intensity.clamp(0.0, 1.0);
+    for pair in STOPS.windows(2) {
+        let (start, from) = pair[0];
+        let (end, to) = pair[1];
+        if intensity <= end {
+            let t = (intensity - start) / (end - start);
+            let channel = |i: usize| (from[i] + (to[i] - from[i]) * t).round() as u8;
+            return Rgba([channel(0), channel(1), channel(2), 255]);
+        }
+    }
+    Rgba([255, 0, 0, 255])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> HeatmapConfig {
+        HeatmapConfig {
+            scope: HeatmapScope { kind: HeatmapScopeKind::Monitor, id: 1 },
+            grid_width: 4,
+            grid_height: 2,
+            include_right_clicks: false,
+        }
+    }
+
+    #[test]
+    fn test_clicks_are_binned_relative_to_area() {
+        let mut heatmap = ClickHeatmap::new(config());
+        assert!(heatmap.add((0, 0), (1920, 1080)));
+        assert!(heatmap.add((1919, 1079), (1920, 1080)));
+        // Same relative position on a 4K monitor lands in the same cell
+        assert!(heatmap.add((3839, 2159), (3840, 2160)));
+        assert!(!heatmap.add((1920, 10), (1920, 1080)));
+
+        let grid = heatmap.grid();
+        assert_eq!(grid.cells, vec![1, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(grid.total_clicks, 3);
+    }
+
+    #[test]
+    fn test_render_marks_hot_spot_and_keeps_background_elsewhere() {
+        let mut heatmap = ClickHeatmap::new(HeatmapConfig { grid_width: 16, grid_height: 16, ..config() });
+        for _ in 0..10 {
+            heatmap.add((10, 10), (160, 160));
+        }
+        let background = RgbaImage::from_pixel(160, 160, Rgba([20, 20, 20, 255]));
+        let image = render_heatmap(&heatmap.grid(), 160, 160, Some(background), 0.8);
+
+        let hot = image.get_pixel(15, 15);
+        assert!(hot.0[0] > 150 && hot.0[2] < 100, "hot spot should be red: {:?}", hot);
+        assert_eq!(image.get_pixel(150, 150), &Rgba([20, 20, 20, 255]));
+        assert_eq!(heat_colour(0.0), Rgba([0, 0, 255, 255]));
+    }
+}
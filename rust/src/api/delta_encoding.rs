@@ -0,0 +1,463 @@
+use anyhow::{anyhow, Result};
+use base64::{Engine as _, engine::general_purpose};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use flutter_rust_bridge::frb;
+use image::RgbaImage;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::api::screenshot_capture::{capture_screen_image, decode_base64_image, encode_image, ScreenshotFormat};
+
+// Store encoder / decoder state with unique IDs
+lazy_static::lazy_static! {
+    static ref DELTA_ENCODERS: Mutex<HashMap<u64, Arc<Mutex<DeltaEncoder>>>> = Mutex::new(HashMap::new());
+    static ref DELTA_DECODERS: Mutex<HashMap<u64, Arc<Mutex<DeltaDecoder>>>> = Mutex::new(HashMap::new());
+    static ref NEXT_CODEC_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+/// Identifies a delta-encoded frame
+const FRAME_MAGIC: &[u8; 4] = b"PTWD";
+const FRAME_VERSION: u8 = 1;
+const FRAME_TYPE_KEY: u8 = 0;
+const FRAME_TYPE_DELTA: u8 = 1;
+/// magic + version + type + width + height + tile size + frame index
+const HEADER_SIZE: usize = 4 + 1 + 1 + 4 + 4 + 2 + 4;
+
+/// Configuration of a delta encoder
+#[derive(Debug, Clone)]
+pub struct DeltaEncoderConfig {
+    /// Edge length of the square tiles frames are compared in (8-1024)
+    pub tile_size: u32,
+    /// A full keyframe is emitted every this many frames (0 = only when required)
+    pub keyframe_interval: u32,
+    /// A keyframe replaces the delta once this share of tiles changed (0.0-1.0)
+    pub max_changed_ratio: f32,
+}
+
+impl Default for DeltaEncoderConfig {
+    fn default() -> Self {
+        DeltaEncoderConfig {
+            tile_size: 64,
+            keyframe_interval: 30,
+            max_changed_ratio: 0.6,
+        }
+    }
+}
+
+/// An encoded frame as produced by the delta encoder.
+///
+/// Layout (little endian): `"PTWD"`, version u8, type u8 (0 = key, 1 = delta),
+/// width u32, height u32, tile size u16, frame index u32, then either the PNG
+/// encoded keyframe or a u32 tile count followed by a deflate stream of
+/// `tile x u16, tile y u16, RGBA pixels` records (edge tiles are clipped).
+#[derive(Debug, Clone)]
+pub struct EncodedFrame {
+    pub data: Vec<u8>,
+    pub is_keyframe: bool,
+    pub frame_index: u32,
+    pub changed_tiles: u32,
+    pub total_tiles: u32,
+}
+
+#[frb(sync)]
+pub fn create_delta_encoder(config: DeltaEncoderConfig) -> Result<u64, String> {
+    if !(8..=1024).contains(&config.tile_size) {
+        return Err("Tile size must be between 8 and 1024".to_string());
+    }
+
+    let encoder_id = NEXT_CODEC_ID.fetch_add(1, Ordering::SeqCst);
+    DELTA_ENCODERS
+        .lock()
+        .unwrap()
+        .insert(encoder_id, Arc::new(Mutex::new(DeltaEncoder::new(config))));
+    Ok(encoder_id)
+}
+
+/// Encodes a base64 image (e.g. from `take_full_screenshot`) against the previous frame.
+pub fn encode_delta_frame(encoder_id: u64, base64_image: String) -> Result<EncodedFrame> {
+    let image = decode_base64_image(&base64_image)?;
+    get_encoder(encoder_id)?.lock().unwrap().encode(&image)
+}
+
+/// Captures the primary screen and encodes it against the previous frame.
+pub fn capture_delta_frame(encoder_id: u64) -> Result<EncodedFrame> {
+    let encoder = get_encoder(encoder_id)?;
+    let image = capture_screen_image()?;
+    let mut encoder = encoder.lock().unwrap();
+    encoder.encode(&image)
+}
+
+/// Makes the next encoded frame a keyframe, e.g. after an upload failed.
+#[frb(sync)]
+pub fn force_delta_keyframe(encoder_id: u64) -> bool {
+    match get_encoder(encoder_id) {
+        Ok(encoder) => {
+            encoder.lock().unwrap().force_keyframe();
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[frb(sync)]
+pub fn destroy_delta_encoder(encoder_id: u64) -> bool {
+    DELTA_ENCODERS.lock().unwrap().remove(&encoder_id).is_some()
+}
+
+#[frb(sync)]
+pub fn create_delta_decoder() -> u64 {
+    let decoder_id = NEXT_CODEC_ID.fetch_add(1, Ordering::SeqCst);
+    DELTA_DECODERS
+        .lock()
+        .unwrap()
+        .insert(decoder_id, Arc::new(Mutex::new(DeltaDecoder::new())));
+    decoder_id
+}
+
+/// Applies an encoded frame and returns the rebuilt full image as base64 PNG.
+pub fn decode_delta_frame(decoder_id: u64, data: Vec<u8>) -> Result<String> {
+    let decoder = DELTA_DECODERS
+        .lock()
+        .unwrap()
+        .get(&decoder_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Delta decoder {} not found", decoder_id))?;
+
+    let mut decoder = decoder.lock().unwrap();
+    let image = decoder.decode(&data)?;
+    let png = encode_image(image, ScreenshotFormat::Png, 0)?;
+    Ok(general_purpose::STANDARD.encode(&png))
+}
+
+#[frb(sync)]
+pub fn destroy_delta_decoder(decoder_id: u64) -> bool {
+    DELTA_DECODERS.lock().unwrap().remove(&decoder_id).is_some()
+}
+
+fn get_encoder(encoder_id: u64) -> Result<Arc<Mutex<DeltaEncoder>>> {
+    DELTA_ENCODERS
+        .lock()
+        .unwrap()
+        .get(&encoder_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Delta encoder {} not found", encoder_id))
+}
+
+pub(crate) struct DeltaEncoder {
+    config: DeltaEncoderConfig,
+    previous: Option<RgbaImage>,
+    frame_index: u32,
+    frames_since_keyframe: u32,
+    force_keyframe: bool,
+}
+
+impl DeltaEncoder {
+    pub(crate) fn new(config: DeltaEncoderConfig) -> Self {
+        DeltaEncoder {
+            config,
+            previous: None,
+            frame_index: 0,
+            frames_since_keyframe: 0,
+            force_keyframe: false,
+        }
+    }
+
+    pub(crate) fn force_keyframe(&mut self) {
+        self.force_keyframe = true;
+    }
+
+    pub(crate) fn encode(&mut self, image: &RgbaImage) -> Result<EncodedFrame> {
+        let tile_size = self.config.tile_size;
+        let tiles_x = image.width().div_ceil(tile_size);
+        let tiles_y = image.height().div_ceil(tile_size);
+        let total_tiles = tiles_x * tiles_y;
+
+        let changed = match &self.previous {
+            Some(previous) if previous.dimensions() == image.dimensions() => Some(changed_tiles(previous, image, tile_size)),
+            _ => None,
+        };
+
+        let keyframe_due = self.config.keyframe_interval > 0 && self.frames_since_keyframe + 1 >= self.config.keyframe_interval;
+        let frame = match changed {
+            Some(changed)
+                if !self.force_keyframe
+                    && !keyframe_due
+                    && (changed.len() as f32) <= total_tiles as f32 * self.config.max_changed_ratio =>
+            {
+                self.frames_since_keyframe += 1;
+                let data = self.encode_delta(image, &changed)?;
+                EncodedFrame {
+                    data,
+                    is_keyframe: false,
+                    frame_index: self.frame_index,
+                    changed_tiles: changed.len() as u32,
+                    total_tiles,
+                }
+            }
+            _ => {
+                self.frames_since_keyframe = 0;
+                self.force_keyframe = false;
+                let mut data = self.header(FRAME_TYPE_KEY, image);
+                data.extend(encode_image(image, ScreenshotFormat::Png, 0)?);
+                EncodedFrame {
+                    data,
+                    is_keyframe: true,
+                    frame_index: self.frame_index,
+                    changed_tiles: total_tiles,
+                    total_tiles,
+                }
+            }
+        };
+
+        self.previous = Some(image.clone());
+        self.frame_index = self.frame_index.wrapping_add(1);
+        Ok(frame)
+    }
+
+    fn header(&self, frame_type: u8, image: &RgbaImage) -> Vec<u8> {
+        let mut data = Vec::with_capacity(HEADER_SIZE);
+        data.extend_from_slice(FRAME_MAGIC);
+        data.push(FRAME_VERSION);
+        data.push(frame_type);
+        data.extend_from_slice(&image.width().to_le_bytes());
+        data.extend_from_slice(&image.height().to_le_bytes());
+        data.extend_from_slice(&(self.config.tile_size as u16).to_le_bytes());
+        data.extend_from_slice(&self.frame_index.to_le_bytes());
+        data
+    }
+
+    fn encode_delta(&self, image: &RgbaImage, changed: &[(u32, u32)]) -> Result<Vec<u8>> {
+        let tile_size = self.config.tile_size;
+        let mut data = self.header(FRAME_TYPE_DELTA, image);
+        data.extend_from_slice(&(changed.len() as u32).to_le_bytes());
+
+        let mut deflate = DeflateEncoder::new(data, Compression::default());
+        for &(tile_x, tile_y) in changed {
+            deflate.write_all(&(tile_x as u16).to_le_bytes())?;
+            deflate.write_all(&(tile_y as u16).to_le_bytes())?;
+            let (x0, y0, width, height) = tile_bounds(image, tile_size, tile_x, tile_y);
+            for y in y0..y0 + height {
+                deflate.write_all(row_slice(image, x0, y, width))?;
+            }
+        }
+        Ok(deflate.finish()?)
+    }
+}
+
+pub(crate) struct DeltaDecoder {
+    current: Option<RgbaImage>,
+    frame_index: u32,
+}
+
+impl DeltaDecoder {
+    pub(crate) fn new() -> Self {
+        DeltaDecoder {
+            current: None,
+            frame_index: 0,
+        }
+    }
+
+    /// Applies an encoded frame and returns the reconstructed image
+    pub(crate) fn decode(&mut self, data: &[u8]) -> Result<&RgbaImage> {
+        if data.len() < HEADER_SIZE || &data[0..4] != FRAME_MAGIC {
+            return Err(anyhow!("Not a delta-encoded frame"));
+        }
+        if data[4] != FRAME_VERSION {
+            return Err(anyhow!("Unsupported delta frame version {}", data[4]));
+        }
+        let frame_type = data[5];
+        let width = u32::from_le_bytes(data[6..10].try_into().unwrap());
+        let height = u32::from_le_bytes(data[10..14].try_into().unwrap());
+        let tile_size = u16::from_le_bytes(data[14..16].try_into().unwrap()) as u32;
+        let frame_index = u32::from_le_bytes(data[16..20].try_into().unwrap());
+        let payload = &data[HEADER_SIZE..];
+
+        match frame_type {
+            FRAME_TYPE_KEY => {
+                let image = image::load_from_memory(payload)
+                    .map_err(|e| anyhow!("Failed to decode keyframe: {}", e))?
+                    .to_rgba8();
+                if image.dimensions() != (width, height) {
+                    return Err(anyhow!("Keyframe size does not match its header"));
+                }
+                self.current = Some(image);
+            }
+            FRAME_TYPE_DELTA => {
+                let expected = self.frame_index.wrapping_add(1);
+                let previous = match &self.current {
+                    Some(current) if current.dimensions() == (width, height) && frame_index == expected => current,
+                    _ => return Err(anyhow!("Delta frame {} does not follow the previous frame, a keyframe is required", frame_index)),
+                };
+                if tile_size == 0 || payload.len() < 4 {
+                    return Err(anyhow!("Malformed delta frame"));
+                }
+
+                // The grid comes from the frame size, so a tile inside it never overflows the pixel math
+                let tiles_x = width.div_ceil(tile_size);
+                let tiles_y = height.div_ceil(tile_size);
+                let tile_count = u32::from_le_bytes(payload[0..4].try_into().unwrap());
+                if tile_count as u64 > tiles_x as u64 * tiles_y as u64 {
+                    return Err(anyhow!("Delta frame lists {} tiles, the frame only has {}x{}", tile_count, tiles_x, tiles_y));
+                }
+
+                // Patch a copy, a frame that fails halfway must not leave a half-updated image
+                let mut next = previous.clone();
+                let mut inflate = DeflateDecoder::new(&payload[4..]);
+                let mut position = [0u8; 4];
+                let mut row = Vec::new();
+                for _ in 0..tile_count {
+                    inflate.read_exact(&mut position)?;
+                    let tile_x = u16::from_le_bytes([position[0], position[1]]) as u32;
+                    let tile_y = u16::from_le_bytes([position[2], position[3]]) as u32;
+                    if tile_x >= tiles_x || tile_y >= tiles_y {
+                        return Err(anyhow!("Delta tile ({}, {}) is outside the frame", tile_x, tile_y));
+                    }
+
+                    let (x0, y0, tile_width, tile_height) = tile_bounds(&next, tile_size, tile_x, tile_y);
+                    row.resize(tile_width as usize * 4, 0);
+                    for y in y0..y0 + tile_height {
+                        inflate.read_exact(&mut row)?;
+                        row_slice_mut(&mut next, x0, y, tile_width).copy_from_slice(&row);
+                    }
+                }
+                self.current = Some(next);
+            }
+            other => return Err(anyhow!("Unknown delta frame type {}", other)),
+        }
+
+        self.frame_index = frame_index;
+        Ok(self.current.as_ref().unwrap())
+    }
+}
+
+/// Returns the (tile x, tile y) coordinates of all tiles that differ between the frames
+fn changed_tiles(previous: &RgbaImage, current: &RgbaImage, tile_size: u32) -> Vec<(u32, u32)> {
+    let mut changed = Vec::new();
+    for tile_y in 0..current.height().div_ceil(tile_size) {
+        for tile_x in 0..current.width().div_ceil(tile_size) {
+            let (x0, y0, width, height) = tile_bounds(current, tile_size, tile_x, tile_y);
+            let differs = (y0..y0 + height).any(|y| row_slice(previous, x0, y, width) != row_slice(current, x0, y, width));
+            if differs {
+                changed.push((tile_x, tile_y));
+            }
+        }
+    }
+    changed
+}
+
+/// Pixel bounds (x, y, width, height) of a tile, clipped at the image edges
+fn tile_bounds(image: &RgbaImage, tile_size: u32, tile_x: u32, tile_y: u32) -> (u32, u32, u32, u32) {
+    let x0 = tile_x * tile_size;
+    let y0 = tile_y * tile_size;
+    (x0, y0, tile_size.min(image.width() - x0), tile_size.min(image.height() - y0))
+}
+
+fn row_slice(image: &RgbaImage, x: u32, y: u32, width: u32) -> &[u8] {
+    let start = (y as usize * image.width() as usize + x as usize) * 4;
+    &image.as_raw()[start..start + width as usize * 4]
+}
+
+fn row_slice_mut(image: &mut RgbaImage, x: u32, y: u32, width: u32) -> &mut [u8] {
+    let start = (y as usize * image.width() as usize + x as usize) * 4;
+    let end = start + width as usize * 4;
+    let raw: &mut [u8] = image;
+    &mut raw[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    fn desktop(width: u32, height: u32) -> RgbaImage {
+        RgbaImage::from_fn(width, height, |x, y| Rgba([(x * 3) as u8, (y * 5) as u8, ((x + y) % 256) as u8, 255]))
+    }
+
+    #[test]
+    fn test_round_trip_is_pixel_exact() {
+        let config = DeltaEncoderConfig { tile_size: 16, keyframe_interval: 5, max_changed_ratio: 0.6 };
+        let mut encoder = DeltaEncoder::new(config);
+        let mut decoder = DeltaDecoder::new();
+        let mut frame = desktop(100, 70);
+
+        for step in 0..12u32 {
+            // Move a small "cursor" around, including the clipped edge tiles
+            let x = (step * 13) % 100;
+            let y = (step * 11) % 70;
+            frame.put_pixel(x, y, Rgba([255, 0, (step * 20) as u8, 255]));
+            frame.put_pixel(99, 69, Rgba([step as u8, 1, 2, 255]));
+
+            let encoded = encoder.encode(&frame).unwrap();
+            assert_eq!(encoded.is_keyframe, step % 5 == 0, "frame {}", step);
+            if !encoded.is_keyframe {
+                assert!(encoded.changed_tiles <= 2);
+            }
+            assert_eq!(decoder.decode(&encoded.data).unwrap(), &frame);
+        }
+    }
+
+    #[test]
+    fn test_size_change_and_large_change_emit_keyframes() {
+        let mut encoder = DeltaEncoder::new(DeltaEncoderConfig::default());
+        assert!(encoder.encode(&desktop(128, 128)).unwrap().is_keyframe);
+        assert!(!encoder.encode(&desktop(128, 128)).unwrap().is_keyframe);
+        assert!(encoder.encode(&desktop(64, 64)).unwrap().is_keyframe);
+
+        let inverted = RgbaImage::from_fn(64, 64, |x, y| {
+            let Rgba([r, g, b, a]) = *desktop(64, 64).get_pixel(x, y);
+            Rgba([255 - r, 255 - g, 255 - b, a])
+        });
+        assert!(encoder.encode(&inverted).unwrap().is_keyframe);
+    }
+
+    #[test]
+    fn test_delta_without_keyframe_is_rejected() {
+        let config = DeltaEncoderConfig { tile_size: 16, ..DeltaEncoderConfig::default() };
+        let mut encoder = DeltaEncoder::new(config);
+        let first = encoder.encode(&desktop(64, 64)).unwrap();
+        let mut changed = desktop(64, 64);
+        changed.put_pixel(1, 1, Rgba([0, 0, 0, 255]));
+        let delta = encoder.encode(&changed).unwrap();
+        assert!(!delta.is_keyframe);
+
+        assert!(DeltaDecoder::new().decode(&delta.data).is_err());
+        let mut decoder = DeltaDecoder::new();
+        decoder.decode(&first.data).unwrap();
+        assert_eq!(decoder.decode(&delta.data).unwrap(), &changed);
+    }
+
+    #[test]
+    fn test_malformed_delta_keeps_previous_frame() {
+        let config = DeltaEncoderConfig { tile_size: 16, ..DeltaEncoderConfig::default() };
+        let mut encoder = DeltaEncoder::new(config);
+        let first = encoder.encode(&desktop(64, 64)).unwrap();
+        let mut changed = desktop(64, 64);
+        changed.put_pixel(1, 1, Rgba([0, 0, 0, 255]));
+        changed.put_pixel(63, 63, Rgba([0, 0, 0, 255]));
+        let delta = encoder.encode(&changed).unwrap();
+
+        let mut decoder = DeltaDecoder::new();
+        decoder.decode(&first.data).unwrap();
+
+        // Truncated after the first tile
+        let mut truncated = delta.data.clone();
+        truncated.truncate(truncated.len() - 20);
+        assert!(decoder.decode(&truncated).is_err());
+
+        // Huge tile size that used to overflow the tile offset
+        let mut oversized = delta.data.clone();
+        oversized[14..16].copy_from_slice(&u16::MAX.to_le_bytes());
+        assert!(decoder.decode(&oversized).is_err());
+
+        // More tiles than the frame has
+        let mut too_many = delta.data.clone();
+        too_many[HEADER_SIZE..HEADER_SIZE + 4].copy_from_slice(&17u32.to_le_bytes());
+        assert!(decoder.decode(&too_many).is_err());
+
+        assert_eq!(decoder.decode(&delta.data).unwrap(), &changed);
+    }
+}
@@ -1,6 +1,13 @@
 use anyhow::{anyhow, Result};
+use crate::api::screenshot_capture::unix_time_ms;
+use std::collections::{HashMap, VecDeque};
 use std::process::Command;
-use notify_rust::Notification;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use notify_rust::{Notification, Timeout};
+#[cfg(all(unix, not(target_os = "macos")))]
+use notify_rust::Urgency;
 
 /// Sends a desktop notification with the specified title and message.
 ///
@@ -12,10 +19,13 @@ use notify_rust::Notification;
 ///
 /// # Returns
 ///
-/// A `Result` indicating success or failure
+/// The backend that showed the notification
 ///
 /// # Cross-platform Compatibility
 ///
+/// The platform API is tried first, then command-line tools (see
+/// `set_notification_backend_preference` to pin or exclude backends):
+///
 /// - Windows: Uses PowerShell or Windows Toast Notifications
 /// - macOS: Uses AppleScript or terminal-notifier
 /// - Linux: Uses notify-send, kdialog, zenity, or xmessage
@@ -23,9 +33,9 @@ use notify_rust::Notification;
 /// # Example
 ///
 /// ```rust
-/// use crate::api::desktop_notification::send_notification;
+/// use crate::api::desktop_notification::{send_notification, NotificationBackend};
 ///
-/// fn notify_user() -> Result<()> {
+/// fn notify_user() -> Result<NotificationBackend> {
 ///     send_notification(
 ///         "Task Complete".to_string(),
 ///         "Your long-running task has finished successfully!".to_string(),
@@ -34,231 +44,454 @@ use notify_rust::Notification;
 /// }
 /// ```
 #[flutter_rust_bridge::frb]
-pub fn send_notification(title: String, message: String, icon_path: Option<String>) -> Result<()> {
-    if Notification::new()
-        .summary(&title)
-        .body(&message)
-        .icon(icon_path.as_deref().unwrap_or(""))
-        .show()
-        .is_ok()
-    {
-        return Ok(())
+pub fn send_notification(title: String, message: String, icon_path: Option<String>) -> Result<NotificationBackend> {
+    NotificationBuilder {
+        title: &title,
+        message: &message,
+        icon_path: icon_path.as_deref(),
+        timeout: None,
+        urgency: None,
+        actions: Vec::new(),
+    }
+    .send()
+}
+
+/// A way of showing notifications. `NotifyRust` talks to the platform API
+/// directly, the others shell out to a command-line tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationBackend {
+    NotifyRust,
+    NotifySend,
+    Kdialog,
+    Zenity,
+    /// Opens a window that blocks until it is clicked away
+    Xmessage,
+    TerminalNotifier,
+    AppleScript,
+    PowerShellToast,
+    PowerShellBalloon,
+    /// Opens a dialog that blocks until it is clicked away
+    MessageBox,
+}
+
+/// Restricts which backends `send_notification` may use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationBackendPreference {
+    /// Use only this backend, ignoring the fallback chain
+    pub pinned: Option<NotificationBackend>,
+    /// Never use these backends
+    pub excluded: Vec<NotificationBackend>,
+}
+
+impl Default for NotificationBackendPreference {
+    fn default() -> Self {
+        // Blocking windows are opt-in, they interrupt whatever the user is doing
+        NotificationBackendPreference {
+            pinned: None,
+            excluded: vec![NotificationBackend::Xmessage, NotificationBackend::MessageBox],
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref BACKEND_PREFERENCE: Mutex<NotificationBackendPreference> = Mutex::new(NotificationBackendPreference::default());
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_notification_backend_preference(preference: NotificationBackendPreference) {
+    println!("[NOTIFICATION] Backend preference: {:?}", preference);
+    *BACKEND_PREFERENCE.lock().unwrap() = preference;
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_notification_backend_preference() -> NotificationBackendPreference {
+    BACKEND_PREFERENCE.lock().unwrap().clone()
+}
+
+/// Backends of this platform in fallback order
+fn platform_backends() -> Vec<NotificationBackend> {
+    use NotificationBackend::*;
+    if cfg!(target_os = "windows") {
+        vec![NotifyRust, PowerShellToast, PowerShellBalloon, MessageBox]
+    } else if cfg!(target_os = "macos") {
+        vec![NotifyRust, TerminalNotifier, AppleScript]
+    } else if cfg!(target_os = "linux") {
+        vec![NotifyRust, NotifySend, Kdialog, Zenity, Xmessage]
+    } else {
+        vec![NotifyRust]
+    }
+}
+
+/// Applies the preference to the fallback chain
+fn ordered_backends(backends: Vec<NotificationBackend>, preference: &NotificationBackendPreference) -> Vec<NotificationBackend> {
+    match preference.pinned {
+        // Pinning also overrides the default exclusions
+        Some(pinned) => backends.into_iter().filter(|backend| *backend == pinned).collect(),
+        None => backends
+            .into_iter()
+            .filter(|backend| !preference.excluded.contains(backend))
+            .collect(),
     }
-    fallback_send_notification(title, message, icon_path)
 }
 
-fn fallback_send_notification(title: String, message: String, icon_path: Option<String>) -> Result<()> {
+/// Tries the allowed backends in order and returns the one that worked
+fn send_with_fallback(builder: &NotificationBuilder) -> Result<NotificationBackend> {
+    let preference = BACKEND_PREFERENCE.lock().unwrap().clone();
+    let backends = ordered_backends(platform_backends(), &preference);
+    if backends.is_empty() {
+        return Err(anyhow!("All notification backends are excluded"));
+    }
+
+    let mut errors = Vec::new();
+    for backend in backends {
+        match send_with_backend(backend, builder) {
+            Ok(()) => {
+                if !errors.is_empty() {
+                    println!("[NOTIFICATION] Sent via {:?} after: {}", backend, errors.join("; "));
+                }
+                return Ok(backend);
+            }
+            Err(e) => errors.push(format!("{:?}: {}", backend, e)),
+        }
+    }
+    Err(anyhow!("No notification backend succeeded ({})", errors.join("; ")))
+}
+
+fn send_with_backend(backend: NotificationBackend, builder: &NotificationBuilder) -> Result<()> {
+    if backend == NotificationBackend::NotifyRust {
+        return builder
+            .to_notification()
+            .show()
+            .map(|_| ())
+            .map_err(|e| anyhow!("{}", e));
+    }
+
     #[cfg(target_os = "windows")]
     {
-        return send_notification_windows(&title, &message, icon_path.as_deref());
+        match backend {
+            NotificationBackend::PowerShellToast => return send_windows_toast(builder),
+            NotificationBackend::PowerShellBalloon => return send_windows_balloon(builder),
+            NotificationBackend::MessageBox => return send_windows_message_box(builder),
+            _ => {}
+        }
     }
     #[cfg(target_os = "macos")]
     {
-        return send_notification_macos(&title, &message, icon_path.as_deref());
+        match backend {
+            NotificationBackend::TerminalNotifier => return send_terminal_notifier(builder),
+            NotificationBackend::AppleScript => return send_apple_script(builder),
+            _ => {}
+        }
     }
     #[cfg(target_os = "linux")]
     {
-        return send_notification_linux(&title, &message, icon_path.as_deref());
+        match backend {
+            NotificationBackend::NotifySend => return send_notify_send(builder),
+            NotificationBackend::Kdialog => return send_kdialog(builder),
+            NotificationBackend::Zenity => return send_zenity(builder),
+            NotificationBackend::Xmessage => return send_xmessage(builder),
+            _ => {}
+        }
     }
-    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
-    {
-        return Err(anyhow!("Notifications not supported on this platform"));
+
+    Err(anyhow!("Not available on this platform"))
+}
+
+/// Checks whether a command-line tool is on the PATH
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn command_exists(command: &str) -> bool {
+    Command::new("sh")
+        .args(["-c", &format!("command -v {}", command)])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Runs a notification command, failing when it is missing or exits with an error
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn run_notification_command(command: &str, args: &[String]) -> Result<()> {
+    if !command_exists(command) {
+        return Err(anyhow!("{} not found", command));
+    }
+    let status = Command::new(command).args(args).status()?;
+    if !status.success() {
+        return Err(anyhow!("{} exited with {}", command, status));
     }
+    Ok(())
 }
 
 #[cfg(target_os = "windows")]
-fn send_notification_windows(title: &str, message: &str, _icon_path: Option<&str>) -> Result<()> {
-    // Try Windows 10+ toast notification first
-    if let Ok(status) = Command::new("powershell")
-        .args([
-            "-Command",
-            &format!(
-                r#"
-                [Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType=WindowsRuntime] | Out-Null;
-                [Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType=WindowsRuntime] | Out-Null;
-
-                $app = '{{{:?}}}';
-                $template = @'
-                <toast>
-                    <visual>
-                        <binding template='ToastGeneric'>
-                            <text>{}</text>
-                            <text>{}</text>
-                        </binding>
-                    </visual>
-                </toast>
-                '@;
-
-                $xml = New-Object Windows.Data.Xml.Dom.XmlDocument;
-                $xml.LoadXml($template);
-                $toast = [Windows.UI.Notifications.ToastNotification]::new($xml);
-                [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($app).Show($toast);
-                "#,
-                "PowerShell",
-                escape_powershell_string(title),
-                escape_powershell_string(message)
-            ),
-        ])
-        .status()
-    {
-        if status.success() {
-            return Ok(());
-        }
+fn run_powershell(script: &str) -> Result<()> {
+    let status = Command::new("powershell")
+        .args(["-Command", script])
+        .status()?;
+    if !status.success() {
+        return Err(anyhow!("PowerShell exited with {}", status));
     }
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn send_windows_toast(builder: &NotificationBuilder) -> Result<()> {
+    // Toasts only know "short" (~7 s) and "long" (~25 s)
+    let duration = match builder.timeout {
+        Some(0) => "long",
+        Some(seconds) if seconds > 7 => "long",
+        _ if matches!(builder.urgency, Some(NotificationUrgency::Critical)) => "long",
+        _ => "short",
+    };
 
-    // Fallback to older style notification using PowerShell
-    let script = format!(
+    // Windows 10+ toast notification
+    run_powershell(&format!(
+        r#"
+        [Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType=WindowsRuntime] | Out-Null;
+        [Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType=WindowsRuntime] | Out-Null;
+
+        $app = '{{{:?}}}';
+        $template = @'
+        <toast duration='{}'>
+            <visual>
+                <binding template='ToastGeneric'>
+                    <text>{}</text>
+                    <text>{}</text>
+                </binding>
+            </visual>
+        </toast>
+        '@;
+
+        $xml = New-Object Windows.Data.Xml.Dom.XmlDocument;
+        $xml.LoadXml($template);
+        $toast = [Windows.UI.Notifications.ToastNotification]::new($xml);
+        [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($app).Show($toast);
+        "#,
+        "PowerShell",
+        duration,
+        escape_powershell_string(builder.title),
+        escape_powershell_string(builder.message)
+    ))
+}
+
+#[cfg(target_os = "windows")]
+fn send_windows_balloon(builder: &NotificationBuilder) -> Result<()> {
+    // Older style notification using PowerShell
+    run_powershell(&format!(
         r#"
         [System.Reflection.Assembly]::LoadWithPartialName('System.Windows.Forms') | Out-Null;
         $notification = New-Object System.Windows.Forms.NotifyIcon;
-        $notification.Icon = [System.Drawing.SystemIcons]::Information;
+        $notification.Icon = [System.Drawing.SystemIcons]::{};
         $notification.BalloonTipTitle = '{}';
         $notification.BalloonTipText = '{}';
         $notification.Visible = $true;
-        $notification.ShowBalloonTip(5000);
+        $notification.ShowBalloonTip({});
         "#,
-        escape_powershell_string(title),
-        escape_powershell_string(message)
-    );
-
-    let status = Command::new("powershell")
-        .args(["-Command", &script])
-        .status()?;
-
-    if !status.success() {
-        // Last resort - message box (blocks UI but at least shows notification)
-        let msgbox_script = format!(
-            r#"
-            Add-Type -AssemblyName PresentationFramework;
-            [System.Windows.MessageBox]::Show('{}', '{}')
-            "#,
-            escape_powershell_string(message),
-            escape_powershell_string(title)
-        );
-        
-        let status = Command::new("powershell")
-            .args(["-Command", &msgbox_script])
-            .status()?;
-            
-        if !status.success() {
-            return Err(anyhow!("Failed to show notification on Windows"));
-        }
-    }
+        if matches!(builder.urgency, Some(NotificationUrgency::Critical)) { "Warning" } else { "Information" },
+        escape_powershell_string(builder.title),
+        escape_powershell_string(builder.message),
+        builder.timeout_ms().filter(|ms| *ms > 0).unwrap_or(5000)
+    ))
+}
 
-    Ok(())
+#[cfg(target_os = "windows")]
+fn send_windows_message_box(builder: &NotificationBuilder) -> Result<()> {
+    // Blocks until the user closes it
+    run_powershell(&format!(
+        r#"
+        Add-Type -AssemblyName PresentationFramework;
+        [System.Windows.MessageBox]::Show('{}', '{}')
+        "#,
+        escape_powershell_string(builder.message),
+        escape_powershell_string(builder.title)
+    ))
 }
 
 #[cfg(target_os = "macos")]
-fn send_notification_macos(title: &str, message: &str, icon_path: Option<&str>) -> Result<()> {
-    // Try terminal-notifier first (it's more feature-rich)
-    if Command::new("sh")
-        .args(["-c", "command -v terminal-notifier"])
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
-    {
-        let mut cmd = Command::new("terminal-notifier");
-        cmd.args(["-title", title, "-message", message]);
-        
-        if let Some(icon) = icon_path {
-            cmd.args(["-appIcon", icon]);
-        }
-        
-        let status = cmd.status()?;
-        if status.success() {
-            return Ok(());
-        }
+fn send_terminal_notifier(builder: &NotificationBuilder) -> Result<()> {
+    let mut args = vec!["-title".to_string(), builder.title.to_string(), "-message".to_string(), builder.message.to_string()];
+    if let Some(icon) = builder.icon_path {
+        args.extend(["-appIcon".to_string(), icon.to_string()]);
+    }
+    // No timeouts on macOS, critical notifications at least play a sound
+    if matches!(builder.urgency, Some(NotificationUrgency::Critical)) {
+        args.extend(["-sound".to_string(), "default".to_string()]);
     }
+    run_notification_command("terminal-notifier", &args)
+}
 
-    // Fallback to AppleScript
+#[cfg(target_os = "macos")]
+fn send_apple_script(builder: &NotificationBuilder) -> Result<()> {
     let apple_script = format!(
-        r#"display notification "{}" with title "{}""#,
-        escape_applescript_string(message),
-        escape_applescript_string(title)
+        r#"display notification "{}" with title "{}"{}"#,
+        escape_applescript_string(builder.message),
+        escape_applescript_string(builder.title),
+        if matches!(builder.urgency, Some(NotificationUrgency::Critical)) { r#" sound name "default""# } else { "" }
     );
+    run_notification_command("osascript", &["-e".to_string(), apple_script])
+}
 
-    let status = Command::new("osascript")
-        .args(["-e", &apple_script])
-        .status()?;
+#[cfg(target_os = "linux")]
+fn send_notify_send(builder: &NotificationBuilder) -> Result<()> {
+    run_notification_command("notify-send", &builder.notify_send_args())
+}
 
-    if !status.success() {
-        return Err(anyhow!("Failed to show notification on macOS"));
-    }
+#[cfg(target_os = "linux")]
+fn send_kdialog(builder: &NotificationBuilder) -> Result<()> {
+    // KDE's kdialog
+    run_notification_command("kdialog", &builder.kdialog_args())
+}
 
-    Ok(())
+#[cfg(target_os = "linux")]
+fn send_zenity(builder: &NotificationBuilder) -> Result<()> {
+    // GNOME/GTK's zenity
+    let text = format!("{}: {}", builder.title, builder.message);
+    run_notification_command("zenity", &["--notification".to_string(), "--text".to_string(), text])
 }
 
 #[cfg(target_os = "linux")]
-fn send_notification_linux(title: &str, message: &str, icon_path: Option<&str>) -> Result<()> {
-    // First try with notify-send (most common notification tool)
-    if Command::new("sh")
-        .args(["-c", "command -v notify-send"])
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false) 
+fn send_xmessage(builder: &NotificationBuilder) -> Result<()> {
+    // Last resort, blocks until dismissed
+    let text = format!("{}\n\n{}", builder.title, builder.message);
+    run_notification_command("xmessage", &["-center".to_string(), text])
+}
+
+/// What a backend can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NotificationBackendCapabilities {
+    /// Buttons reported back through `send_interactive_notification`
+    pub actions: bool,
+    pub body_markup: bool,
+    pub icons: bool,
+    /// Notifications stay in a notification center until dismissed
+    pub persistence: bool,
+}
+
+/// Identity of the freedesktop notification server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationServerInfo {
+    pub name: String,
+    pub vendor: String,
+    pub version: String,
+    pub spec_version: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationBackendProbe {
+    pub backend: NotificationBackend,
+    pub available: bool,
+    /// Excluded or not pinned by the current preference
+    pub disabled: bool,
+    pub capabilities: NotificationBackendCapabilities,
+    /// Set for backends going through a freedesktop notification server
+    pub server: Option<NotificationServerInfo>,
+}
+
+/// Checks which notification backends work on this machine and what they support.
+/// On Linux the capabilities come from the server's `GetCapabilities` and
+/// `GetServerInformation`, command-line tools only add what they can pass on.
+pub fn probe_notification_backends() -> Vec<NotificationBackendProbe> {
+    let preference = BACKEND_PREFERENCE.lock().unwrap().clone();
+    let enabled = ordered_backends(platform_backends(), &preference);
+    let server = probe_notification_server();
+
+    platform_backends()
+        .into_iter()
+        .map(|backend| {
+            let (available, capabilities, server) = probe_backend(backend, server.as_ref());
+            NotificationBackendProbe {
+                backend,
+                available,
+                disabled: !enabled.contains(&backend),
+                capabilities,
+                server,
+            }
+        })
+        .collect()
+}
+
+/// Capabilities and identity of the freedesktop server, `None` without one
+fn probe_notification_server() -> Option<(Vec<String>, NotificationServerInfo)> {
+    #[cfg(all(unix, not(target_os = "macos")))]
     {
-        let mut cmd = Command::new("notify-send");
-        cmd.args([title, message]);
-        
-        if let Some(icon) = icon_path {
-            cmd.args(["--icon", icon]);
-        }
-        
-        let status = cmd.status()?;
-        if status.success() {
-            return Ok(());
-        }
+        let capabilities = notify_rust::get_capabilities().ok()?;
+        let info = notify_rust::get_server_information().ok()?;
+        Some((
+            capabilities,
+            NotificationServerInfo {
+                name: info.name,
+                vendor: info.vendor,
+                version: info.version,
+                spec_version: info.spec_version,
+            },
+        ))
     }
-    
-    // Try KDE's kdialog
-    if Command::new("sh")
-        .args(["-c", "command -v kdialog"])
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
+    #[cfg(not(all(unix, not(target_os = "macos"))))]
     {
-        let status = Command::new("kdialog")
-            .args(["--title", title, "--passivepopup", message, "5"])
-            .status()?;
-            
-        if status.success() {
-            return Ok(());
-        }
+        None
     }
-    
-    // Try GNOME/GTK's zenity
-    if Command::new("sh")
-        .args(["-c", "command -v zenity"])
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
+}
+
+/// Maps `GetCapabilities` strings, see the Desktop Notifications Specification
+fn capabilities_from_server(capabilities: &[String]) -> NotificationBackendCapabilities {
+    let has = |name: &str| capabilities.iter().any(|c| c == name);
+    NotificationBackendCapabilities {
+        actions: has("actions"),
+        body_markup: has("body-markup"),
+        icons: has("icon-static") || has("icon-multi"),
+        persistence: has("persistence"),
+    }
+}
+
+fn probe_backend(
+    backend: NotificationBackend,
+    server: Option<&(Vec<String>, NotificationServerInfo)>,
+) -> (bool, NotificationBackendCapabilities, Option<NotificationServerInfo>) {
+    use NotificationBackend::*;
+    let none = NotificationBackendCapabilities::default();
+    match backend {
+        NotifyRust | NotifySend if cfg!(all(unix, not(target_os = "macos"))) => match server {
+            Some((capabilities, info)) => {
+                let mut capabilities = capabilities_from_server(capabilities);
+                // Actions need the D-Bus connection notify-send does not keep
+                capabilities.actions &= backend == NotifyRust;
+                let available = backend == NotifyRust || tool_available(backend);
+                (available, capabilities, Some(info.clone()))
+            }
+            None => (false, none, None),
+        },
+        NotifyRust => (true, NotificationBackendCapabilities { icons: true, persistence: true, ..none }, None),
+        TerminalNotifier => (tool_available(backend), NotificationBackendCapabilities { icons: true, persistence: true, ..none }, None),
+        AppleScript | PowerShellToast => (tool_available(backend), NotificationBackendCapabilities { persistence: true, ..none }, None),
+        _ => (tool_available(backend), none, None),
+    }
+}
+
+/// Whether the command behind a command-line backend exists
+fn tool_available(backend: NotificationBackend) -> bool {
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     {
-        let status = Command::new("zenity")
-            .args(["--notification", "--text", &format!("{}: {}", title, message)])
-            .status()?;
-            
-        if status.success() {
-            return Ok(());
-        }
+        let command = match backend {
+            NotificationBackend::NotifySend => "notify-send",
+            NotificationBackend::Kdialog => "kdialog",
+            NotificationBackend::Zenity => "zenity",
+            NotificationBackend::Xmessage => "xmessage",
+            NotificationBackend::TerminalNotifier => "terminal-notifier",
+            NotificationBackend::AppleScript => "osascript",
+            _ => return false,
+        };
+        command_exists(command)
     }
-    
-    // Last resort, use xmessage if available
-    if Command::new("sh")
-        .args(["-c", "command -v xmessage"])
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
+    #[cfg(target_os = "windows")]
     {
-        let status = Command::new("xmessage")
-            .args(["-center", &format!("{}\n\n{}", title, message)])
-            .status()?;
-            
-        if status.success() {
-            return Ok(());
-        }
+        // PowerShell ships with every supported Windows version
+        matches!(
+            backend,
+            NotificationBackend::PowerShellToast | NotificationBackend::PowerShellBalloon | NotificationBackend::MessageBox
+        )
+    }
+    #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+    {
+        let _ = backend;
+        false
     }
-
-    Err(anyhow!("No supported notification system found on Linux"))
 }
 
 /// Escapes special characters in a string for use in PowerShell commands.
@@ -293,12 +526,8 @@ pub fn send_notification_with_options(
     icon_path: Option<String>, 
     timeout_seconds: Option<u64>,
     urgency_level: Option<i32>
-) -> Result<()> {
-    let urgency = urgency_level.map(|level| match level {
-        0 => NotificationUrgency::Low,
-        1 => NotificationUrgency::Normal,
-        _ => NotificationUrgency::Critical,
-    });
+) -> Result<NotificationBackend> {
+    let urgency = urgency_level.map(urgency_from_level);
     
     let builder = NotificationBuilder {
         title: &title,
@@ -313,27 +542,443 @@ pub fn send_notification_with_options(
 }
 
 /// Urgency level for notifications (primarily used on Linux, but mapped to other platforms)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NotificationUrgency {
     Low,
     Normal,
     Critical,
 }
 
-struct NotificationBuilder<'a> {
-    title: &'a str,
-    message: &'a str,
-    icon_path: Option<&'a str>,
-    timeout: Option<u64>,
-    urgency: Option<NotificationUrgency>,
-    actions: Vec<(&'a str, &'a str)>, // (action_id, label)
+pub(crate) struct NotificationBuilder<'a> {
+    pub(crate) title: &'a str,
+    pub(crate) message: &'a str,
+    pub(crate) icon_path: Option<&'a str>,
+    pub(crate) timeout: Option<u64>,
+    pub(crate) urgency: Option<NotificationUrgency>,
+    pub(crate) actions: Vec<(&'a str, &'a str)>, // (action_id, label)
 }
 
 impl<'a> NotificationBuilder<'a> {
-    /// Sends the notification with the configured options
-    fn send(self) -> Result<()> {
-        // Default implementation falls back to basic notification
-        send_notification(self.title.to_string(), self.message.to_string(), self.icon_path.map(String::from))
+    /// Sends the notification with the configured options, returns the backend used.
+    /// Command-line fallbacks keep urgency and timeout where the tool supports them,
+    /// actions need the notification server and are dropped.
+    fn send(self) -> Result<NotificationBackend> {
+        send_with_fallback(&self)
+    }
+
+    /// Timeout in milliseconds, `Some(0)` means the notification never expires
+    fn timeout_ms(&self) -> Option<u32> {
+        self.timeout.map(|seconds| seconds.saturating_mul(1000).min(u32::MAX as u64) as u32)
+    }
+
+    /// Maps the options to notify-rust. Urgency and actions are only sent on
+    /// Linux/BSD, the other platforms have no equivalent hints.
+    pub(crate) fn to_notification(&self) -> Notification {
+        let mut notification = Notification::new();
+        notification
+            .summary(self.title)
+            .body(self.message)
+            .icon(self.icon_path.unwrap_or(""));
+
+        if let Some(timeout_ms) = self.timeout_ms() {
+            notification.timeout(if timeout_ms == 0 { Timeout::Never } else { Timeout::Milliseconds(timeout_ms) });
+        }
+
+        #[cfg(all(unix, not(target_os = "macos")))]
+        {
+            if let Some(urgency) = self.urgency {
+                notification.urgency(match urgency {
+                    NotificationUrgency::Low => Urgency::Low,
+                    NotificationUrgency::Normal => Urgency::Normal,
+                    NotificationUrgency::Critical => Urgency::Critical,
+                });
+            }
+            for (action_id, label) in &self.actions {
+                notification.action(action_id, label);
+            }
+        }
+
+        notification
+    }
+
+    /// Arguments for `notify-send`
+    #[cfg(any(target_os = "linux", test))]
+    fn notify_send_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(urgency) = self.urgency {
+            let level = match urgency {
+                NotificationUrgency::Low => "low",
+                NotificationUrgency::Normal => "normal",
+                NotificationUrgency::Critical => "critical",
+            };
+            args.push(format!("--urgency={}", level));
+        }
+        if let Some(timeout_ms) = self.timeout_ms() {
+            args.push(format!("--expire-time={}", timeout_ms));
+        }
+        if let Some(icon) = self.icon_path {
+            args.push(format!("--icon={}", icon));
+        }
+        // Titles starting with "-" must not be read as options
+        args.push("--".to_string());
+        args.push(self.title.to_string());
+        args.push(self.message.to_string());
+        args
+    }
+
+    /// Arguments for `kdialog`, which takes the popup timeout in seconds
+    #[cfg(any(target_os = "linux", test))]
+    fn kdialog_args(&self) -> Vec<String> {
+        let seconds = match self.timeout {
+            Some(0) => 3600, // kdialog has no sticky popups
+            Some(seconds) => seconds,
+            None => 5,
+        };
+        vec![
+            "--title".to_string(),
+            self.title.to_string(),
+            "--passivepopup".to_string(),
+            self.message.to_string(),
+            seconds.to_string(),
+        ]
+    }
+}
+
+// Notification gate: dedupe, per-category rate cap, quiet hours and DND
+lazy_static::lazy_static! {
+    static ref NOTIFICATION_GATE: Mutex<NotificationGate> = Mutex::new(NotificationGate::new(NotificationGateConfig::default()));
+    static ref GATE_FLUSHER_RUNNING: Mutex<bool> = Mutex::new(false);
+}
+
+/// How often queued notifications are checked for delivery
+const GATE_FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+/// Oldest queued notifications are dropped beyond this
+const MAX_QUEUED_NOTIFICATIONS: usize = 50;
+
+/// Daily quiet period in local time. `start_minute` > `end_minute` spans midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    /// Minutes after local midnight, e.g. 22 * 60
+    pub start_minute: u32,
+    pub end_minute: u32,
+    /// Offset of local time from UTC, e.g. `DateTime.now().timeZoneOffset.inMinutes`.
+    /// Not updated by the gate: pass the new offset to `configure_notification_gate`
+    /// after a DST switch or time zone change, otherwise quiet hours are shifted.
+    pub utc_offset_minutes: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationGateConfig {
+    /// Identical notifications (same category, title and message) within this window are dropped
+    pub dedupe_window_seconds: u64,
+    /// Notifications allowed per category within `rate_window_seconds`, 0 disables the cap
+    pub max_per_category: u32,
+    pub rate_window_seconds: u64,
+    pub quiet_hours: Option<QuietHours>,
+    pub do_not_disturb: bool,
+}
+
+impl Default for NotificationGateConfig {
+    fn default() -> Self {
+        NotificationGateConfig {
+            dedupe_window_seconds: 300,
+            max_per_category: 5,
+            rate_window_seconds: 600,
+            quiet_hours: None,
+            do_not_disturb: false,
+        }
+    }
+}
+
+/// What the gate did with a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateDecision {
+    Sent,
+    Deduplicated,
+    RateLimited,
+    /// Held back by quiet hours / do-not-disturb, delivered once they end
+    Queued,
+}
+
+/// Result of `send_gated_notification`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GatedSendResult {
+    pub decision: GateDecision,
+    /// Backend that showed the notification, only set when it was sent right away
+    pub backend: Option<NotificationBackend>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CategoryGateStats {
+    pub category: String,
+    pub sent: u64,
+    pub deduplicated: u64,
+    pub rate_limited: u64,
+    pub queued: u64,
+    /// Queued notifications dropped because the queue was full
+    pub dropped: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NotificationGateStats {
+    /// Notifications currently waiting for quiet hours / DND to end
+    pub pending: u32,
+    pub categories: Vec<CategoryGateStats>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct GatedNotification {
+    category: String,
+    title: String,
+    message: String,
+    icon_path: Option<String>,
+    urgency: Option<NotificationUrgency>,
+}
+
+struct NotificationGate {
+    config: NotificationGateConfig,
+    /// Last delivery per (category, title, message)
+    last_sent: HashMap<(String, String, String), u64>,
+    /// Delivery times per category inside the rate window
+    category_sends: HashMap<String, VecDeque<u64>>,
+    queue: VecDeque<GatedNotification>,
+    stats: HashMap<String, CategoryGateStats>,
+}
+
+impl NotificationGate {
+    fn new(config: NotificationGateConfig) -> Self {
+        NotificationGate {
+            config,
+            last_sent: HashMap::new(),
+            category_sends: HashMap::new(),
+            queue: VecDeque::new(),
+            stats: HashMap::new(),
+        }
+    }
+
+    fn stats_for(&mut self, category: &str) -> &mut CategoryGateStats {
+        self.stats.entry(category.to_string()).or_insert_with(|| CategoryGateStats {
+            category: category.to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// Quiet hours hold back everything but critical notifications, DND holds back everything
+    fn is_held_back(&self, notification: &GatedNotification, now_ms: u64) -> bool {
+        if self.config.do_not_disturb {
+            return true;
+        }
+        let critical = matches!(notification.urgency, Some(NotificationUrgency::Critical));
+        !critical && self.config.quiet_hours.is_some_and(|quiet| in_quiet_hours(&quiet, now_ms))
+    }
+
+    fn is_duplicate(&self, notification: &GatedNotification, now_ms: u64) -> bool {
+        let window_ms = self.config.dedupe_window_seconds * 1000;
+        let key = (notification.category.clone(), notification.title.clone(), notification.message.clone());
+        self.last_sent.get(&key).is_some_and(|sent| now_ms.saturating_sub(*sent) < window_ms)
+            || self.queue.iter().any(|queued| queued == notification)
+    }
+
+    fn is_rate_limited(&mut self, category: &str, now_ms: u64) -> bool {
+        if self.config.max_per_category == 0 {
+            return false;
+        }
+        let window_ms = self.config.rate_window_seconds * 1000;
+        let sends = self.category_sends.entry(category.to_string()).or_default();
+        while sends.front().is_some_and(|sent| now_ms.saturating_sub(*sent) >= window_ms) {
+            sends.pop_front();
+        }
+        sends.len() >= self.config.max_per_category as usize
+    }
+
+    fn record_send(&mut self, notification: &GatedNotification, now_ms: u64) {
+        let key = (notification.category.clone(), notification.title.clone(), notification.message.clone());
+        self.last_sent.insert(key, now_ms);
+        self.category_sends.entry(notification.category.clone()).or_default().push_back(now_ms);
+        self.stats_for(&notification.category).sent += 1;
+    }
+
+    /// Undoes `record_send` after the delivery failed, so a notification that never
+    /// showed up neither blocks its retry as a duplicate nor uses up the rate budget
+    fn rollback_send(&mut self, notification: &GatedNotification, sent_at_ms: u64) {
+        let key = (notification.category.clone(), notification.title.clone(), notification.message.clone());
+        if self.last_sent.get(&key) == Some(&sent_at_ms) {
+            self.last_sent.remove(&key);
+        }
+        if let Some(sends) = self.category_sends.get_mut(&notification.category) {
+            if let Some(index) = sends.iter().rposition(|sent| *sent == sent_at_ms) {
+                sends.remove(index);
+            }
+        }
+        let stats = self.stats_for(&notification.category);
+        stats.sent = stats.sent.saturating_sub(1);
+    }
+
+    /// Decides about a new notification. `Sent` means the caller must deliver it now,
+    /// and call `rollback_send` if that fails.
+    fn admit(&mut self, notification: GatedNotification, now_ms: u64) -> GateDecision {
+        let category = notification.category.clone();
+        if self.is_duplicate(&notification, now_ms) {
+            self.stats_for(&category).deduplicated += 1;
+            return GateDecision::Deduplicated;
+        }
+        if self.is_held_back(&notification, now_ms) {
+            if self.queue.len() >= MAX_QUEUED_NOTIFICATIONS {
+                if let Some(dropped) = self.queue.pop_front() {
+                    self.stats_for(&dropped.category).dropped += 1;
+                }
+            }
+            self.queue.push_back(notification);
+            self.stats_for(&category).queued += 1;
+            return GateDecision::Queued;
+        }
+        if self.is_rate_limited(&category, now_ms) {
+            self.stats_for(&category).rate_limited += 1;
+            return GateDecision::RateLimited;
+        }
+        self.record_send(&notification, now_ms);
+        GateDecision::Sent
+    }
+
+    /// Takes the queued notifications that may be delivered now. Ones over the
+    /// rate cap stay queued for the next flush.
+    fn take_deliverable(&mut self, now_ms: u64) -> Vec<GatedNotification> {
+        let mut deliverable = Vec::new();
+        let mut remaining = VecDeque::new();
+        while let Some(notification) = self.queue.pop_front() {
+            if self.is_held_back(&notification, now_ms) || self.is_rate_limited(&notification.category, now_ms) {
+                remaining.push_back(notification);
+            } else {
+                self.record_send(&notification, now_ms);
+                deliverable.push(notification);
+            }
+        }
+        self.queue = remaining;
+        deliverable
+    }
+}
+
+fn in_quiet_hours(quiet: &QuietHours, now_ms: u64) -> bool {
+    let local_minutes = (now_ms / 60_000) as i64 + quiet.utc_offset_minutes as i64;
+    let minute = local_minutes.rem_euclid(24 * 60) as u32;
+    if quiet.start_minute <= quiet.end_minute {
+        (quiet.start_minute..quiet.end_minute).contains(&minute)
+    } else {
+        minute >= quiet.start_minute || minute < quiet.end_minute
+    }
+}
+
+fn deliver(notification: &GatedNotification) -> Result<NotificationBackend> {
+    NotificationBuilder {
+        title: &notification.title,
+        message: &notification.message,
+        icon_path: notification.icon_path.as_deref(),
+        timeout: None,
+        urgency: notification.urgency,
+        actions: Vec::new(),
+    }
+    .send()
+}
+
+/// Replaces the gate configuration. Counters and queued notifications are kept,
+/// so this is also how a changed UTC offset for quiet hours is applied.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_notification_gate(config: NotificationGateConfig) {
+    NOTIFICATION_GATE.lock().unwrap().config = config;
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_do_not_disturb(enabled: bool) {
+    println!("[NOTIFICATION] Do not disturb {}", if enabled { "on" } else { "off" });
+    NOTIFICATION_GATE.lock().unwrap().config.do_not_disturb = enabled;
+}
+
+/// Sends a notification through the gate. `category` groups notifications for
+/// rate limiting and statistics, e.g. "idle_reminder". Urgency levels as in
+/// `send_notification_with_options`; critical ones ignore quiet hours.
+/// Queued notifications are delivered later without reporting their backend.
+#[flutter_rust_bridge::frb]
+pub fn send_gated_notification(
+    category: String,
+    title: String,
+    message: String,
+    icon_path: Option<String>,
+    urgency_level: Option<i32>,
+) -> Result<GatedSendResult> {
+    let notification = GatedNotification {
+        category,
+        title,
+        message,
+        icon_path,
+        urgency: urgency_level.map(urgency_from_level),
+    };
+
+    let now_ms = unix_time_ms();
+    let decision = NOTIFICATION_GATE.lock().unwrap().admit(notification.clone(), now_ms);
+    let mut backend = None;
+    match decision {
+        GateDecision::Sent => match deliver(&notification) {
+            Ok(used) => backend = Some(used),
+            Err(e) => {
+                NOTIFICATION_GATE.lock().unwrap().rollback_send(&notification, now_ms);
+                return Err(e);
+            }
+        },
+        GateDecision::Queued => ensure_gate_flusher(),
+        _ => println!("[NOTIFICATION] {:?} notification in '{}'", decision, notification.category),
+    }
+    Ok(GatedSendResult { decision, backend })
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_notification_gate_stats() -> NotificationGateStats {
+    let gate = NOTIFICATION_GATE.lock().unwrap();
+    let mut categories: Vec<CategoryGateStats> = gate.stats.values().cloned().collect();
+    categories.sort_by(|a, b| a.category.cmp(&b.category));
+    NotificationGateStats {
+        pending: gate.queue.len() as u32,
+        categories,
+    }
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn reset_notification_gate_stats() {
+    NOTIFICATION_GATE.lock().unwrap().stats.clear();
+}
+
+/// Starts the thread delivering queued notifications, it exits once the queue is empty
+fn ensure_gate_flusher() {
+    let mut running = GATE_FLUSHER_RUNNING.lock().unwrap();
+    if *running {
+        return;
+    }
+    *running = true;
+
+    thread::spawn(|| loop {
+        thread::sleep(GATE_FLUSH_INTERVAL);
+        let now_ms = unix_time_ms();
+        let deliverable = NOTIFICATION_GATE.lock().unwrap().take_deliverable(now_ms);
+        for notification in &deliverable {
+            if let Err(e) = deliver(notification) {
+                println!("[NOTIFICATION] Failed to deliver queued notification: {}", e);
+                NOTIFICATION_GATE.lock().unwrap().rollback_send(notification, now_ms);
+            }
+        }
+
+        // Checked under the flag lock so a notification queued meanwhile restarts the flusher
+        let mut running = GATE_FLUSHER_RUNNING.lock().unwrap();
+        if NOTIFICATION_GATE.lock().unwrap().queue.is_empty() {
+            *running = false;
+            return;
+        }
+    });
+}
+
+fn urgency_from_level(level: i32) -> NotificationUrgency {
+    match level {
+        0 => NotificationUrgency::Low,
+        1 => NotificationUrgency::Normal,
+        _ => NotificationUrgency::Critical,
     }
 }
 
@@ -359,4 +1004,214 @@ mod tests {
         assert_eq!(builder.timeout, Some(5));
         assert_eq!(builder.actions.len(), 2);
     }
+
+    fn critical_builder() -> NotificationBuilder<'static> {
+        NotificationBuilder {
+            title: "Still clocked in",
+            message: "Your timer has been running for 10 hours",
+            icon_path: Some("/path/to/icon.png"),
+            timeout: Some(30),
+            urgency: Some(NotificationUrgency::Critical),
+            actions: vec![("stop", "Stop timer"), ("keep", "Keep running")],
+        }
+    }
+
+    #[test]
+    fn test_builder_maps_to_notify_rust() {
+        let notification = critical_builder().to_notification();
+        assert_eq!(notification.summary, "Still clocked in");
+        assert_eq!(notification.icon, "/path/to/icon.png");
+        assert_eq!(notification.timeout, Timeout::Milliseconds(30_000));
+
+        #[cfg(all(unix, not(target_os = "macos")))]
+        {
+            use notify_rust::Hint;
+            assert!(notification.hints.contains(&Hint::Urgency(Urgency::Critical)));
+            // notify-rust stores actions as flat identifier / label pairs
+            assert_eq!(notification.actions, vec!["stop", "Stop timer", "keep", "Keep running"]);
+        }
+
+        let sticky = NotificationBuilder { timeout: Some(0), ..critical_builder() }.to_notification();
+        assert_eq!(sticky.timeout, Timeout::Never);
+        let basic = NotificationBuilder { timeout: None, ..critical_builder() }.to_notification();
+        assert_eq!(basic.timeout, Timeout::Default);
+    }
+
+    #[test]
+    fn test_command_line_fallback_arguments() {
+        assert_eq!(
+            critical_builder().notify_send_args(),
+            vec![
+                "--urgency=critical",
+                "--expire-time=30000",
+                "--icon=/path/to/icon.png",
+                "--",
+                "Still clocked in",
+                "Your timer has been running for 10 hours",
+            ]
+        );
+
+        let plain = NotificationBuilder {
+            title: "-title",
+            message: "Body",
+            icon_path: None,
+            timeout: None,
+            urgency: None,
+            actions: Vec::new(),
+        };
+        assert_eq!(plain.notify_send_args(), vec!["--", "-title", "Body"]);
+        assert_eq!(plain.kdialog_args(), vec!["--title", "-title", "--passivepopup", "Body", "5"]);
+        assert_eq!(critical_builder().kdialog_args()[4], "30");
+    }
+
+    fn gated(category: &str, title: &str, urgency: Option<NotificationUrgency>) -> GatedNotification {
+        GatedNotification {
+            category: category.to_string(),
+            title: title.to_string(),
+            message: "Message".to_string(),
+            icon_path: None,
+            urgency,
+        }
+    }
+
+    #[test]
+    fn test_gate_dedupes_and_caps_rate_per_category() {
+        let mut gate = NotificationGate::new(NotificationGateConfig {
+            dedupe_window_seconds: 60,
+            max_per_category: 2,
+            rate_window_seconds: 600,
+            quiet_hours: None,
+            do_not_disturb: false,
+        });
+
+        assert_eq!(gate.admit(gated("idle", "Idle", None), 0), GateDecision::Sent);
+        assert_eq!(gate.admit(gated("idle", "Idle", None), 30_000), GateDecision::Deduplicated);
+        assert_eq!(gate.admit(gated("idle", "Idle", None), 61_000), GateDecision::Sent);
+        assert_eq!(gate.admit(gated("idle", "Still idle", None), 62_000), GateDecision::RateLimited);
+        // Other categories have their own budget, and the window slides
+        assert_eq!(gate.admit(gated("sync", "Synced", None), 62_000), GateDecision::Sent);
+        assert_eq!(gate.admit(gated("idle", "Still idle", None), 600_001), GateDecision::Sent);
+
+        let idle = &gate.stats["idle"];
+        assert_eq!((idle.sent, idle.deduplicated, idle.rate_limited), (3, 1, 1));
+    }
+
+    #[test]
+    fn test_failed_delivery_is_not_counted() {
+        let mut gate = NotificationGate::new(NotificationGateConfig { max_per_category: 1, ..Default::default() });
+
+        assert_eq!(gate.admit(gated("idle", "Idle", None), 1_000), GateDecision::Sent);
+        gate.rollback_send(&gated("idle", "Idle", None), 1_000);
+        assert_eq!(gate.stats["idle"].sent, 0);
+
+        // Neither a duplicate nor over the cap of one
+        assert_eq!(gate.admit(gated("idle", "Idle", None), 2_000), GateDecision::Sent);
+        assert_eq!(gate.admit(gated("idle", "Idle", None), 3_000), GateDecision::Deduplicated);
+        assert_eq!(gate.stats["idle"].sent, 1);
+    }
+
+    #[test]
+    fn test_quiet_hours_queue_until_they_end() {
+        // 22:00 - 07:00 in UTC+2
+        let quiet = QuietHours { start_minute: 22 * 60, end_minute: 7 * 60, utc_offset_minutes: 120 };
+        let at_local = |hour: u64, minute: u64| ((hour + 24 - 2) % 24 * 60 + minute) * 60_000;
+        assert!(in_quiet_hours(&quiet, at_local(23, 30)));
+        assert!(in_quiet_hours(&quiet, at_local(6, 59)));
+        assert!(!in_quiet_hours(&quiet, at_local(7, 0)));
+
+        let mut gate = NotificationGate::new(NotificationGateConfig { quiet_hours: Some(quiet), ..Default::default() });
+        assert_eq!(gate.admit(gated("reminder", "Log your hours", None), at_local(23, 0)), GateDecision::Queued);
+        assert_eq!(gate.admit(gated("reminder", "Log your hours", None), at_local(23, 5)), GateDecision::Deduplicated);
+        assert_eq!(
+            gate.admit(gated("alert", "Still clocked in", Some(NotificationUrgency::Critical)), at_local(23, 10)),
+            GateDecision::Sent
+        );
+
+        assert!(gate.take_deliverable(at_local(6, 0)).is_empty());
+        let delivered = gate.take_deliverable(at_local(7, 0));
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].title, "Log your hours");
+        assert!(gate.queue.is_empty());
+
+        gate.config.do_not_disturb = true;
+        assert_eq!(
+            gate.admit(gated("alert", "Critical during DND", Some(NotificationUrgency::Critical)), at_local(12, 0)),
+            GateDecision::Queued
+        );
+    }
+
+    #[test]
+    fn test_backend_preference_filters_fallback_chain() {
+        use NotificationBackend::*;
+        let linux = vec![NotifyRust, NotifySend, Kdialog, Zenity, Xmessage];
+
+        let default = ordered_backends(linux.clone(), &NotificationBackendPreference::default());
+        assert_eq!(default, vec![NotifyRust, NotifySend, Kdialog, Zenity]);
+
+        let excluded = NotificationBackendPreference { pinned: None, excluded: vec![NotifyRust, Kdialog] };
+        assert_eq!(ordered_backends(linux.clone(), &excluded), vec![NotifySend, Zenity, Xmessage]);
+
+        // Pinning a blocking backend is an explicit opt-in
+        let pinned = NotificationBackendPreference { pinned: Some(Xmessage), ..Default::default() };
+        assert_eq!(ordered_backends(linux.clone(), &pinned), vec![Xmessage]);
+        let foreign = NotificationBackendPreference { pinned: Some(PowerShellToast), excluded: Vec::new() };
+        assert!(ordered_backends(linux, &foreign).is_empty());
+    }
+
+    #[test]
+    fn test_server_capabilities_are_mapped() {
+        let capabilities = |names: &[&str]| capabilities_from_server(&names.iter().map(|n| n.to_string()).collect::<Vec<_>>());
+        assert_eq!(capabilities(&["body"]), NotificationBackendCapabilities::default());
+        assert_eq!(
+            capabilities(&["actions", "body", "body-markup", "icon-static", "persistence"]),
+            NotificationBackendCapabilities { actions: true, body_markup: true, icons: true, persistence: true }
+        );
+        assert!(capabilities(&["icon-multi"]).icons);
+    }
+
+    /// Needs `dbus-daemon` on the PATH, run with
+    /// `cargo test -- --ignored desktop_notification`
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[ignore]
+    fn test_probe_reads_server_on_private_bus() {
+        use crate::api::test_support::StandInBus;
+
+        let _bus = StandInBus::start();
+        let probes = probe_notification_backends();
+        let notify_rust = probes.iter().find(|p| p.backend == NotificationBackend::NotifyRust).unwrap();
+        assert!(notify_rust.available && !notify_rust.disabled);
+        assert!(notify_rust.capabilities.actions && !notify_rust.capabilities.body_markup);
+        assert_eq!(notify_rust.server.as_ref().unwrap().name, "stand-in");
+        assert_eq!(notify_rust.server.as_ref().unwrap().spec_version, "1.2");
+
+        let xmessage = probes.iter().find(|p| p.backend == NotificationBackend::Xmessage).unwrap();
+        assert!(xmessage.disabled);
+
+        let backend = send_notification("Probe".to_string(), "Delivered".to_string(), None).unwrap();
+        assert_eq!(backend, NotificationBackend::NotifyRust);
+
+        let gated = send_gated_notification("probe".to_string(), "Probe".to_string(), "Gated".to_string(), None, None).unwrap();
+        assert_eq!(gated, GatedSendResult { decision: GateDecision::Sent, backend: Some(NotificationBackend::NotifyRust) });
+    }
+
+    #[test]
+    fn test_reconfigured_utc_offset_moves_quiet_hours() {
+        // 22:00 - 07:00, configured in winter time (UTC+1)
+        let winter = QuietHours { start_minute: 22 * 60, end_minute: 7 * 60, utc_offset_minutes: 60 };
+        let mut gate = NotificationGate::new(NotificationGateConfig { quiet_hours: Some(winter), ..Default::default() });
+
+        // 05:30 UTC is 07:30 in summer time, but the stale offset still says 06:30
+        let now = (5 * 60 + 30) * 60_000;
+        assert_eq!(gate.admit(gated("reminder", "Log your hours", None), now), GateDecision::Queued);
+
+        // What `configure_notification_gate` does once Dart reports UTC+2
+        gate.config = NotificationGateConfig {
+            quiet_hours: Some(QuietHours { utc_offset_minutes: 120, ..winter }),
+            ..Default::default()
+        };
+        let delivered = gate.take_deliverable(now);
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(gate.admit(gated("reminder", "Start tracking", None), now), GateDecision::Sent);
+    }
 }
@@ -0,0 +1,368 @@
+use flutter_rust_bridge::frb;
+use screenshots::Screen;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Monitor layout cache, mouse events are converted at 20 Hz
+lazy_static::lazy_static! {
+    static ref GEOMETRY_CACHE: Mutex<Option<(Instant, Vec<DisplayGeometry>)>> = Mutex::new(None);
+}
+
+/// How long a monitor layout is reused before it is queried again
+const GEOMETRY_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Coordinate spaces used by the crate.
+///
+/// All coordinates emitted by the crate (`MouseEvent.coords`, `WindowDetails.position`)
+/// are in `Physical` desktop pixels, the same pixels a screenshot is made of. A monitor's
+/// physical size is its logical size multiplied by its scale factor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoordinateSpace {
+    /// Scale-independent points, as used by macOS and by Flutter
+    Logical,
+    /// Device pixels
+    Physical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl DisplayRect {
+    fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x as f64
+            && y >= self.y as f64
+            && x < self.x as f64 + self.width as f64
+            && y < self.y as f64 + self.height as f64
+    }
+}
+
+/// Bounds of a monitor in both coordinate spaces
+#[derive(Debug, Clone)]
+pub struct DisplayGeometry {
+    /// Same id as `screenshots`' `display_info.id`
+    pub id: u32,
+    pub is_primary: bool,
+    pub scale_factor: f32,
+    pub logical: DisplayRect,
+    pub physical: DisplayRect,
+}
+
+/// The monitor a point or window is on, with coordinates relative to its top-left corner
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MonitorLocation {
+    /// Same id as `DisplayGeometry.id` and `capture_monitor`
+    pub monitor_id: u32,
+    /// Monitor-relative physical pixels
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Returns the current monitor layout, bypassing the cache.
+#[frb(sync)]
+pub fn get_display_geometry() -> Result<Vec<DisplayGeometry>, String> {
+    let displays = query_display_geometry()?;
+    *GEOMETRY_CACHE.lock().unwrap() = Some((Instant::now(), displays.clone()));
+    Ok(displays)
+}
+
+/// The space mouse and window coordinates are reported in by the OS before normalisation.
+#[frb(sync)]
+pub fn native_coordinate_space() -> CoordinateSpace {
+    if cfg!(target_os = "macos") {
+        CoordinateSpace::Logical
+    } else {
+        CoordinateSpace::Physical
+    }
+}
+
+/// Converts a logical point to physical desktop pixels.
+/// Returns `None` when the point is not on any monitor.
+#[frb(sync)]
+pub fn logical_to_physical(x: f64, y: f64) -> Option<(f64, f64)> {
+    convert_point(&cached_display_geometry(), (x, y), CoordinateSpace::Logical, CoordinateSpace::Physical)
+}
+
+/// Converts a point in physical desktop pixels to logical points.
+/// Returns `None` when the point is not on any monitor.
+#[frb(sync)]
+pub fn physical_to_logical(x: f64, y: f64) -> Option<(f64, f64)> {
+    convert_point(&cached_display_geometry(), (x, y), CoordinateSpace::Physical, CoordinateSpace::Logical)
+}
+
+/// Converts a point reported by the OS (device_query, x-win) to physical desktop pixels.
+/// Points outside every monitor are returned unchanged.
+pub(crate) fn native_point_to_physical(point: (i32, i32)) -> (i32, i32) {
+    let space = native_coordinate_space();
+    if space == CoordinateSpace::Physical {
+        return point;
+    }
+    convert_point(&cached_display_geometry(), (point.0 as f64, point.1 as f64), space, CoordinateSpace::Physical)
+        .map(|(x, y)| (x.round() as i32, y.round() as i32))
+        .unwrap_or(point)
+}
+
+/// Converts an (x, y, width, height) rectangle reported by the OS to physical desktop pixels,
+/// using the scale factor of the monitor containing its centre.
+pub(crate) fn native_rect_to_physical(rect: (i32, i32, i32, i32)) -> (i32, i32, i32, i32) {
+    let space = native_coordinate_space();
+    if space == CoordinateSpace::Physical {
+        return rect;
+    }
+    convert_rect(&cached_display_geometry(), rect, space, CoordinateSpace::Physical)
+}
+
+/// Finds the monitor containing a point given in physical desktop pixels.
+pub(crate) fn locate_point(point: (i32, i32)) -> Option<MonitorLocation> {
+    locate_point_in(&cached_display_geometry(), point)
+}
+
+/// Finds the monitor holding a window given in physical desktop pixels.
+pub(crate) fn locate_rect(rect: (i32, i32, i32, i32)) -> Option<MonitorLocation> {
+    locate_rect_in(&cached_display_geometry(), rect)
+}
+
+fn locate_point_in(displays: &[DisplayGeometry], point: (i32, i32)) -> Option<MonitorLocation> {
+    displays
+        .iter()
+        .find(|d| d.physical.contains(point.0 as f64, point.1 as f64))
+        .map(|d| MonitorLocation {
+            monitor_id: d.id,
+            x: point.0 - d.physical.x,
+            y: point.1 - d.physical.y,
+        })
+}
+
+/// Picks the monitor with the largest overlap, so a window hanging over an edge
+/// belongs to the monitor showing most of it
+fn locate_rect_in(displays: &[DisplayGeometry], rect: (i32, i32, i32, i32)) -> Option<MonitorLocation> {
+    let (x, y, width, height) = rect;
+    let overlap = |d: &DisplayGeometry| -> i64 {
+        let b = &d.physical;
+        let overlap_x = (x + width).min(b.x + b.width as i32) - x.max(b.x);
+        let overlap_y = (y + height).min(b.y + b.height as i32) - y.max(b.y);
+        overlap_x.max(0) as i64 * overlap_y.max(0) as i64
+    };
+
+    let display = displays
+        .iter()
+        .map(|d| (overlap(d), d))
+        .filter(|(area, _)| *area > 0)
+        .max_by_key(|(area, _)| *area)
+        .map(|(_, d)| d)?;
+    Some(MonitorLocation {
+        monitor_id: display.id,
+        x: x - display.physical.x,
+        y: y - display.physical.y,
+    })
+}
+
+/// Monitor layout, refreshed at most every `GEOMETRY_CACHE_TTL`
+pub(crate) fn cached_display_geometry() -> Vec<DisplayGeometry> {
+    let mut cache = GEOMETRY_CACHE.lock().unwrap();
+    if let Some((updated, displays)) = cache.as_ref() {
+        if updated.elapsed() < GEOMETRY_CACHE_TTL {
+            return displays.clone();
+        }
+    }
+
+    match query_display_geometry() {
+        Ok(displays) => {
+            *cache = Some((Instant::now(), displays.clone()));
+            displays
+        }
+        Err(e) => {
+            println!("[GEOMETRY] {}", e);
+            // Keep using the stale layout rather than dropping conversions altogether
+            cache.as_ref().map(|(_, displays)| displays.clone()).unwrap_or_default()
+        }
+    }
+}
+
+fn query_display_geometry() -> Result<Vec<DisplayGeometry>, String> {
+    let screens = Screen::all().map_err(|e| format!("Failed to get screens: {}", e))?;
+    let monitors: Vec<NativeMonitor> = screens
+        .iter()
+        .map(|screen| {
+            let info = screen.display_info;
+            NativeMonitor {
+                id: info.id,
+                is_primary: info.is_primary,
+                scale_factor: info.scale_factor,
+                bounds: DisplayRect { x: info.x, y: info.y, width: info.width, height: info.height },
+            }
+        })
+        .collect();
+    Ok(build_display_geometry(&monitors, native_coordinate_space()))
+}
+
+/// Monitor as reported by `display_info`, in the native coordinate space
+struct NativeMonitor {
+    id: u32,
+    is_primary: bool,
+    scale_factor: f32,
+    bounds: DisplayRect,
+}
+
+/// Derives the bounds in the other coordinate space for every monitor.
+///
+/// Sizes use each monitor's own scale factor. Origins are scaled by one factor for the
+/// whole layout (the largest scale going to physical, the smallest going to logical), so
+/// monitors with different scale factors never overlap in the converted space.
+fn build_display_geometry(monitors: &[NativeMonitor], native_space: CoordinateSpace) -> Vec<DisplayGeometry> {
+    let scale_of = |monitor: &NativeMonitor| if monitor.scale_factor > 0.0 { monitor.scale_factor as f64 } else { 1.0 };
+    let scales = monitors.iter().map(scale_of);
+    let origin_factor = match native_space {
+        CoordinateSpace::Logical => scales.reduce(f64::max).unwrap_or(1.0),
+        CoordinateSpace::Physical => 1.0 / scales.reduce(f64::min).unwrap_or(1.0),
+    };
+
+    monitors
+        .iter()
+        .map(|monitor| {
+            let scale = scale_of(monitor);
+            let size_factor = match native_space {
+                CoordinateSpace::Logical => scale,
+                CoordinateSpace::Physical => 1.0 / scale,
+            };
+            let bounds = monitor.bounds;
+            let converted = DisplayRect {
+                x: (bounds.x as f64 * origin_factor).round() as i32,
+                y: (bounds.y as f64 * origin_factor).round() as i32,
+                width: (bounds.width as f64 * size_factor).round() as u32,
+                height: (bounds.height as f64 * size_factor).round() as u32,
+            };
+
+            let (logical, physical) = match native_space {
+                CoordinateSpace::Logical => (bounds, converted),
+                CoordinateSpace::Physical => (converted, bounds),
+            };
+            DisplayGeometry {
+                id: monitor.id,
+                is_primary: monitor.is_primary,
+                scale_factor: scale as f32,
+                logical,
+                physical,
+            }
+        })
+        .collect()
+}
+
+fn bounds_in(display: &DisplayGeometry, space: CoordinateSpace) -> &DisplayRect {
+    match space {
+        CoordinateSpace::Logical => &display.logical,
+        CoordinateSpace::Physical => &display.physical,
+    }
+}
+
+/// Maps a point between spaces through the monitor that contains it
+pub(crate) fn convert_point(displays: &[DisplayGeometry], point: (f64, f64), from: CoordinateSpace, to: CoordinateSpace) -> Option<(f64, f64)> {
+    let display = displays.iter().find(|d| bounds_in(d, from).contains(point.0, point.1))?;
+    if from == to {
+        return Some(point);
+    }
+
+    let source = bounds_in(display, from);
+    let target = bounds_in(display, to);
+    let scale_x = target.width as f64 / source.width.max(1) as f64;
+    let scale_y = target.height as f64 / source.height.max(1) as f64;
+    Some((
+        target.x as f64 + (point.0 - source.x as f64) * scale_x,
+        target.y as f64 + (point.1 - source.y as f64) * scale_y,
+    ))
+}
+
+pub(crate) fn convert_rect(displays: &[DisplayGeometry], rect: (i32, i32, i32, i32), from: CoordinateSpace, to: CoordinateSpace) -> (i32, i32, i32, i32) {
+    let (x, y, width, height) = rect;
+    let centre = (x as f64 + width as f64 / 2.0, y as f64 + height as f64 / 2.0);
+    let display = match displays.iter().find(|d| bounds_in(d, from).contains(centre.0, centre.1)) {
+        Some(display) => display,
+        None => return rect,
+    };
+
+    let source = bounds_in(display, from);
+    let target = bounds_in(display, to);
+    let scale = target.width as f64 / source.width.max(1) as f64;
+    (
+        (target.x as f64 + (x - source.x) as f64 * scale).round() as i32,
+        (target.y as f64 + (y - source.y) as f64 * scale).round() as i32,
+        (width as f64 * scale).round() as i32,
+        (height as f64 * scale).round() as i32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(id: u32, scale_factor: f32, x: i32, width: u32, height: u32) -> NativeMonitor {
+        NativeMonitor { id, is_primary: id == 1, scale_factor, bounds: DisplayRect { x, y: 0, width, height } }
+    }
+
+    /// A 150% laptop panel with a 100% monitor to its right, as reported by macOS
+    fn mixed_dpi_layout() -> Vec<DisplayGeometry> {
+        build_display_geometry(
+            &[monitor(1, 1.5, 0, 1280, 800), monitor(2, 1.0, 1280, 1920, 1080)],
+            CoordinateSpace::Logical,
+        )
+    }
+
+    #[test]
+    fn test_geometry_scales_bounds_without_overlap() {
+        let displays = mixed_dpi_layout();
+        assert_eq!(displays[0].physical, DisplayRect { x: 0, y: 0, width: 1920, height: 1200 });
+        assert_eq!(displays[1].physical, DisplayRect { x: 1920, y: 0, width: 1920, height: 1080 });
+
+        // Windows / X11 report physical bounds
+        let physical = build_display_geometry(
+            &[monitor(1, 1.5, 0, 1920, 1200), monitor(2, 1.0, 1920, 1920, 1080)],
+            CoordinateSpace::Physical,
+        );
+        assert_eq!(physical[0].logical, DisplayRect { x: 0, y: 0, width: 1280, height: 800 });
+        assert_eq!(physical[1].logical, DisplayRect { x: 1920, y: 0, width: 1920, height: 1080 });
+    }
+
+    #[test]
+    fn test_point_round_trip() {
+        let displays = mixed_dpi_layout();
+        let physical = convert_point(&displays, (100.0, 200.0), CoordinateSpace::Logical, CoordinateSpace::Physical).unwrap();
+        assert_eq!(physical, (150.0, 300.0));
+        let logical = convert_point(&displays, physical, CoordinateSpace::Physical, CoordinateSpace::Logical).unwrap();
+        assert_eq!(logical, (100.0, 200.0));
+
+        assert!(convert_point(&displays, (-10.0, 0.0), CoordinateSpace::Logical, CoordinateSpace::Physical).is_none());
+    }
+
+    #[test]
+    fn test_monitor_location_is_relative_to_monitor() {
+        let displays = mixed_dpi_layout();
+        assert_eq!(
+            locate_point_in(&displays, (2000, 40)),
+            Some(MonitorLocation { monitor_id: 2, x: 80, y: 40 })
+        );
+        assert_eq!(locate_point_in(&displays, (5000, 40)), None);
+
+        // A window mostly on the second monitor, hanging over its left edge
+        assert_eq!(
+            locate_rect_in(&displays, (1800, 100, 800, 600)),
+            Some(MonitorLocation { monitor_id: 2, x: -120, y: 100 })
+        );
+    }
+
+    #[test]
+    fn test_rect_uses_monitor_of_its_centre() {
+        let displays = mixed_dpi_layout();
+        assert_eq!(
+            convert_rect(&displays, (10, 10, 100, 50), CoordinateSpace::Logical, CoordinateSpace::Physical),
+            (15, 15, 150, 75)
+        );
+        // Monitor 2 starts at logical 1280, which is physical 1920 next to the 1.5x panel
+        let second = convert_rect(&displays, (1300, 0, 100, 100), CoordinateSpace::Logical, CoordinateSpace::Physical);
+        assert_eq!(second, (1940, 0, 100, 100));
+    }
+}
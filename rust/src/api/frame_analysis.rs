@@ -0,0 +1,179 @@
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use image::RgbaImage;
+
+use crate::api::screenshot_capture::decode_base64_image;
+
+/// Upper bound on the number of pixels inspected per frame.
+/// Larger frames are sampled with a stride so analysis stays cheap on 4K screens.
+const MAX_SAMPLED_PIXELS: u64 = 250_000;
+
+/// Luminance histogram resolution (each bin covers 4 luminance levels)
+const HISTOGRAM_BINS: usize = 64;
+
+/// Luminance below which a pixel counts as "dark"
+const DARK_LUMINANCE: f32 = 16.0;
+
+/// Frames with a luminance variance below this are treated as a single flat colour
+const FLAT_VARIANCE: f32 = 2.0;
+
+/// Share of pixels in one histogram bin above which a frame is considered uniform
+const UNIFORM_BIN_RATIO: f32 = 0.98;
+
+/// Share of pixels in one dark bin above which a frame probably shows a lock screen
+/// (a dark background with only a clock or password box on top)
+const LOCK_SCREEN_BIN_RATIO: f32 = 0.93;
+
+/// Rough classification of a captured frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameClassification {
+    /// Regular desktop content
+    Normal,
+    /// All black or fully transparent, usually a failed capture
+    Blank,
+    /// A single flat colour that is not black
+    Uniform,
+    /// Almost entirely dark with a small amount of content, e.g. a locked session
+    LikelyLockScreen,
+}
+
+/// Result of the post-capture image analysis pass
+#[derive(Debug, Clone)]
+pub struct FrameAnalysis {
+    pub classification: FrameClassification,
+    /// Mean luminance (0-255)
+    pub mean_luminance: f32,
+    /// Luminance variance
+    pub luminance_variance: f32,
+    /// Share of sampled pixels falling into the most populated histogram bin (0.0-1.0)
+    pub dominant_bin_ratio: f32,
+    /// Share of sampled pixels darker than the "dark" threshold (0.0-1.0)
+    pub dark_pixel_ratio: f32,
+}
+
+impl FrameAnalysis {
+    pub fn is_blank(&self) -> bool {
+        self.classification == FrameClassification::Blank
+    }
+}
+
+/// Analyses a base64 encoded image, e.g. the output of `take_full_screenshot`.
+#[frb(sync)]
+pub fn analyze_screenshot(base64_image: String) -> Result<FrameAnalysis> {
+    let image = decode_base64_image(&base64_image)?;
+    Ok(analyze_frame(&image))
+}
+
+/// Classifies a frame using luminance variance and histogram checks.
+pub(crate) fn analyze_frame(image: &RgbaImage) -> FrameAnalysis {
+    let pixel_count = image.width() as u64 * image.height() as u64;
+    if pixel_count == 0 {
+        return FrameAnalysis {
+            classification: FrameClassification::Blank,
+            mean_luminance: 0.0,
+            luminance_variance: 0.0,
+            dominant_bin_ratio: 1.0,
+            dark_pixel_ratio: 1.0,
+        };
+    }
+
+    let stride = (pixel_count / MAX_SAMPLED_PIXELS).max(1) as usize;
+    let mut histogram = [0u64; HISTOGRAM_BINS];
+    let mut sum = 0f64;
+    let mut sum_sq = 0f64;
+    let mut dark = 0u64;
+    let mut samples = 0u64;
+
+    for pixel in image.pixels().step_by(stride) {
+        let [r, g, b, a] = pixel.0;
+        // Fully transparent pixels carry no content, treat them as black
+        let luminance = if a == 0 {
+            0.0
+        } else {
+            0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32
+        };
+
+        histogram[(luminance as usize).min(255) * HISTOGRAM_BINS / 256] += 1;
+        sum += luminance as f64;
+        sum_sq += (luminance * luminance) as f64;
+        if luminance < DARK_LUMINANCE {
+            dark += 1;
+        }
+        samples += 1;
+    }
+
+    let mean = sum / samples as f64;
+    let variance = (sum_sq / samples as f64 - mean * mean).max(0.0);
+    let (dominant_bin, dominant_count) = histogram
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, count)| **count)
+        .map(|(bin, count)| (bin, *count))
+        .unwrap_or((0, 0));
+
+    let mean_luminance = mean as f32;
+    let luminance_variance = variance as f32;
+    let dominant_bin_ratio = dominant_count as f32 / samples as f32;
+    let dark_pixel_ratio = dark as f32 / samples as f32;
+    // Luminance at the centre of the dominant bin
+    let dominant_luminance = (dominant_bin * 256 / HISTOGRAM_BINS + 2) as f32;
+
+    let classification = if luminance_variance < FLAT_VARIANCE || dominant_bin_ratio >= UNIFORM_BIN_RATIO {
+        if dominant_luminance < DARK_LUMINANCE {
+            FrameClassification::Blank
+        } else {
+            FrameClassification::Uniform
+        }
+    } else if dominant_bin_ratio >= LOCK_SCREEN_BIN_RATIO && dominant_luminance < DARK_LUMINANCE * 3.0 {
+        FrameClassification::LikelyLockScreen
+    } else {
+        FrameClassification::Normal
+    };
+
+    FrameAnalysis {
+        classification,
+        mean_luminance,
+        luminance_variance,
+        dominant_bin_ratio,
+        dark_pixel_ratio,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_black_and_transparent_frames_are_blank() {
+        let black = RgbaImage::from_pixel(64, 64, Rgba([0, 0, 0, 255]));
+        let transparent = RgbaImage::from_pixel(64, 64, Rgba([200, 200, 200, 0]));
+
+        assert_eq!(analyze_frame(&black).classification, FrameClassification::Blank);
+        assert_eq!(analyze_frame(&transparent).classification, FrameClassification::Blank);
+    }
+
+    #[test]
+    fn test_solid_colour_is_uniform() {
+        let white = RgbaImage::from_pixel(64, 64, Rgba([255, 255, 255, 255]));
+        assert_eq!(analyze_frame(&white).classification, FrameClassification::Uniform);
+    }
+
+    #[test]
+    fn test_dark_frame_with_small_content_is_lock_screen() {
+        let mut image = RgbaImage::from_pixel(100, 100, Rgba([5, 5, 10, 255]));
+        // A small "clock" in the middle covering 4% of the frame
+        for y in 40..60 {
+            for x in 40..60 {
+                image.put_pixel(x, y, Rgba([240, 240, 240, 255]));
+            }
+        }
+        assert_eq!(analyze_frame(&image).classification, FrameClassification::LikelyLockScreen);
+    }
+
+    #[test]
+    fn test_gradient_is_normal() {
+        let image = RgbaImage::from_fn(256, 64, |x, y| Rgba([x as u8, (y * 4) as u8, 128, 255]));
+        assert_eq!(analyze_frame(&image).classification, FrameClassification::Normal);
+    }
+}
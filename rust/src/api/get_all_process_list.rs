@@ -1,4 +1,6 @@
-use sysinfo::{System, ProcessesToUpdate};
+use crate::api::cmdline_redaction::redact_command_line;
+use sysinfo::{Pid, Process, System, ProcessesToUpdate, Users};
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 
 /// Struct to represent process information
@@ -10,18 +12,35 @@ pub struct ProcessInfo {
     pub cmd: Vec<String>,
     pub memory_usage: u64,
     pub cpu_usage: f32,
+    /// `None` for root processes or when the parent is not visible
+    pub parent_pid: Option<u32>,
+    /// Name of the owning user, `None` if it could not be resolved
+    pub user: Option<String>,
+    /// `None` when the executable path is not readable (e.g. other users' processes)
+    pub exe_path: Option<String>,
+    /// Seconds since the Unix epoch
+    pub start_time: u64,
+    /// Seconds the process has been running
+    pub run_time: u64,
+    /// e.g. "Runnable", "Sleeping", "Zombie"
+    pub status: String,
+    /// Total bytes read from disk since the process started
+    pub disk_read_bytes: u64,
+    /// Total bytes written to disk since the process started
+    pub disk_written_bytes: u64,
+}
+
+/// A process with its descendants, for rendering the process tree
+#[derive(Debug, Clone)]
+pub struct ProcessTreeNode {
+    pub process: ProcessInfo,
+    pub children: Vec<ProcessTreeNode>,
 }
 
 /// Returns a list of all running processes on the system
 /// Works on macOS, Linux, and Windows
 pub fn get_all_processes() -> Vec<ProcessInfo> {
-    // Create a new system instance with all data
-    let mut system = System::new_all();
-    
-    // Some versions/platforms need a small delay and multiple refreshes
-    system.refresh_all();
-    std::thread::sleep(Duration::from_millis(50));
-    system.refresh_processes(ProcessesToUpdate::All, true);
+    let system = refreshed_system();
     
     // Log platform info for debugging
     println!("Platform: {}", std::env::consts::OS);
@@ -38,21 +57,106 @@ pub fn get_all_processes() -> Vec<ProcessInfo> {
     }
     
     // Collect and convert process information
+    let users = Users::new_with_refreshed_list();
     system
         .processes()
         .iter()
-        .map(|(pid, process)| {
-            ProcessInfo {
-                pid: pid.as_u32(),
-                name: process.name().to_string_lossy().into_owned(),
-                cmd: process.cmd().iter().map(|s| s.to_string_lossy().into_owned()).collect(),
-                memory_usage: process.memory(),
-                cpu_usage: process.cpu_usage(),
-            }
-        })
+        .map(|(pid, process)| process_info_from(*pid, process, &users))
         .collect()
 }
 
+/// Returns `root_pid` with all of its descendants, `None` if the process does not exist.
+/// Threads (Linux tasks) are left out so only real child processes show up.
+pub fn get_process_tree(root_pid: u32) -> Option<ProcessTreeNode> {
+    let system = refreshed_system();
+    let users = Users::new_with_refreshed_list();
+    let processes: Vec<ProcessInfo> = system
+        .processes()
+        .iter()
+        .filter(|(_, process)| process.thread_kind().is_none())
+        .map(|(pid, process)| process_info_from(*pid, process, &users))
+        .collect();
+
+    build_process_tree(processes, root_pid)
+}
+
+fn refreshed_system() -> System {
+    // Create a new system instance with all data
+    let mut system = System::new_all();
+    
+    // Some versions/platforms need a small delay and multiple refreshes
+    system.refresh_all();
+    std::thread::sleep(Duration::from_millis(50));
+    system.refresh_processes(ProcessesToUpdate::All, true);
+    system
+}
+
+/// Converts a sysinfo process, shared by every function returning `ProcessInfo`
+pub(crate) fn process_info_from(pid: Pid, process: &Process, users: &Users) -> ProcessInfo {
+    let disk_usage = process.disk_usage();
+    let cmd: Vec<String> = process.cmd().iter().map(|s| s.to_string_lossy().into_owned()).collect();
+    ProcessInfo {
+        pid: pid.as_u32(),
+        name: process.name().to_string_lossy().into_owned(),
+        // Command lines often carry passwords and tokens, never let them leave unredacted
+        cmd: redact_command_line(&cmd),
+        memory_usage: process.memory(),
+        cpu_usage: process.cpu_usage(),
+        parent_pid: process.parent().map(|parent| parent.as_u32()),
+        user: process
+            .user_id()
+            .and_then(|uid| users.get_user_by_id(uid))
+            .map(|user| user.name().to_string()),
+        exe_path: process.exe().map(|path| path.to_string_lossy().into_owned()),
+        start_time: process.start_time(),
+        run_time: process.run_time(),
+        status: process.status().to_string(),
+        disk_read_bytes: disk_usage.total_read_bytes,
+        disk_written_bytes: disk_usage.total_written_bytes,
+    }
+}
+
+fn build_process_tree(processes: Vec<ProcessInfo>, root_pid: u32) -> Option<ProcessTreeNode> {
+    let mut by_pid: HashMap<u32, ProcessInfo> = HashMap::new();
+    let mut children_of: HashMap<u32, Vec<u32>> = HashMap::new();
+    for process in processes {
+        if let Some(parent_pid) = process.parent_pid {
+            children_of.entry(parent_pid).or_default().push(process.pid);
+        }
+        by_pid.insert(process.pid, process);
+    }
+    for children in children_of.values_mut() {
+        children.sort_unstable();
+    }
+
+    // Guards against parent cycles caused by PID reuse between refreshes
+    let mut visited = HashSet::new();
+    build_node(root_pid, &mut by_pid, &children_of, &mut visited)
+}
+
+fn build_node(
+    pid: u32,
+    by_pid: &mut HashMap<u32, ProcessInfo>,
+    children_of: &HashMap<u32, Vec<u32>>,
+    visited: &mut HashSet<u32>,
+) -> Option<ProcessTreeNode> {
+    if !visited.insert(pid) {
+        return None;
+    }
+    let process = by_pid.remove(&pid)?;
+    let children = children_of
+        .get(&pid)
+        .map(|child_pids| {
+            child_pids
+                .iter()
+                .filter_map(|child| build_node(*child, by_pid, children_of, visited))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(ProcessTreeNode { process, children })
+}
+
 /// Provides platform-specific information about process access
 pub fn get_process_access_info() -> String {
     match std::env::consts::OS {
@@ -86,10 +190,60 @@ pub fn kill_process(pid: u32) -> bool {
     system.refresh_all();
     
     // Cast u32 to usize since Pid implements From<usize> but not From<u32>
-    let sys_pid = sysinfo::Pid::from(pid as usize);
+    let sys_pid = Pid::from(pid as usize);
     if let Some(process) = system.process(sys_pid) {
         process.kill()
     } else {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, parent_pid: Option<u32>) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            name: format!("p{}", pid),
+            cmd: Vec::new(),
+            memory_usage: 0,
+            cpu_usage: 0.0,
+            parent_pid,
+            user: None,
+            exe_path: None,
+            start_time: 0,
+            run_time: 0,
+            status: "Sleeping".to_string(),
+            disk_read_bytes: 0,
+            disk_written_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn test_build_process_tree_nests_descendants() {
+        let processes = vec![
+            process(1, None),
+            process(10, Some(1)),
+            process(12, Some(10)),
+            process(11, Some(10)),
+            process(20, Some(1)),
+            process(30, Some(99)),
+        ];
+
+        let tree = build_process_tree(processes, 10).unwrap();
+        assert_eq!(tree.process.pid, 10);
+        let child_pids: Vec<u32> = tree.children.iter().map(|c| c.process.pid).collect();
+        assert_eq!(child_pids, vec![11, 12]);
+        assert!(tree.children.iter().all(|c| c.children.is_empty()));
+
+        assert!(build_process_tree(vec![process(1, None)], 2).is_none());
+    }
+
+    #[test]
+    fn test_build_process_tree_survives_parent_cycles() {
+        let tree = build_process_tree(vec![process(1, Some(2)), process(2, Some(1))], 1).unwrap();
+        assert_eq!(tree.children.len(), 1);
+        assert!(tree.children[0].children.is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::api::resource_budget::throttled_interval;
 use crate::frb_generated::StreamSink;
 use device_query::{DeviceQuery, DeviceState};
 use serde::Serialize;
@@ -5,6 +6,9 @@ use std::collections::HashSet;
 use std::thread;
 use std::time::Duration;
 
+/// Input devices are polled at 20 Hz unless the resource budget slows it down
+pub(crate) const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
 #[derive(Serialize, Debug)]
 pub struct KeyboardEvent {
     pub key: String,
@@ -47,7 +51,7 @@ pub fn start_keyboard_listener(sink: StreamSink<KeyboardEvent>) -> Result<(), St
             }
 
             previous_keys = current_keys;
-            thread::sleep(Duration::from_millis(50));
+            thread::sleep(throttled_interval(INPUT_POLL_INTERVAL));
         }
     });
 
@@ -7,6 +7,30 @@ pub mod get_all_process_list;
 pub mod keyboard_listener;
 pub mod mouse_listener;
 pub mod active_window_listener;
+pub mod screenshot_capture;
+pub mod screenshot_scheduler;
+pub mod frame_analysis;
+pub mod screenshot_watermark;
+pub mod timelapse_recorder;
+pub mod capture_preview;
+pub mod screenshot_spool;
+pub mod screenshot_cursor;
+pub mod delta_encoding;
+pub mod window_capture;
+pub mod display_geometry;
+pub mod click_heatmap;
+pub mod process_sampler;
+pub mod process_watcher;
+pub mod process_termination;
+pub mod app_policy;
+pub mod cmdline_redaction;
+pub mod system_monitor;
+pub mod resource_budget;
+pub mod notification_actions;
+pub mod persistent_notification;
+
+#[cfg(test)]
+pub(crate) mod test_support;
 
 // Re-export types needed by frb_generated.rs
 pub use std::sync::{Arc, Mutex};
\ No newline at end of file
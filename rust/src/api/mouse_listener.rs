@@ -1,15 +1,21 @@
+use crate::api::click_heatmap::record_click;
+use crate::api::display_geometry::{locate_point, native_point_to_physical, MonitorLocation};
+use crate::api::keyboard_listener::INPUT_POLL_INTERVAL;
+use crate::api::resource_budget::throttled_interval;
 use crate::frb_generated::StreamSink;
 use device_query::{DeviceQuery, DeviceState};
 use serde::Serialize;
 use std::collections::HashSet;
 use std::thread;
-use std::time::Duration;
 
 #[derive(Serialize, Debug)]
 pub struct MouseEvent {
     pub button: String,
     pub is_button_press: bool,
+    /// Cursor position in physical desktop pixels (see `CoordinateSpace`)
     pub coords: (i32, i32),
+    /// Monitor under the cursor with monitor-relative coordinates
+    pub monitor: Option<MonitorLocation>,
     pub is_left_click: bool,
     pub is_right_click: bool,
 }
@@ -54,7 +60,12 @@ pub fn start_mouse_listener(sink: StreamSink<MouseEvent>) -> Result<(), String>
                 .map(|(name, _, _)| name.clone())
                 .collect();
 
-            let current_coords = mouse_state.coords;
+            let current_coords = native_point_to_physical(mouse_state.coords);
+            let current_monitor = if current_events.is_empty() && previous_buttons.is_empty() {
+                None // No event is emitted, skip the lookup
+            } else {
+                locate_point(current_coords)
+            };
 
             // For new button press events: buttons present now but not previously.
             for (name, is_left, is_right) in current_events.iter() {
@@ -63,9 +74,11 @@ pub fn start_mouse_listener(sink: StreamSink<MouseEvent>) -> Result<(), String>
                         button: name.clone(),
                         is_button_press: true,
                         coords: current_coords,
+                        monitor: current_monitor,
                         is_left_click: *is_left,
                         is_right_click: *is_right,
                     };
+                    record_click(&event);
                     let _ = sink.add(event);
                 }
             }
@@ -82,6 +95,7 @@ pub fn start_mouse_listener(sink: StreamSink<MouseEvent>) -> Result<(), String>
                     button: name.clone(),
                     is_button_press: false,
                     coords: current_coords,
+                    monitor: current_monitor,
                     is_left_click: is_left,
                     is_right_click: is_right,
                 };
@@ -89,7 +103,7 @@ pub fn start_mouse_listener(sink: StreamSink<MouseEvent>) -> Result<(), String>
             }
 
             previous_buttons = current_buttons;
-            thread::sleep(Duration::from_millis(50));
+            thread::sleep(throttled_interval(INPUT_POLL_INTERVAL));
         }
     });
 
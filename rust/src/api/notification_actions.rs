@@ -0,0 +1,238 @@
+use crate::frb_generated::StreamSink;
+use anyhow::Result;
+use std::time::Duration;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+use crate::api::desktop_notification::NotificationBuilder;
+#[cfg(all(unix, not(target_os = "macos")))]
+use anyhow::anyhow;
+#[cfg(all(unix, not(target_os = "macos")))]
+use notify_rust::{ActionResponse, CloseReason};
+#[cfg(all(unix, not(target_os = "macos")))]
+use std::sync::mpsc;
+
+/// Time the user has to respond before the notification is closed and reported as `Expired`
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// A button shown on an interactive notification
+#[derive(Debug, Clone)]
+pub struct NotificationAction {
+    /// Reported back as `action_id` of an `ActionInvoked` event
+    pub id: String,
+    pub label: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationEventKind {
+    ActionInvoked,
+    /// Closed by the user
+    Dismissed,
+    /// Timed out
+    Expired,
+    /// Closed by the app or for a reason the server did not specify
+    Closed,
+}
+
+/// How an interactive notification ended. Only the first event is reported,
+/// servers also send a close after an action was invoked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationEvent {
+    pub kind: NotificationEventKind,
+    /// Id of the invoked action, only set for `ActionInvoked`
+    pub action_id: Option<String>,
+}
+
+/// Shows a notification with action buttons and streams the user's response.
+/// Returns the notification id assigned by the notification server.
+///
+/// Needs a freedesktop notification server (Linux / BSD), which reports the
+/// `ActionInvoked` and `NotificationClosed` signals back over D-Bus. Without a
+/// response within ten minutes the notification is closed and `Expired` is reported.
+pub fn send_interactive_notification(
+    title: String,
+    body: String,
+    actions: Vec<NotificationAction>,
+    sink: StreamSink<NotificationEvent>,
+) -> Result<u32> {
+    show_interactive_notification(&title, &body, &actions, RESPONSE_TIMEOUT, move |event| {
+        println!("[NOTIFICATION] Interactive notification ended: {:?}", event);
+        let _ = sink.add(event);
+    })
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn show_interactive_notification(
+    title: &str,
+    body: &str,
+    actions: &[NotificationAction],
+    timeout: Duration,
+    on_event: impl FnOnce(NotificationEvent) + Send + 'static,
+) -> Result<u32> {
+    use zbus2::blocking::{fdo::DBusProxy, Connection, MessageIterator};
+
+    // Subscribe before showing, a click right after the notification appears would be missed otherwise
+    let connection = Connection::session().map_err(|e| anyhow!("Failed to connect to the session bus: {}", e))?;
+    let proxy = DBusProxy::new(&connection)?;
+    proxy.add_match("interface='org.freedesktop.Notifications',member='ActionInvoked'")?;
+    proxy.add_match("interface='org.freedesktop.Notifications',member='NotificationClosed'")?;
+    let messages = MessageIterator::from(&connection);
+
+    let builder = NotificationBuilder {
+        title,
+        message: body,
+        icon_path: None,
+        timeout: None,
+        urgency: None,
+        actions: actions.iter().map(|action| (action.id.as_str(), action.label.as_str())).collect(),
+    };
+    let handle = builder
+        .to_notification()
+        .show()
+        .map_err(|e| anyhow!("Failed to show interactive notification: {}", e))?;
+    let id = handle.id();
+
+    // Blocks until the server reports an action or the close
+    let (response_tx, response_rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        if let Some(event) = messages.flatten().find_map(|message| event_from_signal(&message, id)) {
+            let _ = response_tx.send(event);
+        }
+    });
+
+    std::thread::spawn(move || match response_rx.recv_timeout(timeout) {
+        Ok(event) => on_event(event),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            on_event(NotificationEvent { kind: NotificationEventKind::Expired, action_id: None });
+            // The close signal also ends the listener thread
+            let _ = connection.call_method(
+                Some("org.freedesktop.Notifications"),
+                "/org/freedesktop/Notifications",
+                Some("org.freedesktop.Notifications"),
+                "CloseNotification",
+                &id,
+            );
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            println!("[NOTIFICATION] Lost the session bus while waiting for notification {}", id);
+        }
+    });
+
+    Ok(id)
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn show_interactive_notification(
+    _title: &str,
+    _body: &str,
+    _actions: &[NotificationAction],
+    _timeout: Duration,
+    _on_event: impl FnOnce(NotificationEvent) + Send + 'static,
+) -> Result<u32> {
+    Err(anyhow::anyhow!("Interactive notifications need a freedesktop notification server"))
+}
+
+/// The event for an `ActionInvoked` or `NotificationClosed` signal about notification `id`
+#[cfg(all(unix, not(target_os = "macos")))]
+fn event_from_signal(message: &zbus2::Message, id: u32) -> Option<NotificationEvent> {
+    let header = message.header().ok()?;
+    if header.message_type().ok()? != zbus2::MessageType::Signal {
+        return None;
+    }
+    match header.member().ok()??.as_str() {
+        "ActionInvoked" => match message.body::<(u32, String)>() {
+            Ok((nid, action)) if nid == id => Some(event_from_response(&ActionResponse::Custom(&action))),
+            _ => None,
+        },
+        "NotificationClosed" => match message.body::<(u32, u32)>() {
+            Ok((nid, reason)) if nid == id => Some(event_from_response(&ActionResponse::Closed(reason.into()))),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn event_from_response(response: &ActionResponse) -> NotificationEvent {
+    let kind = match response {
+        ActionResponse::Custom(action_id) => {
+            return NotificationEvent {
+                kind: NotificationEventKind::ActionInvoked,
+                action_id: Some(action_id.to_string()),
+            }
+        }
+        ActionResponse::Closed(CloseReason::Dismissed) => NotificationEventKind::Dismissed,
+        ActionResponse::Closed(CloseReason::Expired) => NotificationEventKind::Expired,
+        ActionResponse::Closed(_) => NotificationEventKind::Closed,
+    };
+    NotificationEvent { kind, action_id: None }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::api::test_support::StandInBus;
+
+    fn invoked(action_id: &str) -> NotificationEvent {
+        NotificationEvent { kind: NotificationEventKind::ActionInvoked, action_id: Some(action_id.to_string()) }
+    }
+
+    fn closed(kind: NotificationEventKind) -> NotificationEvent {
+        NotificationEvent { kind, action_id: None }
+    }
+
+    #[test]
+    fn test_close_reasons_map_to_events() {
+        assert_eq!(
+            event_from_response(&ActionResponse::Custom("keep")),
+            invoked("keep")
+        );
+        assert_eq!(event_from_response(&ActionResponse::Closed(CloseReason::Dismissed)), closed(NotificationEventKind::Dismissed));
+        assert_eq!(event_from_response(&ActionResponse::Closed(CloseReason::Expired)), closed(NotificationEventKind::Expired));
+        assert_eq!(event_from_response(&ActionResponse::Closed(CloseReason::CloseAction)), closed(NotificationEventKind::Closed));
+    }
+
+    /// Needs `dbus-daemon` on the PATH, run with
+    /// `cargo test -- --ignored notification_actions`
+    #[test]
+    #[ignore]
+    fn test_actions_and_close_reasons_on_private_bus() {
+        let bus = StandInBus::start();
+        let actions = vec![
+            NotificationAction { id: "keep".to_string(), label: "Keep".to_string() },
+            NotificationAction { id: "discard".to_string(), label: "Discard".to_string() },
+        ];
+        let show = |timeout| {
+            let (tx, rx) = mpsc::channel();
+            let id = show_interactive_notification("Idle for 10 minutes", "Keep this time?", &actions, timeout, move |event| {
+                tx.send(event).unwrap();
+            })
+            .unwrap();
+            (id, rx)
+        };
+
+        // Emitted right away, the listener subscribes before the notification is shown
+        let (id, events) = show(RESPONSE_TIMEOUT);
+        bus.emit("ActionInvoked", &(id, "keep"));
+        assert_eq!(
+            events.recv_timeout(Duration::from_secs(5)).unwrap(),
+            invoked("keep")
+        );
+        assert_eq!(bus.calls()[0].actions, vec!["keep", "Keep", "discard", "Discard"]);
+
+        let (id, events) = show(RESPONSE_TIMEOUT);
+        bus.emit("NotificationClosed", &(id, 1u32));
+        assert_eq!(events.recv_timeout(Duration::from_secs(5)).unwrap(), closed(NotificationEventKind::Expired));
+
+        // No response at all closes the notification
+        let (id, events) = show(Duration::from_millis(200));
+        assert_eq!(events.recv_timeout(Duration::from_secs(5)).unwrap(), closed(NotificationEventKind::Expired));
+        for _ in 0..50 {
+            if bus.closed().contains(&id) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        assert_eq!(bus.closed(), vec![id]);
+        assert!(events.recv_timeout(Duration::from_millis(200)).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use anyhow::{anyhow, Result};
+
+#[cfg(all(unix, not(target_os = "macos")))]
+use crate::api::desktop_notification::{NotificationBuilder, NotificationUrgency};
+#[cfg(all(unix, not(target_os = "macos")))]
+use notify_rust::NotificationHandle;
+#[cfg(all(unix, not(target_os = "macos")))]
+use std::collections::HashMap;
+#[cfg(all(unix, not(target_os = "macos")))]
+use std::panic::{catch_unwind, AssertUnwindSafe};
+#[cfg(all(unix, not(target_os = "macos")))]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(all(unix, not(target_os = "macos")))]
+use std::sync::Mutex;
+
+// Notifications that can still be updated or closed, by handle id
+#[cfg(all(unix, not(target_os = "macos")))]
+lazy_static::lazy_static! {
+    static ref PERSISTENT_NOTIFICATIONS: Mutex<HashMap<u64, NotificationHandle>> = Mutex::new(HashMap::new());
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+static NEXT_HANDLE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Shows a notification that stays until `close_notification`, e.g. a live
+/// "Tracking: Task X — 01:23:45" status. Returns a handle id for updates.
+///
+/// Needs a freedesktop notification server: updates re-send the notification
+/// with its `replaces_id`, so the server swaps it in place.
+pub fn show_persistent_notification(title: String, body: String, icon_path: Option<String>) -> Result<u64> {
+    show_persistent(&title, &body, icon_path.as_deref())
+}
+
+/// Replaces title and body of a persistent notification in place
+pub fn update_notification(handle_id: u64, title: String, body: String) -> Result<()> {
+    update_persistent(handle_id, &title, &body)
+}
+
+/// Closes a persistent notification and forgets its handle
+pub fn close_notification(handle_id: u64) -> Result<()> {
+    close_persistent(handle_id)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn show_persistent(title: &str, body: &str, icon_path: Option<&str>) -> Result<u64> {
+    let builder = NotificationBuilder {
+        title,
+        message: body,
+        icon_path,
+        timeout: Some(0), // never expires
+        urgency: Some(NotificationUrgency::Normal),
+        actions: Vec::new(),
+    };
+    let mut notification = builder.to_notification();
+    notification.hint(notify_rust::Hint::Resident(true));
+    let handle = notification
+        .show()
+        .map_err(|e| anyhow!("Failed to show persistent notification: {}", e))?;
+
+    let handle_id = NEXT_HANDLE_ID.fetch_add(1, Ordering::SeqCst);
+    println!("[NOTIFICATION] Persistent notification {} shown (server id {})", handle_id, handle.id());
+    PERSISTENT_NOTIFICATIONS.lock().unwrap().insert(handle_id, handle);
+    Ok(handle_id)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn update_persistent(handle_id: u64, title: &str, body: &str) -> Result<()> {
+    let mut notifications = PERSISTENT_NOTIFICATIONS.lock().unwrap();
+    let handle = notifications
+        .get_mut(&handle_id)
+        .ok_or_else(|| anyhow!("Unknown notification handle {}", handle_id))?;
+
+    handle.summary(title).body(body);
+    // notify-rust panics when the D-Bus call fails, e.g. after the server restarted
+    catch_unwind(AssertUnwindSafe(|| handle.update()))
+        .map_err(|_| anyhow!("Failed to update notification {}", handle_id))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn close_persistent(handle_id: u64) -> Result<()> {
+    let handle = PERSISTENT_NOTIFICATIONS
+        .lock()
+        .unwrap()
+        .remove(&handle_id)
+        .ok_or_else(|| anyhow!("Unknown notification handle {}", handle_id))?;
+
+    println!("[NOTIFICATION] Closing persistent notification {}", handle_id);
+    catch_unwind(AssertUnwindSafe(|| handle.close()))
+        .map_err(|_| anyhow!("Failed to close notification {}", handle_id))
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn show_persistent(_title: &str, _body: &str, _icon_path: Option<&str>) -> Result<u64> {
+    Err(anyhow!("Persistent notifications need a freedesktop notification server"))
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn update_persistent(handle_id: u64, _title: &str, _body: &str) -> Result<()> {
+    Err(anyhow!("Unknown notification handle {}", handle_id))
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn close_persistent(handle_id: u64) -> Result<()> {
+    Err(anyhow!("Unknown notification handle {}", handle_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_handles_are_rejected() {
+        assert!(update_notification(u64::MAX, "Tracking".to_string(), "00:00:01".to_string()).is_err());
+        assert!(close_notification(u64::MAX).is_err());
+    }
+
+    /// Needs `dbus-daemon` on the PATH, run with
+    /// `cargo test -- --ignored persistent_notification`
+    #[cfg(target_os = "linux")]
+    #[test]
+    #[ignore]
+    fn test_updates_replace_the_notification_on_private_bus() {
+        use crate::api::test_support::StandInBus;
+
+        let bus = StandInBus::start();
+        let handle_id = show_persistent_notification("Tracking: Task X".to_string(), "00:00:00".to_string(), None).unwrap();
+        update_notification(handle_id, "Tracking: Task X".to_string(), "01:23:45".to_string()).unwrap();
+
+        let calls = bus.calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].replaces_id, 0);
+        assert_eq!(calls[0].expire_timeout, 0);
+        assert_eq!(calls[1].replaces_id, 1);
+        assert_eq!(calls[1].body, "01:23:45");
+        assert_eq!(calls[1].summary, "Tracking: Task X");
+
+        close_notification(handle_id).unwrap();
+        assert_eq!(bus.closed(), vec![1]);
+        assert!(update_notification(handle_id, "Tracking".to_string(), "x".to_string()).is_err());
+    }
+}
@@ -0,0 +1,289 @@
+use flutter_rust_bridge::frb;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+use crate::api::screenshot_capture::unix_time_ms;
+
+// One sampler per app: it owns the only long-lived `System`
+lazy_static::lazy_static! {
+    static ref PROCESS_HISTORY: Mutex<HashMap<u32, ProcessHistoryBuffer>> = Mutex::new(HashMap::new());
+    static ref SAMPLER_RUNNING: Mutex<Option<Arc<Mutex<bool>>>> = Mutex::new(None);
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessSamplerConfig {
+    /// Time between two refreshes, at least `MINIMUM_CPU_UPDATE_INTERVAL` (200 ms)
+    pub interval_ms: u64,
+    /// Number of samples kept per process
+    pub history_len: u32,
+}
+
+impl Default for ProcessSamplerConfig {
+    fn default() -> Self {
+        ProcessSamplerConfig {
+            interval_ms: 5_000,
+            history_len: 720, // one hour at the default interval
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProcessSample {
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    /// Percentage of one core, can exceed 100 for multi-threaded processes
+    pub cpu_usage: f32,
+    pub memory_bytes: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessHistory {
+    pub pid: u32,
+    pub name: String,
+    /// Oldest first
+    pub samples: Vec<ProcessSample>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSortKey {
+    Cpu,
+    Memory,
+}
+
+/// Usage of one process aggregated over a time window
+#[derive(Debug, Clone)]
+pub struct ProcessUsageSummary {
+    pub pid: u32,
+    pub name: String,
+    pub average_cpu: f32,
+    pub peak_cpu: f32,
+    pub average_memory_bytes: u64,
+    pub peak_memory_bytes: u64,
+    pub sample_count: u32,
+}
+
+struct ProcessHistoryBuffer {
+    name: String,
+    /// Tells a reused PID apart from the process the samples belong to
+    start_time: u64,
+    samples: VecDeque<ProcessSample>,
+}
+
+/// Starts the background process sampler. Returns `false` if it is already running.
+///
+/// Unlike `get_all_processes`, the sampler refreshes the same `System` every
+/// `interval_ms`, so CPU usage is measured over the full interval. Only CPU and
+/// memory are refreshed, which keeps it cheap enough to run all day.
+#[frb(sync)]
+pub fn start_process_sampler(config: ProcessSamplerConfig) -> bool {
+    let mut running_guard = SAMPLER_RUNNING.lock().unwrap();
+    if running_guard.is_some() {
+        return false;
+    }
+
+    let running = Arc::new(Mutex::new(true));
+    *running_guard = Some(Arc::clone(&running));
+
+    let interval = Duration::from_millis(config.interval_ms).max(MINIMUM_CPU_UPDATE_INTERVAL);
+    let history_len = config.history_len.max(1) as usize;
+
+    thread::spawn(move || {
+        println!("[SAMPLER] Process sampler started, interval {:?}", interval);
+        let mut system = System::new();
+        let refresh_kind = ProcessRefreshKind::nothing().with_cpu().with_memory().without_tasks();
+        // The first refresh only establishes the CPU baseline
+        system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+
+        loop {
+            thread::sleep(interval);
+            if !*running.lock().unwrap() {
+                break;
+            }
+            system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+
+            let timestamp_ms = unix_time_ms();
+            let mut history = PROCESS_HISTORY.lock().unwrap();
+            // Checked again under the history lock: stop_process_sampler clears the
+            // history under the same lock, so nothing is written after it
+            if !*running.lock().unwrap() {
+                break;
+            }
+            let processes = system.processes().iter().map(|(pid, process)| {
+                let sample = ProcessSample {
+                    timestamp_ms,
+                    cpu_usage: process.cpu_usage(),
+                    memory_bytes: process.memory(),
+                };
+                (pid.as_u32(), process.start_time(), process.name().to_string_lossy(), sample)
+            });
+            record_samples(&mut history, processes, history_len);
+        }
+
+        println!("[SAMPLER] Process sampler stopped");
+    });
+
+    true
+}
+
+#[frb(sync)]
+pub fn stop_process_sampler() -> bool {
+    match SAMPLER_RUNNING.lock().unwrap().take() {
+        Some(running) => {
+            if let Ok(mut guard) = running.lock() {
+                *guard = false;
+            }
+            // The sampler thread only writes after seeing the flag set while holding this lock
+            PROCESS_HISTORY.lock().unwrap().clear();
+            true
+        }
+        None => false,
+    }
+}
+
+#[frb(sync)]
+pub fn is_process_sampler_running() -> bool {
+    SAMPLER_RUNNING.lock().unwrap().is_some()
+}
+
+/// Returns the recorded samples of a process, `None` if it is not being tracked.
+#[frb(sync)]
+pub fn get_process_history(pid: u32) -> Option<ProcessHistory> {
+    let history = PROCESS_HISTORY.lock().unwrap();
+    history.get(&pid).map(|buffer| ProcessHistory {
+        pid,
+        name: buffer.name.clone(),
+        samples: buffer.samples.iter().copied().collect(),
+    })
+}
+
+/// Returns the `n` processes with the highest average usage over the last `window_seconds`.
+#[frb(sync)]
+pub fn top_processes(by: ProcessSortKey, n: u32, window_seconds: u64) -> Vec<ProcessUsageSummary> {
+    let since_ms = unix_time_ms().saturating_sub(window_seconds * 1000);
+    let history = PROCESS_HISTORY.lock().unwrap();
+    rank_processes(&history, by, n as usize, since_ms)
+}
+
+/// Adds one refresh worth of samples and forgets processes that exited.
+///
+/// A PID whose start time changed belongs to a new process, its old samples are dropped.
+fn record_samples<'a>(
+    history: &mut HashMap<u32, ProcessHistoryBuffer>,
+    processes: impl Iterator<Item = (u32, u64, Cow<'a, str>, ProcessSample)>,
+    history_len: usize,
+) {
+    let mut alive = HashSet::with_capacity(history.len());
+    for (pid, start_time, name, sample) in processes {
+        alive.insert(pid);
+        let buffer = history.entry(pid).or_insert_with(|| ProcessHistoryBuffer {
+            name: name.to_string(),
+            start_time,
+            samples: VecDeque::with_capacity(history_len.min(64)),
+        });
+        if buffer.start_time != start_time {
+            buffer.name = name.into_owned();
+            buffer.start_time = start_time;
+            buffer.samples.clear();
+        }
+        push_sample(buffer, sample, history_len);
+    }
+    history.retain(|pid, _| alive.contains(pid));
+}
+
+fn push_sample(buffer: &mut ProcessHistoryBuffer, sample: ProcessSample, history_len: usize) {
+    while buffer.samples.len() >= history_len {
+        buffer.samples.pop_front();
+    }
+    buffer.samples.push_back(sample);
+}
+
+fn summarize(pid: u32, buffer: &ProcessHistoryBuffer, since_ms: u64) -> Option<ProcessUsageSummary> {
+    let samples: Vec<&ProcessSample> = buffer.samples.iter().filter(|s| s.timestamp_ms >= since_ms).collect();
+    if samples.is_empty() {
+        return None;
+    }
+
+    let count = samples.len();
+    Some(ProcessUsageSummary {
+        pid,
+        name: buffer.name.clone(),
+        average_cpu: samples.iter().map(|s| s.cpu_usage).sum::<f32>() / count as f32,
+        peak_cpu: samples.iter().map(|s| s.cpu_usage).fold(0.0, f32::max),
+        average_memory_bytes: samples.iter().map(|s| s.memory_bytes).sum::<u64>() / count as u64,
+        peak_memory_bytes: samples.iter().map(|s| s.memory_bytes).max().unwrap_or(0),
+        sample_count: count as u32,
+    })
+}
+
+fn rank_processes(history: &HashMap<u32, ProcessHistoryBuffer>, by: ProcessSortKey, n: usize, since_ms: u64) -> Vec<ProcessUsageSummary> {
+    let mut summaries: Vec<ProcessUsageSummary> = history
+        .iter()
+        .filter_map(|(pid, buffer)| summarize(*pid, buffer, since_ms))
+        .collect();
+
+    match by {
+        ProcessSortKey::Cpu => summaries.sort_by(|a, b| b.average_cpu.total_cmp(&a.average_cpu)),
+        ProcessSortKey::Memory => summaries.sort_by_key(|s| std::cmp::Reverse(s.average_memory_bytes)),
+    }
+    summaries.truncate(n);
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(name: &str, samples: &[(u64, f32, u64)]) -> ProcessHistoryBuffer {
+        let mut buffer = ProcessHistoryBuffer { name: name.to_string(), start_time: 0, samples: VecDeque::new() };
+        for &(timestamp_ms, cpu_usage, memory_bytes) in samples {
+            push_sample(&mut buffer, ProcessSample { timestamp_ms, cpu_usage, memory_bytes }, 3);
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_latest_samples() {
+        let buffer = buffer("a", &[(1, 1.0, 1), (2, 2.0, 2), (3, 3.0, 3), (4, 4.0, 4)]);
+        let timestamps: Vec<u64> = buffer.samples.iter().map(|s| s.timestamp_ms).collect();
+        assert_eq!(timestamps, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_ranking_uses_only_samples_in_window() {
+        let mut history = HashMap::new();
+        // "burst" was busy before the window and idle inside it
+        history.insert(1, buffer("burst", &[(1_000, 90.0, 100), (5_000, 0.0, 100), (6_000, 0.0, 100)]));
+        history.insert(2, buffer("steady", &[(1_000, 10.0, 500), (5_000, 20.0, 700), (6_000, 30.0, 900)]));
+
+        let top = rank_processes(&history, ProcessSortKey::Cpu, 1, 5_000);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].name, "steady");
+        assert_eq!(top[0].average_cpu, 25.0);
+        assert_eq!(top[0].peak_memory_bytes, 900);
+        assert_eq!(top[0].sample_count, 2);
+
+        let by_memory = rank_processes(&history, ProcessSortKey::Memory, 5, 0);
+        assert_eq!(by_memory.iter().map(|s| s.pid).collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_exited_and_reused_pids_are_pruned() {
+        let sample = |timestamp_ms| ProcessSample { timestamp_ms, cpu_usage: 1.0, memory_bytes: 1 };
+        let mut history = HashMap::new();
+        record_samples(
+            &mut history,
+            vec![(1, 100, Cow::from("editor"), sample(1)), (2, 100, Cow::from("build"), sample(1))].into_iter(),
+            10,
+        );
+        // "build" exited and PID 1 now belongs to a new "shell"
+        record_samples(&mut history, vec![(1, 200, Cow::from("shell"), sample(2))].into_iter(), 10);
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[&1].name, "shell");
+        assert_eq!(history[&1].samples.len(), 1);
+    }
+}
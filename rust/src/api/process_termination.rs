@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal, System};
+
+/// How often the targets are checked while waiting for them to exit
+const EXIT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long a hard kill is given to take effect
+const FORCE_KILL_WAIT: Duration = Duration::from_secs(2);
+
+/// Signal sent first. Windows only supports `Kill`, other signals fall back to it there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminateSignal {
+    Term,
+    Interrupt,
+    Hangup,
+    Quit,
+    Kill,
+}
+
+#[derive(Debug, Clone)]
+pub struct TerminateOptions {
+    pub signal: TerminateSignal,
+    /// Time the process gets to exit after `signal` before it is killed, 0 kills right away
+    pub grace_period_ms: u64,
+    /// Also terminate all descendants of the process
+    pub kill_tree: bool,
+}
+
+impl Default for TerminateOptions {
+    fn default() -> Self {
+        TerminateOptions {
+            signal: TerminateSignal::Term,
+            grace_period_ms: 5_000,
+            kill_tree: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminateOutcome {
+    /// Exited within the grace period
+    Exited,
+    /// Had to be killed after the grace period
+    ForceKilled,
+    NotFound,
+    /// The signal could not be delivered, usually a process of another user
+    PermissionDenied,
+    /// Still running even after the hard kill
+    StillRunning,
+}
+
+/// Asks a process to exit with `options.signal`, waits up to the grace period and
+/// only then kills it. With `kill_tree` the descendants are signalled first and
+/// anything still alive at the end counts against the outcome.
+pub fn terminate_process(pid: u32, options: TerminateOptions) -> TerminateOutcome {
+    let mut system = System::new();
+    refresh(&mut system);
+
+    let root = Pid::from_u32(pid);
+    if !is_alive(&system, root) {
+        return TerminateOutcome::NotFound;
+    }
+
+    let mut targets = if options.kill_tree {
+        descendants(&system, root)
+    } else {
+        Vec::new()
+    };
+    // Children first so the parent cannot respawn them
+    targets.push(root);
+
+    let signal = to_sysinfo_signal(options.signal);
+    let mut forced = signal == Signal::Kill;
+    for target in &targets {
+        if let Some(process) = system.process(*target) {
+            match process.kill_with(signal) {
+                Some(true) => {}
+                Some(false) if *target == root => {
+                    refresh(&mut system);
+                    if is_alive(&system, root) {
+                        println!("[TERMINATE] Could not signal process {}", pid);
+                        return TerminateOutcome::PermissionDenied;
+                    }
+                    return TerminateOutcome::Exited;
+                }
+                Some(false) => {}
+                None => {
+                    // Signal not supported on this platform
+                    forced = true;
+                    process.kill();
+                }
+            }
+        }
+    }
+    println!("[TERMINATE] Sent {:?} to {} process(es) rooted at {}", options.signal, targets.len(), pid);
+
+    let grace_period = Duration::from_millis(options.grace_period_ms);
+    if wait_for_exit(&mut system, &targets, grace_period) {
+        return if forced { TerminateOutcome::ForceKilled } else { TerminateOutcome::Exited };
+    }
+
+    for target in &targets {
+        if let Some(process) = system.process(*target).filter(|_| is_alive(&system, *target)) {
+            process.kill();
+        }
+    }
+    println!("[TERMINATE] Grace period over, force killed processes rooted at {}", pid);
+
+    if wait_for_exit(&mut system, &targets, FORCE_KILL_WAIT) {
+        TerminateOutcome::ForceKilled
+    } else {
+        println!("[TERMINATE] Process {} is still running", pid);
+        TerminateOutcome::StillRunning
+    }
+}
+
+fn to_sysinfo_signal(signal: TerminateSignal) -> Signal {
+    match signal {
+        TerminateSignal::Term => Signal::Term,
+        TerminateSignal::Interrupt => Signal::Interrupt,
+        TerminateSignal::Hangup => Signal::Hangup,
+        TerminateSignal::Quit => Signal::Quit,
+        TerminateSignal::Kill => Signal::Kill,
+    }
+}
+
+fn refresh(system: &mut System) {
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing().without_tasks());
+}
+
+/// Zombies have exited already, they only wait for their parent to reap them
+fn is_alive(system: &System, pid: Pid) -> bool {
+    system
+        .process(pid)
+        .is_some_and(|process| process.status() != ProcessStatus::Zombie && process.status() != ProcessStatus::Dead)
+}
+
+/// Returns `true` once none of `targets` is alive, `false` if `timeout` ran out
+fn wait_for_exit(system: &mut System, targets: &[Pid], timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        refresh(system);
+        if !targets.iter().any(|pid| is_alive(system, *pid)) {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        thread::sleep(EXIT_POLL_INTERVAL);
+    }
+}
+
+/// All descendants of `root`, deepest first
+fn descendants(system: &System, root: Pid) -> Vec<Pid> {
+    let mut children_of: HashMap<Pid, Vec<Pid>> = HashMap::new();
+    for (pid, process) in system.processes() {
+        if let Some(parent) = process.parent() {
+            children_of.entry(parent).or_default().push(*pid);
+        }
+    }
+
+    // Every child is found after its parent, reversing puts children first
+    let mut order = Vec::new();
+    let mut queue = vec![root];
+    while let Some(pid) = queue.pop() {
+        for child in children_of.get(&pid).into_iter().flatten() {
+            if *child != root && !order.contains(child) {
+                order.push(*child);
+                queue.push(*child);
+            }
+        }
+    }
+    order.reverse();
+    order
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::{Command, Stdio};
+
+    fn spawn_shell(script: &str) -> std::process::Child {
+        Command::new("sh").arg("-c").arg(script).stdout(Stdio::null()).spawn().unwrap()
+    }
+
+    #[test]
+    fn test_graceful_exit_within_grace_period() {
+        let mut child = spawn_shell("exec sleep 30");
+        thread::sleep(Duration::from_millis(100));
+
+        let options = TerminateOptions { grace_period_ms: 3_000, ..Default::default() };
+        assert_eq!(terminate_process(child.id(), options), TerminateOutcome::Exited);
+        child.wait().unwrap();
+    }
+
+    #[test]
+    fn test_ignored_signal_is_force_killed() {
+        // The ignored disposition survives exec, so sleep never sees SIGTERM
+        let mut child = spawn_shell("trap '' TERM; exec sleep 30");
+        thread::sleep(Duration::from_millis(100));
+
+        let options = TerminateOptions { grace_period_ms: 300, ..Default::default() };
+        assert_eq!(terminate_process(child.id(), options), TerminateOutcome::ForceKilled);
+        child.wait().unwrap();
+    }
+
+    #[test]
+    fn test_kill_tree_and_missing_process() {
+        let mut child = spawn_shell("sleep 30 & sleep 30 & wait");
+        thread::sleep(Duration::from_millis(200));
+
+        let mut system = System::new();
+        refresh(&mut system);
+        let children = descendants(&system, Pid::from_u32(child.id()));
+        assert_eq!(children.len(), 2);
+
+        let options = TerminateOptions { grace_period_ms: 3_000, kill_tree: true, ..Default::default() };
+        assert_eq!(terminate_process(child.id(), options), TerminateOutcome::Exited);
+        child.wait().unwrap();
+        refresh(&mut system);
+        assert!(!children.iter().any(|pid| is_alive(&system, *pid)));
+
+        assert_eq!(terminate_process(child.id(), TerminateOptions::default()), TerminateOutcome::NotFound);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use sysinfo::{Process, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+// Store running watchers with unique IDs
+lazy_static::lazy_static! {
+    static ref PROCESS_WATCHERS: Mutex<HashMap<u64, Arc<Mutex<bool>>>> = Mutex::new(HashMap::new());
+    static ref NEXT_WATCHER_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+/// How often the process table is diffed
+const WATCHER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Selects the processes a watcher reports.
+///
+/// Patterns are case-insensitive. Patterns containing `*` or `?` must match the whole
+/// name / path, plain patterns match anywhere (like `find_process_by_name`).
+/// With no patterns at all every process is reported.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessFilter {
+    /// Matched against the process name, e.g. `zoom*` or `slack`
+    pub name_patterns: Vec<String>,
+    /// Matched against the executable path, e.g. `/Applications/*.app/*`
+    pub path_patterns: Vec<String>,
+    /// Report processes that are already running when the watcher starts as started
+    pub include_existing: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessEventKind {
+    Started,
+    Exited,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessEvent {
+    pub kind: ProcessEventKind,
+    pub pid: u32,
+    pub name: String,
+    pub exe_path: String,
+    /// Seconds since the Unix epoch
+    pub start_time: u64,
+    /// Seconds between start and the moment the exit was noticed, `None` for starts
+    pub run_duration_seconds: Option<u64>,
+}
+
+/// Identity of a running process, the start time tells reused PIDs apart
+#[derive(Debug, Clone, PartialEq)]
+struct TrackedProcess {
+    name: String,
+    exe_path: String,
+    start_time: u64,
+}
+
+/// Starts watching for processes matching `filter` and streams start / exit events.
+/// Returns an id for `stop_process_watcher`.
+#[frb(sync)]
+pub fn start_process_watcher(filter: ProcessFilter, sink: StreamSink<ProcessEvent>) -> Result<u64, String> {
+    if filter.name_patterns.iter().chain(filter.path_patterns.iter()).any(|p| p.trim().is_empty()) {
+        return Err("Process filter patterns must not be empty".to_string());
+    }
+
+    let running = Arc::new(Mutex::new(true));
+    let running_clone = Arc::clone(&running);
+
+    let watcher_id = NEXT_WATCHER_ID.fetch_add(1, Ordering::SeqCst);
+    PROCESS_WATCHERS.lock().unwrap().insert(watcher_id, running);
+
+    thread::spawn(move || {
+        println!("[PROCESS_WATCHER] Watcher {} started", watcher_id);
+        let mut system = System::new();
+        let refresh_kind = ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet).without_tasks();
+        let mut known: HashMap<u32, TrackedProcess> = HashMap::new();
+        let mut first_pass = true;
+
+        while *running_clone.lock().unwrap() {
+            system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+
+            let current: HashMap<u32, TrackedProcess> = system
+                .processes()
+                .iter()
+                .map(|(pid, process)| (pid.as_u32(), tracked_process(process)))
+                .filter(|(_, process)| filter_matches(&filter, &process.name, &process.exe_path))
+                .collect();
+
+            let events = if first_pass && !filter.include_existing {
+                Vec::new()
+            } else {
+                diff_processes(&known, &current, unix_time_seconds())
+            };
+            first_pass = false;
+            known = current;
+
+            for event in events {
+                if sink.add(event).is_err() {
+                    // Dart side stopped listening
+                    println!("[PROCESS_WATCHER] Stream closed, stopping watcher {}", watcher_id);
+                    PROCESS_WATCHERS.lock().unwrap().remove(&watcher_id);
+                    return;
+                }
+            }
+
+            thread::sleep(WATCHER_POLL_INTERVAL);
+        }
+
+        println!("[PROCESS_WATCHER] Watcher {} stopped", watcher_id);
+    });
+
+    Ok(watcher_id)
+}
+
+#[frb(sync)]
+pub fn stop_process_watcher(watcher_id: u64) -> bool {
+    let mut watchers = PROCESS_WATCHERS.lock().unwrap();
+
+    if let Some(running) = watchers.remove(&watcher_id) {
+        if let Ok(mut guard) = running.lock() {
+            *guard = false;
+        }
+        true
+    } else {
+        false
+    }
+}
+
+fn tracked_process(process: &Process) -> TrackedProcess {
+    TrackedProcess {
+        name: process.name().to_string_lossy().into_owned(),
+        exe_path: process.exe().map(|p| p.to_string_lossy().into_owned()).unwrap_or_default(),
+        start_time: process.start_time(),
+    }
+}
+
+/// Compares two snapshots. Exits are reported before starts so a reused PID reads naturally.
+fn diff_processes(previous: &HashMap<u32, TrackedProcess>, current: &HashMap<u32, TrackedProcess>, now: u64) -> Vec<ProcessEvent> {
+    let mut exited: Vec<ProcessEvent> = previous
+        .iter()
+        .filter(|(pid, process)| current.get(pid).is_none_or(|c| c.start_time != process.start_time))
+        .map(|(pid, process)| ProcessEvent {
+            kind: ProcessEventKind::Exited,
+            pid: *pid,
+            name: process.name.clone(),
+            exe_path: process.exe_path.clone(),
+            start_time: process.start_time,
+            run_duration_seconds: Some(now.saturating_sub(process.start_time)),
+        })
+        .collect();
+
+    let mut started: Vec<ProcessEvent> = current
+        .iter()
+        .filter(|(pid, process)| previous.get(pid).is_none_or(|p| p.start_time != process.start_time))
+        .map(|(pid, process)| ProcessEvent {
+            kind: ProcessEventKind::Started,
+            pid: *pid,
+            name: process.name.clone(),
+            exe_path: process.exe_path.clone(),
+            start_time: process.start_time,
+            run_duration_seconds: None,
+        })
+        .collect();
+
+    exited.sort_by_key(|event| event.pid);
+    started.sort_by_key(|event| event.pid);
+    exited.extend(started);
+    exited
+}
+
+pub(crate) fn filter_matches(filter: &ProcessFilter, name: &str, exe_path: &str) -> bool {
+    if filter.name_patterns.is_empty() && filter.path_patterns.is_empty() {
+        return true;
+    }
+    filter.name_patterns.iter().any(|pattern| pattern_matches(pattern, name))
+        || (!exe_path.is_empty() && filter.path_patterns.iter().any(|pattern| pattern_matches(pattern, exe_path)))
+}
+
+/// Case-insensitive match: glob semantics with `*` / `?`, substring otherwise
+pub(crate) fn pattern_matches(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let text = text.to_lowercase();
+    if pattern.contains(['*', '?']) {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let text: Vec<char> = text.chars().collect();
+        wildcard_match(&pattern, &text)
+    } else {
+        text.contains(&pattern)
+    }
+}
+
+/// Iterative glob matcher with backtracking to the last `*`
+fn wildcard_match(pattern: &[char], text: &[char]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // Let the last `*` swallow one more character
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+fn unix_time_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_matching() {
+        assert!(pattern_matches("zoom*", "zoom.us"));
+        assert!(pattern_matches("Zoom*", "ZOOM"));
+        assert!(!pattern_matches("zoom*", "CptHost zoom"));
+        assert!(pattern_matches("slack", "com.tinyspeck.slackmacgap"));
+        assert!(pattern_matches("/applications/*.app/*", "/Applications/Slack.app/Contents/MacOS/Slack"));
+        assert!(pattern_matches("c:\\program files\\*\\teams?.exe", "C:\\Program Files\\Microsoft\\teams2.exe"));
+        assert!(!pattern_matches("*.exe", "teams.exe.bak"));
+    }
+
+    #[test]
+    fn test_filter_uses_name_or_path() {
+        let filter = ProcessFilter {
+            name_patterns: vec!["slack".to_string()],
+            path_patterns: vec!["*/zoom.us.app/*".to_string()],
+            include_existing: false,
+        };
+        assert!(filter_matches(&filter, "Slack Helper", ""));
+        assert!(filter_matches(&filter, "CptHost", "/Applications/zoom.us.app/Contents/Frameworks/CptHost"));
+        assert!(!filter_matches(&filter, "bash", "/bin/bash"));
+        assert!(filter_matches(&ProcessFilter::default(), "bash", "/bin/bash"));
+    }
+
+    #[test]
+    fn test_diff_reports_exits_starts_and_reused_pids() {
+        let process = |name: &str, start_time: u64| TrackedProcess {
+            name: name.to_string(),
+            exe_path: format!("/usr/bin/{}", name),
+            start_time,
+        };
+        let previous = HashMap::from([(10, process("zoom", 100)), (20, process("slack", 200)), (30, process("old", 50))]);
+        let current = HashMap::from([(10, process("zoom", 100)), (30, process("new", 900)), (40, process("teams", 950))]);
+
+        let event = |kind: ProcessEventKind, pid: u32, name: &str, start_time: u64, run_duration_seconds: Option<u64>| ProcessEvent {
+            kind,
+            pid,
+            name: name.to_string(),
+            exe_path: format!("/usr/bin/{}", name),
+            start_time,
+            run_duration_seconds,
+        };
+
+        let events = diff_processes(&previous, &current, 1000);
+        assert_eq!(
+            events,
+            vec![
+                event(ProcessEventKind::Exited, 20, "slack", 200, Some(800)),
+                event(ProcessEventKind::Exited, 30, "old", 50, Some(950)),
+                event(ProcessEventKind::Started, 30, "new", 900, None),
+                event(ProcessEventKind::Started, 40, "teams", 950, None),
+            ]
+        );
+    }
+}
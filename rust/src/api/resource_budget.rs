@@ -0,0 +1,345 @@
+use crate::api::active_window_listener::window_polling_interval;
+use crate::api::keyboard_listener::INPUT_POLL_INTERVAL;
+use flutter_rust_bridge::frb;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+// One budget controller per app, the listeners read the throttle state from here
+lazy_static::lazy_static! {
+    static ref BUDGET_STATE: Mutex<BudgetState> = Mutex::new(BudgetState::default());
+    static ref BUDGET_RUNNING: Mutex<Option<Arc<Mutex<bool>>>> = Mutex::new(None);
+}
+
+/// Asking the OS for the power source is not free (PowerShell on Windows), so it is cached
+const BATTERY_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// How often a deferred encoding re-checks the budget
+const DEFER_POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Longest a capture waits for the resource budget before it is encoded anyway
+pub(crate) const MAX_ENCODING_DEFERRAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct ResourceBudgetConfig {
+    /// CPU budget in percent of the whole machine (all cores), e.g. 1.0
+    pub max_cpu_percent: f32,
+    /// Resident memory budget
+    pub max_memory_bytes: u64,
+    /// Time between two self measurements
+    pub sample_interval_ms: u64,
+    /// Upper bound for slowing down polling because of CPU use
+    pub max_throttle_factor: f64,
+    /// Extra slow-down applied on top while running on battery
+    pub battery_throttle_factor: f64,
+}
+
+impl Default for ResourceBudgetConfig {
+    fn default() -> Self {
+        ResourceBudgetConfig {
+            max_cpu_percent: 1.0,
+            max_memory_bytes: 300 * 1024 * 1024,
+            sample_interval_ms: 5_000,
+            max_throttle_factor: 4.0,
+            battery_throttle_factor: 2.0,
+        }
+    }
+}
+
+/// Own resource use and the resulting throttling
+#[derive(Debug, Clone)]
+pub struct ResourceBudgetStatus {
+    pub running: bool,
+    /// Last measured CPU use in percent of the whole machine
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub on_battery: bool,
+    /// Multiplier applied to all polling intervals, 1.0 means no throttling
+    pub throttle_factor: f64,
+    /// Scheduled screenshots wait before encoding while this is set
+    pub encoding_deferred: bool,
+    pub input_poll_interval_ms: u64,
+    pub window_poll_interval_ms: u64,
+}
+
+#[frb(ignore)]
+#[derive(Debug, Clone)]
+struct BudgetState {
+    cpu_percent: f32,
+    memory_bytes: u64,
+    on_battery: bool,
+    /// Throttling caused by CPU use, without the battery factor
+    cpu_throttle_factor: f64,
+    battery_throttle_factor: f64,
+    encoding_deferred: bool,
+}
+
+impl Default for BudgetState {
+    fn default() -> Self {
+        BudgetState {
+            cpu_percent: 0.0,
+            memory_bytes: 0,
+            on_battery: false,
+            cpu_throttle_factor: 1.0,
+            battery_throttle_factor: 1.0,
+            encoding_deferred: false,
+        }
+    }
+}
+
+impl BudgetState {
+    fn throttle_factor(&self) -> f64 {
+        if self.on_battery {
+            self.cpu_throttle_factor * self.battery_throttle_factor
+        } else {
+            self.cpu_throttle_factor
+        }
+    }
+}
+
+/// Starts measuring the tracker's own CPU and memory use and throttling it to
+/// `config`. Returns `false` if the budget controller is already running.
+#[frb(sync)]
+pub fn start_resource_budget(config: ResourceBudgetConfig) -> bool {
+    let mut running_guard = BUDGET_RUNNING.lock().unwrap();
+    if running_guard.is_some() {
+        return false;
+    }
+
+    let running = Arc::new(Mutex::new(true));
+    *running_guard = Some(Arc::clone(&running));
+
+    let interval = Duration::from_millis(config.sample_interval_ms).max(MINIMUM_CPU_UPDATE_INTERVAL);
+    let core_count = thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f32;
+
+    thread::spawn(move || {
+        println!("[BUDGET] Resource budget started: {}% CPU, {} bytes", config.max_cpu_percent, config.max_memory_bytes);
+        let own_pid = Pid::from_u32(std::process::id());
+        let refresh_kind = ProcessRefreshKind::nothing().with_cpu().with_memory();
+        let mut system = System::new();
+        // The first refresh only establishes the CPU baseline
+        system.refresh_processes_specifics(ProcessesToUpdate::Some(&[own_pid]), false, refresh_kind);
+        let mut last_battery_check: Option<Instant> = None;
+
+        loop {
+            thread::sleep(interval);
+            if !*running.lock().unwrap() {
+                break;
+            }
+            system.refresh_processes_specifics(ProcessesToUpdate::Some(&[own_pid]), false, refresh_kind);
+            let Some(process) = system.process(own_pid) else {
+                continue;
+            };
+            // sysinfo reports percent of one core
+            let cpu_percent = process.cpu_usage() / core_count;
+            let memory_bytes = process.memory();
+
+            let on_battery = if last_battery_check.is_none_or(|checked| checked.elapsed() >= BATTERY_CHECK_INTERVAL) {
+                last_battery_check = Some(Instant::now());
+                Some(is_on_battery_power())
+            } else {
+                None
+            };
+
+            let mut state = BUDGET_STATE.lock().unwrap();
+            let previous_factor = state.throttle_factor();
+            state.cpu_percent = cpu_percent;
+            state.memory_bytes = memory_bytes;
+            state.on_battery = on_battery.unwrap_or(state.on_battery);
+            state.battery_throttle_factor = config.battery_throttle_factor.max(1.0);
+            state.cpu_throttle_factor = next_throttle_factor(
+                state.cpu_throttle_factor,
+                cpu_percent,
+                config.max_cpu_percent,
+                config.max_throttle_factor,
+            );
+            state.encoding_deferred = cpu_percent > config.max_cpu_percent || memory_bytes > config.max_memory_bytes;
+
+            if state.throttle_factor() != previous_factor {
+                println!(
+                    "[BUDGET] CPU {:.2}%, {} bytes, battery {}: throttle factor {:.2}",
+                    cpu_percent,
+                    memory_bytes,
+                    state.on_battery,
+                    state.throttle_factor()
+                );
+            }
+        }
+
+        println!("[BUDGET] Resource budget stopped");
+    });
+
+    true
+}
+
+/// Stops the budget controller and restores the normal polling intervals
+#[frb(sync)]
+pub fn stop_resource_budget() -> bool {
+    match BUDGET_RUNNING.lock().unwrap().take() {
+        Some(running) => {
+            if let Ok(mut guard) = running.lock() {
+                *guard = false;
+            }
+            *BUDGET_STATE.lock().unwrap() = BudgetState::default();
+            true
+        }
+        None => false,
+    }
+}
+
+#[frb(sync)]
+pub fn get_resource_budget_status() -> ResourceBudgetStatus {
+    let running = BUDGET_RUNNING.lock().unwrap().is_some();
+    let state = BUDGET_STATE.lock().unwrap().clone();
+    ResourceBudgetStatus {
+        running,
+        cpu_percent: state.cpu_percent,
+        memory_bytes: state.memory_bytes,
+        on_battery: state.on_battery,
+        throttle_factor: state.throttle_factor(),
+        encoding_deferred: state.encoding_deferred,
+        input_poll_interval_ms: scale(INPUT_POLL_INTERVAL, state.throttle_factor()).as_millis() as u64,
+        window_poll_interval_ms: scale(window_polling_interval(), state.throttle_factor()).as_millis() as u64,
+    }
+}
+
+/// Whether the machine currently runs on battery. `false` for desktops and when unknown.
+pub fn is_on_battery_power() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        on_battery_from_sysfs(std::path::Path::new("/sys/class/power_supply"))
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("pmset")
+            .args(["-g", "batt"])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).contains("'Battery Power'"))
+            .unwrap_or(false)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+
+        // BatteryStatus 1 means "discharging". This runs every minute, so no console may flash up
+        std::process::Command::new("powershell")
+            .args([
+                "-WindowStyle", "Hidden",
+                "-NonInteractive",
+                "-NoProfile",
+                "-Command", "(Get-CimInstance -ClassName Win32_Battery).BatteryStatus",
+            ])
+            .creation_flags(0x08000000) // CREATE_NO_WINDOW
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).lines().any(|line| line.trim() == "1"))
+            .unwrap_or(false)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+    {
+        false
+    }
+}
+
+/// Stretches a polling interval by the current throttle factor
+pub(crate) fn throttled_interval(base: Duration) -> Duration {
+    scale(base, BUDGET_STATE.lock().unwrap().throttle_factor())
+}
+
+/// Whether the budget currently asks to defer encoding, for loops that cannot block
+pub(crate) fn encoding_deferred() -> bool {
+    BUDGET_STATE.lock().unwrap().encoding_deferred
+}
+
+/// Blocks while the budget asks to defer encoding, at most `max_wait`.
+/// Returns `true` if encoding was deferred.
+pub(crate) fn wait_for_encoding_budget(max_wait: Duration) -> bool {
+    if !encoding_deferred() {
+        return false;
+    }
+
+    println!("[BUDGET] Over budget, deferring screenshot encoding");
+    let start = Instant::now();
+    while encoding_deferred() && start.elapsed() < max_wait {
+        thread::sleep(DEFER_POLL_INTERVAL);
+    }
+    true
+}
+
+fn scale(base: Duration, factor: f64) -> Duration {
+    base.mul_f64(factor.max(1.0))
+}
+
+/// Backs off quickly when over budget and recovers slowly once well below it
+fn next_throttle_factor(current: f64, cpu_percent: f32, max_cpu_percent: f32, max_factor: f64) -> f64 {
+    if cpu_percent > max_cpu_percent {
+        (current * 1.5).min(max_factor.max(1.0))
+    } else if cpu_percent < max_cpu_percent / 2.0 {
+        (current / 1.25).max(1.0)
+    } else {
+        current
+    }
+}
+
+/// Plugged in if any mains / USB supply is online, on battery if a battery discharges
+#[cfg(any(target_os = "linux", test))]
+fn on_battery_from_sysfs(root: &std::path::Path) -> bool {
+    let read = |path: std::path::PathBuf| std::fs::read_to_string(path).map(|s| s.trim().to_string()).unwrap_or_default();
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return false;
+    };
+
+    let mut discharging = false;
+    for entry in entries.flatten() {
+        let supply = entry.path();
+        match read(supply.join("type")).as_str() {
+            "Mains" | "USB" if read(supply.join("online")) == "1" => return false,
+            "Battery" if read(supply.join("status")) == "Discharging" => discharging = true,
+            _ => {}
+        }
+    }
+    discharging
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throttle_factor_backs_off_and_recovers() {
+        let mut factor = 1.0;
+        for _ in 0..10 {
+            factor = next_throttle_factor(factor, 3.0, 1.0, 4.0);
+        }
+        assert_eq!(factor, 4.0);
+
+        // Between half the budget and the budget nothing changes
+        assert_eq!(next_throttle_factor(factor, 0.8, 1.0, 4.0), 4.0);
+
+        for _ in 0..10 {
+            factor = next_throttle_factor(factor, 0.1, 1.0, 4.0);
+        }
+        assert_eq!(factor, 1.0);
+        assert_eq!(scale(Duration::from_millis(50), 2.5), Duration::from_millis(125));
+    }
+
+    #[test]
+    fn test_battery_detection_from_sysfs() {
+        let root = std::env::temp_dir().join(format!("power_supply_test_{}", std::process::id()));
+        let supply = |name: &str, files: &[(&str, &str)]| {
+            let dir = root.join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            for (file, content) in files {
+                std::fs::write(dir.join(file), format!("{}\n", content)).unwrap();
+            }
+        };
+
+        supply("BAT0", &[("type", "Battery"), ("status", "Discharging")]);
+        supply("AC", &[("type", "Mains"), ("online", "0")]);
+        assert!(on_battery_from_sysfs(&root));
+
+        supply("AC", &[("type", "Mains"), ("online", "1")]);
+        assert!(!on_battery_from_sysfs(&root));
+
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(!on_battery_from_sysfs(&root));
+    }
+}
@@ -0,0 +1,251 @@
+use anyhow::{anyhow, Result};
+use base64::{Engine as _, engine::general_purpose};
+use flutter_rust_bridge::frb;
+use image::{imageops::FilterType, codecs::jpeg::JpegEncoder, DynamicImage, RgbaImage};
+use screenshots::Screen;
+use std::io::Cursor;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::active_window_listener::get_active_window_info;
+use crate::api::frame_analysis::{analyze_frame, FrameAnalysis};
+use crate::api::screenshot_cursor::{overlay_cursor, CaptureArea};
+use crate::api::screenshot_watermark::{apply_watermark, WatermarkOptions};
+#[cfg(not(target_os = "windows"))]
+use crate::api::take_full_screenshot::capture_primary_screen;
+#[cfg(target_os = "linux")]
+use crate::api::take_full_screenshot::take_screenshot_linux_fallback;
+#[cfg(target_os = "windows")]
+use crate::api::take_full_screenshot::take_full_screenshot;
+
+/// Image encoding used for a captured screenshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenshotFormat {
+    Png,
+    Jpeg,
+}
+
+/// Options applied to a screenshot between capture and encoding
+#[derive(Debug, Clone)]
+pub struct ScreenshotOptions {
+    pub format: ScreenshotFormat,
+    /// JPEG quality from 1 to 100 (ignored for PNG)
+    pub jpeg_quality: u8,
+    /// Downscale the image to at most this width, keeping the aspect ratio
+    pub max_width: Option<u32>,
+    /// Timestamp / identity strip drawn onto the image before encoding
+    pub watermark: Option<WatermarkOptions>,
+    /// Composite the mouse cursor onto the capture
+    pub include_cursor: bool,
+}
+
+impl Default for ScreenshotOptions {
+    fn default() -> Self {
+        ScreenshotOptions {
+            format: ScreenshotFormat::Png,
+            jpeg_quality: 80,
+            max_width: None,
+            watermark: None,
+            include_cursor: false,
+        }
+    }
+}
+
+/// A captured and encoded screenshot
+#[derive(Debug, Clone)]
+pub struct Screenshot {
+    /// Base64 encoded image data, same encoding as `take_full_screenshot`
+    pub data: String,
+    pub format: ScreenshotFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Capture time in milliseconds since the Unix epoch
+    pub captured_at_ms: u64,
+    /// Blank / lock screen detection for the captured frame
+    pub analysis: FrameAnalysis,
+    /// Captured monitor (`display_info.id`), `None` for the primary screen fallback chain
+    pub monitor_id: Option<u32>,
+}
+
+/// Captures the primary screen and encodes it according to `options`.
+///
+/// Uses the same backend fallback chain as `take_full_screenshot`, so the
+/// result always reflects what the tracker would upload.
+pub fn capture_screenshot(options: ScreenshotOptions) -> Result<Screenshot> {
+    let captured_at_ms = unix_time_ms();
+    let frame = capture_screen_frame()?;
+    finish_screenshot(frame.image, &options, captured_at_ms, None, frame.area)
+}
+
+/// Captures a single monitor, identified by the `monitor_id` reported in
+/// `MouseEvent`, `WindowDetails` and `DisplayGeometry`.
+pub fn capture_monitor(monitor_id: u32, options: ScreenshotOptions) -> Result<Screenshot> {
+    let screens = Screen::all().map_err(|e| anyhow!("Failed to get screens: {}", e))?;
+    let screen = screens
+        .into_iter()
+        .find(|screen| screen.display_info.id == monitor_id)
+        .ok_or_else(|| anyhow!("Monitor {} not found", monitor_id))?;
+
+    let captured_at_ms = unix_time_ms();
+    let image = screen
+        .capture()
+        .map_err(|e| anyhow!("Failed to capture monitor {}: {}", monitor_id, e))?;
+    let area = CaptureArea::of_display(&screen.display_info);
+    finish_screenshot(image, &options, captured_at_ms, Some(monitor_id), Some(area))
+}
+
+/// Captures only the monitor showing the active window, leaving other screens out.
+pub fn capture_active_window_monitor(options: ScreenshotOptions) -> Result<Screenshot> {
+    let window = get_active_window_info().map_err(|e| anyhow!(e))?;
+    let monitor = window
+        .monitor
+        .ok_or_else(|| anyhow!("Active window \"{}\" is not on any monitor", window.title))?;
+    capture_monitor(monitor.monitor_id, options)
+}
+
+/// Analyses, prepares and encodes a freshly captured image.
+pub(crate) fn finish_screenshot(
+    image: RgbaImage,
+    options: &ScreenshotOptions,
+    captured_at_ms: u64,
+    monitor_id: Option<u32>,
+    area: Option<CaptureArea>,
+) -> Result<Screenshot> {
+    // Analyse the raw frame, the watermark would otherwise hide a blank capture
+    let analysis = analyze_frame(&image);
+    let image = prepare_image(image, options, captured_at_ms, area);
+    let bytes = encode_image(&image, options.format, options.jpeg_quality)?;
+
+    Ok(Screenshot {
+        data: general_purpose::STANDARD.encode(&bytes),
+        format: options.format,
+        width: image.width(),
+        height: image.height(),
+        captured_at_ms,
+        analysis,
+        monitor_id,
+    })
+}
+
+/// A raw capture together with the part of the desktop it shows
+#[frb(ignore)]
+pub(crate) struct CapturedFrame {
+    pub image: RgbaImage,
+    /// `None` when the backend does not say which area it captured
+    pub area: Option<CaptureArea>,
+}
+
+/// Captures the primary screen into an RGBA buffer.
+pub(crate) fn capture_screen_image() -> Result<RgbaImage> {
+    capture_screen_frame().map(|frame| frame.image)
+}
+
+/// Captures the primary screen into an RGBA buffer, remembering the captured area.
+///
+/// The screenshots crate hands back the raw frame, so nothing is encoded until
+/// the caller does it. When that capture fails or is blank, Linux falls back to
+/// the command-line tools, which see the screen through a different path. A blank
+/// frame is returned when no backend does better. Windows (NirCmd comes first
+/// there) uses the `take_full_screenshot` chain, whose backends only produce
+/// encoded images.
+///
+/// The Linux command-line tools grab the whole multi-monitor desktop, every other
+/// backend grabs the primary screen.
+pub(crate) fn capture_screen_frame() -> Result<CapturedFrame> {
+    #[cfg(target_os = "windows")]
+    {
+        let encoded = take_full_screenshot()?;
+        let image = decode_base64_image(&encoded)?;
+        return Ok(CapturedFrame { image, area: CaptureArea::primary_screen() });
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let primary = capture_primary_screen();
+        match &primary {
+            Ok(image) if !analyze_frame(image).is_blank() => {
+                return primary.map(|image| CapturedFrame { image, area: CaptureArea::primary_screen() });
+            }
+            Ok(_) => println!("[CAPTURE] Primary screen capture is blank"),
+            Err(e) => println!("[CAPTURE] Primary screen capture failed ({})", e),
+        }
+
+        #[cfg(target_os = "linux")]
+        match take_screenshot_linux_fallback().and_then(|encoded| decode_base64_image(&encoded)) {
+            Ok(image) if primary.is_err() || !analyze_frame(&image).is_blank() => {
+                return Ok(CapturedFrame { image, area: CaptureArea::virtual_desktop() });
+            }
+            Ok(_) => println!("[CAPTURE] Linux fallback tools also returned a blank frame"),
+            Err(e) => println!("[CAPTURE] Linux fallback tools failed ({})", e),
+        }
+
+        primary.map(|image| CapturedFrame { image, area: CaptureArea::primary_screen() })
+    }
+}
+
+/// Decodes a base64 encoded image (any format supported by the `image` crate).
+pub(crate) fn decode_base64_image(encoded: &str) -> Result<RgbaImage> {
+    let bytes = general_purpose::STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| anyhow!("Failed to decode base64 image: {}", e))?;
+    let image = image::load_from_memory(&bytes)
+        .map_err(|e| anyhow!("Failed to decode image data: {}", e))?;
+    Ok(image.to_rgba8())
+}
+
+/// Applies the cursor overlay, resizing and the watermark to a freshly captured image.
+///
+/// `area` is the part of the desktop the image shows, the cursor is only drawn when it is known.
+pub(crate) fn prepare_image(mut image: RgbaImage, options: &ScreenshotOptions, captured_at_ms: u64, area: Option<CaptureArea>) -> RgbaImage {
+    // The cursor is placed relative to the captured resolution, so draw it before resizing
+    if options.include_cursor {
+        match area {
+            Some(area) => overlay_cursor(&mut image, area),
+            None => println!("[CURSOR] Captured area unknown, skipping cursor overlay"),
+        }
+    }
+    let mut image = match options.max_width {
+        Some(max_width) => resize_to_max_width(image, max_width),
+        None => image,
+    };
+    if let Some(watermark) = &options.watermark {
+        apply_watermark(&mut image, watermark, captured_at_ms);
+    }
+    image
+}
+
+/// Downscales `image` so it is at most `max_width` pixels wide.
+pub(crate) fn resize_to_max_width(image: RgbaImage, max_width: u32) -> RgbaImage {
+    if max_width == 0 || image.width() <= max_width {
+        return image;
+    }
+    let height = ((image.height() as u64 * max_width as u64) / image.width() as u64).max(1) as u32;
+    image::imageops::resize(&image, max_width, height, FilterType::Triangle)
+}
+
+/// Encodes an RGBA image as PNG or JPEG.
+pub(crate) fn encode_image(image: &RgbaImage, format: ScreenshotFormat, jpeg_quality: u8) -> Result<Vec<u8>> {
+    let mut buffer = Cursor::new(Vec::new());
+    match format {
+        ScreenshotFormat::Png => {
+            image
+                .write_to(&mut buffer, image::ImageOutputFormat::Png)
+                .map_err(|e| anyhow!("Failed to encode PNG: {}", e))?;
+        }
+        ScreenshotFormat::Jpeg => {
+            // JPEG has no alpha channel, so drop it before encoding
+            let rgb = DynamicImage::ImageRgba8(image.clone()).to_rgb8();
+            JpegEncoder::new_with_quality(&mut buffer, jpeg_quality.clamp(1, 100))
+                .encode_image(&rgb)
+                .map_err(|e| anyhow!("Failed to encode JPEG: {}", e))?;
+        }
+    }
+    Ok(buffer.into_inner())
+}
+
+/// Current time in milliseconds since the Unix epoch
+pub(crate) fn unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
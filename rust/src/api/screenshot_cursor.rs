@@ -0,0 +1,254 @@
+use device_query::{DeviceQuery, DeviceState};
+use flutter_rust_bridge::frb;
+use image::{imageops::FilterType, Rgba, RgbaImage};
+use screenshots::{display_info::DisplayInfo, Screen};
+#[cfg(target_os = "linux")]
+use std::sync::Mutex;
+
+/// Classic arrow cursor used when the platform does not expose the real cursor image.
+/// `B` is the black outline, `W` the white fill, the hotspot is the top-left pixel.
+const ARROW_CURSOR: [&str; 19] = [
+    "B           ",
+    "BB          ",
+    "BWB         ",
+    "BWWB        ",
+    "BWWWB       ",
+    "BWWWWB      ",
+    "BWWWWWB     ",
+    "BWWWWWWB    ",
+    "BWWWWWWWB   ",
+    "BWWWWWWWWB  ",
+    "BWWWWWWWWWB ",
+    "BWWWWWWBBBBB",
+    "BWWWBWWB    ",
+    "BWWBBWWB    ",
+    "BWB  BWWB   ",
+    "BB   BWWB   ",
+    "B     BWWB  ",
+    "      BWWB  ",
+    "       BB   ",
+];
+
+/// Cursor bitmap together with its hotspot
+struct CursorImage {
+    image: RgbaImage,
+    hotspot: (u32, u32),
+}
+
+/// Desktop area shown in a captured frame, in the coordinate space reported by `device_query`
+#[frb(ignore)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CaptureArea {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+}
+
+impl CaptureArea {
+    pub(crate) fn of_display(info: &DisplayInfo) -> Self {
+        CaptureArea { x: info.x, y: info.y, width: info.width, height: info.height }
+    }
+
+    /// The first screen, the one `capture_primary_screen` grabs
+    pub(crate) fn primary_screen() -> Option<Self> {
+        let screens = Screen::all().ok()?;
+        screens.first().map(|screen| Self::of_display(&screen.display_info))
+    }
+
+    /// Bounding box of all monitors, the area desktop-wide tools such as
+    /// `import -window root` capture
+    pub(crate) fn virtual_desktop() -> Option<Self> {
+        let screens = Screen::all().ok()?;
+        Self::bounding(screens.iter().map(|screen| Self::of_display(&screen.display_info)))
+    }
+
+    fn bounding(areas: impl Iterator<Item = CaptureArea>) -> Option<Self> {
+        areas.reduce(|a, b| {
+            let x = a.x.min(b.x);
+            let y = a.y.min(b.y);
+            let right = (a.x + a.width as i32).max(b.x + b.width as i32);
+            let bottom = (a.y + a.height as i32).max(b.y + b.height as i32);
+            CaptureArea { x, y, width: (right - x) as u32, height: (bottom - y) as u32 }
+        })
+    }
+}
+
+/// Draws the current mouse cursor onto a frame showing `area` of the desktop.
+///
+/// The cursor is skipped when it is outside the area. Must run before the image
+/// is resized, so the captured resolution can be related to the area bounds.
+pub(crate) fn overlay_cursor(image: &mut RgbaImage, area: CaptureArea) {
+    let cursor = DeviceState::new().get_mouse().coords;
+
+    let position = match cursor_position_in_image(cursor, area, image.dimensions()) {
+        Some(position) => position,
+        None => return,
+    };
+
+    let cursor_image = match platform_cursor_image() {
+        Some(cursor_image) => cursor_image,
+        None => {
+            // Scale the bundled arrow with the monitor so it stays visible on HiDPI captures
+            let scale = (image.width() as f32 / area.width.max(1) as f32).round().max(1.0) as u32;
+            bundled_arrow_cursor(scale)
+        }
+    };
+
+    draw_cursor(image, &cursor_image, position);
+}
+
+/// Maps global cursor coordinates to pixel coordinates within the captured image.
+///
+/// Corrects for the area origin and for the ratio between the area size and the
+/// captured resolution, which is the scale factor on platforms reporting logical
+/// coordinates (macOS). Returns `None` when the cursor is outside the area.
+fn cursor_position_in_image(cursor: (i32, i32), monitor: CaptureArea, image_size: (u32, u32)) -> Option<(i32, i32)> {
+    if monitor.width == 0 || monitor.height == 0 {
+        return None;
+    }
+
+    let relative_x = cursor.0 - monitor.x;
+    let relative_y = cursor.1 - monitor.y;
+    if relative_x < 0 || relative_y < 0 || relative_x >= monitor.width as i32 || relative_y >= monitor.height as i32 {
+        return None;
+    }
+
+    let x = relative_x as f64 * image_size.0 as f64 / monitor.width as f64;
+    let y = relative_y as f64 * image_size.1 as f64 / monitor.height as f64;
+    Some((x.round() as i32, y.round() as i32))
+}
+
+fn bundled_arrow_cursor(scale: u32) -> CursorImage {
+    let width = ARROW_CURSOR[0].len() as u32;
+    let height = ARROW_CURSOR.len() as u32;
+    let image = RgbaImage::from_fn(width, height, |x, y| match ARROW_CURSOR[y as usize].as_bytes()[x as usize] {
+        b'B' => Rgba([0, 0, 0, 255]),
+        b'W' => Rgba([255, 255, 255, 255]),
+        _ => Rgba([0, 0, 0, 0]),
+    });
+
+    let image = if scale > 1 {
+        image::imageops::resize(&image, width * scale, height * scale, FilterType::Nearest)
+    } else {
+        image
+    };
+
+    CursorImage { image, hotspot: (0, 0) }
+}
+
+/// X connection reused across captures, dropped after an error so the next capture reconnects
+#[cfg(target_os = "linux")]
+static CURSOR_CONNECTION: Mutex<Option<x11rb::rust_connection::RustConnection>> = Mutex::new(None);
+
+/// Reads the real cursor image through the XFixes extension
+#[cfg(target_os = "linux")]
+fn platform_cursor_image() -> Option<CursorImage> {
+    use x11rb::protocol::xfixes::ConnectionExt as _;
+
+    let mut connection = CURSOR_CONNECTION.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if connection.is_none() {
+        // Fails on pure Wayland sessions, the bundled arrow is used there
+        let (conn, _) = x11rb::connect(None).ok()?;
+        conn.xfixes_query_version(4, 0).ok()?.reply().ok()?;
+        *connection = Some(conn);
+    }
+    let reply = connection.as_ref()?.xfixes_get_cursor_image().ok().and_then(|cookie| cookie.reply().ok());
+    let Some(reply) = reply else {
+        *connection = None;
+        return None;
+    };
+    drop(connection);
+
+    let (width, height) = (reply.width as u32, reply.height as u32);
+    if width == 0 || height == 0 || reply.cursor_image.len() < (width * height) as usize {
+        return None;
+    }
+
+    // Pixels are premultiplied ARGB
+    let mut image = RgbaImage::new(width, height);
+    for (pixel, argb) in image.pixels_mut().zip(reply.cursor_image.iter()) {
+        let alpha = (argb >> 24) as u8;
+        let unpremultiply = |channel: u32| -> u8 {
+            if alpha == 0 {
+                0
+            } else {
+                ((channel & 0xff) * 255 / alpha as u32).min(255) as u8
+            }
+        };
+        *pixel = Rgba([unpremultiply(argb >> 16), unpremultiply(argb >> 8), unpremultiply(*argb), alpha]);
+    }
+
+    Some(CursorImage {
+        image,
+        hotspot: (reply.xhot as u32, reply.yhot as u32),
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn platform_cursor_image() -> Option<CursorImage> {
+    None
+}
+
+/// Alpha-blends the cursor onto the image with its hotspot at `position`
+fn draw_cursor(image: &mut RgbaImage, cursor: &CursorImage, position: (i32, i32)) {
+    let origin_x = position.0 - cursor.hotspot.0 as i32;
+    let origin_y = position.1 - cursor.hotspot.1 as i32;
+
+    for (cx, cy, source) in cursor.image.enumerate_pixels() {
+        let x = origin_x + cx as i32;
+        let y = origin_y + cy as i32;
+        if x < 0 || y < 0 || x >= image.width() as i32 || y >= image.height() as i32 {
+            continue;
+        }
+
+        let alpha = source.0[3] as u32;
+        if alpha == 0 {
+            continue;
+        }
+        let target = image.get_pixel_mut(x as u32, y as u32);
+        for channel in 0..3 {
+            target.0[channel] = ((source.0[channel] as u32 * alpha + target.0[channel] as u32 * (255 - alpha)) / 255) as u8;
+        }
+        target.0[3] = target.0[3].max(source.0[3]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_position_corrects_offset_and_scale() {
+        // Secondary monitor at x=1920 reported in logical points, captured at 2x
+        let monitor = CaptureArea { x: 1920, y: 0, width: 1440, height: 900 };
+        assert_eq!(cursor_position_in_image((2020, 50), monitor, (2880, 1800)), Some((200, 100)));
+        // Cursor on the other monitor
+        assert_eq!(cursor_position_in_image((100, 50), monitor, (2880, 1800)), None);
+    }
+
+    #[test]
+    fn test_desktop_wide_capture_places_cursor_on_any_monitor() {
+        // Secondary monitor to the left of the primary one, captured as one frame
+        let primary = CaptureArea { x: 0, y: 0, width: 1920, height: 1080 };
+        let left = CaptureArea { x: -1280, y: 200, width: 1280, height: 1024 };
+        let desktop = CaptureArea::bounding([primary, left].into_iter()).unwrap();
+        assert_eq!(desktop, CaptureArea { x: -1280, y: 0, width: 3200, height: 1224 });
+
+        assert_eq!(cursor_position_in_image((-1180, 300), desktop, (3200, 1224)), Some((100, 300)));
+        assert_eq!(cursor_position_in_image((100, 50), desktop, (3200, 1224)), Some((1380, 50)));
+    }
+
+    #[test]
+    fn test_arrow_is_drawn_at_hotspot_and_clipped() {
+        let mut image = RgbaImage::from_pixel(20, 20, Rgba([0, 128, 0, 255]));
+        let cursor = bundled_arrow_cursor(1);
+        draw_cursor(&mut image, &cursor, (15, 10));
+
+        assert_eq!(image.get_pixel(15, 10), &Rgba([0, 0, 0, 255]));
+        assert_eq!(image.get_pixel(16, 12), &Rgba([255, 255, 255, 255]));
+        // Transparent cursor pixels leave the capture untouched
+        assert_eq!(image.get_pixel(19, 10), &Rgba([0, 128, 0, 255]));
+        assert_eq!(image.get_pixel(14, 10), &Rgba([0, 128, 0, 255]));
+    }
+}
@@ -0,0 +1,332 @@
+use crate::api::resource_budget::{wait_for_encoding_budget, MAX_ENCODING_DEFERRAL};
+use crate::api::screenshot_capture::{capture_screen_frame, finish_screenshot, unix_time_ms, Screenshot, ScreenshotOptions};
+use crate::frb_generated::StreamSink;
+use device_query::{DeviceQuery, DeviceState};
+use flutter_rust_bridge::frb;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Store running schedulers with unique IDs
+lazy_static::lazy_static! {
+    static ref SCREENSHOT_SCHEDULERS: Mutex<HashMap<u64, Arc<Mutex<SchedulerFlags>>>> = Mutex::new(HashMap::new());
+    static ref NEXT_SCHEDULER_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+/// How often the scheduler thread wakes up to check flags and user activity
+const SCHEDULER_TICK: Duration = Duration::from_millis(250);
+
+#[derive(Debug)]
+struct SchedulerFlags {
+    running: bool,
+    paused: bool,
+}
+
+/// Configuration for the jittered screenshot scheduler
+#[derive(Debug, Clone)]
+pub struct ScreenshotScheduleConfig {
+    /// Length of each scheduling window in seconds
+    pub interval_seconds: u64,
+    /// Number of screenshots taken at random instants within each window
+    pub captures_per_interval: u32,
+    /// Skip captures once the user has been idle this long (0 disables the check)
+    pub idle_threshold_seconds: u64,
+    /// Fixed RNG seed for reproducible schedules, `None` seeds from the OS
+    pub seed: Option<u64>,
+    pub options: ScreenshotOptions,
+}
+
+/// Starts a scheduler that captures `captures_per_interval` screenshots at random
+/// instants within every `interval_seconds` window and sends them through the sink.
+///
+/// Unlike Dart timers the schedule keeps running while the app is in the background.
+/// Returns an id for `pause_screenshot_scheduler`, `resume_screenshot_scheduler`
+/// and `stop_screenshot_scheduler`.
+#[frb(sync)]
+pub fn start_screenshot_scheduler(config: ScreenshotScheduleConfig, sink: StreamSink<Screenshot>) -> Result<u64, String> {
+    if config.interval_seconds < 2 {
+        return Err("Interval must be at least 2 seconds".to_string());
+    }
+    if config.captures_per_interval == 0 {
+        return Err("At least one capture per interval is required".to_string());
+    }
+
+    let flags = Arc::new(Mutex::new(SchedulerFlags { running: true, paused: false }));
+    let flags_clone = Arc::clone(&flags);
+
+    let scheduler_id = NEXT_SCHEDULER_ID.fetch_add(1, Ordering::SeqCst);
+    SCREENSHOT_SCHEDULERS.lock().unwrap().insert(scheduler_id, flags);
+
+    thread::spawn(move || {
+        run_scheduler(scheduler_id, config, flags_clone, sink);
+        SCREENSHOT_SCHEDULERS.lock().unwrap().remove(&scheduler_id);
+    });
+
+    Ok(scheduler_id)
+}
+
+/// Pauses a scheduler. Capture instants that pass while paused are skipped.
+#[frb(sync)]
+pub fn pause_screenshot_scheduler(scheduler_id: u64) -> bool {
+    set_scheduler_flags(scheduler_id, |flags| flags.paused = true)
+}
+
+/// Resumes a paused scheduler.
+#[frb(sync)]
+pub fn resume_screenshot_scheduler(scheduler_id: u64) -> bool {
+    set_scheduler_flags(scheduler_id, |flags| flags.paused = false)
+}
+
+#[frb(sync)]
+pub fn stop_screenshot_scheduler(scheduler_id: u64) -> bool {
+    let removed = SCREENSHOT_SCHEDULERS.lock().unwrap().remove(&scheduler_id);
+
+    if let Some(flags) = removed {
+        if let Ok(mut guard) = flags.lock() {
+            guard.running = false;
+        }
+        true
+    } else {
+        false
+    }
+}
+
+fn set_scheduler_flags(scheduler_id: u64, update: impl FnOnce(&mut SchedulerFlags)) -> bool {
+    let schedulers = SCREENSHOT_SCHEDULERS.lock().unwrap();
+    match schedulers.get(&scheduler_id) {
+        Some(flags) => {
+            if let Ok(mut guard) = flags.lock() {
+                update(&mut guard);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+fn run_scheduler(
+    scheduler_id: u64,
+    config: ScreenshotScheduleConfig,
+    flags: Arc<Mutex<SchedulerFlags>>,
+    sink: StreamSink<Screenshot>,
+) {
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let interval = Duration::from_secs(config.interval_seconds);
+    let idle_threshold = Duration::from_secs(config.idle_threshold_seconds);
+    let device_state = DeviceState::new();
+    let mut activity = ActivityTracker::new();
+
+    println!("[SCHEDULER] Scheduler {} started: {} capture(s) every {:?}", scheduler_id, config.captures_per_interval, interval);
+
+    loop {
+        let window_start = Instant::now();
+        let offsets = plan_capture_offsets(&mut rng, interval, config.captures_per_interval);
+
+        for offset in offsets {
+            if !wait_until(window_start + offset, &flags, &device_state, &mut activity) {
+                println!("[SCHEDULER] Scheduler {} stopped", scheduler_id);
+                return;
+            }
+
+            if flags.lock().map(|f| f.paused).unwrap_or(false) {
+                println!("[SCHEDULER] Scheduler {} is paused, skipping capture", scheduler_id);
+                continue;
+            }
+
+            if !idle_threshold.is_zero() && activity.idle_for() >= idle_threshold {
+                println!("[SCHEDULER] User idle for {:?}, skipping capture", activity.idle_for());
+                continue;
+            }
+
+            match capture_deferring_encoding(&config.options) {
+                Ok(screenshot) => {
+                    if sink.add(screenshot).is_err() {
+                        // Dart side stopped listening
+                        println!("[SCHEDULER] Stream closed, stopping scheduler {}", scheduler_id);
+                        return;
+                    }
+                }
+                Err(e) => println!("[SCHEDULER] Scheduled capture failed: {}", e),
+            }
+        }
+
+        if !wait_until(window_start + interval, &flags, &device_state, &mut activity) {
+            println!("[SCHEDULER] Scheduler {} stopped", scheduler_id);
+            return;
+        }
+    }
+}
+
+/// Grabs the raw frame at the scheduled moment, but encodes only once the
+/// resource budget allows it
+fn capture_deferring_encoding(options: &ScreenshotOptions) -> anyhow::Result<Screenshot> {
+    let captured_at_ms = unix_time_ms();
+    let frame = capture_screen_frame()?;
+    wait_for_encoding_budget(MAX_ENCODING_DEFERRAL);
+    finish_screenshot(frame.image, options, captured_at_ms, None, frame.area)
+}
+
+/// Sleeps until `deadline` while tracking user activity.
+/// Returns `false` if the scheduler was stopped in the meantime.
+fn wait_until(
+    deadline: Instant,
+    flags: &Arc<Mutex<SchedulerFlags>>,
+    device_state: &DeviceState,
+    activity: &mut ActivityTracker,
+) -> bool {
+    loop {
+        if !flags.lock().map(|f| f.running).unwrap_or(false) {
+            return false;
+        }
+        activity.poll(device_state);
+
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        thread::sleep(SCHEDULER_TICK.min(deadline - now));
+    }
+}
+
+/// Tracks the last time the mouse moved or a key was held down
+struct ActivityTracker {
+    last_coords: Option<(i32, i32)>,
+    last_activity: Instant,
+}
+
+impl ActivityTracker {
+    fn new() -> Self {
+        ActivityTracker {
+            last_coords: None,
+            last_activity: Instant::now(),
+        }
+    }
+
+    fn poll(&mut self, device_state: &DeviceState) {
+        let coords = device_state.get_mouse().coords;
+        let moved = self.last_coords.is_some_and(|last| last != coords);
+        self.last_coords = Some(coords);
+
+        if moved || !device_state.get_keys().is_empty() {
+            self.last_activity = Instant::now();
+        }
+    }
+
+    fn idle_for(&self) -> Duration {
+        self.last_activity.elapsed()
+    }
+}
+
+/// Picks `count` well-distributed random offsets within `interval`.
+///
+/// Mirrors `generateRandomTimestamps` on the Dart side: offsets stay within the
+/// middle 80% of the window and are kept at least `interval / (count + 2)` apart,
+/// falling back to evenly spaced offsets when there is not enough room.
+/// Always returns `count` distinct, ascending offsets unless `interval` is shorter
+/// than two seconds.
+pub(crate) fn plan_capture_offsets(rng: &mut StdRng, interval: Duration, count: u32) -> Vec<Duration> {
+    let total_seconds = interval.as_secs() as i64;
+    let count = count as i64;
+    if count <= 0 || total_seconds <= 1 {
+        return Vec::new();
+    }
+
+    let start_boundary = ((total_seconds as f64 * 0.1).round() as i64).max(1);
+    let end_boundary = ((total_seconds as f64 * 0.9).round() as i64).max(start_boundary + 1);
+    let usable_range = end_boundary - start_boundary;
+    let min_spacing = ((total_seconds as f64 / (count + 2) as f64).round() as i64).max(1);
+
+    // Not enough room for spaced random values, use evenly spaced ones. Millisecond
+    // precision keeps them distinct when there are more captures than seconds.
+    if usable_range < count * min_spacing {
+        let step_ms = usable_range as f64 * 1000.0 / (count + 1) as f64;
+        return (1..=count)
+            .map(|i| Duration::from_millis((start_boundary as f64 * 1000.0 + step_ms * i as f64).round() as u64))
+            .collect();
+    }
+
+    let mut selected: HashSet<i64> = HashSet::new();
+    let max_attempts = count * 10;
+    let mut attempts = 0;
+
+    while (selected.len() as i64) < count && attempts < max_attempts {
+        let candidate = rng.gen_range(0..usable_range) + start_boundary;
+        if selected.iter().all(|s| (s - candidate).abs() >= min_spacing) {
+            selected.insert(candidate);
+        }
+        attempts += 1;
+    }
+
+    // Random attempts ran out before every capture found a spaced slot. Lay the
+    // captures out at the minimum spacing and spread the leftover room randomly.
+    if (selected.len() as i64) < count {
+        let slack = usable_range - (count - 1) * min_spacing;
+        let mut shifts: Vec<i64> = (0..count).map(|_| rng.gen_range(0..=slack)).collect();
+        shifts.sort_unstable();
+        return shifts
+            .into_iter()
+            .enumerate()
+            .map(|(i, shift)| Duration::from_secs((start_boundary + i as i64 * min_spacing + shift) as u64))
+            .collect();
+    }
+
+    let mut seconds: Vec<i64> = selected.into_iter().collect();
+    seconds.sort_unstable();
+    seconds.into_iter().map(|s| Duration::from_secs(s as u64)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_gives_same_schedule() {
+        let interval = Duration::from_secs(600);
+        let first = plan_capture_offsets(&mut StdRng::seed_from_u64(42), interval, 3);
+        let second = plan_capture_offsets(&mut StdRng::seed_from_u64(42), interval, 3);
+
+        assert_eq!(first, second);
+        assert_eq!(first.len(), 3);
+    }
+
+    #[test]
+    fn test_offsets_stay_inside_window_and_apart() {
+        let interval = Duration::from_secs(600);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..100 {
+            let offsets = plan_capture_offsets(&mut rng, interval, 4);
+            assert_eq!(offsets.len(), 4);
+            assert!(offsets.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(offsets.iter().all(|o| *o >= Duration::from_secs(60) && *o <= Duration::from_secs(540)));
+            assert!(offsets.windows(2).all(|pair| pair[1] - pair[0] >= Duration::from_secs(100)));
+        }
+    }
+
+    #[test]
+    fn test_crowded_window_falls_back_to_even_spacing() {
+        let offsets = plan_capture_offsets(&mut StdRng::seed_from_u64(1), Duration::from_secs(10), 20);
+        assert_eq!(offsets.len(), 20);
+        assert!(offsets.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(offsets.iter().all(|o| *o > Duration::from_secs(1) && *o < Duration::from_secs(9)));
+    }
+
+    #[test]
+    fn test_tightly_packed_window_still_gets_every_capture() {
+        // 6 captures 75 s apart fit into the 480 s usable range, but random picks rarely manage it
+        let interval = Duration::from_secs(600);
+        for seed in 0..200 {
+            let offsets = plan_capture_offsets(&mut StdRng::seed_from_u64(seed), interval, 6);
+            assert_eq!(offsets.len(), 6);
+            assert!(offsets.windows(2).all(|pair| pair[1] - pair[0] >= Duration::from_secs(75)));
+            assert!(offsets.iter().all(|o| *o >= Duration::from_secs(60) && *o <= Duration::from_secs(540)));
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use anyhow::{anyhow, Result};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::api::frame_analysis::{analyze_frame, FrameAnalysis};
+use crate::api::resource_budget::{wait_for_encoding_budget, MAX_ENCODING_DEFERRAL};
+use crate::api::screenshot_capture::{
+    capture_screen_frame, encode_image, prepare_image, unix_time_ms, ScreenshotFormat, ScreenshotOptions,
+};
+
+/// Prefix of every file managed by the spool
+const SPOOL_FILE_PREFIX: &str = "screenshot_";
+
+/// Suffix of in-progress writes, never reported as pending
+const TEMP_FILE_SUFFIX: &str = ".tmp";
+
+/// Disambiguates captures taken within the same millisecond
+static SPOOL_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// A screenshot written to the spool directory
+#[derive(Debug, Clone)]
+pub struct SpooledScreenshot {
+    pub path: String,
+    pub format: ScreenshotFormat,
+    pub width: u32,
+    pub height: u32,
+    pub size_bytes: u64,
+    /// Capture time in milliseconds since the Unix epoch
+    pub captured_at_ms: u64,
+    pub analysis: FrameAnalysis,
+}
+
+/// A pending file in the spool directory
+#[derive(Debug, Clone)]
+pub struct SpoolEntry {
+    pub path: String,
+    pub size_bytes: u64,
+    pub captured_at_ms: u64,
+}
+
+/// Result of a quota enforcement pass
+#[derive(Debug, Clone)]
+pub struct SpoolQuotaResult {
+    /// Files removed, oldest first
+    pub deleted_paths: Vec<String>,
+    pub remaining_files: u32,
+    pub remaining_bytes: u64,
+}
+
+/// Captures the primary screen and writes the encoded image into `dir`.
+///
+/// The image is written to a temporary file, synced and then renamed, so readers
+/// never see a partially written screenshot. Only the path crosses FFI, which keeps
+/// large images out of Dart memory while the app is offline.
+pub fn capture_to_file(dir: String, options: ScreenshotOptions) -> Result<SpooledScreenshot> {
+    let captured_at_ms = unix_time_ms();
+    let frame = capture_screen_frame()?;
+    wait_for_encoding_budget(MAX_ENCODING_DEFERRAL);
+    let analysis = analyze_frame(&frame.image);
+    let image = prepare_image(frame.image, &options, captured_at_ms, frame.area);
+    let bytes = encode_image(&image, options.format, options.jpeg_quality)?;
+
+    let extension = match options.format {
+        ScreenshotFormat::Png => "png",
+        ScreenshotFormat::Jpeg => "jpg",
+    };
+    let sequence = SPOOL_SEQUENCE.fetch_add(1, Ordering::Relaxed) % 1000;
+    let file_name = format!("{}{}_{:03}.{}", SPOOL_FILE_PREFIX, captured_at_ms, sequence, extension);
+    let path = write_atomically(Path::new(&dir), &file_name, &bytes)?;
+
+    println!("[SPOOL] Wrote {} ({} bytes)", path.display(), bytes.len());
+
+    Ok(SpooledScreenshot {
+        path: path.to_string_lossy().into_owned(),
+        format: options.format,
+        width: image.width(),
+        height: image.height(),
+        size_bytes: bytes.len() as u64,
+        captured_at_ms,
+        analysis,
+    })
+}
+
+/// Lists the screenshots waiting in the spool directory, oldest first.
+pub fn list_spooled_screenshots(dir: String) -> Result<Vec<SpoolEntry>> {
+    let dir = Path::new(&dir);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| anyhow!("Failed to read spool directory {}: {}", dir.display(), e))? {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let captured_at_ms = match parse_spool_file_name(&name) {
+            Some(ms) => ms,
+            None => continue,
+        };
+        let size_bytes = match entry.metadata() {
+            Ok(metadata) if metadata.is_file() => metadata.len(),
+            _ => continue,
+        };
+
+        entries.push(SpoolEntry {
+            path: entry.path().to_string_lossy().into_owned(),
+            size_bytes,
+            captured_at_ms,
+        });
+    }
+
+    entries.sort_by(|a, b| a.captured_at_ms.cmp(&b.captured_at_ms).then_with(|| a.path.cmp(&b.path)));
+    Ok(entries)
+}
+
+/// Deletes a spooled screenshot, e.g. after a successful upload.
+/// Returns `false` if the file no longer exists.
+pub fn delete_spooled_screenshot(path: String) -> Result<bool> {
+    let path = Path::new(&path);
+    let is_spool_file = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(parse_spool_file_name)
+        .is_some();
+    if !is_spool_file {
+        return Err(anyhow!("Refusing to delete {}: not a spooled screenshot", path.display()));
+    }
+
+    match fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(anyhow!("Failed to delete {}: {}", path.display(), e)),
+    }
+}
+
+/// Deletes the oldest spooled screenshots until the directory uses at most `max_bytes`.
+pub fn enforce_spool_quota(dir: String, max_bytes: u64) -> Result<SpoolQuotaResult> {
+    let entries = list_spooled_screenshots(dir)?;
+    let mut total_bytes: u64 = entries.iter().map(|entry| entry.size_bytes).sum();
+    let mut remaining_files = entries.len() as u32;
+    let mut deleted_paths = Vec::new();
+
+    for entry in entries {
+        if total_bytes <= max_bytes {
+            break;
+        }
+        match fs::remove_file(&entry.path) {
+            Ok(()) => {
+                println!("[SPOOL] Quota exceeded, evicted {}", entry.path);
+                total_bytes -= entry.size_bytes;
+                remaining_files -= 1;
+                deleted_paths.push(entry.path);
+            }
+            Err(e) => println!("[SPOOL] Failed to evict {}: {}", entry.path, e),
+        }
+    }
+
+    Ok(SpoolQuotaResult {
+        deleted_paths,
+        remaining_files,
+        remaining_bytes: total_bytes,
+    })
+}
+
+/// Writes `bytes` to `dir/file_name` via a synced temporary file and a rename.
+fn write_atomically(dir: &Path, file_name: &str, bytes: &[u8]) -> Result<PathBuf> {
+    fs::create_dir_all(dir).map_err(|e| anyhow!("Failed to create spool directory {}: {}", dir.display(), e))?;
+
+    let final_path = dir.join(file_name);
+    let temp_path = dir.join(format!(".{}{}", file_name, TEMP_FILE_SUFFIX));
+
+    let result = (|| -> std::io::Result<()> {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(bytes)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, &final_path)
+    })();
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(anyhow!("Failed to write {}: {}", final_path.display(), e));
+    }
+    Ok(final_path)
+}
+
+/// Extracts the capture time from `screenshot_<ms>_<seq>.<ext>`
+fn parse_spool_file_name(name: &str) -> Option<u64> {
+    let stem = name.strip_prefix(SPOOL_FILE_PREFIX)?;
+    let (stem, extension) = stem.rsplit_once('.')?;
+    if !matches!(extension, "png" | "jpg") {
+        return None;
+    }
+    let millis = stem.split('_').next()?;
+    millis.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_spool(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("pi_task_watch_spool_{}_{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_listing_ignores_temp_and_foreign_files() {
+        let dir = temp_spool("listing");
+        write_atomically(&dir, "screenshot_2000_000.png", &[0; 10]).unwrap();
+        write_atomically(&dir, "screenshot_1000_000.jpg", &[0; 20]).unwrap();
+        fs::write(dir.join(".screenshot_3000_000.png.tmp"), [0; 5]).unwrap();
+        fs::write(dir.join("notes.txt"), [0; 5]).unwrap();
+
+        let entries = list_spooled_screenshots(dir.to_string_lossy().into_owned()).unwrap();
+        let times: Vec<u64> = entries.iter().map(|e| e.captured_at_ms).collect();
+        assert_eq!(times, vec![1000, 2000]);
+        assert_eq!(entries[0].size_bytes, 20);
+        assert!(delete_spooled_screenshot(dir.join("notes.txt").to_string_lossy().into_owned()).is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_quota_evicts_oldest_first() {
+        let dir = temp_spool("quota");
+        for ms in [3000, 1000, 2000, 4000] {
+            write_atomically(&dir, &format!("screenshot_{}_000.png", ms), &[0; 100]).unwrap();
+        }
+
+        let result = enforce_spool_quota(dir.to_string_lossy().into_owned(), 250).unwrap();
+        assert_eq!(result.deleted_paths.len(), 2);
+        assert!(result.deleted_paths[0].ends_with("screenshot_1000_000.png"));
+        assert!(result.deleted_paths[1].ends_with("screenshot_2000_000.png"));
+        assert_eq!(result.remaining_files, 2);
+        assert_eq!(result.remaining_bytes, 200);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
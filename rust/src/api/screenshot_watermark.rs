@@ -0,0 +1,329 @@
+use image::{Rgba, RgbaImage};
+use sysinfo::System;
+
+/// Corner of the screenshot the watermark strip is drawn in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatermarkPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// Options for the timestamp / identity watermark drawn onto screenshots
+#[derive(Debug, Clone)]
+pub struct WatermarkOptions {
+    pub position: WatermarkPosition,
+    /// Opacity of the strip from 0.0 (invisible) to 1.0 (opaque)
+    pub opacity: f32,
+    /// Pixel scale of the bundled 5x7 font, `None` picks one from the image height.
+    /// Clamped to 1..=16.
+    pub scale: Option<u32>,
+    pub include_hostname: bool,
+    pub include_user: bool,
+    /// Odoo task id shown in the strip
+    pub task_id: Option<String>,
+    /// Free text appended to the strip
+    pub custom_text: Option<String>,
+}
+
+impl Default for WatermarkOptions {
+    fn default() -> Self {
+        WatermarkOptions {
+            position: WatermarkPosition::BottomRight,
+            opacity: 0.75,
+            scale: None,
+            include_hostname: true,
+            include_user: true,
+            task_id: None,
+            custom_text: None,
+        }
+    }
+}
+
+const GLYPH_WIDTH: u32 = 5;
+const GLYPH_HEIGHT: u32 = 7;
+/// Blank columns between two glyphs
+const GLYPH_SPACING: u32 = 1;
+/// Largest font pixel scale, 112px high glyphs
+const MAX_SCALE: u32 = 16;
+
+/// Draws the watermark strip onto `image` in place.
+pub(crate) fn apply_watermark(image: &mut RgbaImage, options: &WatermarkOptions, captured_at_ms: u64) {
+    let text = watermark_text(options, captured_at_ms);
+    let opacity = options.opacity.clamp(0.0, 1.0);
+    if text.is_empty() || opacity == 0.0 {
+        return;
+    }
+
+    // Roughly 1/80 of the image height for the glyphs, at least 1px per font pixel
+    let scale = options.scale.unwrap_or(image.height() / (GLYPH_HEIGHT * 80)).clamp(1, MAX_SCALE);
+    let padding = 2 * scale;
+    let advance = (GLYPH_WIDTH + GLYPH_SPACING) * scale;
+    let text_width = (text.chars().count() as u32).saturating_mul(advance) - GLYPH_SPACING * scale;
+    let strip_width = text_width.saturating_add(2 * padding).min(image.width());
+    let strip_height = (GLYPH_HEIGHT * scale + 2 * padding).min(image.height());
+
+    let strip_x = match options.position {
+        WatermarkPosition::TopLeft | WatermarkPosition::BottomLeft => 0,
+        WatermarkPosition::TopRight | WatermarkPosition::BottomRight => image.width() - strip_width,
+    };
+    let strip_y = match options.position {
+        WatermarkPosition::TopLeft | WatermarkPosition::TopRight => 0,
+        WatermarkPosition::BottomLeft | WatermarkPosition::BottomRight => image.height() - strip_height,
+    };
+
+    // Dark background so the text stays readable on any content
+    for y in strip_y..strip_y + strip_height {
+        for x in strip_x..strip_x + strip_width {
+            blend_pixel(image, x, y, [0, 0, 0], opacity * 0.6);
+        }
+    }
+
+    let mut cursor_x = strip_x + padding;
+    let text_y = strip_y + padding;
+    for c in text.chars() {
+        if cursor_x >= image.width() {
+            break;
+        }
+        draw_glyph(image, glyph_for(c), cursor_x, text_y, scale, opacity);
+        cursor_x = cursor_x.saturating_add(advance);
+    }
+}
+
+/// Builds the strip text, e.g. `2026-10-18 09:30:05 UTC | host: dev-01 | user: alice | task: 42`
+pub(crate) fn watermark_text(options: &WatermarkOptions, captured_at_ms: u64) -> String {
+    let mut parts = vec![format_utc_timestamp(captured_at_ms)];
+
+    if options.include_hostname {
+        if let Some(hostname) = System::host_name() {
+            parts.push(format!("host: {}", hostname));
+        }
+    }
+    if options.include_user {
+        if let Ok(user) = std::env::var("USER").or_else(|_| std::env::var("USERNAME")) {
+            parts.push(format!("user: {}", user));
+        }
+    }
+    if let Some(task_id) = options.task_id.as_ref().filter(|t| !t.is_empty()) {
+        parts.push(format!("task: {}", task_id));
+    }
+    if let Some(custom_text) = options.custom_text.as_ref().filter(|t| !t.is_empty()) {
+        parts.push(custom_text.clone());
+    }
+
+    parts.join(" | ")
+}
+
+/// Formats milliseconds since the Unix epoch as `YYYY-MM-DD HH:MM:SS UTC`.
+pub(crate) fn format_utc_timestamp(unix_ms: u64) -> String {
+    let seconds = unix_ms / 1000;
+    let days = (seconds / 86_400) as i64;
+    let second_of_day = seconds % 86_400;
+
+    // Civil-from-days (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        second_of_day / 3600,
+        (second_of_day % 3600) / 60,
+        second_of_day % 60
+    )
+}
+
+fn draw_glyph(image: &mut RgbaImage, glyph: &[u8; 5], x: u32, y: u32, scale: u32, opacity: f32) {
+    for (column, bits) in glyph.iter().enumerate() {
+        for row in 0..GLYPH_HEIGHT {
+            if bits & (1 << row) == 0 {
+                continue;
+            }
+            let px = x.saturating_add(column as u32 * scale);
+            let py = y.saturating_add(row * scale);
+            for dy in 0..scale {
+                for dx in 0..scale {
+                    blend_pixel(image, px + dx, py + dy, [255, 255, 255], opacity);
+                }
+            }
+        }
+    }
+}
+
+fn blend_pixel(image: &mut RgbaImage, x: u32, y: u32, color: [u8; 3], alpha: f32) {
+    if x >= image.width() || y >= image.height() {
+        return;
+    }
+    let Rgba([r, g, b, a]) = *image.get_pixel(x, y);
+    let mix = |base: u8, top: u8| (base as f32 * (1.0 - alpha) + top as f32 * alpha).round() as u8;
+    image.put_pixel(x, y, Rgba([mix(r, color[0]), mix(g, color[1]), mix(b, color[2]), a.max((alpha * 255.0) as u8)]));
+}
+
+fn glyph_for(c: char) -> &'static [u8; 5] {
+    let index = c as u32;
+    if (0x20..=0x7E).contains(&index) {
+        &FONT_5X7[(index - 0x20) as usize]
+    } else {
+        &FONT_5X7[('?' as u32 - 0x20) as usize]
+    }
+}
+
+/// Bundled 5x7 bitmap font covering printable ASCII (0x20-0x7E).
+/// Each glyph is five columns, bit 0 is the top row.
+const FONT_5X7: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x00, 0x5F, 0x00, 0x00], // '!'
+    [0x00, 0x07, 0x00, 0x07, 0x00], // '"'
+    [0x14, 0x7F, 0x14, 0x7F, 0x14], // '#'
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], // '$'
+    [0x23, 0x13, 0x08, 0x64, 0x62], // '%'
+    [0x36, 0x49, 0x55, 0x22, 0x50], // '&'
+    [0x00, 0x05, 0x03, 0x00, 0x00], // '\''
+    [0x00, 0x1C, 0x22, 0x41, 0x00], // '('
+    [0x00, 0x41, 0x22, 0x1C, 0x00], // ')'
+    [0x08, 0x2A, 0x1C, 0x2A, 0x08], // '*'
+    [0x08, 0x08, 0x3E, 0x08, 0x08], // '+'
+    [0x00, 0x50, 0x30, 0x00, 0x00], // ','
+    [0x08, 0x08, 0x08, 0x08, 0x08], // '-'
+    [0x00, 0x60, 0x60, 0x00, 0x00], // '.'
+    [0x20, 0x10, 0x08, 0x04, 0x02], // '/'
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], // '0'
+    [0x00, 0x42, 0x7F, 0x40, 0x00], // '1'
+    [0x42, 0x61, 0x51, 0x49, 0x46], // '2'
+    [0x21, 0x41, 0x45, 0x4B, 0x31], // '3'
+    [0x18, 0x14, 0x12, 0x7F, 0x10], // '4'
+    [0x27, 0x45, 0x45, 0x45, 0x39], // '5'
+    [0x3C, 0x4A, 0x49, 0x49, 0x30], // '6'
+    [0x01, 0x71, 0x09, 0x05, 0x03], // '7'
+    [0x36, 0x49, 0x49, 0x49, 0x36], // '8'
+    [0x06, 0x49, 0x49, 0x29, 0x1E], // '9'
+    [0x00, 0x36, 0x36, 0x00, 0x00], // ':'
+    [0x00, 0x56, 0x36, 0x00, 0x00], // ';'
+    [0x08, 0x14, 0x22, 0x41, 0x00], // '<'
+    [0x14, 0x14, 0x14, 0x14, 0x14], // '='
+    [0x00, 0x41, 0x22, 0x14, 0x08], // '>'
+    [0x02, 0x01, 0x51, 0x09, 0x06], // '?'
+    [0x32, 0x49, 0x79, 0x41, 0x3E], // '@'
+    [0x7E, 0x11, 0x11, 0x11, 0x7E], // 'A'
+    [0x7F, 0x49, 0x49, 0x49, 0x36], // 'B'
+    [0x3E, 0x41, 0x41, 0x41, 0x22], // 'C'
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], // 'D'
+    [0x7F, 0x49, 0x49, 0x49, 0x41], // 'E'
+    [0x7F, 0x09, 0x09, 0x09, 0x01], // 'F'
+    [0x3E, 0x41, 0x49, 0x49, 0x7A], // 'G'
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], // 'H'
+    [0x00, 0x41, 0x7F, 0x41, 0x00], // 'I'
+    [0x20, 0x40, 0x41, 0x3F, 0x01], // 'J'
+    [0x7F, 0x08, 0x14, 0x22, 0x41], // 'K'
+    [0x7F, 0x40, 0x40, 0x40, 0x40], // 'L'
+    [0x7F, 0x02, 0x0C, 0x02, 0x7F], // 'M'
+    [0x7F, 0x04, 0x08, 0x10, 0x7F], // 'N'
+    [0x3E, 0x41, 0x41, 0x41, 0x3E], // 'O'
+    [0x7F, 0x09, 0x09, 0x09, 0x06], // 'P'
+    [0x3E, 0x41, 0x51, 0x21, 0x5E], // 'Q'
+    [0x7F, 0x09, 0x19, 0x29, 0x46], // 'R'
+    [0x46, 0x49, 0x49, 0x49, 0x31], // 'S'
+    [0x01, 0x01, 0x7F, 0x01, 0x01], // 'T'
+    [0x3F, 0x40, 0x40, 0x40, 0x3F], // 'U'
+    [0x1F, 0x20, 0x40, 0x20, 0x1F], // 'V'
+    [0x3F, 0x40, 0x38, 0x40, 0x3F], // 'W'
+    [0x63, 0x14, 0x08, 0x14, 0x63], // 'X'
+    [0x07, 0x08, 0x70, 0x08, 0x07], // 'Y'
+    [0x61, 0x51, 0x49, 0x45, 0x43], // 'Z'
+    [0x00, 0x7F, 0x41, 0x41, 0x00], // '['
+    [0x02, 0x04, 0x08, 0x10, 0x20], // '\\'
+    [0x00, 0x41, 0x41, 0x7F, 0x00], // ']'
+    [0x04, 0x02, 0x01, 0x02, 0x04], // '^'
+    [0x40, 0x40, 0x40, 0x40, 0x40], // '_'
+    [0x00, 0x01, 0x02, 0x04, 0x00], // '`'
+    [0x20, 0x54, 0x54, 0x54, 0x78], // 'a'
+    [0x7F, 0x48, 0x44, 0x44, 0x38], // 'b'
+    [0x38, 0x44, 0x44, 0x44, 0x20], // 'c'
+    [0x38, 0x44, 0x44, 0x48, 0x7F], // 'd'
+    [0x38, 0x54, 0x54, 0x54, 0x18], // 'e'
+    [0x08, 0x7E, 0x09, 0x01, 0x02], // 'f'
+    [0x0C, 0x52, 0x52, 0x52, 0x3E], // 'g'
+    [0x7F, 0x08, 0x04, 0x04, 0x78], // 'h'
+    [0x00, 0x44, 0x7D, 0x40, 0x00], // 'i'
+    [0x20, 0x40, 0x44, 0x3D, 0x00], // 'j'
+    [0x7F, 0x10, 0x28, 0x44, 0x00], // 'k'
+    [0x00, 0x41, 0x7F, 0x40, 0x00], // 'l'
+    [0x7C, 0x04, 0x18, 0x04, 0x78], // 'm'
+    [0x7C, 0x08, 0x04, 0x04, 0x78], // 'n'
+    [0x38, 0x44, 0x44, 0x44, 0x38], // 'o'
+    [0x7C, 0x14, 0x14, 0x14, 0x08], // 'p'
+    [0x08, 0x14, 0x14, 0x18, 0x7C], // 'q'
+    [0x7C, 0x08, 0x04, 0x04, 0x08], // 'r'
+    [0x48, 0x54, 0x54, 0x54, 0x20], // 's'
+    [0x04, 0x3F, 0x44, 0x40, 0x20], // 't'
+    [0x3C, 0x40, 0x40, 0x20, 0x7C], // 'u'
+    [0x1C, 0x20, 0x40, 0x20, 0x1C], // 'v'
+    [0x3C, 0x40, 0x30, 0x40, 0x3C], // 'w'
+    [0x44, 0x28, 0x10, 0x28, 0x44], // 'x'
+    [0x0C, 0x50, 0x50, 0x50, 0x3C], // 'y'
+    [0x44, 0x64, 0x54, 0x4C, 0x44], // 'z'
+    [0x00, 0x08, 0x36, 0x41, 0x00], // '{'
+    [0x00, 0x00, 0x7F, 0x00, 0x00], // '|'
+    [0x00, 0x41, 0x36, 0x08, 0x00], // '}'
+    [0x08, 0x04, 0x08, 0x10, 0x08], // '~'
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_utc_timestamp() {
+        assert_eq!(format_utc_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_utc_timestamp(1_792_315_805_123), "2026-10-18 09:30:05 UTC");
+    }
+
+    #[test]
+    fn test_watermark_only_touches_selected_corner() {
+        let mut image = RgbaImage::from_pixel(400, 300, Rgba([200, 200, 200, 255]));
+        let options = WatermarkOptions {
+            position: WatermarkPosition::BottomRight,
+            opacity: 1.0,
+            scale: Some(2),
+            include_hostname: false,
+            include_user: false,
+            task_id: Some("42".to_string()),
+            custom_text: None,
+        };
+
+        apply_watermark(&mut image, &options, 0);
+
+        assert_eq!(*image.get_pixel(0, 0), Rgba([200, 200, 200, 255]));
+        assert_eq!(*image.get_pixel(399, 0), Rgba([200, 200, 200, 255]));
+        assert_ne!(*image.get_pixel(399, 299), Rgba([200, 200, 200, 255]));
+    }
+
+    #[test]
+    fn test_oversized_scale_and_text_stay_inside_image() {
+        let mut image = RgbaImage::from_pixel(64, 48, Rgba([200, 200, 200, 255]));
+        let options = WatermarkOptions {
+            position: WatermarkPosition::TopLeft,
+            opacity: 1.0,
+            scale: Some(u32::MAX),
+            include_hostname: false,
+            include_user: false,
+            task_id: None,
+            custom_text: Some("x".repeat(100_000)),
+        };
+
+        apply_watermark(&mut image, &options, 0);
+
+        assert_eq!(image.dimensions(), (64, 48));
+        assert_ne!(*image.get_pixel(0, 0), Rgba([200, 200, 200, 255]));
+    }
+}
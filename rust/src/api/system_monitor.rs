@@ -0,0 +1,234 @@
+use crate::api::screenshot_capture::unix_time_ms;
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use sysinfo::{Disks, Networks, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+// Store running monitors with unique IDs
+lazy_static::lazy_static! {
+    static ref SYSTEM_MONITORS: Mutex<HashMap<u64, Arc<Mutex<bool>>>> = Mutex::new(HashMap::new());
+    static ref NEXT_MONITOR_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+/// Shortest allowed time between two streamed snapshots
+const MIN_MONITOR_INTERVAL_MS: u64 = 1_000;
+
+#[derive(Debug, Clone)]
+pub struct CpuCoreUsage {
+    pub name: String,
+    /// Percentage of this core
+    pub usage: f32,
+    pub frequency_mhz: u64,
+}
+
+/// Load averages over 1, 5 and 15 minutes, always 0 on Windows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadAverage {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiskUsageInfo {
+    pub name: String,
+    pub mount_point: String,
+    pub file_system: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+    pub is_removable: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkInterfaceUsage {
+    pub name: String,
+    /// Bytes received since the interface came up
+    pub received_bytes: u64,
+    /// Bytes sent since the interface came up
+    pub transmitted_bytes: u64,
+}
+
+/// Machine health at one point in time
+#[derive(Debug, Clone)]
+pub struct SystemSnapshot {
+    /// Milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    /// Average over all cores, in percent
+    pub global_cpu_usage: f32,
+    pub cpu_cores: Vec<CpuCoreUsage>,
+    pub total_memory_bytes: u64,
+    pub used_memory_bytes: u64,
+    pub available_memory_bytes: u64,
+    pub total_swap_bytes: u64,
+    pub used_swap_bytes: u64,
+    pub load_average: LoadAverage,
+    pub uptime_seconds: u64,
+    /// Seconds since the Unix epoch
+    pub boot_time: u64,
+    pub disks: Vec<DiskUsageInfo>,
+    pub networks: Vec<NetworkInterfaceUsage>,
+}
+
+/// Keeps the sysinfo handles alive between snapshots, CPU usage is measured
+/// between two refreshes of the same `System`
+struct SystemCollector {
+    system: System,
+    disks: Disks,
+    networks: Networks,
+}
+
+impl SystemCollector {
+    fn new() -> Self {
+        let mut system = System::new();
+        // Establishes the CPU baseline
+        system.refresh_cpu_usage();
+        SystemCollector {
+            system,
+            disks: Disks::new_with_refreshed_list(),
+            networks: Networks::new_with_refreshed_list(),
+        }
+    }
+
+    fn snapshot(&mut self) -> SystemSnapshot {
+        self.system.refresh_cpu_all();
+        self.system.refresh_memory();
+        self.disks.refresh(true);
+        self.networks.refresh(true);
+
+        let load = System::load_average();
+        let mut disks: Vec<DiskUsageInfo> = self
+            .disks
+            .list()
+            .iter()
+            .map(|disk| DiskUsageInfo {
+                name: disk.name().to_string_lossy().into_owned(),
+                mount_point: disk.mount_point().to_string_lossy().into_owned(),
+                file_system: disk.file_system().to_string_lossy().into_owned(),
+                total_bytes: disk.total_space(),
+                available_bytes: disk.available_space(),
+                is_removable: disk.is_removable(),
+            })
+            .collect();
+        disks.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+
+        let mut networks: Vec<NetworkInterfaceUsage> = self
+            .networks
+            .list()
+            .iter()
+            .map(|(name, data)| NetworkInterfaceUsage {
+                name: name.clone(),
+                received_bytes: data.total_received(),
+                transmitted_bytes: data.total_transmitted(),
+            })
+            .collect();
+        networks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        SystemSnapshot {
+            timestamp_ms: unix_time_ms(),
+            global_cpu_usage: self.system.global_cpu_usage(),
+            cpu_cores: self
+                .system
+                .cpus()
+                .iter()
+                .map(|cpu| CpuCoreUsage {
+                    name: cpu.name().to_string(),
+                    usage: cpu.cpu_usage(),
+                    frequency_mhz: cpu.frequency(),
+                })
+                .collect(),
+            total_memory_bytes: self.system.total_memory(),
+            used_memory_bytes: self.system.used_memory(),
+            available_memory_bytes: self.system.available_memory(),
+            total_swap_bytes: self.system.total_swap(),
+            used_swap_bytes: self.system.used_swap(),
+            load_average: LoadAverage {
+                one: load.one,
+                five: load.five,
+                fifteen: load.fifteen,
+            },
+            uptime_seconds: System::uptime(),
+            boot_time: System::boot_time(),
+            disks,
+            networks,
+        }
+    }
+}
+
+/// Returns the current machine health. Blocks for about 200 ms to measure CPU usage.
+pub fn get_system_snapshot() -> SystemSnapshot {
+    let mut collector = SystemCollector::new();
+    thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+    collector.snapshot()
+}
+
+/// Streams a `SystemSnapshot` every `interval_ms` (at least one second).
+/// CPU usage in each snapshot is the average since the previous one.
+/// Returns an id for `stop_system_monitor`.
+#[frb(sync)]
+pub fn start_system_monitor(interval_ms: u64, sink: StreamSink<SystemSnapshot>) -> Result<u64, String> {
+    let interval = Duration::from_millis(interval_ms.max(MIN_MONITOR_INTERVAL_MS));
+
+    let running = Arc::new(Mutex::new(true));
+    let running_clone = Arc::clone(&running);
+
+    let monitor_id = NEXT_MONITOR_ID.fetch_add(1, Ordering::SeqCst);
+    SYSTEM_MONITORS.lock().unwrap().insert(monitor_id, running);
+
+    thread::spawn(move || {
+        println!("[SYSTEM_MONITOR] Monitor {} started, interval {:?}", monitor_id, interval);
+        let mut collector = SystemCollector::new();
+
+        loop {
+            thread::sleep(interval);
+            if !*running_clone.lock().unwrap() {
+                break;
+            }
+
+            if sink.add(collector.snapshot()).is_err() {
+                // Dart side stopped listening
+                println!("[SYSTEM_MONITOR] Stream closed, stopping monitor {}", monitor_id);
+                SYSTEM_MONITORS.lock().unwrap().remove(&monitor_id);
+                return;
+            }
+        }
+
+        println!("[SYSTEM_MONITOR] Monitor {} stopped", monitor_id);
+    });
+
+    Ok(monitor_id)
+}
+
+#[frb(sync)]
+pub fn stop_system_monitor(monitor_id: u64) -> bool {
+    let mut monitors = SYSTEM_MONITORS.lock().unwrap();
+
+    if let Some(running) = monitors.remove(&monitor_id) {
+        if let Ok(mut guard) = running.lock() {
+            *guard = false;
+        }
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_reports_machine_state() {
+        let snapshot = get_system_snapshot();
+        assert!(!snapshot.cpu_cores.is_empty());
+        assert!(snapshot.cpu_cores.iter().all(|core| (0.0..=100.0).contains(&core.usage)));
+        assert!(snapshot.total_memory_bytes > 0);
+        assert!(snapshot.used_memory_bytes <= snapshot.total_memory_bytes);
+        assert!(snapshot.used_swap_bytes <= snapshot.total_swap_bytes);
+        assert!(snapshot.boot_time > 0 && snapshot.boot_time * 1000 < snapshot.timestamp_ms);
+        assert!(snapshot.networks.windows(2).all(|pair| pair[0].name <= pair[1].name));
+    }
+}
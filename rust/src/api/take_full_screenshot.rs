@@ -5,7 +5,10 @@ use std::io::Cursor;
 use std::env;
 use std::process::Command;
 use std::time::Instant;
-use image;
+use image::{self, RgbaImage};
+
+use crate::api::frame_analysis::analyze_frame;
+use crate::api::screenshot_capture::decode_base64_image;
 
 // Imports needed for Windows-specific functions
 #[cfg(target_os = "windows")]
@@ -31,6 +34,10 @@ use std::os::windows::process::CommandExt;
 /// - Linux X11: Works natively
 /// - Linux Wayland: Uses XWayland if available, or attempts fallback methods
 ///
+/// Backends that "succeed" with an all-black frame (locked session, missing Wayland
+/// permission, DRM video overlays) are skipped in favour of the next backend. A blank
+/// frame is only returned when no backend produced real content.
+///
 /// # Windows Screenshot Methods (Professional Ordering - NirCmd Priority)
 ///
 /// **Windows Primary Method:**
@@ -69,6 +76,10 @@ pub fn take_full_screenshot() -> Result<String> {
     println!("╚══════════════════════════════════════════════════════════════╝");
     println!("[SCREENSHOT] 🚀 Starting screenshot capture process");
     let start_time = Instant::now();
+
+    // First blank frame seen, returned only if no backend produces real content
+    #[cfg(target_os = "windows")]
+    let mut blank_fallback: Option<String> = None;
     
     // Platform detection with detailed logging
     let platform = if cfg!(target_os = "windows") {
@@ -127,7 +138,7 @@ pub fn take_full_screenshot() -> Result<String> {
         println!("[SCREENSHOT] └─ Compatibility: Windows 7-11 (universal)");
         
         match take_screenshot_windows_nircmd() {
-            Ok(base64_string) => {
+            Ok(base64_string) if !is_blank_capture(&base64_string) => {
                 let elapsed = start_time.elapsed();
                 println!("[SCREENSHOT] ⭐ SUCCESS: NirCmd method completed successfully!");
                 println!("[SCREENSHOT] 📊 Performance: Screenshot captured in {:.2?}", elapsed);
@@ -136,6 +147,11 @@ pub fn take_full_screenshot() -> Result<String> {
                 println!("[SCREENSHOT] 🏆 Achievement: Used bundled asset (zero-dependency)");
                 return Ok(base64_string);
             },
+            Ok(base64_string) => {
+                println!("[SCREENSHOT] ⚠️  NirCmd returned a blank frame (locked session or protected content)");
+                println!("[SCREENSHOT] 🔄 Falling back to Screenshots crate...");
+                blank_fallback.get_or_insert(base64_string);
+            },
             Err(e) => {
                 println!("[SCREENSHOT] ❌ FAILED: NirCmd method failed - {}", e);
                 println!("[SCREENSHOT] 📊 Failure details: {}", e);
@@ -150,7 +166,7 @@ pub fn take_full_screenshot() -> Result<String> {
         println!("[SCREENSHOT] └─ Reliability: Direct OS integration");
         
         match take_screenshot_with_screenshots_crate() {
-            Ok(base64_string) => {
+            Ok(base64_string) if !is_blank_capture(&base64_string) => {
                 let elapsed = start_time.elapsed();
                 println!("[SCREENSHOT] ✅ SUCCESS: Screenshots crate fallback completed successfully!");
                 println!("[SCREENSHOT] 📊 Performance: Screenshot captured in {:.2?}", elapsed);
@@ -158,6 +174,10 @@ pub fn take_full_screenshot() -> Result<String> {
                 println!("[SCREENSHOT] 💾 Output: Base64 string ({} chars)", base64_string.len());
                 return Ok(base64_string);
             },
+            Ok(base64_string) => {
+                println!("[SCREENSHOT] ⚠️  Screenshots crate returned a blank frame, continuing to additional Windows enterprise methods...");
+                blank_fallback.get_or_insert(base64_string);
+            },
             Err(e) => {
                 println!("[SCREENSHOT] ❌ FAILED: Screenshots crate fallback failed - {}", e);
                 println!("[SCREENSHOT] � Continuing to additional Windows enterprise methods...");
@@ -168,77 +188,105 @@ pub fn take_full_screenshot() -> Result<String> {
                 // Method 3: Memory-based capture (ULTIMATE STEALTH - Hubstaff-style)
                 println!("[SCREENSHOT] Method 3: Memory-based ULTRA-SILENT capture (Hubstaff-style)...");
                 match take_screenshot_windows_memory() {
-                    Ok(base64_string) => {
+                    Ok(base64_string) if !is_blank_capture(&base64_string) => {
                         let elapsed = start_time.elapsed();
                         println!("[SCREENSHOT] ⭐ SUCCESS: Screenshot captured with MEMORY method in {:.2?} (ZERO TRACES)", elapsed);
                         return Ok(base64_string);
                     },
+                    Ok(base64_string) => {
+                        println!("[SCREENSHOT] ⚠️  Memory method returned a blank frame, trying next backend");
+                        blank_fallback.get_or_insert(base64_string);
+                    },
                     Err(e) => println!("[SCREENSHOT] FAILED: Memory screenshot failed: {}", e)
                 }
 
                 // Method 4: DirectShow professional capture
                 println!("[SCREENSHOT] Method 4: DirectShow professional capture...");
                 match take_screenshot_windows_directshow() {
-                    Ok(base64_string) => {
+                    Ok(base64_string) if !is_blank_capture(&base64_string) => {
                         let elapsed = start_time.elapsed();
                         println!("[SCREENSHOT] SUCCESS: Screenshot captured with DirectShow in {:.2?}", elapsed);
                         return Ok(base64_string);
                     },
+                    Ok(base64_string) => {
+                        println!("[SCREENSHOT] ⚠️  DirectShow method returned a blank frame, trying next backend");
+                        blank_fallback.get_or_insert(base64_string);
+                    },
                     Err(e) => println!("[SCREENSHOT] FAILED: DirectShow screenshot failed: {}", e)
                 }
 
                 // Method 5: Win32 API direct calls
                 println!("[SCREENSHOT] Method 5: Win32 API direct capture...");
                 match take_screenshot_windows_win32() {
-                    Ok(base64_string) => {
+                    Ok(base64_string) if !is_blank_capture(&base64_string) => {
                         let elapsed = start_time.elapsed();
                         println!("[SCREENSHOT] SUCCESS: Screenshot captured with Win32 API in {:.2?}", elapsed);
                         return Ok(base64_string);
                     },
+                    Ok(base64_string) => {
+                        println!("[SCREENSHOT] ⚠️  Win32 API method returned a blank frame, trying next backend");
+                        blank_fallback.get_or_insert(base64_string);
+                    },
                     Err(e) => println!("[SCREENSHOT] FAILED: Win32 API screenshot failed: {}", e)
                 }
 
                 // Method 6: PowerShell standard (most compatible)
                 println!("[SCREENSHOT] Method 6: PowerShell standard capture...");
                 match take_screenshot_windows_powershell() {
-                    Ok(base64_string) => {
+                    Ok(base64_string) if !is_blank_capture(&base64_string) => {
                         let elapsed = start_time.elapsed();
                         println!("[SCREENSHOT] SUCCESS: Screenshot captured with PowerShell in {:.2?}", elapsed);
                         return Ok(base64_string);
                     },
+                    Ok(base64_string) => {
+                        println!("[SCREENSHOT] ⚠️  PowerShell method returned a blank frame, trying next backend");
+                        blank_fallback.get_or_insert(base64_string);
+                    },
                     Err(e) => println!("[SCREENSHOT] FAILED: PowerShell screenshot failed: {}", e)
                 }
 
                 // Method 7: WMI enterprise method
                 println!("[SCREENSHOT] Method 7: WMI enterprise capture...");
                 match take_screenshot_windows_wmi() {
-                    Ok(base64_string) => {
+                    Ok(base64_string) if !is_blank_capture(&base64_string) => {
                         let elapsed = start_time.elapsed();
                         println!("[SCREENSHOT] SUCCESS: Screenshot captured with WMI in {:.2?}", elapsed);
                         return Ok(base64_string);
                     },
+                    Ok(base64_string) => {
+                        println!("[SCREENSHOT] ⚠️  WMI method returned a blank frame, trying next backend");
+                        blank_fallback.get_or_insert(base64_string);
+                    },
                     Err(e) => println!("[SCREENSHOT] FAILED: WMI screenshot failed: {}", e)
                 }
 
                 // Method 8: FFmpeg (if available)
                 println!("[SCREENSHOT] Method 8: FFmpeg capture...");
                 match take_screenshot_windows_ffmpeg() {
-                    Ok(base64_string) => {
+                    Ok(base64_string) if !is_blank_capture(&base64_string) => {
                         let elapsed = start_time.elapsed();
                         println!("[SCREENSHOT] SUCCESS: Screenshot captured with FFmpeg in {:.2?}", elapsed);
                         return Ok(base64_string);
                     },
+                    Ok(base64_string) => {
+                        println!("[SCREENSHOT] ⚠️  FFmpeg method returned a blank frame, trying next backend");
+                        blank_fallback.get_or_insert(base64_string);
+                    },
                     Err(e) => println!("[SCREENSHOT] FAILED: FFmpeg screenshot failed: {}", e)
                 }
 
                 // Method 9: C# inline compilation
                 println!("[SCREENSHOT] Method 9: C# inline compilation...");
                 match take_screenshot_windows_csharp() {
-                    Ok(base64_string) => {
+                    Ok(base64_string) if !is_blank_capture(&base64_string) => {
                         let elapsed = start_time.elapsed();
                         println!("[SCREENSHOT] SUCCESS: Screenshot captured with C# inline in {:.2?}", elapsed);
                         return Ok(base64_string);
                     },
+                    Ok(base64_string) => {
+                        println!("[SCREENSHOT] ⚠️  C# inline method returned a blank frame, trying next backend");
+                        blank_fallback.get_or_insert(base64_string);
+                    },
                     Err(e) => println!("[SCREENSHOT] FAILED: C# screenshot failed: {}", e)
                 }
 
@@ -247,10 +295,18 @@ pub fn take_full_screenshot() -> Result<String> {
                 match take_screenshot_windows_vbscript() {
                     Ok(base64_string) => {
                         let elapsed = start_time.elapsed();
+                        if is_blank_capture(&base64_string) {
+                            println!("[SCREENSHOT] ⚠️  All Windows methods returned blank frames, returning the first one");
+                            return Ok(blank_fallback.unwrap_or(base64_string));
+                        }
                         println!("[SCREENSHOT] SUCCESS: Screenshot captured with VBScript in {:.2?}", elapsed);
                         return Ok(base64_string);
                     },
                     Err(e) => {
+                        if let Some(base64_string) = blank_fallback {
+                            println!("[SCREENSHOT] ⚠️  Only blank frames were captured, returning the first one");
+                            return Ok(base64_string);
+                        }
                         println!("[SCREENSHOT] FAILED: All Windows screenshot methods exhausted");
                         return Err(e).context("All 10 Windows screenshot methods failed");
                     }
@@ -272,7 +328,7 @@ pub fn take_full_screenshot() -> Result<String> {
             println!("[SCREENSHOT] └─ Reliability: Direct OS integration");
             
             match take_screenshot_with_screenshots_crate() {
-                Ok(base64_string) => {
+                Ok(base64_string) if !is_blank_capture(&base64_string) => {
                     let elapsed = start_time.elapsed();
                     println!("[SCREENSHOT] ✅ SUCCESS: Primary method completed successfully!");
                     println!("[SCREENSHOT] 📊 Performance: Screenshot captured in {:.2?}", elapsed);
@@ -280,6 +336,27 @@ pub fn take_full_screenshot() -> Result<String> {
                     println!("[SCREENSHOT] 💾 Output: Base64 string ({} chars)", base64_string.len());
                     return Ok(base64_string);
                 },
+                Ok(base64_string) => {
+                    println!("[SCREENSHOT] ⚠️  Primary method returned a blank frame (locked session, missing Wayland permission or protected content)");
+
+                    // The command-line tools may still see the real screen contents
+                    #[cfg(target_os = "linux")]
+                    {
+                        println!("[SCREENSHOT] Method 2: Linux fallback tools...");
+                        match take_screenshot_linux_fallback() {
+                            Ok(fallback_string) if !is_blank_capture(&fallback_string) => {
+                                let elapsed = start_time.elapsed();
+                                println!("[SCREENSHOT] SUCCESS: Screenshot captured with Linux tools in {:.2?}", elapsed);
+                                return Ok(fallback_string);
+                            },
+                            Ok(_) => println!("[SCREENSHOT] Linux fallback tools also returned a blank frame"),
+                            Err(e) => println!("[SCREENSHOT] FAILED: Linux fallback tools failed: {}", e),
+                        }
+                    }
+
+                    println!("[SCREENSHOT] ⚠️  No backend produced a non-blank frame, returning the blank capture");
+                    Ok(base64_string)
+                },
                 Err(primary_error) => {
                     println!("[SCREENSHOT] ❌ FAILED: Primary method failed - {}", primary_error);
                     println!("[SCREENSHOT] 🔄 Falling back to platform-specific methods...");
@@ -322,7 +399,27 @@ pub fn take_full_screenshot() -> Result<String> {
 
 pub fn take_screenshot_with_screenshots_crate() -> Result<String> {
     let start_time = Instant::now();
+    let image = capture_primary_screen()?;
+
+    // Write image to a PNG buffer using a Cursor (which implements both Write and Seek)
+    println!("[SCREENSHOT][screenshots] Encoding to PNG");
+    let mut buffer = Cursor::new(Vec::new());
+    image.write_to(&mut buffer, image::ImageOutputFormat::Png)
+         .map_err(|e| anyhow!("Failed to encode image: {}", e))?;
+    let buffer = buffer.into_inner();
+    
+    // Convert the buffer to a base64 string
+    println!("[SCREENSHOT][screenshots] Converting to base64");
+    let base64_string = general_purpose::STANDARD.encode(&buffer);
     
+    let elapsed = start_time.elapsed();
+    println!("[SCREENSHOT][screenshots] Complete: Generated screenshot in {:.2?}", elapsed);
+    
+    Ok(base64_string)
+}
+
+/// Captures the primary screen with the screenshots crate, without encoding it
+pub(crate) fn capture_primary_screen() -> Result<RgbaImage> {
     println!("[SCREENSHOT][screenshots] Getting list of screens");
     // Get all screens
     let screens = Screen::all().map_err(|e| anyhow!("Failed to get screens: {}", e))?;
@@ -346,22 +443,23 @@ pub fn take_screenshot_with_screenshots_crate() -> Result<String> {
         .map_err(|e| anyhow!("Failed to capture screenshot: {}", e))?;
     
     println!("[SCREENSHOT][screenshots] Image captured: {}x{}", image.width(), image.height());
-    
-    // Write image to a PNG buffer using a Cursor (which implements both Write and Seek)
-    println!("[SCREENSHOT][screenshots] Encoding to PNG");
-    let mut buffer = Cursor::new(Vec::new());
-    image.write_to(&mut buffer, image::ImageOutputFormat::Png)
-         .map_err(|e| anyhow!("Failed to encode image: {}", e))?;
-    let buffer = buffer.into_inner();
-    
-    // Convert the buffer to a base64 string
-    println!("[SCREENSHOT][screenshots] Converting to base64");
-    let base64_string = general_purpose::STANDARD.encode(&buffer);
-    
-    let elapsed = start_time.elapsed();
-    println!("[SCREENSHOT][screenshots] Complete: Generated screenshot in {:.2?}", elapsed);
-    
-    Ok(base64_string)
+    Ok(image)
+}
+
+/// Returns true when a backend "succeeded" but produced an all-black or transparent frame.
+/// Undecodable output is not treated as blank so it is still returned to the caller.
+fn is_blank_capture(base64_string: &str) -> bool {
+    match decode_base64_image(base64_string) {
+        Ok(image) => {
+            let analysis = analyze_frame(&image);
+            if analysis.is_blank() {
+                println!("[SCREENSHOT] 🔍 Frame analysis: blank frame (mean luminance {:.1}, variance {:.1})",
+                         analysis.mean_luminance, analysis.luminance_variance);
+            }
+            analysis.is_blank()
+        },
+        Err(_) => false,
+    }
 }
 
 #[cfg(target_os = "linux")]
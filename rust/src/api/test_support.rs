@@ -0,0 +1,122 @@
+//! Private session bus with a stand-in notification server, shared by the notification tests
+#![cfg(target_os = "linux")]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex, MutexGuard};
+use zbus::zvariant::OwnedValue;
+
+// The bus address lives in an environment variable, so only one bus at a time
+static BUS_LOCK: Mutex<()> = Mutex::new(());
+
+/// Arguments of one `Notify` call
+#[derive(Debug, Clone)]
+pub(crate) struct NotifyCall {
+    pub(crate) replaces_id: u32,
+    pub(crate) summary: String,
+    pub(crate) body: String,
+    pub(crate) actions: Vec<String>,
+    pub(crate) expire_timeout: i32,
+}
+
+/// Minimal `org.freedesktop.Notifications` server that records calls
+struct StandInServer {
+    next_id: u32,
+    calls: Arc<Mutex<Vec<NotifyCall>>>,
+    closed: Arc<Mutex<Vec<u32>>>,
+}
+
+// `crate` keeps the macro off `zbus2`, the zbus 2 used next to notify-rust
+#[zbus::interface(name = "org.freedesktop.Notifications", crate = "zbus")]
+impl StandInServer {
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &mut self,
+        _app_name: String,
+        replaces_id: u32,
+        _app_icon: String,
+        summary: String,
+        body: String,
+        actions: Vec<String>,
+        _hints: HashMap<String, OwnedValue>,
+        expire_timeout: i32,
+    ) -> u32 {
+        self.calls.lock().unwrap().push(NotifyCall { replaces_id, summary, body, actions, expire_timeout });
+        if replaces_id != 0 {
+            return replaces_id;
+        }
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn close_notification(&self, id: u32) {
+        self.closed.lock().unwrap().push(id);
+    }
+
+    fn get_capabilities(&self) -> Vec<String> {
+        vec!["actions".to_string(), "body".to_string()]
+    }
+
+    fn get_server_information(&self) -> (String, String, String, String) {
+        ("stand-in".into(), "test".into(), "1.0".into(), "1.2".into())
+    }
+}
+
+pub(crate) struct StandInBus {
+    daemon: Child,
+    connection: zbus::blocking::Connection,
+    calls: Arc<Mutex<Vec<NotifyCall>>>,
+    closed: Arc<Mutex<Vec<u32>>>,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl StandInBus {
+    /// Starts `dbus-daemon` and points `DBUS_SESSION_BUS_ADDRESS` at it
+    pub(crate) fn start() -> Self {
+        let guard = BUS_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut daemon = Command::new("dbus-daemon")
+            .args(["--session", "--nofork", "--print-address"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("dbus-daemon required");
+        let mut address = String::new();
+        BufReader::new(daemon.stdout.take().unwrap()).read_line(&mut address).unwrap();
+        std::env::set_var("DBUS_SESSION_BUS_ADDRESS", address.trim());
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let closed = Arc::new(Mutex::new(Vec::new()));
+        let server = StandInServer { next_id: 0, calls: Arc::clone(&calls), closed: Arc::clone(&closed) };
+        let connection = zbus::blocking::connection::Builder::session()
+            .unwrap()
+            .name("org.freedesktop.Notifications")
+            .unwrap()
+            .serve_at("/org/freedesktop/Notifications", server)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        StandInBus { daemon, connection, calls, closed, _guard: guard }
+    }
+
+    pub(crate) fn emit<B: serde::Serialize + zbus::zvariant::DynamicType>(&self, signal: &str, body: &B) {
+        self.connection
+            .emit_signal(None::<&str>, "/org/freedesktop/Notifications", "org.freedesktop.Notifications", signal, body)
+            .unwrap();
+    }
+
+    pub(crate) fn calls(&self) -> Vec<NotifyCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    pub(crate) fn closed(&self) -> Vec<u32> {
+        self.closed.lock().unwrap().clone()
+    }
+}
+
+impl Drop for StandInBus {
+    fn drop(&mut self) {
+        let _ = self.daemon.kill();
+        let _ = self.daemon.wait();
+    }
+}
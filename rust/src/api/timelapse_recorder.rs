@@ -0,0 +1,556 @@
+use anyhow::{anyhow, Result};
+use flutter_rust_bridge::frb;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{imageops::FilterType, Delay, Frame, RgbaImage};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::api::resource_budget::{encoding_deferred, MAX_ENCODING_DEFERRAL};
+use crate::api::screenshot_capture::{capture_screen_image, encode_image, resize_to_max_width, ScreenshotFormat};
+
+// Store running recorders with unique IDs
+lazy_static::lazy_static! {
+    static ref TIMELAPSE_RECORDERS: Mutex<HashMap<u64, RecorderHandle>> = Mutex::new(HashMap::new());
+    static ref NEXT_RECORDER_ID: AtomicU64 = AtomicU64::new(1);
+}
+
+/// How often the recorder thread checks for stop / pause requests
+const RECORDER_TICK: Duration = Duration::from_millis(250);
+
+/// Container format of the timelapse file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelapseFormat {
+    /// Animated GIF, plays everywhere but is larger
+    Gif,
+    /// Motion JPEG in an AVI container, much smaller for long sessions
+    MjpegAvi,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelapseConfig {
+    /// Output file, overwritten if it exists
+    pub output_path: String,
+    pub format: TimelapseFormat,
+    /// Seconds between two sampled frames (e.g. 5-30)
+    pub frame_interval_seconds: u64,
+    /// Frames are downsized to at most this width
+    pub max_width: u32,
+    /// Playback speed of the resulting file in frames per second
+    pub playback_fps: u32,
+    /// JPEG quality for MJPEG frames (ignored for GIF)
+    pub jpeg_quality: u8,
+    /// Recording stops once the output file would exceed this size
+    pub max_file_size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimelapseState {
+    Recording,
+    Paused,
+    Stopped,
+    /// Stopped automatically because `max_file_size_bytes` was reached
+    SizeLimitReached,
+    Failed,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelapseStatus {
+    pub state: TimelapseState,
+    pub output_path: String,
+    pub frames_written: u32,
+    pub bytes_written: u64,
+    pub last_error: Option<String>,
+}
+
+struct RecorderShared {
+    stop_requested: bool,
+    status: TimelapseStatus,
+}
+
+struct RecorderHandle {
+    shared: Arc<Mutex<RecorderShared>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Starts recording a low frame rate timelapse of the primary screen.
+///
+/// Frames are captured through the regular screenshot path, downsized and appended
+/// to the output file. Returns an id for the pause / resume / stop functions.
+pub fn start_timelapse_recording(config: TimelapseConfig) -> Result<u64> {
+    if config.frame_interval_seconds == 0 {
+        return Err(anyhow!("Frame interval must be at least 1 second"));
+    }
+    if config.max_file_size_bytes == 0 {
+        return Err(anyhow!("Maximum file size must be greater than zero"));
+    }
+
+    // Open the file up front so path errors are reported to the caller
+    let file = File::create(&config.output_path)
+        .map_err(|e| anyhow!("Failed to create timelapse file {}: {}", config.output_path, e))?;
+
+    let shared = Arc::new(Mutex::new(RecorderShared {
+        stop_requested: false,
+        status: TimelapseStatus {
+            state: TimelapseState::Recording,
+            output_path: config.output_path.clone(),
+            frames_written: 0,
+            bytes_written: 0,
+            last_error: None,
+        },
+    }));
+    let shared_clone = Arc::clone(&shared);
+
+    let recorder_id = NEXT_RECORDER_ID.fetch_add(1, Ordering::SeqCst);
+    let thread = thread::spawn(move || run_recorder(recorder_id, config, file, shared_clone));
+
+    TIMELAPSE_RECORDERS.lock().unwrap().insert(recorder_id, RecorderHandle {
+        shared,
+        thread: Some(thread),
+    });
+
+    Ok(recorder_id)
+}
+
+#[frb(sync)]
+pub fn pause_timelapse_recording(recorder_id: u64) -> bool {
+    set_recording_state(recorder_id, TimelapseState::Recording, TimelapseState::Paused)
+}
+
+#[frb(sync)]
+pub fn resume_timelapse_recording(recorder_id: u64) -> bool {
+    set_recording_state(recorder_id, TimelapseState::Paused, TimelapseState::Recording)
+}
+
+/// Stops the recorder, finalises the output file and returns the final status.
+pub fn stop_timelapse_recording(recorder_id: u64) -> Result<TimelapseStatus> {
+    let handle = TIMELAPSE_RECORDERS
+        .lock()
+        .unwrap()
+        .remove(&recorder_id)
+        .ok_or_else(|| anyhow!("Timelapse recorder {} not found", recorder_id))?;
+
+    handle.shared.lock().unwrap().stop_requested = true;
+    if let Some(thread) = handle.thread {
+        let _ = thread.join();
+    }
+
+    let status = handle.shared.lock().unwrap().status.clone();
+    Ok(status)
+}
+
+#[frb(sync)]
+pub fn get_timelapse_status(recorder_id: u64) -> Option<TimelapseStatus> {
+    let recorders = TIMELAPSE_RECORDERS.lock().unwrap();
+    recorders
+        .get(&recorder_id)
+        .map(|handle| handle.shared.lock().unwrap().status.clone())
+}
+
+fn set_recording_state(recorder_id: u64, from: TimelapseState, to: TimelapseState) -> bool {
+    let recorders = TIMELAPSE_RECORDERS.lock().unwrap();
+    match recorders.get(&recorder_id) {
+        Some(handle) => {
+            let mut shared = handle.shared.lock().unwrap();
+            if shared.status.state == from {
+                shared.status.state = to;
+                true
+            } else {
+                false
+            }
+        }
+        None => false,
+    }
+}
+
+fn run_recorder(recorder_id: u64, config: TimelapseConfig, file: File, shared: Arc<Mutex<RecorderShared>>) {
+    println!("[TIMELAPSE] Recorder {} writing {:?} to {}", recorder_id, config.format, config.output_path);
+
+    let frame_interval = Duration::from_secs(config.frame_interval_seconds);
+    let mut writer: Box<dyn TimelapseWriter> = match config.format {
+        TimelapseFormat::Gif => Box::new(GifTimelapseWriter::new(file, config.playback_fps)),
+        TimelapseFormat::MjpegAvi => Box::new(MjpegAviWriter::new(file, config.playback_fps, config.jpeg_quality)),
+    };
+    let mut frame_size: Option<(u32, u32)> = None;
+    let mut last_frame_bytes = 0u64;
+    let mut next_frame_at = Instant::now();
+    let mut final_state = TimelapseState::Stopped;
+    let mut last_error = None;
+
+    loop {
+        let (stop_requested, state) = {
+            let shared = shared.lock().unwrap();
+            (shared.stop_requested, shared.status.state)
+        };
+        if stop_requested {
+            break;
+        }
+        if state != TimelapseState::Recording || Instant::now() < next_frame_at {
+            thread::sleep(RECORDER_TICK);
+            continue;
+        }
+        // Over budget: hold the frame back a tick at a time, so stop requests are still seen
+        if encoding_deferred() && next_frame_at.elapsed() < MAX_ENCODING_DEFERRAL {
+            thread::sleep(RECORDER_TICK);
+            continue;
+        }
+        next_frame_at = Instant::now() + frame_interval;
+
+        // Predict the next frame from the previous one so the cap is not overshot
+        if writer.bytes_written() + last_frame_bytes > config.max_file_size_bytes {
+            println!("[TIMELAPSE] Recorder {} reached its size limit ({} bytes)", recorder_id, writer.bytes_written());
+            final_state = TimelapseState::SizeLimitReached;
+            break;
+        }
+
+        let frame = match capture_screen_image() {
+            Ok(image) => image,
+            Err(e) => {
+                println!("[TIMELAPSE] Frame capture failed: {}", e);
+                shared.lock().unwrap().status.last_error = Some(e.to_string());
+                continue;
+            }
+        };
+
+        // Every frame must match the size of the first one
+        let frame = match frame_size {
+            None => {
+                let frame = resize_to_max_width(frame, config.max_width);
+                frame_size = Some(frame.dimensions());
+                frame
+            }
+            Some((width, height)) if frame.dimensions() != (width, height) => {
+                image::imageops::resize(&frame, width, height, FilterType::Triangle)
+            }
+            Some(_) => frame,
+        };
+
+        let before = writer.bytes_written();
+        if let Err(e) = writer.write_frame(&frame) {
+            println!("[TIMELAPSE] Failed to write frame: {}", e);
+            final_state = TimelapseState::Failed;
+            last_error = Some(e.to_string());
+            break;
+        }
+        last_frame_bytes = writer.bytes_written() - before;
+
+        let mut shared = shared.lock().unwrap();
+        shared.status.frames_written += 1;
+        shared.status.bytes_written = writer.bytes_written();
+    }
+
+    if let Err(e) = writer.finish() {
+        println!("[TIMELAPSE] Failed to finalise {}: {}", config.output_path, e);
+        final_state = TimelapseState::Failed;
+        last_error = Some(e.to_string());
+    }
+
+    let mut shared = shared.lock().unwrap();
+    shared.status.state = final_state;
+    shared.status.bytes_written = std::fs::metadata(&config.output_path).map(|m| m.len()).unwrap_or(shared.status.bytes_written);
+    if last_error.is_some() {
+        shared.status.last_error = last_error;
+    }
+    println!("[TIMELAPSE] Recorder {} finished: {} frames, {} bytes", recorder_id, shared.status.frames_written, shared.status.bytes_written);
+}
+
+/// Appends frames to a timelapse file. Writers live on the recorder thread, so
+/// the trait and its implementations stay out of the Dart API.
+trait TimelapseWriter: Send {
+    #[frb(ignore)]
+    fn write_frame(&mut self, frame: &RgbaImage) -> Result<()>;
+    #[frb(ignore)]
+    fn bytes_written(&self) -> u64;
+    #[frb(ignore)]
+    fn finish(self: Box<Self>) -> Result<()>;
+}
+
+/// Wraps a writer and counts the bytes passing through it
+struct CountingWriter<W: Write> {
+    inner: W,
+    count: Arc<AtomicU64>,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count.fetch_add(written as u64, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[frb(ignore)]
+struct GifTimelapseWriter {
+    encoder: GifEncoder<CountingWriter<BufWriter<File>>>,
+    count: Arc<AtomicU64>,
+    frame_delay: Delay,
+}
+
+impl GifTimelapseWriter {
+    fn new(file: File, playback_fps: u32) -> Self {
+        let count = Arc::new(AtomicU64::new(0));
+        let writer = CountingWriter {
+            inner: BufWriter::new(file),
+            count: Arc::clone(&count),
+        };
+        // Speed 10 keeps quantisation cheap enough for large frames
+        let mut encoder = GifEncoder::new_with_speed(writer, 10);
+        let _ = encoder.set_repeat(Repeat::Infinite);
+
+        GifTimelapseWriter {
+            encoder,
+            count,
+            frame_delay: Delay::from_numer_denom_ms(1000, playback_fps.max(1)),
+        }
+    }
+}
+
+impl TimelapseWriter for GifTimelapseWriter {
+    fn write_frame(&mut self, frame: &RgbaImage) -> Result<()> {
+        self.encoder
+            .encode_frame(Frame::from_parts(frame.clone(), 0, 0, self.frame_delay))
+            .map_err(|e| anyhow!("Failed to encode GIF frame: {}", e))
+    }
+
+    fn bytes_written(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    fn finish(self: Box<Self>) -> Result<()> {
+        // The GIF trailer is written and the file flushed when the encoder is dropped
+        drop(self.encoder);
+        Ok(())
+    }
+}
+
+const AVI_HEADER_SIZE: u64 = 224;
+/// Position of the "movi" fourcc, idx1 offsets are relative to it
+const AVI_MOVI_OFFSET: u64 = 220;
+
+/// Minimal Motion-JPEG AVI writer (single video stream with an idx1 index)
+#[frb(ignore)]
+struct MjpegAviWriter {
+    file: BufWriter<File>,
+    playback_fps: u32,
+    jpeg_quality: u8,
+    dimensions: Option<(u32, u32)>,
+    /// (offset relative to "movi", chunk size) of every frame
+    index: Vec<(u32, u32)>,
+    bytes_written: u64,
+    max_frame_size: u32,
+}
+
+impl MjpegAviWriter {
+    fn new(file: File, playback_fps: u32, jpeg_quality: u8) -> Self {
+        MjpegAviWriter {
+            file: BufWriter::new(file),
+            playback_fps: playback_fps.max(1),
+            jpeg_quality,
+            dimensions: None,
+            index: Vec::new(),
+            bytes_written: 0,
+            max_frame_size: 0,
+        }
+    }
+
+    /// Writes the RIFF header. Frame counts and sizes are patched in `finish`.
+    fn write_header(&mut self, width: u32, height: u32) -> std::io::Result<()> {
+        let f = &mut self.file;
+        f.write_all(b"RIFF")?;
+        f.write_all(&0u32.to_le_bytes())?; // patched: file size - 8
+        f.write_all(b"AVI ")?;
+
+        f.write_all(b"LIST")?;
+        f.write_all(&192u32.to_le_bytes())?;
+        f.write_all(b"hdrl")?;
+
+        f.write_all(b"avih")?;
+        f.write_all(&56u32.to_le_bytes())?;
+        f.write_all(&(1_000_000 / self.playback_fps).to_le_bytes())?; // microseconds per frame
+        f.write_all(&0u32.to_le_bytes())?; // max bytes per second
+        f.write_all(&0u32.to_le_bytes())?; // padding granularity
+        f.write_all(&0x10u32.to_le_bytes())?; // AVIF_HASINDEX
+        f.write_all(&0u32.to_le_bytes())?; // patched: total frames
+        f.write_all(&0u32.to_le_bytes())?; // initial frames
+        f.write_all(&1u32.to_le_bytes())?; // streams
+        f.write_all(&0u32.to_le_bytes())?; // patched: suggested buffer size
+        f.write_all(&width.to_le_bytes())?;
+        f.write_all(&height.to_le_bytes())?;
+        f.write_all(&[0u8; 16])?; // reserved
+
+        f.write_all(b"LIST")?;
+        f.write_all(&116u32.to_le_bytes())?;
+        f.write_all(b"strl")?;
+
+        f.write_all(b"strh")?;
+        f.write_all(&56u32.to_le_bytes())?;
+        f.write_all(b"vids")?;
+        f.write_all(b"MJPG")?;
+        f.write_all(&0u32.to_le_bytes())?; // flags
+        f.write_all(&0u16.to_le_bytes())?; // priority
+        f.write_all(&0u16.to_le_bytes())?; // language
+        f.write_all(&0u32.to_le_bytes())?; // initial frames
+        f.write_all(&1u32.to_le_bytes())?; // scale
+        f.write_all(&self.playback_fps.to_le_bytes())?; // rate
+        f.write_all(&0u32.to_le_bytes())?; // start
+        f.write_all(&0u32.to_le_bytes())?; // patched: length in frames
+        f.write_all(&0u32.to_le_bytes())?; // patched: suggested buffer size
+        f.write_all(&u32::MAX.to_le_bytes())?; // quality (default)
+        f.write_all(&0u32.to_le_bytes())?; // sample size
+        f.write_all(&0u16.to_le_bytes())?; // frame rect: left, top, right, bottom
+        f.write_all(&0u16.to_le_bytes())?;
+        f.write_all(&(width.min(u16::MAX as u32) as u16).to_le_bytes())?;
+        f.write_all(&(height.min(u16::MAX as u32) as u16).to_le_bytes())?;
+
+        f.write_all(b"strf")?;
+        f.write_all(&40u32.to_le_bytes())?;
+        f.write_all(&40u32.to_le_bytes())?; // BITMAPINFOHEADER size
+        f.write_all(&width.to_le_bytes())?;
+        f.write_all(&height.to_le_bytes())?;
+        f.write_all(&1u16.to_le_bytes())?; // planes
+        f.write_all(&24u16.to_le_bytes())?; // bit count
+        f.write_all(b"MJPG")?;
+        f.write_all(&(width * height * 3).to_le_bytes())?;
+        f.write_all(&[0u8; 16])?; // resolution and palette
+
+        f.write_all(b"LIST")?;
+        f.write_all(&0u32.to_le_bytes())?; // patched: movi size
+        f.write_all(b"movi")?;
+
+        self.bytes_written = AVI_HEADER_SIZE;
+        Ok(())
+    }
+
+    fn patch_u32(&mut self, offset: u64, value: u32) -> std::io::Result<()> {
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.write_all(&value.to_le_bytes())
+    }
+}
+
+impl TimelapseWriter for MjpegAviWriter {
+    fn write_frame(&mut self, frame: &RgbaImage) -> Result<()> {
+        if self.dimensions.is_none() {
+            self.write_header(frame.width(), frame.height())?;
+            self.dimensions = Some(frame.dimensions());
+        }
+
+        let jpeg = encode_image(frame, ScreenshotFormat::Jpeg, self.jpeg_quality)?;
+        let size = jpeg.len() as u32;
+        let offset = (self.bytes_written - AVI_MOVI_OFFSET) as u32;
+
+        self.file.write_all(b"00dc")?;
+        self.file.write_all(&size.to_le_bytes())?;
+        self.file.write_all(&jpeg)?;
+        // RIFF chunks are word aligned
+        if size % 2 == 1 {
+            self.file.write_all(&[0])?;
+        }
+
+        self.index.push((offset, size));
+        self.max_frame_size = self.max_frame_size.max(size);
+        self.bytes_written += 8 + size as u64 + (size % 2) as u64;
+        Ok(())
+    }
+
+    fn bytes_written(&self) -> u64 {
+        // Account for the index written on finish
+        self.bytes_written + 8 + 16 * self.index.len() as u64
+    }
+
+    fn finish(mut self: Box<Self>) -> Result<()> {
+        if self.dimensions.is_none() {
+            // No frame was captured, leave an empty file rather than a broken AVI
+            self.file.flush()?;
+            return Ok(());
+        }
+
+        let movi_end = self.bytes_written;
+        self.file.write_all(b"idx1")?;
+        self.file.write_all(&(16 * self.index.len() as u32).to_le_bytes())?;
+        for (offset, size) in std::mem::take(&mut self.index).iter() {
+            self.file.write_all(b"00dc")?;
+            self.file.write_all(&0x10u32.to_le_bytes())?; // AVIIF_KEYFRAME
+            self.file.write_all(&offset.to_le_bytes())?;
+            self.file.write_all(&size.to_le_bytes())?;
+        }
+        let frames = ((self.file.stream_position()? - movi_end - 8) / 16) as u32;
+        let file_size = self.file.stream_position()?;
+
+        let max_frame_size = self.max_frame_size;
+        self.patch_u32(4, (file_size - 8) as u32)?;
+        self.patch_u32(48, frames)?;
+        self.patch_u32(60, max_frame_size)?;
+        self.patch_u32(140, frames)?;
+        self.patch_u32(144, max_frame_size)?;
+        self.patch_u32(216, (movi_end - AVI_MOVI_OFFSET) as u32)?;
+        self.file.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{AnimationDecoder, Rgba};
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("pi_task_watch_{}_{}", std::process::id(), name))
+    }
+
+    fn test_frames() -> Vec<RgbaImage> {
+        (0..3u8)
+            .map(|i| RgbaImage::from_pixel(32, 24, Rgba([i * 80, 100, 200, 255])))
+            .collect()
+    }
+
+    #[test]
+    fn test_gif_writer_appends_frames() {
+        let path = temp_path("timelapse.gif");
+        let mut writer: Box<dyn TimelapseWriter> = Box::new(GifTimelapseWriter::new(File::create(&path).unwrap(), 4));
+        for frame in test_frames() {
+            writer.write_frame(&frame).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let decoder = image::codecs::gif::GifDecoder::new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(decoder.into_frames().count(), 3);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_avi_writer_produces_indexed_riff() {
+        let path = temp_path("timelapse.avi");
+        let mut writer: Box<dyn TimelapseWriter> = Box::new(MjpegAviWriter::new(File::create(&path).unwrap(), 4, 70));
+        for frame in test_frames() {
+            writer.write_frame(&frame).unwrap();
+        }
+        let predicted_size = writer.bytes_written();
+        writer.finish().unwrap();
+
+        let data = std::fs::read(&path).unwrap();
+        let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(&data[8..12], b"AVI ");
+        assert_eq!(read_u32(4) as usize, data.len() - 8);
+        assert_eq!(predicted_size as usize, data.len());
+        assert_eq!(read_u32(48), 3);
+        assert_eq!(&data[220..224], b"movi");
+        assert_eq!(&data[224..228], b"00dc");
+
+        // idx1 follows the movi list and points back at every frame
+        let idx1 = 216 + 4 + read_u32(216) as usize;
+        assert_eq!(&data[idx1..idx1 + 4], b"idx1");
+        assert_eq!(read_u32(idx1 + 4), 48);
+        let first_offset = read_u32(idx1 + 16) as usize;
+        assert_eq!(&data[220 + first_offset..220 + first_offset + 4], b"00dc");
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,159 @@
+use anyhow::{anyhow, Result};
+use image::RgbaImage;
+
+use crate::api::screenshot_capture::{finish_screenshot, unix_time_ms, Screenshot, ScreenshotOptions};
+
+/// Time given to a freshly redirected window to repaint its offscreen pixmap.
+/// Without a compositor, parts that were covered are only drawn after this expose.
+#[cfg(target_os = "linux")]
+const REDIRECT_SETTLE_TIME: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Captures the contents of a single window, even when other windows cover it.
+///
+/// `window_id` is the id returned by `get_open_windows_info` / `get_active_window_info`.
+/// On X11 the window is redirected through the Composite extension and its offscreen
+/// pixmap is read, so overlapping windows do not show up in the result. The cursor
+/// option is ignored because the cursor is never part of a window pixmap.
+pub fn capture_window_contents(window_id: u32, options: ScreenshotOptions) -> Result<Screenshot> {
+    let captured_at_ms = unix_time_ms();
+    let image = capture_window_image(window_id)?;
+    let options = ScreenshotOptions {
+        include_cursor: false,
+        ..options
+    };
+    finish_screenshot(image, &options, captured_at_ms, None, None)
+}
+
+/// Reads a window's offscreen pixmap through XComposite
+#[cfg(target_os = "linux")]
+pub(crate) fn capture_window_image(window_id: u32) -> Result<RgbaImage> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::composite::{ConnectionExt as _, Redirect};
+    use x11rb::protocol::xproto::{ConnectionExt as _, ImageFormat, ImageOrder, MapState};
+
+    let (conn, _) = x11rb::connect(None).map_err(|e| anyhow!("Failed to connect to the X server: {}", e))?;
+    conn.composite_query_version(0, 4)?
+        .reply()
+        .map_err(|e| anyhow!("Composite extension not available: {}", e))?;
+
+    let attributes = conn
+        .get_window_attributes(window_id)?
+        .reply()
+        .map_err(|e| anyhow!("Window {} not found: {}", window_id, e))?;
+    if attributes.map_state != MapState::VIEWABLE {
+        // Unmapped (e.g. minimised) windows have no pixmap to read
+        return Err(anyhow!("Window {} is not mapped", window_id));
+    }
+    let geometry = conn.get_geometry(window_id)?.reply()?;
+
+    // Automatic redirection can be requested by any number of clients, so this is
+    // harmless when a compositor already redirected the window
+    conn.composite_redirect_window(window_id, Redirect::AUTOMATIC)?.check()?;
+    std::thread::sleep(REDIRECT_SETTLE_TIME);
+
+    let pixmap = conn.generate_id()?;
+    let result = (|| -> Result<RgbaImage> {
+        conn.composite_name_window_pixmap(window_id, pixmap)?.check()?;
+        let reply = conn
+            .get_image(ImageFormat::Z_PIXMAP, pixmap, 0, 0, geometry.width, geometry.height, !0)?
+            .reply()
+            .map_err(|e| anyhow!("Failed to read window pixmap: {}", e))?;
+
+        let bits_per_pixel = conn
+            .setup()
+            .pixmap_formats
+            .iter()
+            .find(|format| format.depth == reply.depth)
+            .map(|format| format.bits_per_pixel)
+            .unwrap_or(0);
+        if bits_per_pixel != 32 {
+            return Err(anyhow!("Unsupported window pixmap format: depth {}, {} bits per pixel", reply.depth, bits_per_pixel));
+        }
+
+        let (width, height) = (geometry.width as u32, geometry.height as u32);
+        // The window may shrink between the geometry query and the read
+        let expected_len = width as usize * height as usize * 4;
+        if reply.data.len() < expected_len {
+            return Err(anyhow!(
+                "Window pixmap reply is too short: {} bytes for {}x{}",
+                reply.data.len(),
+                width,
+                height
+            ));
+        }
+        let stride = reply.data.len() / (height.max(1) as usize);
+        let lsb_first = conn.setup().image_byte_order == ImageOrder::LSB_FIRST;
+        let has_alpha = reply.depth == 32;
+
+        let image = RgbaImage::from_fn(width, height, |x, y| {
+            let offset = y as usize * stride + x as usize * 4;
+            let pixel = &reply.data[offset..offset + 4];
+            let (a, r, g, b) = if lsb_first {
+                (pixel[3], pixel[2], pixel[1], pixel[0])
+            } else {
+                (pixel[0], pixel[1], pixel[2], pixel[3])
+            };
+            image::Rgba([r, g, b, if has_alpha { a } else { 255 }])
+        });
+        Ok(image)
+    })();
+
+    let _ = conn.free_pixmap(pixmap);
+    let _ = conn.composite_unredirect_window(window_id, Redirect::AUTOMATIC);
+    let _ = conn.flush();
+
+    result
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn capture_window_image(_window_id: u32) -> Result<RgbaImage> {
+    Err(anyhow!("Linux-specific function not available on this platform"))
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ConnectionExt as _, CreateWindowAux, WindowClass};
+    use x11rb::COPY_DEPTH_FROM_PARENT;
+
+    /// Needs an X server with the Composite extension, e.g.
+    /// `xvfb-run cargo test -- --ignored window_capture`
+    #[test]
+    #[ignore]
+    fn test_capture_occluded_window_under_xvfb() {
+        let (conn, screen_num) = x11rb::connect(None).expect("X server required");
+        let screen = &conn.setup().roots[screen_num];
+
+        let create = |color: u32, x: i16| {
+            let window = conn.generate_id().unwrap();
+            conn.create_window(
+                COPY_DEPTH_FROM_PARENT,
+                window,
+                screen.root,
+                x,
+                0,
+                200,
+                200,
+                0,
+                WindowClass::INPUT_OUTPUT,
+                0,
+                &CreateWindowAux::new().background_pixel(color),
+            )
+            .unwrap();
+            conn.map_window(window).unwrap();
+            window
+        };
+
+        // The blue window covers most of the red one
+        let red = create(0xff0000, 0);
+        let _blue = create(0x0000ff, 50);
+        conn.flush().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(200));
+
+        let image = capture_window_image(red).unwrap();
+        assert_eq!(image.dimensions(), (200, 200));
+        assert_eq!(image.get_pixel(10, 10), &image::Rgba([255, 0, 0, 255]));
+        assert_eq!(image.get_pixel(150, 100), &image::Rgba([255, 0, 0, 255]));
+    }
+}
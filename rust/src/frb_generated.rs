@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1874832048;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -396847468;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__get_all_process_list__can_access_processes_impl(
+fn wire__crate__api__click_heatmap__add_heatmap_click_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__get_all_process_list__can_access_processes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "can_access_processes",
+            debug_name: "add_heatmap_click",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,11 +67,13 @@ fn wire__crate__api__get_all_process_list__can_access_processes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_heatmap_id = <u64>::sse_decode(&mut deserializer);
+            let api_event = <crate::api::mouse_listener::MouseEvent>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::get_all_process_list::can_access_processes(),
+                        crate::api::click_heatmap::add_heatmap_click(api_heatmap_id, api_event),
                     )?;
                     Ok(output_ok)
                 })())
@@ -79,17 +81,16 @@ fn wire__crate__api__get_all_process_list__can_access_processes_impl(
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__check_linux_environment_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__frame_analysis__analyze_screenshot_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_linux_environment",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "analyze_screenshot",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -101,20 +102,19 @@ fn wire__crate__api__take_full_screenshot__check_linux_environment_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_base64_image = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::check_linux_environment()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok =
+                        crate::api::frame_analysis::analyze_screenshot(api_base64_image)?;
+                    Ok(output_ok)
+                })(),
+            )
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__check_windows_environment_impl(
+fn wire__crate__api__get_all_process_list__can_access_processes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -122,7 +122,7 @@ fn wire__crate__api__take_full_screenshot__check_windows_environment_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_windows_environment",
+            debug_name: "can_access_processes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -138,18 +138,17 @@ fn wire__crate__api__take_full_screenshot__check_windows_environment_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::check_windows_environment()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::get_all_process_list::can_access_processes(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__extract_bundled_nircmd_impl(
+fn wire__crate__api__screenshot_capture__capture_active_window_monitor_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -157,7 +156,7 @@ fn wire__crate__api__take_full_screenshot__extract_bundled_nircmd_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "extract_bundled_nircmd",
+            debug_name: "capture_active_window_monitor",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -171,11 +170,16 @@ fn wire__crate__api__take_full_screenshot__extract_bundled_nircmd_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_options =
+                <crate::api::screenshot_capture::ScreenshotOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::take_full_screenshot::extract_bundled_nircmd()?;
+                        let output_ok =
+                            crate::api::screenshot_capture::capture_active_window_monitor(
+                                api_options,
+                            )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -183,7 +187,7 @@ fn wire__crate__api__take_full_screenshot__extract_bundled_nircmd_impl(
         },
     )
 }
-fn wire__crate__api__get_all_process_list__find_process_by_name_impl(
+fn wire__crate__api__delta_encoding__capture_delta_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -191,7 +195,7 @@ fn wire__crate__api__get_all_process_list__find_process_by_name_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "find_process_by_name",
+            debug_name: "capture_delta_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -205,29 +209,31 @@ fn wire__crate__api__get_all_process_list__find_process_by_name_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_encoder_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::get_all_process_list::find_process_by_name(&api_name),
-                    )?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::delta_encoding::capture_delta_frame(api_encoder_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__active_window_listener__get_active_window_info_impl(
+fn wire__crate__api__screenshot_capture__capture_monitor_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_active_window_info",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "capture_monitor",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -239,15 +245,25 @@ fn wire__crate__api__active_window_listener__get_active_window_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_monitor_id = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::screenshot_capture::ScreenshotOptions>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::active_window_listener::get_active_window_info()?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::screenshot_capture::capture_monitor(
+                            api_monitor_id,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
-fn wire__crate__api__get_all_process_list__get_all_processes_impl(
+fn wire__crate__api__screenshot_capture__capture_screenshot_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -255,7 +271,7 @@ fn wire__crate__api__get_all_process_list__get_all_processes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_all_processes",
+            debug_name: "capture_screenshot",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -269,27 +285,32 @@ fn wire__crate__api__get_all_process_list__get_all_processes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_options =
+                <crate::api::screenshot_capture::ScreenshotOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::get_all_process_list::get_all_processes())?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::screenshot_capture::capture_screenshot(api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__active_window_listener__get_current_platform_impl(
+fn wire__crate__api__screenshot_spool__capture_to_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_current_platform",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "capture_to_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -301,26 +322,33 @@ fn wire__crate__api__active_window_listener__get_current_platform_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::screenshot_capture::ScreenshotOptions>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::active_window_listener::get_current_platform(),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::screenshot_spool::capture_to_file(api_dir, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
-fn wire__crate__api__active_window_listener__get_open_windows_info_impl(
+fn wire__crate__api__window_capture__capture_window_contents_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_open_windows_info",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "capture_window_contents",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -332,15 +360,25 @@ fn wire__crate__api__active_window_listener__get_open_windows_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_window_id = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::screenshot_capture::ScreenshotOptions>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::active_window_listener::get_open_windows_info()?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::window_capture::capture_window_contents(
+                            api_window_id,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
-fn wire__crate__api__get_all_process_list__get_process_access_info_impl(
+fn wire__crate__api__desktop_notification__category_gate_stats_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -348,7 +386,7 @@ fn wire__crate__api__get_all_process_list__get_process_access_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_process_access_info",
+            debug_name: "category_gate_stats_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -366,7 +404,7 @@ fn wire__crate__api__get_all_process_list__get_process_access_info_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::get_all_process_list::get_process_access_info(),
+                        crate::api::desktop_notification::CategoryGateStats::default(),
                     )?;
                     Ok(output_ok)
                 })())
@@ -374,16 +412,17 @@ fn wire__crate__api__get_all_process_list__get_process_access_info_impl(
         },
     )
 }
-fn wire__crate__api__active_window_listener__get_window_icon_data_impl(
+fn wire__crate__api__take_full_screenshot__check_linux_environment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_window_icon_data",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "check_linux_environment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -395,26 +434,30 @@ fn wire__crate__api__active_window_listener__get_window_icon_data_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_window_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok =
-                    crate::api::active_window_listener::get_window_icon_data(api_window_id)?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::check_linux_environment()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__take_full_screenshot__check_windows_environment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "check_windows_environment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -426,16 +469,20 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::check_windows_environment()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__persistent_notification__close_notification_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -443,7 +490,7 @@ fn wire__crate__api__simple__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "close_notification",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -457,29 +504,30 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::persistent_notification::close_notification(api_handle_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__desktop_notification__configure_notification_gate_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "configure_notification_gate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -491,29 +539,29 @@ fn wire__crate__api__take_full_screenshot__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::desktop_notification::NotificationGateConfig>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::take_full_screenshot::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::desktop_notification::configure_notification_gate(api_config);
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__is_nircmd_available_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__click_heatmap__create_click_heatmap_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_nircmd_available",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "create_click_heatmap",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -525,26 +573,24 @@ fn wire__crate__api__take_full_screenshot__is_nircmd_available_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::click_heatmap::HeatmapConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::take_full_screenshot::is_nircmd_available(),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::click_heatmap::create_click_heatmap(api_config)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__active_window_listener__is_platform_supported_impl(
+fn wire__crate__api__delta_encoding__create_delta_decoder_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_platform_supported",
+            debug_name: "create_delta_decoder",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -560,60 +606,23 @@ fn wire__crate__api__active_window_listener__is_platform_supported_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::active_window_listener::is_platform_supported(),
-                )?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::delta_encoding::create_delta_decoder())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_all_process_list__kill_process_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "kill_process",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_pid = <u32>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::get_all_process_list::kill_process(api_pid),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__desktop_notification__register_module_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__delta_encoding__create_delta_encoder_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "register_module",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "create_delta_encoder",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -625,19 +634,17 @@ fn wire__crate__api__desktop_notification__register_module_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::delta_encoding::DeltaEncoderConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::desktop_notification::register_module();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::delta_encoding::create_delta_encoder(api_config)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__desktop_notification__send_notification_impl(
+fn wire__crate__api__delta_encoding__decode_delta_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -645,7 +652,7 @@ fn wire__crate__api__desktop_notification__send_notification_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_notification",
+            debug_name: "decode_delta_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -659,17 +666,15 @@ fn wire__crate__api__desktop_notification__send_notification_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_icon_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_decoder_id = <u64>::sse_decode(&mut deserializer);
+            let api_data = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::desktop_notification::send_notification(
-                            api_title,
-                            api_message,
-                            api_icon_path,
+                        let output_ok = crate::api::delta_encoding::decode_delta_frame(
+                            api_decoder_id,
+                            api_data,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -678,7 +683,7 @@ fn wire__crate__api__desktop_notification__send_notification_impl(
         },
     )
 }
-fn wire__crate__api__desktop_notification__send_notification_with_options_impl(
+fn wire__crate__api__screenshot_spool__delete_spooled_screenshot_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -686,7 +691,7 @@ fn wire__crate__api__desktop_notification__send_notification_with_options_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_notification_with_options",
+            debug_name: "delete_spooled_screenshot",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -700,23 +705,13 @@ fn wire__crate__api__desktop_notification__send_notification_with_options_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_message = <String>::sse_decode(&mut deserializer);
-            let api_icon_path = <Option<String>>::sse_decode(&mut deserializer);
-            let api_timeout_seconds = <Option<u64>>::sse_decode(&mut deserializer);
-            let api_urgency_level = <Option<i32>>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let output_ok =
-                            crate::api::desktop_notification::send_notification_with_options(
-                                api_title,
-                                api_message,
-                                api_icon_path,
-                                api_timeout_seconds,
-                                api_urgency_level,
-                            )?;
+                            crate::api::screenshot_spool::delete_spooled_screenshot(api_path)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -724,44 +719,7 @@ fn wire__crate__api__desktop_notification__send_notification_with_options_impl(
         },
     )
 }
-fn wire__crate__api__keyboard_listener__start_keyboard_listener_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_keyboard_listener",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::keyboard_listener::KeyboardEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::keyboard_listener::start_keyboard_listener(api_sink)?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__mouse_listener__start_mouse_listener_impl(
+fn wire__crate__api__delta_encoding__delta_encoder_config_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -769,7 +727,7 @@ fn wire__crate__api__mouse_listener__start_mouse_listener_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_mouse_listener",
+            debug_name: "delta_encoder_config_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -783,28 +741,26 @@ fn wire__crate__api__mouse_listener__start_mouse_listener_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::mouse_listener::MouseEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::mouse_listener::start_mouse_listener(api_sink)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::delta_encoding::DeltaEncoderConfig::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__active_window_listener__start_window_listener_impl(
+fn wire__crate__api__click_heatmap__destroy_click_heatmap_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_window_listener",
+            debug_name: "destroy_click_heatmap",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -818,28 +774,25 @@ fn wire__crate__api__active_window_listener__start_window_listener_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::active_window_listener::WindowDetails,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_heatmap_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::active_window_listener::start_window_listener(api_sink),
+                    crate::api::click_heatmap::destroy_click_heatmap(api_heatmap_id),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__active_window_listener__start_window_listener_stream_impl(
+fn wire__crate__api__delta_encoding__destroy_delta_decoder_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_window_listener_stream",
+            debug_name: "destroy_delta_decoder",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -853,28 +806,25 @@ fn wire__crate__api__active_window_listener__start_window_listener_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::active_window_listener::WindowDetails,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_decoder_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::active_window_listener::start_window_listener_stream(api_sink),
+                    crate::api::delta_encoding::destroy_delta_decoder(api_decoder_id),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__active_window_listener__stop_window_listener_impl(
+fn wire__crate__api__delta_encoding__destroy_delta_encoder_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_window_listener",
+            debug_name: "destroy_delta_encoder",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -888,18 +838,18 @@ fn wire__crate__api__active_window_listener__stop_window_listener_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_listener_id = <u64>::sse_decode(&mut deserializer);
+            let api_encoder_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::active_window_listener::stop_window_listener(api_listener_id),
+                    crate::api::delta_encoding::destroy_delta_encoder(api_encoder_id),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_full_screenshot_impl(
+fn wire__crate__api__delta_encoding__encode_delta_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -907,7 +857,7 @@ fn wire__crate__api__take_full_screenshot__take_full_screenshot_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_full_screenshot",
+            debug_name: "encode_delta_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -921,11 +871,16 @@ fn wire__crate__api__take_full_screenshot__take_full_screenshot_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_encoder_id = <u64>::sse_decode(&mut deserializer);
+            let api_base64_image = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::take_full_screenshot::take_full_screenshot()?;
+                        let output_ok = crate::api::delta_encoding::encode_delta_frame(
+                            api_encoder_id,
+                            api_base64_image,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -933,7 +888,7 @@ fn wire__crate__api__take_full_screenshot__take_full_screenshot_impl(
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_linux_fallback_impl(
+fn wire__crate__api__screenshot_spool__enforce_spool_quota_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -941,7 +896,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_linux_fallback_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_linux_fallback",
+            debug_name: "enforce_spool_quota",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -955,12 +910,16 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_linux_fallback_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <String>::sse_decode(&mut deserializer);
+            let api_max_bytes = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_linux_fallback()?;
+                        let output_ok = crate::api::screenshot_spool::enforce_spool_quota(
+                            api_dir,
+                            api_max_bytes,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -968,17 +927,16 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_linux_fallback_impl(
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_csharp_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__click_heatmap__export_click_heatmap_grid_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_csharp",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "export_click_heatmap_grid",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -990,20 +948,17 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_csharp_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_heatmap_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_csharp()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::click_heatmap::export_click_heatmap_grid(api_heatmap_id)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_directshow_impl(
+fn wire__crate__api__click_heatmap__export_click_heatmap_png_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1011,7 +966,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_directshow_im
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_directshow",
+            debug_name: "export_click_heatmap_png",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1025,12 +980,22 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_directshow_im
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_heatmap_id = <u64>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_background_base64 = <Option<String>>::sse_decode(&mut deserializer);
+            let api_opacity = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_directshow()?;
+                        let output_ok = crate::api::click_heatmap::export_click_heatmap_png(
+                            api_heatmap_id,
+                            api_width,
+                            api_height,
+                            api_background_base64,
+                            api_opacity,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1038,7 +1003,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_directshow_im
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_ffmpeg_impl(
+fn wire__crate__api__take_full_screenshot__extract_bundled_nircmd_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1046,7 +1011,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_ffmpeg_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_ffmpeg",
+            debug_name: "extract_bundled_nircmd",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1064,8 +1029,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_ffmpeg_impl(
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_ffmpeg()?;
+                        let output_ok = crate::api::take_full_screenshot::extract_bundled_nircmd()?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1073,7 +1037,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_ffmpeg_impl(
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_memory_impl(
+fn wire__crate__api__get_all_process_list__find_process_by_name_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1081,7 +1045,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_memory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_memory",
+            debug_name: "find_process_by_name",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1095,30 +1059,29 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_memory_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_memory()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::get_all_process_list::find_process_by_name(&api_name),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_nircmd_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__delta_encoding__force_delta_keyframe_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_nircmd",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "force_delta_keyframe",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1130,20 +1093,18 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_nircmd_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_encoder_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_nircmd()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::delta_encoding::force_delta_keyframe(api_encoder_id),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_powershell_impl(
+fn wire__crate__api__frame_analysis__frame_analysis_is_blank_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1151,7 +1112,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_powershell_im
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_powershell",
+            debug_name: "frame_analysis_is_blank",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1165,30 +1126,30 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_powershell_im
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::frame_analysis::FrameAnalysis>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_powershell()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::frame_analysis::FrameAnalysis::is_blank(&api_that),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_vbscript_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__active_window_listener__get_active_window_info_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_vbscript",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_active_window_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1201,19 +1162,14 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_vbscript_impl
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_vbscript()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::active_window_listener::get_active_window_info()?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_win32_impl(
+fn wire__crate__api__get_all_process_list__get_all_processes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1221,7 +1177,7 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_win32_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_win32",
+            debug_name: "get_all_processes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1237,28 +1193,25 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_win32_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_win32()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::get_all_process_list::get_all_processes())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_windows_wmi_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__cmdline_redaction__get_cmdline_redaction_patterns_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "take_screenshot_windows_wmi",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_cmdline_redaction_patterns",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1271,44 +1224,56 @@ fn wire__crate__api__take_full_screenshot__take_screenshot_windows_wmi_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::take_screenshot_windows_wmi()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::cmdline_redaction::get_cmdline_redaction_patterns(),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__take_screenshot_with_screenshots_crate_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__active_window_listener__get_current_platform_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "take_screenshot_with_screenshots_crate", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
-            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
-            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end(); move |context|  {
-                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
-                         let output_ok = crate::api::take_full_screenshot::take_screenshot_with_screenshots_crate()?;   Ok(output_ok)
-                    })())
-                } })
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_current_platform",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::active_window_listener::get_current_platform(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-fn wire__crate__api__take_full_screenshot__test_all_available_methods_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__display_geometry__get_display_geometry_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_all_available_methods",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_display_geometry",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1321,29 +1286,23 @@ fn wire__crate__api__take_full_screenshot__test_all_available_methods_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_all_available_methods()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::display_geometry::get_display_geometry()?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_bundled_nircmd_extraction_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__desktop_notification__get_notification_backend_preference_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_bundled_nircmd_extraction",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_notification_backend_preference",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1356,29 +1315,25 @@ fn wire__crate__api__take_full_screenshot__test_bundled_nircmd_extraction_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_bundled_nircmd_extraction()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::desktop_notification::get_notification_backend_preference(),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_linux_environment_check_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__desktop_notification__get_notification_gate_stats_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_linux_environment_check",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_notification_gate_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1391,29 +1346,25 @@ fn wire__crate__api__take_full_screenshot__test_linux_environment_check_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_linux_environment_check()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::desktop_notification::get_notification_gate_stats(),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_linux_fallback_methods_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__active_window_listener__get_open_windows_info_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_linux_fallback_methods",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_open_windows_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1426,19 +1377,14 @@ fn wire__crate__api__take_full_screenshot__test_linux_fallback_methods_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_linux_fallback_methods()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::active_window_listener::get_open_windows_info()?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_nircmd_availability_impl(
+fn wire__crate__api__get_all_process_list__get_process_access_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1446,7 +1392,7 @@ fn wire__crate__api__take_full_screenshot__test_nircmd_availability_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_nircmd_availability",
+            debug_name: "get_process_access_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1464,7 +1410,7 @@ fn wire__crate__api__take_full_screenshot__test_nircmd_availability_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok(
-                        crate::api::take_full_screenshot::test_nircmd_availability(),
+                        crate::api::get_all_process_list::get_process_access_info(),
                     )?;
                     Ok(output_ok)
                 })())
@@ -1472,17 +1418,16 @@ fn wire__crate__api__take_full_screenshot__test_nircmd_availability_impl(
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_nircmd_capabilities_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__process_sampler__get_process_history_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_nircmd_capabilities",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_process_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1494,20 +1439,17 @@ fn wire__crate__api__take_full_screenshot__test_nircmd_capabilities_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_nircmd_capabilities()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::process_sampler::get_process_history(api_pid))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_nircmd_screenshot_simple_impl(
+fn wire__crate__api__get_all_process_list__get_process_tree_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1515,7 +1457,7 @@ fn wire__crate__api__take_full_screenshot__test_nircmd_screenshot_simple_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_nircmd_screenshot_simple",
+            debug_name: "get_process_tree",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1529,30 +1471,29 @@ fn wire__crate__api__take_full_screenshot__test_nircmd_screenshot_simple_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_root_pid = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_nircmd_screenshot_simple()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::get_all_process_list::get_process_tree(api_root_pid),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_screenshots_crate_method_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__resource_budget__get_resource_budget_status_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_screenshots_crate_method",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_resource_budget_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1565,19 +1506,15 @@ fn wire__crate__api__take_full_screenshot__test_screenshots_crate_method_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_screenshots_crate_method()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::resource_budget::get_resource_budget_status())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_environment_check_impl(
+fn wire__crate__api__system_monitor__get_system_snapshot_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1585,7 +1522,7 @@ fn wire__crate__api__take_full_screenshot__test_windows_environment_check_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_environment_check",
+            debug_name: "get_system_snapshot",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1601,28 +1538,25 @@ fn wire__crate__api__take_full_screenshot__test_windows_environment_check_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_environment_check()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::system_monitor::get_system_snapshot())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_1_nircmd_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__timelapse_recorder__get_timelapse_status_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_1_nircmd",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_timelapse_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1634,30 +1568,27 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_1_nircmd_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_recorder_id = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_1_nircmd()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::timelapse_recorder::get_timelapse_status(api_recorder_id),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_2_powershell_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__active_window_listener__get_window_icon_data_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_2_powershell",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_window_icon_data",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1669,20 +1600,47 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_2_powershell_impl
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_window_id = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_2_powershell()?;
-                        Ok(output_ok)
-                    })(),
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::active_window_listener::get_window_icon_data(api_window_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_3_memory_impl(
+fn wire__crate__api__simple__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1690,7 +1648,7 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_3_memory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_3_memory",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1706,18 +1664,17 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_3_memory_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_3_memory()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_4_directshow_impl(
+fn wire__crate__api__take_full_screenshot__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1725,7 +1682,7 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_4_directshow_impl
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_4_directshow",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1741,18 +1698,17 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_4_directshow_impl
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_4_directshow()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::take_full_screenshot::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_5_win32_impl(
+fn wire__crate__api__take_full_screenshot__is_nircmd_available_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1760,7 +1716,7 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_5_win32_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_5_win32",
+            debug_name: "is_nircmd_available",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1776,18 +1732,17 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_5_win32_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_5_win32()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::take_full_screenshot::is_nircmd_available(),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_6_wmi_impl(
+fn wire__crate__api__resource_budget__is_on_battery_power_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1795,7 +1750,7 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_6_wmi_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_6_wmi",
+            debug_name: "is_on_battery_power",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1811,28 +1766,25 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_6_wmi_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_6_wmi()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::resource_budget::is_on_battery_power())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_7_ffmpeg_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__active_window_listener__is_platform_supported_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_7_ffmpeg",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "is_platform_supported",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -1845,19 +1797,46 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_7_ffmpeg_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_7_ffmpeg()?;
-                        Ok(output_ok)
-                    })(),
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::active_window_listener::is_platform_supported(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__process_sampler__is_process_sampler_running_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_process_sampler_running",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::process_sampler::is_process_sampler_running())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_8_csharp_impl(
+fn wire__crate__api__get_all_process_list__kill_process_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1865,7 +1844,7 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_8_csharp_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_8_csharp",
+            debug_name: "kill_process",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1879,20 +1858,20 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_8_csharp_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_8_csharp()?;
-                        Ok(output_ok)
-                    })(),
-                )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::get_all_process_list::kill_process(api_pid),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__take_full_screenshot__test_windows_method_9_vbscript_impl(
+fn wire__crate__api__screenshot_spool__list_spooled_screenshots_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1900,7 +1879,7 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_9_vbscript_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "test_windows_method_9_vbscript",
+            debug_name: "list_spooled_screenshots",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1914,12 +1893,13 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_9_vbscript_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dir = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let output_ok =
-                            crate::api::take_full_screenshot::test_windows_method_9_vbscript()?;
+                            crate::api::screenshot_spool::list_spooled_screenshots(api_dir)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1927,281 +1907,4674 @@ fn wire__crate__api__take_full_screenshot__test_windows_method_9_vbscript_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<
-        crate::api::keyboard_listener::KeyboardEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<
-        crate::api::mouse_listener::MouseEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<
-        crate::api::active_window_listener::WindowDetails,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
+fn wire__crate__api__display_geometry__logical_to_physical_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "logical_to_physical",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_x = <f64>::sse_decode(&mut deserializer);
+            let api_y = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::display_geometry::logical_to_physical(api_x, api_y),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__display_geometry__native_coordinate_space_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "native_coordinate_space",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::display_geometry::native_coordinate_space())?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__desktop_notification__notification_backend_capabilities_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "notification_backend_capabilities_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::desktop_notification::NotificationBackendCapabilities::default(
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__desktop_notification__notification_backend_preference_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "notification_backend_preference_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::desktop_notification::NotificationBackendPreference::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__desktop_notification__notification_gate_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "notification_gate_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::desktop_notification::NotificationGateConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::api::keyboard_listener::KeyboardEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_key = <String>::sse_decode(deserializer);
-        let mut var_isKeyPress = <bool>::sse_decode(deserializer);
-        return crate::api::keyboard_listener::KeyboardEvent {
-            key: var_key,
-            is_key_press: var_isKeyPress,
-        };
-    }
+fn wire__crate__api__desktop_notification__notification_gate_stats_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "notification_gate_stats_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::desktop_notification::NotificationGateStats::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
-}
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+fn wire__crate__api__screenshot_scheduler__pause_screenshot_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_screenshot_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheduler_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::screenshot_scheduler::pause_screenshot_scheduler(api_scheduler_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for Vec<crate::api::get_all_process_list::ProcessInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::get_all_process_list::ProcessInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
-    }
+fn wire__crate__api__timelapse_recorder__pause_timelapse_recording_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pause_timelapse_recording",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_recorder_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::timelapse_recorder::pause_timelapse_recording(api_recorder_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for Vec<crate::api::active_window_listener::WindowDetails> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::active_window_listener::WindowDetails>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
-    }
+fn wire__crate__api__display_geometry__physical_to_logical_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "physical_to_logical",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_x = <f64>::sse_decode(&mut deserializer);
+            let api_y = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::display_geometry::physical_to_logical(api_x, api_y),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for crate::api::mouse_listener::MouseEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_button = <String>::sse_decode(deserializer);
-        let mut var_isButtonPress = <bool>::sse_decode(deserializer);
-        let mut var_coords = <(i32, i32)>::sse_decode(deserializer);
-        let mut var_isLeftClick = <bool>::sse_decode(deserializer);
-        let mut var_isRightClick = <bool>::sse_decode(deserializer);
-        return crate::api::mouse_listener::MouseEvent {
-            button: var_button,
-            is_button_press: var_isButtonPress,
-            coords: var_coords,
-            is_left_click: var_isLeftClick,
-            is_right_click: var_isRightClick,
-        };
-    }
+fn wire__crate__api__desktop_notification__probe_notification_backends_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_notification_backends",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::desktop_notification::probe_notification_backends(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
+fn wire__crate__api__process_watcher__process_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::process_watcher::ProcessFilter::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for Option<i32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
+fn wire__crate__api__process_sampler__process_sampler_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "process_sampler_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::process_sampler::ProcessSamplerConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
-}
-
-impl SseDecode for crate::api::get_all_process_list::ProcessInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_pid = <u32>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_cmd = <Vec<String>>::sse_decode(deserializer);
-        let mut var_memoryUsage = <u64>::sse_decode(deserializer);
-        let mut var_cpuUsage = <f32>::sse_decode(deserializer);
-        return crate::api::get_all_process_list::ProcessInfo {
-            pid: var_pid,
-            name: var_name,
-            cmd: var_cmd,
-            memory_usage: var_memoryUsage,
-            cpu_usage: var_cpuUsage,
-        };
-    }
-}
-
-impl SseDecode for (i32, i32) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <i32>::sse_decode(deserializer);
-        let mut var_field1 = <i32>::sse_decode(deserializer);
-        return (var_field0, var_field1);
-    }
+fn wire__crate__api__desktop_notification__register_module_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "register_module",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::desktop_notification::register_module();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for (i32, i32, i32, i32) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <i32>::sse_decode(deserializer);
-        let mut var_field1 = <i32>::sse_decode(deserializer);
-        let mut var_field2 = <i32>::sse_decode(deserializer);
-        let mut var_field3 = <i32>::sse_decode(deserializer);
-        return (var_field0, var_field1, var_field2, var_field3);
-    }
+fn wire__crate__api__click_heatmap__reset_click_heatmap_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_click_heatmap",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_heatmap_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::click_heatmap::reset_click_heatmap(api_heatmap_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__desktop_notification__reset_notification_gate_stats_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_notification_gate_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::desktop_notification::reset_notification_gate_stats();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
-    }
+fn wire__crate__api__resource_budget__resource_budget_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resource_budget_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::resource_budget::ResourceBudgetConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
-    }
+fn wire__crate__api__screenshot_scheduler__resume_screenshot_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_screenshot_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheduler_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::screenshot_scheduler::resume_screenshot_scheduler(api_scheduler_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+fn wire__crate__api__timelapse_recorder__resume_timelapse_recording_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resume_timelapse_recording",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_recorder_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::timelapse_recorder::resume_timelapse_recording(api_recorder_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
 }
-
-impl SseDecode for crate::api::active_window_listener::WindowDetails {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <u32>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_position = <(i32, i32, i32, i32)>::sse_decode(deserializer);
-        let mut var_isFullScreen = <bool>::sse_decode(deserializer);
-        let mut var_processName = <String>::sse_decode(deserializer);
-        let mut var_processPath = <String>::sse_decode(deserializer);
-        let mut var_processId = <u32>::sse_decode(deserializer);
-        let mut var_os = <String>::sse_decode(deserializer);
-        return crate::api::active_window_listener::WindowDetails {
-            id: var_id,
-            title: var_title,
-            position: var_position,
-            is_full_screen: var_isFullScreen,
-            process_name: var_processName,
+fn wire__crate__api__screenshot_capture__screenshot_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "screenshot_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::screenshot_capture::ScreenshotOptions::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop_notification__send_gated_notification_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_gated_notification",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_category = <String>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_icon_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_urgency_level = <Option<i32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::desktop_notification::send_gated_notification(
+                            api_category,
+                            api_title,
+                            api_message,
+                            api_icon_path,
+                            api_urgency_level,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__notification_actions__send_interactive_notification_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_interactive_notification",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            let api_actions =
+                <Vec<crate::api::notification_actions::NotificationAction>>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_sink = <StreamSink<
+                crate::api::notification_actions::NotificationEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::notification_actions::send_interactive_notification(
+                                api_title,
+                                api_body,
+                                api_actions,
+                                api_sink,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop_notification__send_notification_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_notification",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_icon_path = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::desktop_notification::send_notification(
+                            api_title,
+                            api_message,
+                            api_icon_path,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__desktop_notification__send_notification_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_notification_with_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_icon_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_timeout_seconds = <Option<u64>>::sse_decode(&mut deserializer);
+            let api_urgency_level = <Option<i32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::desktop_notification::send_notification_with_options(
+                                api_title,
+                                api_message,
+                                api_icon_path,
+                                api_timeout_seconds,
+                                api_urgency_level,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__cmdline_redaction__set_cmdline_redaction_patterns_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_cmdline_redaction_patterns",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_patterns = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::cmdline_redaction::set_cmdline_redaction_patterns(api_patterns)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__desktop_notification__set_do_not_disturb_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_do_not_disturb",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::desktop_notification::set_do_not_disturb(api_enabled);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__desktop_notification__set_notification_backend_preference_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_notification_backend_preference",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_preference =
+                <crate::api::desktop_notification::NotificationBackendPreference>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::desktop_notification::set_notification_backend_preference(
+                        api_preference,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__persistent_notification__show_persistent_notification_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "show_persistent_notification",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            let api_icon_path = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::persistent_notification::show_persistent_notification(
+                                api_title,
+                                api_body,
+                                api_icon_path,
+                            )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__app_policy__start_app_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_app_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::app_policy::PolicyConfig>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::app_policy::PolicyViolation,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::app_policy::start_app_policy(api_config, api_sink)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__capture_preview__start_capture_preview_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_capture_preview",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_fps = <f64>::sse_decode(&mut deserializer);
+            let api_max_width = <u32>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::capture_preview::PreviewFrame,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::capture_preview::start_capture_preview(
+                    api_fps,
+                    api_max_width,
+                    api_sink,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__keyboard_listener__start_keyboard_listener_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_keyboard_listener",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::keyboard_listener::KeyboardEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::keyboard_listener::start_keyboard_listener(api_sink)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mouse_listener__start_mouse_listener_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_mouse_listener",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::mouse_listener::MouseEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::mouse_listener::start_mouse_listener(api_sink)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__process_sampler__start_process_sampler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_process_sampler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::process_sampler::ProcessSamplerConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::process_sampler::start_process_sampler(api_config),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__process_watcher__start_process_watcher_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_process_watcher",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filter =
+                <crate::api::process_watcher::ProcessFilter>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::process_watcher::ProcessEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::process_watcher::start_process_watcher(api_filter, api_sink)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__resource_budget__start_resource_budget_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_resource_budget",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::resource_budget::ResourceBudgetConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::resource_budget::start_resource_budget(api_config),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__screenshot_scheduler__start_screenshot_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_screenshot_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::screenshot_scheduler::ScreenshotScheduleConfig>::sse_decode(
+                    &mut deserializer,
+                );
+            let api_sink = <StreamSink<
+                crate::api::screenshot_capture::Screenshot,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::screenshot_scheduler::start_screenshot_scheduler(
+                    api_config, api_sink,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__system_monitor__start_system_monitor_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_system_monitor",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_interval_ms = <u64>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::system_monitor::SystemSnapshot,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::system_monitor::start_system_monitor(api_interval_ms, api_sink)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__timelapse_recorder__start_timelapse_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_timelapse_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config =
+                <crate::api::timelapse_recorder::TimelapseConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::timelapse_recorder::start_timelapse_recording(api_config)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__active_window_listener__start_window_listener_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_window_listener",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::active_window_listener::WindowDetails,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::active_window_listener::start_window_listener(api_sink),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__active_window_listener__start_window_listener_stream_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_window_listener_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::active_window_listener::WindowDetails,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::active_window_listener::start_window_listener_stream(api_sink),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__app_policy__stop_app_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_app_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_engine_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::app_policy::stop_app_policy(api_engine_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__capture_preview__stop_capture_preview_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_capture_preview",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_preview_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::capture_preview::stop_capture_preview(api_preview_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__process_sampler__stop_process_sampler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_process_sampler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::process_sampler::stop_process_sampler())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__process_watcher__stop_process_watcher_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_process_watcher",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_watcher_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::process_watcher::stop_process_watcher(api_watcher_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__resource_budget__stop_resource_budget_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_resource_budget",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::resource_budget::stop_resource_budget())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__screenshot_scheduler__stop_screenshot_scheduler_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_screenshot_scheduler",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scheduler_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::screenshot_scheduler::stop_screenshot_scheduler(api_scheduler_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__system_monitor__stop_system_monitor_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_system_monitor",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_monitor_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::system_monitor::stop_system_monitor(api_monitor_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__timelapse_recorder__stop_timelapse_recording_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_timelapse_recording",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_recorder_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::timelapse_recorder::stop_timelapse_recording(
+                            api_recorder_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__active_window_listener__stop_window_listener_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_window_listener",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_listener_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::active_window_listener::stop_window_listener(api_listener_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_full_screenshot_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_full_screenshot",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::take_full_screenshot::take_full_screenshot()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_linux_fallback_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_linux_fallback",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_linux_fallback()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_csharp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_csharp",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_csharp()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_directshow_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_directshow",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_directshow()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_ffmpeg_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_ffmpeg",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_ffmpeg()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_memory()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_nircmd_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_nircmd",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_nircmd()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_powershell_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_powershell",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_powershell()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_vbscript_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_vbscript",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_vbscript()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_win32_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_win32",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_win32()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_windows_wmi_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_screenshot_windows_wmi",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::take_screenshot_windows_wmi()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__take_screenshot_with_screenshots_crate_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "take_screenshot_with_screenshots_crate", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::take_full_screenshot::take_screenshot_with_screenshots_crate()?;   Ok(output_ok)
+                    })())
+                } })
+}
+fn wire__crate__api__process_termination__terminate_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "terminate_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::process_termination::TerminateOptions::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__process_termination__terminate_process_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "terminate_process",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::process_termination::TerminateOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::process_termination::terminate_process(api_pid, api_options),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_all_available_methods_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_all_available_methods",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_all_available_methods()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_bundled_nircmd_extraction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_bundled_nircmd_extraction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_bundled_nircmd_extraction()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_linux_environment_check_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_linux_environment_check",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_linux_environment_check()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_linux_fallback_methods_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_linux_fallback_methods",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_linux_fallback_methods()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_nircmd_availability_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_nircmd_availability",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::take_full_screenshot::test_nircmd_availability(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_nircmd_capabilities_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_nircmd_capabilities",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_nircmd_capabilities()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_nircmd_screenshot_simple_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_nircmd_screenshot_simple",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_nircmd_screenshot_simple()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_screenshots_crate_method_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_screenshots_crate_method",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_screenshots_crate_method()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_environment_check_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_environment_check",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_environment_check()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_1_nircmd_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_1_nircmd",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_1_nircmd()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_2_powershell_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_2_powershell",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_2_powershell()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_3_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_3_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_3_memory()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_4_directshow_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_4_directshow",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_4_directshow()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_5_win32_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_5_win32",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_5_win32()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_6_wmi_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_6_wmi",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_6_wmi()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_7_ffmpeg_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_7_ffmpeg",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_7_ffmpeg()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_8_csharp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_8_csharp",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_8_csharp()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__take_full_screenshot__test_windows_method_9_vbscript_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "test_windows_method_9_vbscript",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::take_full_screenshot::test_windows_method_9_vbscript()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__process_sampler__top_processes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "top_processes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_by =
+                <crate::api::process_sampler::ProcessSortKey>::sse_decode(&mut deserializer);
+            let api_n = <u32>::sse_decode(&mut deserializer);
+            let api_window_seconds = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::process_sampler::top_processes(
+                    api_by,
+                    api_n,
+                    api_window_seconds,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__persistent_notification__update_notification_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_notification",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle_id = <u64>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::persistent_notification::update_notification(
+                            api_handle_id,
+                            api_title,
+                            api_body,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__screenshot_watermark__watermark_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watermark_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::screenshot_watermark::WatermarkOptions::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::keyboard_listener::KeyboardEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::mouse_listener::MouseEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::notification_actions::NotificationEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::app_policy::PolicyViolation,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::capture_preview::PreviewFrame,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::process_watcher::ProcessEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::screenshot_capture::Screenshot,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::system_monitor::SystemSnapshot,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::active_window_listener::WindowDetails,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::CategoryGateStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_sent = <u64>::sse_decode(deserializer);
+        let mut var_deduplicated = <u64>::sse_decode(deserializer);
+        let mut var_rateLimited = <u64>::sse_decode(deserializer);
+        let mut var_queued = <u64>::sse_decode(deserializer);
+        let mut var_dropped = <u64>::sse_decode(deserializer);
+        return crate::api::desktop_notification::CategoryGateStats {
+            category: var_category,
+            sent: var_sent,
+            deduplicated: var_deduplicated,
+            rate_limited: var_rateLimited,
+            queued: var_queued,
+            dropped: var_dropped,
+        };
+    }
+}
+
+impl SseDecode for crate::api::display_geometry::CoordinateSpace {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::display_geometry::CoordinateSpace::Logical,
+            1 => crate::api::display_geometry::CoordinateSpace::Physical,
+            _ => unreachable!("Invalid variant for CoordinateSpace: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::system_monitor::CpuCoreUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_usage = <f32>::sse_decode(deserializer);
+        let mut var_frequencyMhz = <u64>::sse_decode(deserializer);
+        return crate::api::system_monitor::CpuCoreUsage {
+            name: var_name,
+            usage: var_usage,
+            frequency_mhz: var_frequencyMhz,
+        };
+    }
+}
+
+impl SseDecode for crate::api::delta_encoding::DeltaEncoderConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tileSize = <u32>::sse_decode(deserializer);
+        let mut var_keyframeInterval = <u32>::sse_decode(deserializer);
+        let mut var_maxChangedRatio = <f32>::sse_decode(deserializer);
+        return crate::api::delta_encoding::DeltaEncoderConfig {
+            tile_size: var_tileSize,
+            keyframe_interval: var_keyframeInterval,
+            max_changed_ratio: var_maxChangedRatio,
+        };
+    }
+}
+
+impl SseDecode for crate::api::system_monitor::DiskUsageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_mountPoint = <String>::sse_decode(deserializer);
+        let mut var_fileSystem = <String>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_availableBytes = <u64>::sse_decode(deserializer);
+        let mut var_isRemovable = <bool>::sse_decode(deserializer);
+        return crate::api::system_monitor::DiskUsageInfo {
+            name: var_name,
+            mount_point: var_mountPoint,
+            file_system: var_fileSystem,
+            total_bytes: var_totalBytes,
+            available_bytes: var_availableBytes,
+            is_removable: var_isRemovable,
+        };
+    }
+}
+
+impl SseDecode for crate::api::display_geometry::DisplayGeometry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_isPrimary = <bool>::sse_decode(deserializer);
+        let mut var_scaleFactor = <f32>::sse_decode(deserializer);
+        let mut var_logical = <crate::api::display_geometry::DisplayRect>::sse_decode(deserializer);
+        let mut var_physical =
+            <crate::api::display_geometry::DisplayRect>::sse_decode(deserializer);
+        return crate::api::display_geometry::DisplayGeometry {
+            id: var_id,
+            is_primary: var_isPrimary,
+            scale_factor: var_scaleFactor,
+            logical: var_logical,
+            physical: var_physical,
+        };
+    }
+}
+
+impl SseDecode for crate::api::display_geometry::DisplayRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <i32>::sse_decode(deserializer);
+        let mut var_y = <i32>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        return crate::api::display_geometry::DisplayRect {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for crate::api::delta_encoding::EncodedFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_isKeyframe = <bool>::sse_decode(deserializer);
+        let mut var_frameIndex = <u32>::sse_decode(deserializer);
+        let mut var_changedTiles = <u32>::sse_decode(deserializer);
+        let mut var_totalTiles = <u32>::sse_decode(deserializer);
+        return crate::api::delta_encoding::EncodedFrame {
+            data: var_data,
+            is_keyframe: var_isKeyframe,
+            frame_index: var_frameIndex,
+            changed_tiles: var_changedTiles,
+            total_tiles: var_totalTiles,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::frame_analysis::FrameAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_classification =
+            <crate::api::frame_analysis::FrameClassification>::sse_decode(deserializer);
+        let mut var_meanLuminance = <f32>::sse_decode(deserializer);
+        let mut var_luminanceVariance = <f32>::sse_decode(deserializer);
+        let mut var_dominantBinRatio = <f32>::sse_decode(deserializer);
+        let mut var_darkPixelRatio = <f32>::sse_decode(deserializer);
+        return crate::api::frame_analysis::FrameAnalysis {
+            classification: var_classification,
+            mean_luminance: var_meanLuminance,
+            luminance_variance: var_luminanceVariance,
+            dominant_bin_ratio: var_dominantBinRatio,
+            dark_pixel_ratio: var_darkPixelRatio,
+        };
+    }
+}
+
+impl SseDecode for crate::api::frame_analysis::FrameClassification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::frame_analysis::FrameClassification::Normal,
+            1 => crate::api::frame_analysis::FrameClassification::Blank,
+            2 => crate::api::frame_analysis::FrameClassification::Uniform,
+            3 => crate::api::frame_analysis::FrameClassification::LikelyLockScreen,
+            _ => unreachable!("Invalid variant for FrameClassification: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::GateDecision {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::desktop_notification::GateDecision::Sent,
+            1 => crate::api::desktop_notification::GateDecision::Deduplicated,
+            2 => crate::api::desktop_notification::GateDecision::RateLimited,
+            3 => crate::api::desktop_notification::GateDecision::Queued,
+            _ => unreachable!("Invalid variant for GateDecision: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::GatedSendResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_decision =
+            <crate::api::desktop_notification::GateDecision>::sse_decode(deserializer);
+        let mut var_backend =
+            <Option<crate::api::desktop_notification::NotificationBackend>>::sse_decode(
+                deserializer,
+            );
+        return crate::api::desktop_notification::GatedSendResult {
+            decision: var_decision,
+            backend: var_backend,
+        };
+    }
+}
+
+impl SseDecode for crate::api::click_heatmap::HeatmapConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scope = <crate::api::click_heatmap::HeatmapScope>::sse_decode(deserializer);
+        let mut var_gridWidth = <u32>::sse_decode(deserializer);
+        let mut var_gridHeight = <u32>::sse_decode(deserializer);
+        let mut var_includeRightClicks = <bool>::sse_decode(deserializer);
+        return crate::api::click_heatmap::HeatmapConfig {
+            scope: var_scope,
+            grid_width: var_gridWidth,
+            grid_height: var_gridHeight,
+            include_right_clicks: var_includeRightClicks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::click_heatmap::HeatmapGrid {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_scope = <crate::api::click_heatmap::HeatmapScope>::sse_decode(deserializer);
+        let mut var_gridWidth = <u32>::sse_decode(deserializer);
+        let mut var_gridHeight = <u32>::sse_decode(deserializer);
+        let mut var_cells = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_totalClicks = <u64>::sse_decode(deserializer);
+        return crate::api::click_heatmap::HeatmapGrid {
+            scope: var_scope,
+            grid_width: var_gridWidth,
+            grid_height: var_gridHeight,
+            cells: var_cells,
+            total_clicks: var_totalClicks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::click_heatmap::HeatmapScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::click_heatmap::HeatmapScopeKind>::sse_decode(deserializer);
+        let mut var_id = <u32>::sse_decode(deserializer);
+        return crate::api::click_heatmap::HeatmapScope {
+            kind: var_kind,
+            id: var_id,
+        };
+    }
+}
+
+impl SseDecode for crate::api::click_heatmap::HeatmapScopeKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::click_heatmap::HeatmapScopeKind::Monitor,
+            1 => crate::api::click_heatmap::HeatmapScopeKind::Window,
+            _ => unreachable!("Invalid variant for HeatmapScopeKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::keyboard_listener::KeyboardEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_isKeyPress = <bool>::sse_decode(deserializer);
+        return crate::api::keyboard_listener::KeyboardEvent {
+            key: var_key,
+            is_key_press: var_isKeyPress,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::desktop_notification::CategoryGateStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::desktop_notification::CategoryGateStats>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::system_monitor::CpuCoreUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::system_monitor::CpuCoreUsage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::system_monitor::DiskUsageInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::system_monitor::DiskUsageInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::display_geometry::DisplayGeometry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::display_geometry::DisplayGeometry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::system_monitor::NetworkInterfaceUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::system_monitor::NetworkInterfaceUsage>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::notification_actions::NotificationAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::notification_actions::NotificationAction>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::desktop_notification::NotificationBackend> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::desktop_notification::NotificationBackend>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::desktop_notification::NotificationBackendProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::desktop_notification::NotificationBackendProbe>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::app_policy::PolicyRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::app_policy::PolicyRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::get_all_process_list::ProcessInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::get_all_process_list::ProcessInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::process_sampler::ProcessSample> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::process_sampler::ProcessSample>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::get_all_process_list::ProcessTreeNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::get_all_process_list::ProcessTreeNode>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::process_sampler::ProcessUsageSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::process_sampler::ProcessUsageSummary>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::screenshot_spool::SpoolEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::screenshot_spool::SpoolEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::active_window_listener::WindowDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::active_window_listener::WindowDetails>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::system_monitor::LoadAverage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_one = <f64>::sse_decode(deserializer);
+        let mut var_five = <f64>::sse_decode(deserializer);
+        let mut var_fifteen = <f64>::sse_decode(deserializer);
+        return crate::api::system_monitor::LoadAverage {
+            one: var_one,
+            five: var_five,
+            fifteen: var_fifteen,
+        };
+    }
+}
+
+impl SseDecode for crate::api::display_geometry::MonitorLocation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_monitorId = <u32>::sse_decode(deserializer);
+        let mut var_x = <i32>::sse_decode(deserializer);
+        let mut var_y = <i32>::sse_decode(deserializer);
+        return crate::api::display_geometry::MonitorLocation {
+            monitor_id: var_monitorId,
+            x: var_x,
+            y: var_y,
+        };
+    }
+}
+
+impl SseDecode for crate::api::mouse_listener::MouseEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_button = <String>::sse_decode(deserializer);
+        let mut var_isButtonPress = <bool>::sse_decode(deserializer);
+        let mut var_coords = <(i32, i32)>::sse_decode(deserializer);
+        let mut var_monitor =
+            <Option<crate::api::display_geometry::MonitorLocation>>::sse_decode(deserializer);
+        let mut var_isLeftClick = <bool>::sse_decode(deserializer);
+        let mut var_isRightClick = <bool>::sse_decode(deserializer);
+        return crate::api::mouse_listener::MouseEvent {
+            button: var_button,
+            is_button_press: var_isButtonPress,
+            coords: var_coords,
+            monitor: var_monitor,
+            is_left_click: var_isLeftClick,
+            is_right_click: var_isRightClick,
+        };
+    }
+}
+
+impl SseDecode for crate::api::system_monitor::NetworkInterfaceUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_receivedBytes = <u64>::sse_decode(deserializer);
+        let mut var_transmittedBytes = <u64>::sse_decode(deserializer);
+        return crate::api::system_monitor::NetworkInterfaceUsage {
+            name: var_name,
+            received_bytes: var_receivedBytes,
+            transmitted_bytes: var_transmittedBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::notification_actions::NotificationAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        return crate::api::notification_actions::NotificationAction {
+            id: var_id,
+            label: var_label,
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::NotificationBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::desktop_notification::NotificationBackend::NotifyRust,
+            1 => crate::api::desktop_notification::NotificationBackend::NotifySend,
+            2 => crate::api::desktop_notification::NotificationBackend::Kdialog,
+            3 => crate::api::desktop_notification::NotificationBackend::Zenity,
+            4 => crate::api::desktop_notification::NotificationBackend::Xmessage,
+            5 => crate::api::desktop_notification::NotificationBackend::TerminalNotifier,
+            6 => crate::api::desktop_notification::NotificationBackend::AppleScript,
+            7 => crate::api::desktop_notification::NotificationBackend::PowerShellToast,
+            8 => crate::api::desktop_notification::NotificationBackend::PowerShellBalloon,
+            9 => crate::api::desktop_notification::NotificationBackend::MessageBox,
+            _ => unreachable!("Invalid variant for NotificationBackend: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::NotificationBackendCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_actions = <bool>::sse_decode(deserializer);
+        let mut var_bodyMarkup = <bool>::sse_decode(deserializer);
+        let mut var_icons = <bool>::sse_decode(deserializer);
+        let mut var_persistence = <bool>::sse_decode(deserializer);
+        return crate::api::desktop_notification::NotificationBackendCapabilities {
+            actions: var_actions,
+            body_markup: var_bodyMarkup,
+            icons: var_icons,
+            persistence: var_persistence,
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::NotificationBackendPreference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pinned =
+            <Option<crate::api::desktop_notification::NotificationBackend>>::sse_decode(
+                deserializer,
+            );
+        let mut var_excluded =
+            <Vec<crate::api::desktop_notification::NotificationBackend>>::sse_decode(deserializer);
+        return crate::api::desktop_notification::NotificationBackendPreference {
+            pinned: var_pinned,
+            excluded: var_excluded,
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::NotificationBackendProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backend =
+            <crate::api::desktop_notification::NotificationBackend>::sse_decode(deserializer);
+        let mut var_available = <bool>::sse_decode(deserializer);
+        let mut var_disabled = <bool>::sse_decode(deserializer);
+        let mut var_capabilities =
+            <crate::api::desktop_notification::NotificationBackendCapabilities>::sse_decode(
+                deserializer,
+            );
+        let mut var_server =
+            <Option<crate::api::desktop_notification::NotificationServerInfo>>::sse_decode(
+                deserializer,
+            );
+        return crate::api::desktop_notification::NotificationBackendProbe {
+            backend: var_backend,
+            available: var_available,
+            disabled: var_disabled,
+            capabilities: var_capabilities,
+            server: var_server,
+        };
+    }
+}
+
+impl SseDecode for crate::api::notification_actions::NotificationEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::notification_actions::NotificationEventKind>::sse_decode(deserializer);
+        let mut var_actionId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::notification_actions::NotificationEvent {
+            kind: var_kind,
+            action_id: var_actionId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::notification_actions::NotificationEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::notification_actions::NotificationEventKind::ActionInvoked,
+            1 => crate::api::notification_actions::NotificationEventKind::Dismissed,
+            2 => crate::api::notification_actions::NotificationEventKind::Expired,
+            3 => crate::api::notification_actions::NotificationEventKind::Closed,
+            _ => unreachable!("Invalid variant for NotificationEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::NotificationGateConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dedupeWindowSeconds = <u64>::sse_decode(deserializer);
+        let mut var_maxPerCategory = <u32>::sse_decode(deserializer);
+        let mut var_rateWindowSeconds = <u64>::sse_decode(deserializer);
+        let mut var_quietHours =
+            <Option<crate::api::desktop_notification::QuietHours>>::sse_decode(deserializer);
+        let mut var_doNotDisturb = <bool>::sse_decode(deserializer);
+        return crate::api::desktop_notification::NotificationGateConfig {
+            dedupe_window_seconds: var_dedupeWindowSeconds,
+            max_per_category: var_maxPerCategory,
+            rate_window_seconds: var_rateWindowSeconds,
+            quiet_hours: var_quietHours,
+            do_not_disturb: var_doNotDisturb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::NotificationGateStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pending = <u32>::sse_decode(deserializer);
+        let mut var_categories =
+            <Vec<crate::api::desktop_notification::CategoryGateStats>>::sse_decode(deserializer);
+        return crate::api::desktop_notification::NotificationGateStats {
+            pending: var_pending,
+            categories: var_categories,
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::NotificationServerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_vendor = <String>::sse_decode(deserializer);
+        let mut var_version = <String>::sse_decode(deserializer);
+        let mut var_specVersion = <String>::sse_decode(deserializer);
+        return crate::api::desktop_notification::NotificationServerInfo {
+            name: var_name,
+            vendor: var_vendor,
+            version: var_version,
+            spec_version: var_specVersion,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::display_geometry::MonitorLocation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::display_geometry::MonitorLocation>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::desktop_notification::NotificationBackend> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::desktop_notification::NotificationBackend>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::desktop_notification::NotificationServerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::desktop_notification::NotificationServerInfo>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::process_sampler::ProcessHistory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::process_sampler::ProcessHistory>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::get_all_process_list::ProcessTreeNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::get_all_process_list::ProcessTreeNode>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::desktop_notification::QuietHours> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::desktop_notification::QuietHours>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<(f64, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<(f64, f64)>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::timelapse_recorder::TimelapseStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::timelapse_recorder::TimelapseStatus>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::screenshot_watermark::WatermarkOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::screenshot_watermark::WatermarkOptions>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::app_policy::PolicyAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::app_policy::PolicyAction::Log,
+            1 => crate::api::app_policy::PolicyAction::Notify,
+            2 => crate::api::app_policy::PolicyAction::WarnThenKill,
+            3 => crate::api::app_policy::PolicyAction::Kill,
+            _ => unreachable!("Invalid variant for PolicyAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::PolicyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rules = <Vec<crate::api::app_policy::PolicyRule>>::sse_decode(deserializer);
+        let mut var_intervalMs = <u64>::sse_decode(deserializer);
+        return crate::api::app_policy::PolicyConfig {
+            rules: var_rules,
+            interval_ms: var_intervalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::PolicyEnforcement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::app_policy::PolicyEnforcement::Logged,
+            1 => crate::api::app_policy::PolicyEnforcement::Notified,
+            2 => crate::api::app_policy::PolicyEnforcement::Warned,
+            3 => crate::api::app_policy::PolicyEnforcement::Killed,
+            4 => crate::api::app_policy::PolicyEnforcement::KillFailed,
+            5 => crate::api::app_policy::PolicyEnforcement::Exited,
+            _ => unreachable!("Invalid variant for PolicyEnforcement: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::PolicyRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_namePatterns = <Vec<String>>::sse_decode(deserializer);
+        let mut var_pathPatterns = <Vec<String>>::sse_decode(deserializer);
+        let mut var_windowTitlePatterns = <Vec<String>>::sse_decode(deserializer);
+        let mut var_action = <crate::api::app_policy::PolicyAction>::sse_decode(deserializer);
+        let mut var_graceSeconds = <u32>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        return crate::api::app_policy::PolicyRule {
+            id: var_id,
+            name_patterns: var_namePatterns,
+            path_patterns: var_pathPatterns,
+            window_title_patterns: var_windowTitlePatterns,
+            action: var_action,
+            grace_seconds: var_graceSeconds,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::app_policy::PolicyViolation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ruleId = <String>::sse_decode(deserializer);
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_processName = <String>::sse_decode(deserializer);
+        let mut var_exePath = <Option<String>>::sse_decode(deserializer);
+        let mut var_windowTitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_enforcement =
+            <crate::api::app_policy::PolicyEnforcement>::sse_decode(deserializer);
+        let mut var_killAtMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        return crate::api::app_policy::PolicyViolation {
+            rule_id: var_ruleId,
+            pid: var_pid,
+            process_name: var_processName,
+            exe_path: var_exePath,
+            window_title: var_windowTitle,
+            enforcement: var_enforcement,
+            kill_at_ms: var_killAtMs,
+            timestamp_ms: var_timestampMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::capture_preview::PreviewFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_jpeg = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_capturedAtMs = <u64>::sse_decode(deserializer);
+        let mut var_classification =
+            <crate::api::frame_analysis::FrameClassification>::sse_decode(deserializer);
+        return crate::api::capture_preview::PreviewFrame {
+            jpeg: var_jpeg,
+            width: var_width,
+            height: var_height,
+            captured_at_ms: var_capturedAtMs,
+            classification: var_classification,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_watcher::ProcessEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::process_watcher::ProcessEventKind>::sse_decode(deserializer);
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_exePath = <String>::sse_decode(deserializer);
+        let mut var_startTime = <u64>::sse_decode(deserializer);
+        let mut var_runDurationSeconds = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::process_watcher::ProcessEvent {
+            kind: var_kind,
+            pid: var_pid,
+            name: var_name,
+            exe_path: var_exePath,
+            start_time: var_startTime,
+            run_duration_seconds: var_runDurationSeconds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_watcher::ProcessEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::process_watcher::ProcessEventKind::Started,
+            1 => crate::api::process_watcher::ProcessEventKind::Exited,
+            _ => unreachable!("Invalid variant for ProcessEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_watcher::ProcessFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_namePatterns = <Vec<String>>::sse_decode(deserializer);
+        let mut var_pathPatterns = <Vec<String>>::sse_decode(deserializer);
+        let mut var_includeExisting = <bool>::sse_decode(deserializer);
+        return crate::api::process_watcher::ProcessFilter {
+            name_patterns: var_namePatterns,
+            path_patterns: var_pathPatterns,
+            include_existing: var_includeExisting,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_sampler::ProcessHistory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_samples =
+            <Vec<crate::api::process_sampler::ProcessSample>>::sse_decode(deserializer);
+        return crate::api::process_sampler::ProcessHistory {
+            pid: var_pid,
+            name: var_name,
+            samples: var_samples,
+        };
+    }
+}
+
+impl SseDecode for crate::api::get_all_process_list::ProcessInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_cmd = <Vec<String>>::sse_decode(deserializer);
+        let mut var_memoryUsage = <u64>::sse_decode(deserializer);
+        let mut var_cpuUsage = <f32>::sse_decode(deserializer);
+        let mut var_parentPid = <Option<u32>>::sse_decode(deserializer);
+        let mut var_user = <Option<String>>::sse_decode(deserializer);
+        let mut var_exePath = <Option<String>>::sse_decode(deserializer);
+        let mut var_startTime = <u64>::sse_decode(deserializer);
+        let mut var_runTime = <u64>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_diskReadBytes = <u64>::sse_decode(deserializer);
+        let mut var_diskWrittenBytes = <u64>::sse_decode(deserializer);
+        return crate::api::get_all_process_list::ProcessInfo {
+            pid: var_pid,
+            name: var_name,
+            cmd: var_cmd,
+            memory_usage: var_memoryUsage,
+            cpu_usage: var_cpuUsage,
+            parent_pid: var_parentPid,
+            user: var_user,
+            exe_path: var_exePath,
+            start_time: var_startTime,
+            run_time: var_runTime,
+            status: var_status,
+            disk_read_bytes: var_diskReadBytes,
+            disk_written_bytes: var_diskWrittenBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_sampler::ProcessSample {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        let mut var_cpuUsage = <f32>::sse_decode(deserializer);
+        let mut var_memoryBytes = <u64>::sse_decode(deserializer);
+        return crate::api::process_sampler::ProcessSample {
+            timestamp_ms: var_timestampMs,
+            cpu_usage: var_cpuUsage,
+            memory_bytes: var_memoryBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_sampler::ProcessSamplerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_intervalMs = <u64>::sse_decode(deserializer);
+        let mut var_historyLen = <u32>::sse_decode(deserializer);
+        return crate::api::process_sampler::ProcessSamplerConfig {
+            interval_ms: var_intervalMs,
+            history_len: var_historyLen,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_sampler::ProcessSortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::process_sampler::ProcessSortKey::Cpu,
+            1 => crate::api::process_sampler::ProcessSortKey::Memory,
+            _ => unreachable!("Invalid variant for ProcessSortKey: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::get_all_process_list::ProcessTreeNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_process =
+            <crate::api::get_all_process_list::ProcessInfo>::sse_decode(deserializer);
+        let mut var_children =
+            <Vec<crate::api::get_all_process_list::ProcessTreeNode>>::sse_decode(deserializer);
+        return crate::api::get_all_process_list::ProcessTreeNode {
+            process: var_process,
+            children: var_children,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_sampler::ProcessUsageSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pid = <u32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_averageCpu = <f32>::sse_decode(deserializer);
+        let mut var_peakCpu = <f32>::sse_decode(deserializer);
+        let mut var_averageMemoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_peakMemoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_sampleCount = <u32>::sse_decode(deserializer);
+        return crate::api::process_sampler::ProcessUsageSummary {
+            pid: var_pid,
+            name: var_name,
+            average_cpu: var_averageCpu,
+            peak_cpu: var_peakCpu,
+            average_memory_bytes: var_averageMemoryBytes,
+            peak_memory_bytes: var_peakMemoryBytes,
+            sample_count: var_sampleCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::desktop_notification::QuietHours {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMinute = <u32>::sse_decode(deserializer);
+        let mut var_endMinute = <u32>::sse_decode(deserializer);
+        let mut var_utcOffsetMinutes = <i32>::sse_decode(deserializer);
+        return crate::api::desktop_notification::QuietHours {
+            start_minute: var_startMinute,
+            end_minute: var_endMinute,
+            utc_offset_minutes: var_utcOffsetMinutes,
+        };
+    }
+}
+
+impl SseDecode for (f64, f64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <f64>::sse_decode(deserializer);
+        let mut var_field1 = <f64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (i32, i32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <i32>::sse_decode(deserializer);
+        let mut var_field1 = <i32>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for (i32, i32, i32, i32) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <i32>::sse_decode(deserializer);
+        let mut var_field1 = <i32>::sse_decode(deserializer);
+        let mut var_field2 = <i32>::sse_decode(deserializer);
+        let mut var_field3 = <i32>::sse_decode(deserializer);
+        return (var_field0, var_field1, var_field2, var_field3);
+    }
+}
+
+impl SseDecode for crate::api::resource_budget::ResourceBudgetConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxCpuPercent = <f32>::sse_decode(deserializer);
+        let mut var_maxMemoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_sampleIntervalMs = <u64>::sse_decode(deserializer);
+        let mut var_maxThrottleFactor = <f64>::sse_decode(deserializer);
+        let mut var_batteryThrottleFactor = <f64>::sse_decode(deserializer);
+        return crate::api::resource_budget::ResourceBudgetConfig {
+            max_cpu_percent: var_maxCpuPercent,
+            max_memory_bytes: var_maxMemoryBytes,
+            sample_interval_ms: var_sampleIntervalMs,
+            max_throttle_factor: var_maxThrottleFactor,
+            battery_throttle_factor: var_batteryThrottleFactor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::resource_budget::ResourceBudgetStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_running = <bool>::sse_decode(deserializer);
+        let mut var_cpuPercent = <f32>::sse_decode(deserializer);
+        let mut var_memoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_onBattery = <bool>::sse_decode(deserializer);
+        let mut var_throttleFactor = <f64>::sse_decode(deserializer);
+        let mut var_encodingDeferred = <bool>::sse_decode(deserializer);
+        let mut var_inputPollIntervalMs = <u64>::sse_decode(deserializer);
+        let mut var_windowPollIntervalMs = <u64>::sse_decode(deserializer);
+        return crate::api::resource_budget::ResourceBudgetStatus {
+            running: var_running,
+            cpu_percent: var_cpuPercent,
+            memory_bytes: var_memoryBytes,
+            on_battery: var_onBattery,
+            throttle_factor: var_throttleFactor,
+            encoding_deferred: var_encodingDeferred,
+            input_poll_interval_ms: var_inputPollIntervalMs,
+            window_poll_interval_ms: var_windowPollIntervalMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_capture::Screenshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_data = <String>::sse_decode(deserializer);
+        let mut var_format =
+            <crate::api::screenshot_capture::ScreenshotFormat>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_capturedAtMs = <u64>::sse_decode(deserializer);
+        let mut var_analysis =
+            <crate::api::frame_analysis::FrameAnalysis>::sse_decode(deserializer);
+        let mut var_monitorId = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::screenshot_capture::Screenshot {
+            data: var_data,
+            format: var_format,
+            width: var_width,
+            height: var_height,
+            captured_at_ms: var_capturedAtMs,
+            analysis: var_analysis,
+            monitor_id: var_monitorId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_capture::ScreenshotFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::screenshot_capture::ScreenshotFormat::Png,
+            1 => crate::api::screenshot_capture::ScreenshotFormat::Jpeg,
+            _ => unreachable!("Invalid variant for ScreenshotFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_capture::ScreenshotOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_format =
+            <crate::api::screenshot_capture::ScreenshotFormat>::sse_decode(deserializer);
+        let mut var_jpegQuality = <u8>::sse_decode(deserializer);
+        let mut var_maxWidth = <Option<u32>>::sse_decode(deserializer);
+        let mut var_watermark =
+            <Option<crate::api::screenshot_watermark::WatermarkOptions>>::sse_decode(deserializer);
+        let mut var_includeCursor = <bool>::sse_decode(deserializer);
+        return crate::api::screenshot_capture::ScreenshotOptions {
+            format: var_format,
+            jpeg_quality: var_jpegQuality,
+            max_width: var_maxWidth,
+            watermark: var_watermark,
+            include_cursor: var_includeCursor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_scheduler::ScreenshotScheduleConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_intervalSeconds = <u64>::sse_decode(deserializer);
+        let mut var_capturesPerInterval = <u32>::sse_decode(deserializer);
+        let mut var_idleThresholdSeconds = <u64>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_options =
+            <crate::api::screenshot_capture::ScreenshotOptions>::sse_decode(deserializer);
+        return crate::api::screenshot_scheduler::ScreenshotScheduleConfig {
+            interval_seconds: var_intervalSeconds,
+            captures_per_interval: var_capturesPerInterval,
+            idle_threshold_seconds: var_idleThresholdSeconds,
+            seed: var_seed,
+            options: var_options,
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_spool::SpoolEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_capturedAtMs = <u64>::sse_decode(deserializer);
+        return crate::api::screenshot_spool::SpoolEntry {
+            path: var_path,
+            size_bytes: var_sizeBytes,
+            captured_at_ms: var_capturedAtMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_spool::SpoolQuotaResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_deletedPaths = <Vec<String>>::sse_decode(deserializer);
+        let mut var_remainingFiles = <u32>::sse_decode(deserializer);
+        let mut var_remainingBytes = <u64>::sse_decode(deserializer);
+        return crate::api::screenshot_spool::SpoolQuotaResult {
+            deleted_paths: var_deletedPaths,
+            remaining_files: var_remainingFiles,
+            remaining_bytes: var_remainingBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_spool::SpooledScreenshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_format =
+            <crate::api::screenshot_capture::ScreenshotFormat>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_capturedAtMs = <u64>::sse_decode(deserializer);
+        let mut var_analysis =
+            <crate::api::frame_analysis::FrameAnalysis>::sse_decode(deserializer);
+        return crate::api::screenshot_spool::SpooledScreenshot {
+            path: var_path,
+            format: var_format,
+            width: var_width,
+            height: var_height,
+            size_bytes: var_sizeBytes,
+            captured_at_ms: var_capturedAtMs,
+            analysis: var_analysis,
+        };
+    }
+}
+
+impl SseDecode for crate::api::system_monitor::SystemSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        let mut var_globalCpuUsage = <f32>::sse_decode(deserializer);
+        let mut var_cpuCores =
+            <Vec<crate::api::system_monitor::CpuCoreUsage>>::sse_decode(deserializer);
+        let mut var_totalMemoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_usedMemoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_availableMemoryBytes = <u64>::sse_decode(deserializer);
+        let mut var_totalSwapBytes = <u64>::sse_decode(deserializer);
+        let mut var_usedSwapBytes = <u64>::sse_decode(deserializer);
+        let mut var_loadAverage =
+            <crate::api::system_monitor::LoadAverage>::sse_decode(deserializer);
+        let mut var_uptimeSeconds = <u64>::sse_decode(deserializer);
+        let mut var_bootTime = <u64>::sse_decode(deserializer);
+        let mut var_disks =
+            <Vec<crate::api::system_monitor::DiskUsageInfo>>::sse_decode(deserializer);
+        let mut var_networks =
+            <Vec<crate::api::system_monitor::NetworkInterfaceUsage>>::sse_decode(deserializer);
+        return crate::api::system_monitor::SystemSnapshot {
+            timestamp_ms: var_timestampMs,
+            global_cpu_usage: var_globalCpuUsage,
+            cpu_cores: var_cpuCores,
+            total_memory_bytes: var_totalMemoryBytes,
+            used_memory_bytes: var_usedMemoryBytes,
+            available_memory_bytes: var_availableMemoryBytes,
+            total_swap_bytes: var_totalSwapBytes,
+            used_swap_bytes: var_usedSwapBytes,
+            load_average: var_loadAverage,
+            uptime_seconds: var_uptimeSeconds,
+            boot_time: var_bootTime,
+            disks: var_disks,
+            networks: var_networks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_termination::TerminateOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_signal =
+            <crate::api::process_termination::TerminateSignal>::sse_decode(deserializer);
+        let mut var_gracePeriodMs = <u64>::sse_decode(deserializer);
+        let mut var_killTree = <bool>::sse_decode(deserializer);
+        return crate::api::process_termination::TerminateOptions {
+            signal: var_signal,
+            grace_period_ms: var_gracePeriodMs,
+            kill_tree: var_killTree,
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_termination::TerminateOutcome {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::process_termination::TerminateOutcome::Exited,
+            1 => crate::api::process_termination::TerminateOutcome::ForceKilled,
+            2 => crate::api::process_termination::TerminateOutcome::NotFound,
+            3 => crate::api::process_termination::TerminateOutcome::PermissionDenied,
+            4 => crate::api::process_termination::TerminateOutcome::StillRunning,
+            _ => unreachable!("Invalid variant for TerminateOutcome: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::process_termination::TerminateSignal {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::process_termination::TerminateSignal::Term,
+            1 => crate::api::process_termination::TerminateSignal::Interrupt,
+            2 => crate::api::process_termination::TerminateSignal::Hangup,
+            3 => crate::api::process_termination::TerminateSignal::Quit,
+            4 => crate::api::process_termination::TerminateSignal::Kill,
+            _ => unreachable!("Invalid variant for TerminateSignal: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::timelapse_recorder::TimelapseConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_format =
+            <crate::api::timelapse_recorder::TimelapseFormat>::sse_decode(deserializer);
+        let mut var_frameIntervalSeconds = <u64>::sse_decode(deserializer);
+        let mut var_maxWidth = <u32>::sse_decode(deserializer);
+        let mut var_playbackFps = <u32>::sse_decode(deserializer);
+        let mut var_jpegQuality = <u8>::sse_decode(deserializer);
+        let mut var_maxFileSizeBytes = <u64>::sse_decode(deserializer);
+        return crate::api::timelapse_recorder::TimelapseConfig {
+            output_path: var_outputPath,
+            format: var_format,
+            frame_interval_seconds: var_frameIntervalSeconds,
+            max_width: var_maxWidth,
+            playback_fps: var_playbackFps,
+            jpeg_quality: var_jpegQuality,
+            max_file_size_bytes: var_maxFileSizeBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::timelapse_recorder::TimelapseFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::timelapse_recorder::TimelapseFormat::Gif,
+            1 => crate::api::timelapse_recorder::TimelapseFormat::MjpegAvi,
+            _ => unreachable!("Invalid variant for TimelapseFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::timelapse_recorder::TimelapseState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::timelapse_recorder::TimelapseState::Recording,
+            1 => crate::api::timelapse_recorder::TimelapseState::Paused,
+            2 => crate::api::timelapse_recorder::TimelapseState::Stopped,
+            3 => crate::api::timelapse_recorder::TimelapseState::SizeLimitReached,
+            4 => crate::api::timelapse_recorder::TimelapseState::Failed,
+            _ => unreachable!("Invalid variant for TimelapseState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::timelapse_recorder::TimelapseStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_state =
+            <crate::api::timelapse_recorder::TimelapseState>::sse_decode(deserializer);
+        let mut var_outputPath = <String>::sse_decode(deserializer);
+        let mut var_framesWritten = <u32>::sse_decode(deserializer);
+        let mut var_bytesWritten = <u64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::timelapse_recorder::TimelapseStatus {
+            state: var_state,
+            output_path: var_outputPath,
+            frames_written: var_framesWritten,
+            bytes_written: var_bytesWritten,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::screenshot_watermark::WatermarkOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_position =
+            <crate::api::screenshot_watermark::WatermarkPosition>::sse_decode(deserializer);
+        let mut var_opacity = <f32>::sse_decode(deserializer);
+        let mut var_scale = <Option<u32>>::sse_decode(deserializer);
+        let mut var_includeHostname = <bool>::sse_decode(deserializer);
+        let mut var_includeUser = <bool>::sse_decode(deserializer);
+        let mut var_taskId = <Option<String>>::sse_decode(deserializer);
+        let mut var_customText = <Option<String>>::sse_decode(deserializer);
+        return crate::api::screenshot_watermark::WatermarkOptions {
+            position: var_position,
+            opacity: var_opacity,
+            scale: var_scale,
+            include_hostname: var_includeHostname,
+            include_user: var_includeUser,
+            task_id: var_taskId,
+            custom_text: var_customText,
+        };
+    }
+}
+
+impl SseDecode for crate::api::screenshot_watermark::WatermarkPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::screenshot_watermark::WatermarkPosition::TopLeft,
+            1 => crate::api::screenshot_watermark::WatermarkPosition::TopRight,
+            2 => crate::api::screenshot_watermark::WatermarkPosition::BottomLeft,
+            3 => crate::api::screenshot_watermark::WatermarkPosition::BottomRight,
+            _ => unreachable!("Invalid variant for WatermarkPosition: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::active_window_listener::WindowDetails {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <u32>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_position = <(i32, i32, i32, i32)>::sse_decode(deserializer);
+        let mut var_monitor =
+            <Option<crate::api::display_geometry::MonitorLocation>>::sse_decode(deserializer);
+        let mut var_isFullScreen = <bool>::sse_decode(deserializer);
+        let mut var_processName = <String>::sse_decode(deserializer);
+        let mut var_processPath = <String>::sse_decode(deserializer);
+        let mut var_processId = <u32>::sse_decode(deserializer);
+        let mut var_os = <String>::sse_decode(deserializer);
+        return crate::api::active_window_listener::WindowDetails {
+            id: var_id,
+            title: var_title,
+            position: var_position,
+            monitor: var_monitor,
+            is_full_screen: var_isFullScreen,
+            process_name: var_processName,
             process_path: var_processPath,
             process_id: var_processId,
             os: var_os,
@@ -2209,625 +6582,3699 @@ impl SseDecode for crate::api::active_window_listener::WindowDetails {
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__get_all_process_list__can_access_processes_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__take_full_screenshot__check_linux_environment_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__take_full_screenshot__check_windows_environment_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__take_full_screenshot__extract_bundled_nircmd_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__get_all_process_list__find_process_by_name_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__api__get_all_process_list__get_all_processes_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__get_all_process_list__get_process_access_info_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        14 => {
-            wire__crate__api__take_full_screenshot__init_app_impl(port, ptr, rust_vec_len, data_len)
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__click_heatmap__add_heatmap_click_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__get_all_process_list__can_access_processes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__screenshot_capture__capture_active_window_monitor_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__delta_encoding__capture_delta_frame_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__screenshot_capture__capture_monitor_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__screenshot_capture__capture_screenshot_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__screenshot_spool__capture_to_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__window_capture__capture_window_contents_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__desktop_notification__category_gate_stats_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__take_full_screenshot__check_linux_environment_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__take_full_screenshot__check_windows_environment_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__persistent_notification__close_notification_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__delta_encoding__decode_delta_frame_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__screenshot_spool__delete_spooled_screenshot_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__delta_encoding__delta_encoder_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__delta_encoding__encode_delta_frame_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__screenshot_spool__enforce_spool_quota_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__click_heatmap__export_click_heatmap_png_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__take_full_screenshot__extract_bundled_nircmd_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__get_all_process_list__find_process_by_name_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__frame_analysis__frame_analysis_is_blank_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__get_all_process_list__get_all_processes_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__get_all_process_list__get_process_access_info_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__get_all_process_list__get_process_tree_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__system_monitor__get_system_snapshot_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        49 => {
+            wire__crate__api__take_full_screenshot__init_app_impl(port, ptr, rust_vec_len, data_len)
+        }
+        50 => wire__crate__api__take_full_screenshot__is_nircmd_available_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__resource_budget__is_on_battery_power_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__get_all_process_list__kill_process_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        55 => wire__crate__api__screenshot_spool__list_spooled_screenshots_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => {
+            wire__crate__api__desktop_notification__notification_backend_capabilities_default_impl(
+                port,
+                ptr,
+                rust_vec_len,
+                data_len,
+            )
+        }
+        59 => wire__crate__api__desktop_notification__notification_backend_preference_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        60 => wire__crate__api__desktop_notification__notification_gate_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => wire__crate__api__desktop_notification__notification_gate_stats_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__api__desktop_notification__probe_notification_backends_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        66 => wire__crate__api__process_watcher__process_filter_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => wire__crate__api__process_sampler__process_sampler_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        68 => wire__crate__api__desktop_notification__register_module_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__resource_budget__resource_budget_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => wire__crate__api__screenshot_capture__screenshot_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        75 => wire__crate__api__desktop_notification__send_gated_notification_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => wire__crate__api__notification_actions__send_interactive_notification_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        77 => wire__crate__api__desktop_notification__send_notification_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        78 => wire__crate__api__desktop_notification__send_notification_with_options_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        82 => wire__crate__api__persistent_notification__show_persistent_notification_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        85 => wire__crate__api__keyboard_listener__start_keyboard_listener_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => wire__crate__api__mouse_listener__start_mouse_listener_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        92 => wire__crate__api__timelapse_recorder__start_timelapse_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        102 => wire__crate__api__timelapse_recorder__stop_timelapse_recording_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        104 => wire__crate__api__take_full_screenshot__take_full_screenshot_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__api__take_full_screenshot__take_screenshot_linux_fallback_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        106 => wire__crate__api__take_full_screenshot__take_screenshot_windows_csharp_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        107 => wire__crate__api__take_full_screenshot__take_screenshot_windows_directshow_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        108 => wire__crate__api__take_full_screenshot__take_screenshot_windows_ffmpeg_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        109 => wire__crate__api__take_full_screenshot__take_screenshot_windows_memory_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__take_full_screenshot__take_screenshot_windows_nircmd_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => wire__crate__api__take_full_screenshot__take_screenshot_windows_powershell_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        112 => wire__crate__api__take_full_screenshot__take_screenshot_windows_vbscript_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__crate__api__take_full_screenshot__take_screenshot_windows_win32_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => wire__crate__api__take_full_screenshot__take_screenshot_windows_wmi_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        115 => wire__crate__api__take_full_screenshot__take_screenshot_with_screenshots_crate_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__crate__api__process_termination__terminate_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => wire__crate__api__process_termination__terminate_process_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        118 => wire__crate__api__take_full_screenshot__test_all_available_methods_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        119 => wire__crate__api__take_full_screenshot__test_bundled_nircmd_extraction_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        120 => wire__crate__api__take_full_screenshot__test_linux_environment_check_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        121 => wire__crate__api__take_full_screenshot__test_linux_fallback_methods_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        122 => wire__crate__api__take_full_screenshot__test_nircmd_availability_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        123 => wire__crate__api__take_full_screenshot__test_nircmd_capabilities_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        124 => wire__crate__api__take_full_screenshot__test_nircmd_screenshot_simple_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        125 => wire__crate__api__take_full_screenshot__test_screenshots_crate_method_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        126 => wire__crate__api__take_full_screenshot__test_windows_environment_check_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        127 => wire__crate__api__take_full_screenshot__test_windows_method_1_nircmd_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        128 => wire__crate__api__take_full_screenshot__test_windows_method_2_powershell_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        129 => wire__crate__api__take_full_screenshot__test_windows_method_3_memory_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        130 => wire__crate__api__take_full_screenshot__test_windows_method_4_directshow_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        131 => wire__crate__api__take_full_screenshot__test_windows_method_5_win32_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        132 => wire__crate__api__take_full_screenshot__test_windows_method_6_wmi_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        133 => wire__crate__api__take_full_screenshot__test_windows_method_7_ffmpeg_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        134 => wire__crate__api__take_full_screenshot__test_windows_method_8_csharp_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        135 => wire__crate__api__take_full_screenshot__test_windows_method_9_vbscript_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        137 => wire__crate__api__persistent_notification__update_notification_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        138 => wire__crate__api__screenshot_watermark__watermark_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire__crate__api__frame_analysis__analyze_screenshot_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__desktop_notification__configure_notification_gate_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => {
+            wire__crate__api__click_heatmap__create_click_heatmap_impl(ptr, rust_vec_len, data_len)
+        }
+        16 => {
+            wire__crate__api__delta_encoding__create_delta_decoder_impl(ptr, rust_vec_len, data_len)
+        }
+        17 => {
+            wire__crate__api__delta_encoding__create_delta_encoder_impl(ptr, rust_vec_len, data_len)
+        }
+        21 => {
+            wire__crate__api__click_heatmap__destroy_click_heatmap_impl(ptr, rust_vec_len, data_len)
+        }
+        22 => wire__crate__api__delta_encoding__destroy_delta_decoder_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__delta_encoding__destroy_delta_encoder_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__click_heatmap__export_click_heatmap_grid_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => {
+            wire__crate__api__delta_encoding__force_delta_keyframe_impl(ptr, rust_vec_len, data_len)
+        }
+        32 => wire__crate__api__active_window_listener__get_active_window_info_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__cmdline_redaction__get_cmdline_redaction_patterns_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__active_window_listener__get_current_platform_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__display_geometry__get_display_geometry_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__desktop_notification__get_notification_backend_preference_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__desktop_notification__get_notification_gate_stats_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__active_window_listener__get_open_windows_info_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => {
+            wire__crate__api__process_sampler__get_process_history_impl(ptr, rust_vec_len, data_len)
+        }
+        43 => wire__crate__api__resource_budget__get_resource_budget_status_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__timelapse_recorder__get_timelapse_status_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__crate__api__active_window_listener__get_window_icon_data_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__active_window_listener__is_platform_supported_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__process_sampler__is_process_sampler_running_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__display_geometry__logical_to_physical_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__display_geometry__native_coordinate_space_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => wire__crate__api__screenshot_scheduler__pause_screenshot_scheduler_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        63 => wire__crate__api__timelapse_recorder__pause_timelapse_recording_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__api__display_geometry__physical_to_logical_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => {
+            wire__crate__api__click_heatmap__reset_click_heatmap_impl(ptr, rust_vec_len, data_len)
+        }
+        70 => wire__crate__api__desktop_notification__reset_notification_gate_stats_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__screenshot_scheduler__resume_screenshot_scheduler_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__timelapse_recorder__resume_timelapse_recording_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        79 => wire__crate__api__cmdline_redaction__set_cmdline_redaction_patterns_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        80 => wire__crate__api__desktop_notification__set_do_not_disturb_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        81 => wire__crate__api__desktop_notification__set_notification_backend_preference_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        83 => wire__crate__api__app_policy__start_app_policy_impl(ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__capture_preview__start_capture_preview_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        87 => wire__crate__api__process_sampler__start_process_sampler_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        88 => wire__crate__api__process_watcher__start_process_watcher_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        89 => wire__crate__api__resource_budget__start_resource_budget_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => wire__crate__api__screenshot_scheduler__start_screenshot_scheduler_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        91 => {
+            wire__crate__api__system_monitor__start_system_monitor_impl(ptr, rust_vec_len, data_len)
+        }
+        93 => wire__crate__api__active_window_listener__start_window_listener_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        94 => wire__crate__api__active_window_listener__start_window_listener_stream_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        95 => wire__crate__api__app_policy__stop_app_policy_impl(ptr, rust_vec_len, data_len),
+        96 => wire__crate__api__capture_preview__stop_capture_preview_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        97 => wire__crate__api__process_sampler__stop_process_sampler_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        98 => wire__crate__api__process_watcher__stop_process_watcher_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__api__resource_budget__stop_resource_budget_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => wire__crate__api__screenshot_scheduler__stop_screenshot_scheduler_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        101 => {
+            wire__crate__api__system_monitor__stop_system_monitor_impl(ptr, rust_vec_len, data_len)
+        }
+        103 => wire__crate__api__active_window_listener__stop_window_listener_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        136 => wire__crate__api__process_sampler__top_processes_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::CategoryGateStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.sent.into_into_dart().into_dart(),
+            self.deduplicated.into_into_dart().into_dart(),
+            self.rate_limited.into_into_dart().into_dart(),
+            self.queued.into_into_dart().into_dart(),
+            self.dropped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::CategoryGateStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::CategoryGateStats>
+    for crate::api::desktop_notification::CategoryGateStats
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::CategoryGateStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::display_geometry::CoordinateSpace {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Logical => 0.into_dart(),
+            Self::Physical => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::display_geometry::CoordinateSpace
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::display_geometry::CoordinateSpace>
+    for crate::api::display_geometry::CoordinateSpace
+{
+    fn into_into_dart(self) -> crate::api::display_geometry::CoordinateSpace {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::system_monitor::CpuCoreUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.usage.into_into_dart().into_dart(),
+            self.frequency_mhz.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::system_monitor::CpuCoreUsage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::system_monitor::CpuCoreUsage>
+    for crate::api::system_monitor::CpuCoreUsage
+{
+    fn into_into_dart(self) -> crate::api::system_monitor::CpuCoreUsage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::delta_encoding::DeltaEncoderConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tile_size.into_into_dart().into_dart(),
+            self.keyframe_interval.into_into_dart().into_dart(),
+            self.max_changed_ratio.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::delta_encoding::DeltaEncoderConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::delta_encoding::DeltaEncoderConfig>
+    for crate::api::delta_encoding::DeltaEncoderConfig
+{
+    fn into_into_dart(self) -> crate::api::delta_encoding::DeltaEncoderConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::system_monitor::DiskUsageInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.mount_point.into_into_dart().into_dart(),
+            self.file_system.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.available_bytes.into_into_dart().into_dart(),
+            self.is_removable.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::system_monitor::DiskUsageInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::system_monitor::DiskUsageInfo>
+    for crate::api::system_monitor::DiskUsageInfo
+{
+    fn into_into_dart(self) -> crate::api::system_monitor::DiskUsageInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::display_geometry::DisplayGeometry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.is_primary.into_into_dart().into_dart(),
+            self.scale_factor.into_into_dart().into_dart(),
+            self.logical.into_into_dart().into_dart(),
+            self.physical.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::display_geometry::DisplayGeometry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::display_geometry::DisplayGeometry>
+    for crate::api::display_geometry::DisplayGeometry
+{
+    fn into_into_dart(self) -> crate::api::display_geometry::DisplayGeometry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::display_geometry::DisplayRect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::display_geometry::DisplayRect
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::display_geometry::DisplayRect>
+    for crate::api::display_geometry::DisplayRect
+{
+    fn into_into_dart(self) -> crate::api::display_geometry::DisplayRect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::delta_encoding::EncodedFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.is_keyframe.into_into_dart().into_dart(),
+            self.frame_index.into_into_dart().into_dart(),
+            self.changed_tiles.into_into_dart().into_dart(),
+            self.total_tiles.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::delta_encoding::EncodedFrame
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::delta_encoding::EncodedFrame>
+    for crate::api::delta_encoding::EncodedFrame
+{
+    fn into_into_dart(self) -> crate::api::delta_encoding::EncodedFrame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::frame_analysis::FrameAnalysis {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.classification.into_into_dart().into_dart(),
+            self.mean_luminance.into_into_dart().into_dart(),
+            self.luminance_variance.into_into_dart().into_dart(),
+            self.dominant_bin_ratio.into_into_dart().into_dart(),
+            self.dark_pixel_ratio.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::frame_analysis::FrameAnalysis
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::frame_analysis::FrameAnalysis>
+    for crate::api::frame_analysis::FrameAnalysis
+{
+    fn into_into_dart(self) -> crate::api::frame_analysis::FrameAnalysis {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::frame_analysis::FrameClassification {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::Blank => 1.into_dart(),
+            Self::Uniform => 2.into_dart(),
+            Self::LikelyLockScreen => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::frame_analysis::FrameClassification
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::frame_analysis::FrameClassification>
+    for crate::api::frame_analysis::FrameClassification
+{
+    fn into_into_dart(self) -> crate::api::frame_analysis::FrameClassification {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::GateDecision {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Sent => 0.into_dart(),
+            Self::Deduplicated => 1.into_dart(),
+            Self::RateLimited => 2.into_dart(),
+            Self::Queued => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::GateDecision
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::GateDecision>
+    for crate::api::desktop_notification::GateDecision
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::GateDecision {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::GatedSendResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.decision.into_into_dart().into_dart(),
+            self.backend.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::GatedSendResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::GatedSendResult>
+    for crate::api::desktop_notification::GatedSendResult
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::GatedSendResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::click_heatmap::HeatmapConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.scope.into_into_dart().into_dart(),
+            self.grid_width.into_into_dart().into_dart(),
+            self.grid_height.into_into_dart().into_dart(),
+            self.include_right_clicks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::click_heatmap::HeatmapConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::click_heatmap::HeatmapConfig>
+    for crate::api::click_heatmap::HeatmapConfig
+{
+    fn into_into_dart(self) -> crate::api::click_heatmap::HeatmapConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::click_heatmap::HeatmapGrid {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.scope.into_into_dart().into_dart(),
+            self.grid_width.into_into_dart().into_dart(),
+            self.grid_height.into_into_dart().into_dart(),
+            self.cells.into_into_dart().into_dart(),
+            self.total_clicks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::click_heatmap::HeatmapGrid
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::click_heatmap::HeatmapGrid>
+    for crate::api::click_heatmap::HeatmapGrid
+{
+    fn into_into_dart(self) -> crate::api::click_heatmap::HeatmapGrid {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::click_heatmap::HeatmapScope {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::click_heatmap::HeatmapScope
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::click_heatmap::HeatmapScope>
+    for crate::api::click_heatmap::HeatmapScope
+{
+    fn into_into_dart(self) -> crate::api::click_heatmap::HeatmapScope {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::click_heatmap::HeatmapScopeKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Monitor => 0.into_dart(),
+            Self::Window => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::click_heatmap::HeatmapScopeKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::click_heatmap::HeatmapScopeKind>
+    for crate::api::click_heatmap::HeatmapScopeKind
+{
+    fn into_into_dart(self) -> crate::api::click_heatmap::HeatmapScopeKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::keyboard_listener::KeyboardEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.is_key_press.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::keyboard_listener::KeyboardEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::keyboard_listener::KeyboardEvent>
+    for crate::api::keyboard_listener::KeyboardEvent
+{
+    fn into_into_dart(self) -> crate::api::keyboard_listener::KeyboardEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::system_monitor::LoadAverage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.one.into_into_dart().into_dart(),
+            self.five.into_into_dart().into_dart(),
+            self.fifteen.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::system_monitor::LoadAverage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::system_monitor::LoadAverage>
+    for crate::api::system_monitor::LoadAverage
+{
+    fn into_into_dart(self) -> crate::api::system_monitor::LoadAverage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::display_geometry::MonitorLocation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.monitor_id.into_into_dart().into_dart(),
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::display_geometry::MonitorLocation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::display_geometry::MonitorLocation>
+    for crate::api::display_geometry::MonitorLocation
+{
+    fn into_into_dart(self) -> crate::api::display_geometry::MonitorLocation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::mouse_listener::MouseEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.button.into_into_dart().into_dart(),
+            self.is_button_press.into_into_dart().into_dart(),
+            self.coords.into_into_dart().into_dart(),
+            self.monitor.into_into_dart().into_dart(),
+            self.is_left_click.into_into_dart().into_dart(),
+            self.is_right_click.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::mouse_listener::MouseEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::mouse_listener::MouseEvent>
+    for crate::api::mouse_listener::MouseEvent
+{
+    fn into_into_dart(self) -> crate::api::mouse_listener::MouseEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::system_monitor::NetworkInterfaceUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.received_bytes.into_into_dart().into_dart(),
+            self.transmitted_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::system_monitor::NetworkInterfaceUsage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::system_monitor::NetworkInterfaceUsage>
+    for crate::api::system_monitor::NetworkInterfaceUsage
+{
+    fn into_into_dart(self) -> crate::api::system_monitor::NetworkInterfaceUsage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::notification_actions::NotificationAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::notification_actions::NotificationAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::notification_actions::NotificationAction>
+    for crate::api::notification_actions::NotificationAction
+{
+    fn into_into_dart(self) -> crate::api::notification_actions::NotificationAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::NotificationBackend {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NotifyRust => 0.into_dart(),
+            Self::NotifySend => 1.into_dart(),
+            Self::Kdialog => 2.into_dart(),
+            Self::Zenity => 3.into_dart(),
+            Self::Xmessage => 4.into_dart(),
+            Self::TerminalNotifier => 5.into_dart(),
+            Self::AppleScript => 6.into_dart(),
+            Self::PowerShellToast => 7.into_dart(),
+            Self::PowerShellBalloon => 8.into_dart(),
+            Self::MessageBox => 9.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::NotificationBackend
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::NotificationBackend>
+    for crate::api::desktop_notification::NotificationBackend
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::NotificationBackend {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::desktop_notification::NotificationBackendCapabilities
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.actions.into_into_dart().into_dart(),
+            self.body_markup.into_into_dart().into_dart(),
+            self.icons.into_into_dart().into_dart(),
+            self.persistence.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::NotificationBackendCapabilities
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::desktop_notification::NotificationBackendCapabilities,
+    > for crate::api::desktop_notification::NotificationBackendCapabilities
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::NotificationBackendCapabilities {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::desktop_notification::NotificationBackendPreference
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pinned.into_into_dart().into_dart(),
+            self.excluded.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::NotificationBackendPreference
+{
+}
+impl
+    flutter_rust_bridge::IntoIntoDart<
+        crate::api::desktop_notification::NotificationBackendPreference,
+    > for crate::api::desktop_notification::NotificationBackendPreference
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::NotificationBackendPreference {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::NotificationBackendProbe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.backend.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+            self.disabled.into_into_dart().into_dart(),
+            self.capabilities.into_into_dart().into_dart(),
+            self.server.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::NotificationBackendProbe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::NotificationBackendProbe>
+    for crate::api::desktop_notification::NotificationBackendProbe
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::NotificationBackendProbe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::notification_actions::NotificationEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.action_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::notification_actions::NotificationEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::notification_actions::NotificationEvent>
+    for crate::api::notification_actions::NotificationEvent
+{
+    fn into_into_dart(self) -> crate::api::notification_actions::NotificationEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::notification_actions::NotificationEventKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ActionInvoked => 0.into_dart(),
+            Self::Dismissed => 1.into_dart(),
+            Self::Expired => 2.into_dart(),
+            Self::Closed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::notification_actions::NotificationEventKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::notification_actions::NotificationEventKind>
+    for crate::api::notification_actions::NotificationEventKind
+{
+    fn into_into_dart(self) -> crate::api::notification_actions::NotificationEventKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::NotificationGateConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dedupe_window_seconds.into_into_dart().into_dart(),
+            self.max_per_category.into_into_dart().into_dart(),
+            self.rate_window_seconds.into_into_dart().into_dart(),
+            self.quiet_hours.into_into_dart().into_dart(),
+            self.do_not_disturb.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::NotificationGateConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::NotificationGateConfig>
+    for crate::api::desktop_notification::NotificationGateConfig
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::NotificationGateConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::NotificationGateStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pending.into_into_dart().into_dart(),
+            self.categories.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::NotificationGateStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::NotificationGateStats>
+    for crate::api::desktop_notification::NotificationGateStats
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::NotificationGateStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::NotificationServerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.vendor.into_into_dart().into_dart(),
+            self.version.into_into_dart().into_dart(),
+            self.spec_version.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::NotificationServerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::NotificationServerInfo>
+    for crate::api::desktop_notification::NotificationServerInfo
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::NotificationServerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::PolicyAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Log => 0.into_dart(),
+            Self::Notify => 1.into_dart(),
+            Self::WarnThenKill => 2.into_dart(),
+            Self::Kill => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::PolicyAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::PolicyAction>
+    for crate::api::app_policy::PolicyAction
+{
+    fn into_into_dart(self) -> crate::api::app_policy::PolicyAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::PolicyConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rules.into_into_dart().into_dart(),
+            self.interval_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::PolicyConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::PolicyConfig>
+    for crate::api::app_policy::PolicyConfig
+{
+    fn into_into_dart(self) -> crate::api::app_policy::PolicyConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::PolicyEnforcement {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Logged => 0.into_dart(),
+            Self::Notified => 1.into_dart(),
+            Self::Warned => 2.into_dart(),
+            Self::Killed => 3.into_dart(),
+            Self::KillFailed => 4.into_dart(),
+            Self::Exited => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::PolicyEnforcement
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::PolicyEnforcement>
+    for crate::api::app_policy::PolicyEnforcement
+{
+    fn into_into_dart(self) -> crate::api::app_policy::PolicyEnforcement {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::PolicyRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name_patterns.into_into_dart().into_dart(),
+            self.path_patterns.into_into_dart().into_dart(),
+            self.window_title_patterns.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.grace_seconds.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::PolicyRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::PolicyRule>
+    for crate::api::app_policy::PolicyRule
+{
+    fn into_into_dart(self) -> crate::api::app_policy::PolicyRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::app_policy::PolicyViolation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rule_id.into_into_dart().into_dart(),
+            self.pid.into_into_dart().into_dart(),
+            self.process_name.into_into_dart().into_dart(),
+            self.exe_path.into_into_dart().into_dart(),
+            self.window_title.into_into_dart().into_dart(),
+            self.enforcement.into_into_dart().into_dart(),
+            self.kill_at_ms.into_into_dart().into_dart(),
+            self.timestamp_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::app_policy::PolicyViolation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::app_policy::PolicyViolation>
+    for crate::api::app_policy::PolicyViolation
+{
+    fn into_into_dart(self) -> crate::api::app_policy::PolicyViolation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::capture_preview::PreviewFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.jpeg.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.captured_at_ms.into_into_dart().into_dart(),
+            self.classification.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::capture_preview::PreviewFrame
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::capture_preview::PreviewFrame>
+    for crate::api::capture_preview::PreviewFrame
+{
+    fn into_into_dart(self) -> crate::api::capture_preview::PreviewFrame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_watcher::ProcessEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.exe_path.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.run_duration_seconds.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_watcher::ProcessEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_watcher::ProcessEvent>
+    for crate::api::process_watcher::ProcessEvent
+{
+    fn into_into_dart(self) -> crate::api::process_watcher::ProcessEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_watcher::ProcessEventKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Started => 0.into_dart(),
+            Self::Exited => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_watcher::ProcessEventKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_watcher::ProcessEventKind>
+    for crate::api::process_watcher::ProcessEventKind
+{
+    fn into_into_dart(self) -> crate::api::process_watcher::ProcessEventKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_watcher::ProcessFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name_patterns.into_into_dart().into_dart(),
+            self.path_patterns.into_into_dart().into_dart(),
+            self.include_existing.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_watcher::ProcessFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_watcher::ProcessFilter>
+    for crate::api::process_watcher::ProcessFilter
+{
+    fn into_into_dart(self) -> crate::api::process_watcher::ProcessFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_sampler::ProcessHistory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.samples.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_sampler::ProcessHistory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_sampler::ProcessHistory>
+    for crate::api::process_sampler::ProcessHistory
+{
+    fn into_into_dart(self) -> crate::api::process_sampler::ProcessHistory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::get_all_process_list::ProcessInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.cmd.into_into_dart().into_dart(),
+            self.memory_usage.into_into_dart().into_dart(),
+            self.cpu_usage.into_into_dart().into_dart(),
+            self.parent_pid.into_into_dart().into_dart(),
+            self.user.into_into_dart().into_dart(),
+            self.exe_path.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.run_time.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.disk_read_bytes.into_into_dart().into_dart(),
+            self.disk_written_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::get_all_process_list::ProcessInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::get_all_process_list::ProcessInfo>
+    for crate::api::get_all_process_list::ProcessInfo
+{
+    fn into_into_dart(self) -> crate::api::get_all_process_list::ProcessInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_sampler::ProcessSample {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.timestamp_ms.into_into_dart().into_dart(),
+            self.cpu_usage.into_into_dart().into_dart(),
+            self.memory_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_sampler::ProcessSample
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_sampler::ProcessSample>
+    for crate::api::process_sampler::ProcessSample
+{
+    fn into_into_dart(self) -> crate::api::process_sampler::ProcessSample {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_sampler::ProcessSamplerConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.interval_ms.into_into_dart().into_dart(),
+            self.history_len.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_sampler::ProcessSamplerConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_sampler::ProcessSamplerConfig>
+    for crate::api::process_sampler::ProcessSamplerConfig
+{
+    fn into_into_dart(self) -> crate::api::process_sampler::ProcessSamplerConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_sampler::ProcessSortKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Cpu => 0.into_dart(),
+            Self::Memory => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_sampler::ProcessSortKey
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_sampler::ProcessSortKey>
+    for crate::api::process_sampler::ProcessSortKey
+{
+    fn into_into_dart(self) -> crate::api::process_sampler::ProcessSortKey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::get_all_process_list::ProcessTreeNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.process.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::get_all_process_list::ProcessTreeNode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::get_all_process_list::ProcessTreeNode>
+    for crate::api::get_all_process_list::ProcessTreeNode
+{
+    fn into_into_dart(self) -> crate::api::get_all_process_list::ProcessTreeNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_sampler::ProcessUsageSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pid.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.average_cpu.into_into_dart().into_dart(),
+            self.peak_cpu.into_into_dart().into_dart(),
+            self.average_memory_bytes.into_into_dart().into_dart(),
+            self.peak_memory_bytes.into_into_dart().into_dart(),
+            self.sample_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_sampler::ProcessUsageSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_sampler::ProcessUsageSummary>
+    for crate::api::process_sampler::ProcessUsageSummary
+{
+    fn into_into_dart(self) -> crate::api::process_sampler::ProcessUsageSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::desktop_notification::QuietHours {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_minute.into_into_dart().into_dart(),
+            self.end_minute.into_into_dart().into_dart(),
+            self.utc_offset_minutes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::desktop_notification::QuietHours
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::desktop_notification::QuietHours>
+    for crate::api::desktop_notification::QuietHours
+{
+    fn into_into_dart(self) -> crate::api::desktop_notification::QuietHours {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::resource_budget::ResourceBudgetConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_cpu_percent.into_into_dart().into_dart(),
+            self.max_memory_bytes.into_into_dart().into_dart(),
+            self.sample_interval_ms.into_into_dart().into_dart(),
+            self.max_throttle_factor.into_into_dart().into_dart(),
+            self.battery_throttle_factor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::resource_budget::ResourceBudgetConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::resource_budget::ResourceBudgetConfig>
+    for crate::api::resource_budget::ResourceBudgetConfig
+{
+    fn into_into_dart(self) -> crate::api::resource_budget::ResourceBudgetConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::resource_budget::ResourceBudgetStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.running.into_into_dart().into_dart(),
+            self.cpu_percent.into_into_dart().into_dart(),
+            self.memory_bytes.into_into_dart().into_dart(),
+            self.on_battery.into_into_dart().into_dart(),
+            self.throttle_factor.into_into_dart().into_dart(),
+            self.encoding_deferred.into_into_dart().into_dart(),
+            self.input_poll_interval_ms.into_into_dart().into_dart(),
+            self.window_poll_interval_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::resource_budget::ResourceBudgetStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::resource_budget::ResourceBudgetStatus>
+    for crate::api::resource_budget::ResourceBudgetStatus
+{
+    fn into_into_dart(self) -> crate::api::resource_budget::ResourceBudgetStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_capture::Screenshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.data.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.captured_at_ms.into_into_dart().into_dart(),
+            self.analysis.into_into_dart().into_dart(),
+            self.monitor_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_capture::Screenshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_capture::Screenshot>
+    for crate::api::screenshot_capture::Screenshot
+{
+    fn into_into_dart(self) -> crate::api::screenshot_capture::Screenshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_capture::ScreenshotFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Png => 0.into_dart(),
+            Self::Jpeg => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_capture::ScreenshotFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_capture::ScreenshotFormat>
+    for crate::api::screenshot_capture::ScreenshotFormat
+{
+    fn into_into_dart(self) -> crate::api::screenshot_capture::ScreenshotFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_capture::ScreenshotOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format.into_into_dart().into_dart(),
+            self.jpeg_quality.into_into_dart().into_dart(),
+            self.max_width.into_into_dart().into_dart(),
+            self.watermark.into_into_dart().into_dart(),
+            self.include_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_capture::ScreenshotOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_capture::ScreenshotOptions>
+    for crate::api::screenshot_capture::ScreenshotOptions
+{
+    fn into_into_dart(self) -> crate::api::screenshot_capture::ScreenshotOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_scheduler::ScreenshotScheduleConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.interval_seconds.into_into_dart().into_dart(),
+            self.captures_per_interval.into_into_dart().into_dart(),
+            self.idle_threshold_seconds.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.options.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_scheduler::ScreenshotScheduleConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_scheduler::ScreenshotScheduleConfig>
+    for crate::api::screenshot_scheduler::ScreenshotScheduleConfig
+{
+    fn into_into_dart(self) -> crate::api::screenshot_scheduler::ScreenshotScheduleConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_spool::SpoolEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+            self.captured_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_spool::SpoolEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_spool::SpoolEntry>
+    for crate::api::screenshot_spool::SpoolEntry
+{
+    fn into_into_dart(self) -> crate::api::screenshot_spool::SpoolEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_spool::SpoolQuotaResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.deleted_paths.into_into_dart().into_dart(),
+            self.remaining_files.into_into_dart().into_dart(),
+            self.remaining_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_spool::SpoolQuotaResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_spool::SpoolQuotaResult>
+    for crate::api::screenshot_spool::SpoolQuotaResult
+{
+    fn into_into_dart(self) -> crate::api::screenshot_spool::SpoolQuotaResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_spool::SpooledScreenshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+            self.captured_at_ms.into_into_dart().into_dart(),
+            self.analysis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_spool::SpooledScreenshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_spool::SpooledScreenshot>
+    for crate::api::screenshot_spool::SpooledScreenshot
+{
+    fn into_into_dart(self) -> crate::api::screenshot_spool::SpooledScreenshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::system_monitor::SystemSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.timestamp_ms.into_into_dart().into_dart(),
+            self.global_cpu_usage.into_into_dart().into_dart(),
+            self.cpu_cores.into_into_dart().into_dart(),
+            self.total_memory_bytes.into_into_dart().into_dart(),
+            self.used_memory_bytes.into_into_dart().into_dart(),
+            self.available_memory_bytes.into_into_dart().into_dart(),
+            self.total_swap_bytes.into_into_dart().into_dart(),
+            self.used_swap_bytes.into_into_dart().into_dart(),
+            self.load_average.into_into_dart().into_dart(),
+            self.uptime_seconds.into_into_dart().into_dart(),
+            self.boot_time.into_into_dart().into_dart(),
+            self.disks.into_into_dart().into_dart(),
+            self.networks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::system_monitor::SystemSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::system_monitor::SystemSnapshot>
+    for crate::api::system_monitor::SystemSnapshot
+{
+    fn into_into_dart(self) -> crate::api::system_monitor::SystemSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_termination::TerminateOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.signal.into_into_dart().into_dart(),
+            self.grace_period_ms.into_into_dart().into_dart(),
+            self.kill_tree.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_termination::TerminateOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_termination::TerminateOptions>
+    for crate::api::process_termination::TerminateOptions
+{
+    fn into_into_dart(self) -> crate::api::process_termination::TerminateOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_termination::TerminateOutcome {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Exited => 0.into_dart(),
+            Self::ForceKilled => 1.into_dart(),
+            Self::NotFound => 2.into_dart(),
+            Self::PermissionDenied => 3.into_dart(),
+            Self::StillRunning => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_termination::TerminateOutcome
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_termination::TerminateOutcome>
+    for crate::api::process_termination::TerminateOutcome
+{
+    fn into_into_dart(self) -> crate::api::process_termination::TerminateOutcome {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::process_termination::TerminateSignal {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Term => 0.into_dart(),
+            Self::Interrupt => 1.into_dart(),
+            Self::Hangup => 2.into_dart(),
+            Self::Quit => 3.into_dart(),
+            Self::Kill => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::process_termination::TerminateSignal
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::process_termination::TerminateSignal>
+    for crate::api::process_termination::TerminateSignal
+{
+    fn into_into_dart(self) -> crate::api::process_termination::TerminateSignal {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::timelapse_recorder::TimelapseConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.output_path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.frame_interval_seconds.into_into_dart().into_dart(),
+            self.max_width.into_into_dart().into_dart(),
+            self.playback_fps.into_into_dart().into_dart(),
+            self.jpeg_quality.into_into_dart().into_dart(),
+            self.max_file_size_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::timelapse_recorder::TimelapseConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::timelapse_recorder::TimelapseConfig>
+    for crate::api::timelapse_recorder::TimelapseConfig
+{
+    fn into_into_dart(self) -> crate::api::timelapse_recorder::TimelapseConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::timelapse_recorder::TimelapseFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Gif => 0.into_dart(),
+            Self::MjpegAvi => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::timelapse_recorder::TimelapseFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::timelapse_recorder::TimelapseFormat>
+    for crate::api::timelapse_recorder::TimelapseFormat
+{
+    fn into_into_dart(self) -> crate::api::timelapse_recorder::TimelapseFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::timelapse_recorder::TimelapseState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Recording => 0.into_dart(),
+            Self::Paused => 1.into_dart(),
+            Self::Stopped => 2.into_dart(),
+            Self::SizeLimitReached => 3.into_dart(),
+            Self::Failed => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::timelapse_recorder::TimelapseState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::timelapse_recorder::TimelapseState>
+    for crate::api::timelapse_recorder::TimelapseState
+{
+    fn into_into_dart(self) -> crate::api::timelapse_recorder::TimelapseState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::timelapse_recorder::TimelapseStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.state.into_into_dart().into_dart(),
+            self.output_path.into_into_dart().into_dart(),
+            self.frames_written.into_into_dart().into_dart(),
+            self.bytes_written.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::timelapse_recorder::TimelapseStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::timelapse_recorder::TimelapseStatus>
+    for crate::api::timelapse_recorder::TimelapseStatus
+{
+    fn into_into_dart(self) -> crate::api::timelapse_recorder::TimelapseStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_watermark::WatermarkOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.position.into_into_dart().into_dart(),
+            self.opacity.into_into_dart().into_dart(),
+            self.scale.into_into_dart().into_dart(),
+            self.include_hostname.into_into_dart().into_dart(),
+            self.include_user.into_into_dart().into_dart(),
+            self.task_id.into_into_dart().into_dart(),
+            self.custom_text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_watermark::WatermarkOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_watermark::WatermarkOptions>
+    for crate::api::screenshot_watermark::WatermarkOptions
+{
+    fn into_into_dart(self) -> crate::api::screenshot_watermark::WatermarkOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::screenshot_watermark::WatermarkPosition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::TopLeft => 0.into_dart(),
+            Self::TopRight => 1.into_dart(),
+            Self::BottomLeft => 2.into_dart(),
+            Self::BottomRight => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::screenshot_watermark::WatermarkPosition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::screenshot_watermark::WatermarkPosition>
+    for crate::api::screenshot_watermark::WatermarkPosition
+{
+    fn into_into_dart(self) -> crate::api::screenshot_watermark::WatermarkPosition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::active_window_listener::WindowDetails {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.position.into_into_dart().into_dart(),
+            self.monitor.into_into_dart().into_dart(),
+            self.is_full_screen.into_into_dart().into_dart(),
+            self.process_name.into_into_dart().into_dart(),
+            self.process_path.into_into_dart().into_dart(),
+            self.process_id.into_into_dart().into_dart(),
+            self.os.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::active_window_listener::WindowDetails
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::active_window_listener::WindowDetails>
+    for crate::api::active_window_listener::WindowDetails
+{
+    fn into_into_dart(self) -> crate::api::active_window_listener::WindowDetails {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::keyboard_listener::KeyboardEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::mouse_listener::MouseEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::notification_actions::NotificationEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::app_policy::PolicyViolation,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::capture_preview::PreviewFrame,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::process_watcher::ProcessEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::screenshot_capture::Screenshot,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::system_monitor::SystemSnapshot,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::active_window_listener::WindowDetails,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::CategoryGateStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.category, serializer);
+        <u64>::sse_encode(self.sent, serializer);
+        <u64>::sse_encode(self.deduplicated, serializer);
+        <u64>::sse_encode(self.rate_limited, serializer);
+        <u64>::sse_encode(self.queued, serializer);
+        <u64>::sse_encode(self.dropped, serializer);
+    }
+}
+
+impl SseEncode for crate::api::display_geometry::CoordinateSpace {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::display_geometry::CoordinateSpace::Logical => 0,
+                crate::api::display_geometry::CoordinateSpace::Physical => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::system_monitor::CpuCoreUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <f32>::sse_encode(self.usage, serializer);
+        <u64>::sse_encode(self.frequency_mhz, serializer);
+    }
+}
+
+impl SseEncode for crate::api::delta_encoding::DeltaEncoderConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.tile_size, serializer);
+        <u32>::sse_encode(self.keyframe_interval, serializer);
+        <f32>::sse_encode(self.max_changed_ratio, serializer);
+    }
+}
+
+impl SseEncode for crate::api::system_monitor::DiskUsageInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.mount_point, serializer);
+        <String>::sse_encode(self.file_system, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+        <u64>::sse_encode(self.available_bytes, serializer);
+        <bool>::sse_encode(self.is_removable, serializer);
+    }
+}
+
+impl SseEncode for crate::api::display_geometry::DisplayGeometry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.id, serializer);
+        <bool>::sse_encode(self.is_primary, serializer);
+        <f32>::sse_encode(self.scale_factor, serializer);
+        <crate::api::display_geometry::DisplayRect>::sse_encode(self.logical, serializer);
+        <crate::api::display_geometry::DisplayRect>::sse_encode(self.physical, serializer);
+    }
+}
+
+impl SseEncode for crate::api::display_geometry::DisplayRect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.x, serializer);
+        <i32>::sse_encode(self.y, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+    }
+}
+
+impl SseEncode for crate::api::delta_encoding::EncodedFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.data, serializer);
+        <bool>::sse_encode(self.is_keyframe, serializer);
+        <u32>::sse_encode(self.frame_index, serializer);
+        <u32>::sse_encode(self.changed_tiles, serializer);
+        <u32>::sse_encode(self.total_tiles, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::frame_analysis::FrameAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::frame_analysis::FrameClassification>::sse_encode(
+            self.classification,
+            serializer,
+        );
+        <f32>::sse_encode(self.mean_luminance, serializer);
+        <f32>::sse_encode(self.luminance_variance, serializer);
+        <f32>::sse_encode(self.dominant_bin_ratio, serializer);
+        <f32>::sse_encode(self.dark_pixel_ratio, serializer);
+    }
+}
+
+impl SseEncode for crate::api::frame_analysis::FrameClassification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::frame_analysis::FrameClassification::Normal => 0,
+                crate::api::frame_analysis::FrameClassification::Blank => 1,
+                crate::api::frame_analysis::FrameClassification::Uniform => 2,
+                crate::api::frame_analysis::FrameClassification::LikelyLockScreen => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::GateDecision {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::desktop_notification::GateDecision::Sent => 0,
+                crate::api::desktop_notification::GateDecision::Deduplicated => 1,
+                crate::api::desktop_notification::GateDecision::RateLimited => 2,
+                crate::api::desktop_notification::GateDecision::Queued => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::GatedSendResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::desktop_notification::GateDecision>::sse_encode(self.decision, serializer);
+        <Option<crate::api::desktop_notification::NotificationBackend>>::sse_encode(
+            self.backend,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::click_heatmap::HeatmapConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::click_heatmap::HeatmapScope>::sse_encode(self.scope, serializer);
+        <u32>::sse_encode(self.grid_width, serializer);
+        <u32>::sse_encode(self.grid_height, serializer);
+        <bool>::sse_encode(self.include_right_clicks, serializer);
+    }
+}
+
+impl SseEncode for crate::api::click_heatmap::HeatmapGrid {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::click_heatmap::HeatmapScope>::sse_encode(self.scope, serializer);
+        <u32>::sse_encode(self.grid_width, serializer);
+        <u32>::sse_encode(self.grid_height, serializer);
+        <Vec<u32>>::sse_encode(self.cells, serializer);
+        <u64>::sse_encode(self.total_clicks, serializer);
+    }
+}
+
+impl SseEncode for crate::api::click_heatmap::HeatmapScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::click_heatmap::HeatmapScopeKind>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::click_heatmap::HeatmapScopeKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::click_heatmap::HeatmapScopeKind::Monitor => 0,
+                crate::api::click_heatmap::HeatmapScopeKind::Window => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::keyboard_listener::KeyboardEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <bool>::sse_encode(self.is_key_press, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::desktop_notification::CategoryGateStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::desktop_notification::CategoryGateStats>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::system_monitor::CpuCoreUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::system_monitor::CpuCoreUsage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::system_monitor::DiskUsageInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::system_monitor::DiskUsageInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::display_geometry::DisplayGeometry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::display_geometry::DisplayGeometry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::system_monitor::NetworkInterfaceUsage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::system_monitor::NetworkInterfaceUsage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::notification_actions::NotificationAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::notification_actions::NotificationAction>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::desktop_notification::NotificationBackend> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::desktop_notification::NotificationBackend>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::desktop_notification::NotificationBackendProbe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::desktop_notification::NotificationBackendProbe>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::app_policy::PolicyRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::app_policy::PolicyRule>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::get_all_process_list::ProcessInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::get_all_process_list::ProcessInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::process_sampler::ProcessSample> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::process_sampler::ProcessSample>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::get_all_process_list::ProcessTreeNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::get_all_process_list::ProcessTreeNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::process_sampler::ProcessUsageSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::process_sampler::ProcessUsageSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::screenshot_spool::SpoolEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::screenshot_spool::SpoolEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::active_window_listener::WindowDetails> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::active_window_listener::WindowDetails>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::system_monitor::LoadAverage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.one, serializer);
+        <f64>::sse_encode(self.five, serializer);
+        <f64>::sse_encode(self.fifteen, serializer);
+    }
+}
+
+impl SseEncode for crate::api::display_geometry::MonitorLocation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.monitor_id, serializer);
+        <i32>::sse_encode(self.x, serializer);
+        <i32>::sse_encode(self.y, serializer);
+    }
+}
+
+impl SseEncode for crate::api::mouse_listener::MouseEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.button, serializer);
+        <bool>::sse_encode(self.is_button_press, serializer);
+        <(i32, i32)>::sse_encode(self.coords, serializer);
+        <Option<crate::api::display_geometry::MonitorLocation>>::sse_encode(
+            self.monitor,
+            serializer,
+        );
+        <bool>::sse_encode(self.is_left_click, serializer);
+        <bool>::sse_encode(self.is_right_click, serializer);
+    }
+}
+
+impl SseEncode for crate::api::system_monitor::NetworkInterfaceUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <u64>::sse_encode(self.received_bytes, serializer);
+        <u64>::sse_encode(self.transmitted_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::notification_actions::NotificationAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.label, serializer);
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::NotificationBackend {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::desktop_notification::NotificationBackend::NotifyRust => 0,
+                crate::api::desktop_notification::NotificationBackend::NotifySend => 1,
+                crate::api::desktop_notification::NotificationBackend::Kdialog => 2,
+                crate::api::desktop_notification::NotificationBackend::Zenity => 3,
+                crate::api::desktop_notification::NotificationBackend::Xmessage => 4,
+                crate::api::desktop_notification::NotificationBackend::TerminalNotifier => 5,
+                crate::api::desktop_notification::NotificationBackend::AppleScript => 6,
+                crate::api::desktop_notification::NotificationBackend::PowerShellToast => 7,
+                crate::api::desktop_notification::NotificationBackend::PowerShellBalloon => 8,
+                crate::api::desktop_notification::NotificationBackend::MessageBox => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::NotificationBackendCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.actions, serializer);
+        <bool>::sse_encode(self.body_markup, serializer);
+        <bool>::sse_encode(self.icons, serializer);
+        <bool>::sse_encode(self.persistence, serializer);
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::NotificationBackendPreference {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::desktop_notification::NotificationBackend>>::sse_encode(
+            self.pinned,
+            serializer,
+        );
+        <Vec<crate::api::desktop_notification::NotificationBackend>>::sse_encode(
+            self.excluded,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::NotificationBackendProbe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::desktop_notification::NotificationBackend>::sse_encode(
+            self.backend,
+            serializer,
+        );
+        <bool>::sse_encode(self.available, serializer);
+        <bool>::sse_encode(self.disabled, serializer);
+        <crate::api::desktop_notification::NotificationBackendCapabilities>::sse_encode(
+            self.capabilities,
+            serializer,
+        );
+        <Option<crate::api::desktop_notification::NotificationServerInfo>>::sse_encode(
+            self.server,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::notification_actions::NotificationEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::notification_actions::NotificationEventKind>::sse_encode(
+            self.kind, serializer,
+        );
+        <Option<String>>::sse_encode(self.action_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::notification_actions::NotificationEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::notification_actions::NotificationEventKind::ActionInvoked => 0,
+                crate::api::notification_actions::NotificationEventKind::Dismissed => 1,
+                crate::api::notification_actions::NotificationEventKind::Expired => 2,
+                crate::api::notification_actions::NotificationEventKind::Closed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::NotificationGateConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.dedupe_window_seconds, serializer);
+        <u32>::sse_encode(self.max_per_category, serializer);
+        <u64>::sse_encode(self.rate_window_seconds, serializer);
+        <Option<crate::api::desktop_notification::QuietHours>>::sse_encode(
+            self.quiet_hours,
+            serializer,
+        );
+        <bool>::sse_encode(self.do_not_disturb, serializer);
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::NotificationGateStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pending, serializer);
+        <Vec<crate::api::desktop_notification::CategoryGateStats>>::sse_encode(
+            self.categories,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::desktop_notification::NotificationServerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.vendor, serializer);
+        <String>::sse_encode(self.version, serializer);
+        <String>::sse_encode(self.spec_version, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::display_geometry::MonitorLocation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::display_geometry::MonitorLocation>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::desktop_notification::NotificationBackend> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::desktop_notification::NotificationBackend>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::desktop_notification::NotificationServerInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::desktop_notification::NotificationServerInfo>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::process_sampler::ProcessHistory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::process_sampler::ProcessHistory>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::get_all_process_list::ProcessTreeNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::get_all_process_list::ProcessTreeNode>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::desktop_notification::QuietHours> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::desktop_notification::QuietHours>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<(f64, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <(f64, f64)>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::timelapse_recorder::TimelapseStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::timelapse_recorder::TimelapseStatus>::sse_encode(value, serializer);
         }
-        15 => wire__crate__api__take_full_screenshot__is_nircmd_available_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => wire__crate__api__get_all_process_list__kill_process_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        18 => wire__crate__api__desktop_notification__register_module_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        19 => wire__crate__api__desktop_notification__send_notification_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        20 => wire__crate__api__desktop_notification__send_notification_with_options_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        21 => wire__crate__api__keyboard_listener__start_keyboard_listener_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        22 => wire__crate__api__mouse_listener__start_mouse_listener_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        26 => wire__crate__api__take_full_screenshot__take_full_screenshot_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        27 => wire__crate__api__take_full_screenshot__take_screenshot_linux_fallback_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        28 => wire__crate__api__take_full_screenshot__take_screenshot_windows_csharp_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        29 => wire__crate__api__take_full_screenshot__take_screenshot_windows_directshow_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        30 => wire__crate__api__take_full_screenshot__take_screenshot_windows_ffmpeg_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        31 => wire__crate__api__take_full_screenshot__take_screenshot_windows_memory_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        32 => wire__crate__api__take_full_screenshot__take_screenshot_windows_nircmd_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        33 => wire__crate__api__take_full_screenshot__take_screenshot_windows_powershell_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        34 => wire__crate__api__take_full_screenshot__take_screenshot_windows_vbscript_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        35 => wire__crate__api__take_full_screenshot__take_screenshot_windows_win32_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        36 => wire__crate__api__take_full_screenshot__take_screenshot_windows_wmi_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        37 => wire__crate__api__take_full_screenshot__take_screenshot_with_screenshots_crate_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        38 => wire__crate__api__take_full_screenshot__test_all_available_methods_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        39 => wire__crate__api__take_full_screenshot__test_bundled_nircmd_extraction_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        40 => wire__crate__api__take_full_screenshot__test_linux_environment_check_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        41 => wire__crate__api__take_full_screenshot__test_linux_fallback_methods_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        42 => wire__crate__api__take_full_screenshot__test_nircmd_availability_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        43 => wire__crate__api__take_full_screenshot__test_nircmd_capabilities_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        44 => wire__crate__api__take_full_screenshot__test_nircmd_screenshot_simple_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        45 => wire__crate__api__take_full_screenshot__test_screenshots_crate_method_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        46 => wire__crate__api__take_full_screenshot__test_windows_environment_check_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        47 => wire__crate__api__take_full_screenshot__test_windows_method_1_nircmd_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        48 => wire__crate__api__take_full_screenshot__test_windows_method_2_powershell_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        49 => wire__crate__api__take_full_screenshot__test_windows_method_3_memory_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        50 => wire__crate__api__take_full_screenshot__test_windows_method_4_directshow_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        51 => wire__crate__api__take_full_screenshot__test_windows_method_5_win32_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        52 => wire__crate__api__take_full_screenshot__test_windows_method_6_wmi_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        53 => wire__crate__api__take_full_screenshot__test_windows_method_7_ffmpeg_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        54 => wire__crate__api__take_full_screenshot__test_windows_method_8_csharp_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        55 => wire__crate__api__take_full_screenshot__test_windows_method_9_vbscript_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        6 => wire__crate__api__active_window_listener__get_active_window_info_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__api__active_window_listener__get_current_platform_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__active_window_listener__get_open_windows_info_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__active_window_listener__get_window_icon_data_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__active_window_listener__is_platform_supported_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        23 => wire__crate__api__active_window_listener__start_window_listener_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        24 => wire__crate__api__active_window_listener__start_window_listener_stream_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        25 => wire__crate__api__active_window_listener__stop_window_listener_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::screenshot_watermark::WatermarkOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::screenshot_watermark::WatermarkOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::api::app_policy::PolicyAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::app_policy::PolicyAction::Log => 0,
+                crate::api::app_policy::PolicyAction::Notify => 1,
+                crate::api::app_policy::PolicyAction::WarnThenKill => 2,
+                crate::api::app_policy::PolicyAction::Kill => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::app_policy::PolicyConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::app_policy::PolicyRule>>::sse_encode(self.rules, serializer);
+        <u64>::sse_encode(self.interval_ms, serializer);
     }
 }
 
-// Section: rust2dart
+impl SseEncode for crate::api::app_policy::PolicyEnforcement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::app_policy::PolicyEnforcement::Logged => 0,
+                crate::api::app_policy::PolicyEnforcement::Notified => 1,
+                crate::api::app_policy::PolicyEnforcement::Warned => 2,
+                crate::api::app_policy::PolicyEnforcement::Killed => 3,
+                crate::api::app_policy::PolicyEnforcement::KillFailed => 4,
+                crate::api::app_policy::PolicyEnforcement::Exited => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::keyboard_listener::KeyboardEvent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.key.into_into_dart().into_dart(),
-            self.is_key_press.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::app_policy::PolicyRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <Vec<String>>::sse_encode(self.name_patterns, serializer);
+        <Vec<String>>::sse_encode(self.path_patterns, serializer);
+        <Vec<String>>::sse_encode(self.window_title_patterns, serializer);
+        <crate::api::app_policy::PolicyAction>::sse_encode(self.action, serializer);
+        <u32>::sse_encode(self.grace_seconds, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::keyboard_listener::KeyboardEvent
-{
+
+impl SseEncode for crate::api::app_policy::PolicyViolation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.rule_id, serializer);
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.process_name, serializer);
+        <Option<String>>::sse_encode(self.exe_path, serializer);
+        <Option<String>>::sse_encode(self.window_title, serializer);
+        <crate::api::app_policy::PolicyEnforcement>::sse_encode(self.enforcement, serializer);
+        <Option<u64>>::sse_encode(self.kill_at_ms, serializer);
+        <u64>::sse_encode(self.timestamp_ms, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::keyboard_listener::KeyboardEvent>
-    for crate::api::keyboard_listener::KeyboardEvent
-{
-    fn into_into_dart(self) -> crate::api::keyboard_listener::KeyboardEvent {
-        self
+
+impl SseEncode for crate::api::capture_preview::PreviewFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.jpeg, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.captured_at_ms, serializer);
+        <crate::api::frame_analysis::FrameClassification>::sse_encode(
+            self.classification,
+            serializer,
+        );
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::mouse_listener::MouseEvent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.button.into_into_dart().into_dart(),
-            self.is_button_press.into_into_dart().into_dart(),
-            self.coords.into_into_dart().into_dart(),
-            self.is_left_click.into_into_dart().into_dart(),
-            self.is_right_click.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::process_watcher::ProcessEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::process_watcher::ProcessEventKind>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.exe_path, serializer);
+        <u64>::sse_encode(self.start_time, serializer);
+        <Option<u64>>::sse_encode(self.run_duration_seconds, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::mouse_listener::MouseEvent
-{
+
+impl SseEncode for crate::api::process_watcher::ProcessEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::process_watcher::ProcessEventKind::Started => 0,
+                crate::api::process_watcher::ProcessEventKind::Exited => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::mouse_listener::MouseEvent>
-    for crate::api::mouse_listener::MouseEvent
-{
-    fn into_into_dart(self) -> crate::api::mouse_listener::MouseEvent {
-        self
+
+impl SseEncode for crate::api::process_watcher::ProcessFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.name_patterns, serializer);
+        <Vec<String>>::sse_encode(self.path_patterns, serializer);
+        <bool>::sse_encode(self.include_existing, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::get_all_process_list::ProcessInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.pid.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.cmd.into_into_dart().into_dart(),
-            self.memory_usage.into_into_dart().into_dart(),
-            self.cpu_usage.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::process_sampler::ProcessHistory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Vec<crate::api::process_sampler::ProcessSample>>::sse_encode(self.samples, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::get_all_process_list::ProcessInfo
-{
+
+impl SseEncode for crate::api::get_all_process_list::ProcessInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Vec<String>>::sse_encode(self.cmd, serializer);
+        <u64>::sse_encode(self.memory_usage, serializer);
+        <f32>::sse_encode(self.cpu_usage, serializer);
+        <Option<u32>>::sse_encode(self.parent_pid, serializer);
+        <Option<String>>::sse_encode(self.user, serializer);
+        <Option<String>>::sse_encode(self.exe_path, serializer);
+        <u64>::sse_encode(self.start_time, serializer);
+        <u64>::sse_encode(self.run_time, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <u64>::sse_encode(self.disk_read_bytes, serializer);
+        <u64>::sse_encode(self.disk_written_bytes, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::get_all_process_list::ProcessInfo>
-    for crate::api::get_all_process_list::ProcessInfo
-{
-    fn into_into_dart(self) -> crate::api::get_all_process_list::ProcessInfo {
-        self
+
+impl SseEncode for crate::api::process_sampler::ProcessSample {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.timestamp_ms, serializer);
+        <f32>::sse_encode(self.cpu_usage, serializer);
+        <u64>::sse_encode(self.memory_bytes, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::active_window_listener::WindowDetails {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.position.into_into_dart().into_dart(),
-            self.is_full_screen.into_into_dart().into_dart(),
-            self.process_name.into_into_dart().into_dart(),
-            self.process_path.into_into_dart().into_dart(),
-            self.process_id.into_into_dart().into_dart(),
-            self.os.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::process_sampler::ProcessSamplerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.interval_ms, serializer);
+        <u32>::sse_encode(self.history_len, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::active_window_listener::WindowDetails
-{
+
+impl SseEncode for crate::api::process_sampler::ProcessSortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::process_sampler::ProcessSortKey::Cpu => 0,
+                crate::api::process_sampler::ProcessSortKey::Memory => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::active_window_listener::WindowDetails>
-    for crate::api::active_window_listener::WindowDetails
-{
-    fn into_into_dart(self) -> crate::api::active_window_listener::WindowDetails {
-        self
+
+impl SseEncode for crate::api::get_all_process_list::ProcessTreeNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::get_all_process_list::ProcessInfo>::sse_encode(self.process, serializer);
+        <Vec<crate::api::get_all_process_list::ProcessTreeNode>>::sse_encode(
+            self.children,
+            serializer,
+        );
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::process_sampler::ProcessUsageSummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <u32>::sse_encode(self.pid, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <f32>::sse_encode(self.average_cpu, serializer);
+        <f32>::sse_encode(self.peak_cpu, serializer);
+        <u64>::sse_encode(self.average_memory_bytes, serializer);
+        <u64>::sse_encode(self.peak_memory_bytes, serializer);
+        <u32>::sse_encode(self.sample_count, serializer);
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::keyboard_listener::KeyboardEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for crate::api::desktop_notification::QuietHours {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <u32>::sse_encode(self.start_minute, serializer);
+        <u32>::sse_encode(self.end_minute, serializer);
+        <i32>::sse_encode(self.utc_offset_minutes, serializer);
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::mouse_listener::MouseEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for (f64, f64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <f64>::sse_encode(self.0, serializer);
+        <f64>::sse_encode(self.1, serializer);
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::active_window_listener::WindowDetails,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for (i32, i32) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(self.0, serializer);
+        <i32>::sse_encode(self.1, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for (i32, i32, i32, i32) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.0, serializer);
+        <i32>::sse_encode(self.1, serializer);
+        <i32>::sse_encode(self.2, serializer);
+        <i32>::sse_encode(self.3, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::resource_budget::ResourceBudgetConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <f32>::sse_encode(self.max_cpu_percent, serializer);
+        <u64>::sse_encode(self.max_memory_bytes, serializer);
+        <u64>::sse_encode(self.sample_interval_ms, serializer);
+        <f64>::sse_encode(self.max_throttle_factor, serializer);
+        <f64>::sse_encode(self.battery_throttle_factor, serializer);
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for crate::api::resource_budget::ResourceBudgetStatus {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.running, serializer);
+        <f32>::sse_encode(self.cpu_percent, serializer);
+        <u64>::sse_encode(self.memory_bytes, serializer);
+        <bool>::sse_encode(self.on_battery, serializer);
+        <f64>::sse_encode(self.throttle_factor, serializer);
+        <bool>::sse_encode(self.encoding_deferred, serializer);
+        <u64>::sse_encode(self.input_poll_interval_ms, serializer);
+        <u64>::sse_encode(self.window_poll_interval_ms, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::screenshot_capture::Screenshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.data, serializer);
+        <crate::api::screenshot_capture::ScreenshotFormat>::sse_encode(self.format, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.captured_at_ms, serializer);
+        <crate::api::frame_analysis::FrameAnalysis>::sse_encode(self.analysis, serializer);
+        <Option<u32>>::sse_encode(self.monitor_id, serializer);
     }
 }
 
-impl SseEncode for crate::api::keyboard_listener::KeyboardEvent {
+impl SseEncode for crate::api::screenshot_capture::ScreenshotFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.key, serializer);
-        <bool>::sse_encode(self.is_key_press, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::screenshot_capture::ScreenshotFormat::Png => 0,
+                crate::api::screenshot_capture::ScreenshotFormat::Jpeg => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::screenshot_capture::ScreenshotOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <crate::api::screenshot_capture::ScreenshotFormat>::sse_encode(self.format, serializer);
+        <u8>::sse_encode(self.jpeg_quality, serializer);
+        <Option<u32>>::sse_encode(self.max_width, serializer);
+        <Option<crate::api::screenshot_watermark::WatermarkOptions>>::sse_encode(
+            self.watermark,
+            serializer,
+        );
+        <bool>::sse_encode(self.include_cursor, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::screenshot_scheduler::ScreenshotScheduleConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <u64>::sse_encode(self.interval_seconds, serializer);
+        <u32>::sse_encode(self.captures_per_interval, serializer);
+        <u64>::sse_encode(self.idle_threshold_seconds, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+        <crate::api::screenshot_capture::ScreenshotOptions>::sse_encode(self.options, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::get_all_process_list::ProcessInfo> {
+impl SseEncode for crate::api::screenshot_spool::SpoolEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::get_all_process_list::ProcessInfo>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+        <u64>::sse_encode(self.captured_at_ms, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::active_window_listener::WindowDetails> {
+impl SseEncode for crate::api::screenshot_spool::SpoolQuotaResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::active_window_listener::WindowDetails>::sse_encode(item, serializer);
-        }
+        <Vec<String>>::sse_encode(self.deleted_paths, serializer);
+        <u32>::sse_encode(self.remaining_files, serializer);
+        <u64>::sse_encode(self.remaining_bytes, serializer);
     }
 }
 
-impl SseEncode for crate::api::mouse_listener::MouseEvent {
+impl SseEncode for crate::api::screenshot_spool::SpooledScreenshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.button, serializer);
-        <bool>::sse_encode(self.is_button_press, serializer);
-        <(i32, i32)>::sse_encode(self.coords, serializer);
-        <bool>::sse_encode(self.is_left_click, serializer);
-        <bool>::sse_encode(self.is_right_click, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <crate::api::screenshot_capture::ScreenshotFormat>::sse_encode(self.format, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+        <u64>::sse_encode(self.captured_at_ms, serializer);
+        <crate::api::frame_analysis::FrameAnalysis>::sse_encode(self.analysis, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::system_monitor::SystemSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <u64>::sse_encode(self.timestamp_ms, serializer);
+        <f32>::sse_encode(self.global_cpu_usage, serializer);
+        <Vec<crate::api::system_monitor::CpuCoreUsage>>::sse_encode(self.cpu_cores, serializer);
+        <u64>::sse_encode(self.total_memory_bytes, serializer);
+        <u64>::sse_encode(self.used_memory_bytes, serializer);
+        <u64>::sse_encode(self.available_memory_bytes, serializer);
+        <u64>::sse_encode(self.total_swap_bytes, serializer);
+        <u64>::sse_encode(self.used_swap_bytes, serializer);
+        <crate::api::system_monitor::LoadAverage>::sse_encode(self.load_average, serializer);
+        <u64>::sse_encode(self.uptime_seconds, serializer);
+        <u64>::sse_encode(self.boot_time, serializer);
+        <Vec<crate::api::system_monitor::DiskUsageInfo>>::sse_encode(self.disks, serializer);
+        <Vec<crate::api::system_monitor::NetworkInterfaceUsage>>::sse_encode(
+            self.networks,
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Option<i32> {
+impl SseEncode for crate::api::process_termination::TerminateOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
-        }
+        <crate::api::process_termination::TerminateSignal>::sse_encode(self.signal, serializer);
+        <u64>::sse_encode(self.grace_period_ms, serializer);
+        <bool>::sse_encode(self.kill_tree, serializer);
     }
 }
 
-impl SseEncode for Option<u64> {
+impl SseEncode for crate::api::process_termination::TerminateOutcome {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::process_termination::TerminateOutcome::Exited => 0,
+                crate::api::process_termination::TerminateOutcome::ForceKilled => 1,
+                crate::api::process_termination::TerminateOutcome::NotFound => 2,
+                crate::api::process_termination::TerminateOutcome::PermissionDenied => 3,
+                crate::api::process_termination::TerminateOutcome::StillRunning => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::get_all_process_list::ProcessInfo {
+impl SseEncode for crate::api::process_termination::TerminateSignal {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.pid, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <Vec<String>>::sse_encode(self.cmd, serializer);
-        <u64>::sse_encode(self.memory_usage, serializer);
-        <f32>::sse_encode(self.cpu_usage, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::process_termination::TerminateSignal::Term => 0,
+                crate::api::process_termination::TerminateSignal::Interrupt => 1,
+                crate::api::process_termination::TerminateSignal::Hangup => 2,
+                crate::api::process_termination::TerminateSignal::Quit => 3,
+                crate::api::process_termination::TerminateSignal::Kill => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for (i32, i32) {
+impl SseEncode for crate::api::timelapse_recorder::TimelapseConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.0, serializer);
-        <i32>::sse_encode(self.1, serializer);
+        <String>::sse_encode(self.output_path, serializer);
+        <crate::api::timelapse_recorder::TimelapseFormat>::sse_encode(self.format, serializer);
+        <u64>::sse_encode(self.frame_interval_seconds, serializer);
+        <u32>::sse_encode(self.max_width, serializer);
+        <u32>::sse_encode(self.playback_fps, serializer);
+        <u8>::sse_encode(self.jpeg_quality, serializer);
+        <u64>::sse_encode(self.max_file_size_bytes, serializer);
     }
 }
 
-impl SseEncode for (i32, i32, i32, i32) {
+impl SseEncode for crate::api::timelapse_recorder::TimelapseFormat {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.0, serializer);
-        <i32>::sse_encode(self.1, serializer);
-        <i32>::sse_encode(self.2, serializer);
-        <i32>::sse_encode(self.3, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::timelapse_recorder::TimelapseFormat::Gif => 0,
+                crate::api::timelapse_recorder::TimelapseFormat::MjpegAvi => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::timelapse_recorder::TimelapseState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::timelapse_recorder::TimelapseState::Recording => 0,
+                crate::api::timelapse_recorder::TimelapseState::Paused => 1,
+                crate::api::timelapse_recorder::TimelapseState::Stopped => 2,
+                crate::api::timelapse_recorder::TimelapseState::SizeLimitReached => 3,
+                crate::api::timelapse_recorder::TimelapseState::Failed => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::timelapse_recorder::TimelapseStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::timelapse_recorder::TimelapseState>::sse_encode(self.state, serializer);
+        <String>::sse_encode(self.output_path, serializer);
+        <u32>::sse_encode(self.frames_written, serializer);
+        <u64>::sse_encode(self.bytes_written, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
     }
 }
 
@@ -2857,12 +10304,50 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::screenshot_watermark::WatermarkOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::screenshot_watermark::WatermarkPosition>::sse_encode(
+            self.position,
+            serializer,
+        );
+        <f32>::sse_encode(self.opacity, serializer);
+        <Option<u32>>::sse_encode(self.scale, serializer);
+        <bool>::sse_encode(self.include_hostname, serializer);
+        <bool>::sse_encode(self.include_user, serializer);
+        <Option<String>>::sse_encode(self.task_id, serializer);
+        <Option<String>>::sse_encode(self.custom_text, serializer);
+    }
+}
+
+impl SseEncode for crate::api::screenshot_watermark::WatermarkPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::screenshot_watermark::WatermarkPosition::TopLeft => 0,
+                crate::api::screenshot_watermark::WatermarkPosition::TopRight => 1,
+                crate::api::screenshot_watermark::WatermarkPosition::BottomLeft => 2,
+                crate::api::screenshot_watermark::WatermarkPosition::BottomRight => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::active_window_listener::WindowDetails {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u32>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.title, serializer);
         <(i32, i32, i32, i32)>::sse_encode(self.position, serializer);
+        <Option<crate::api::display_geometry::MonitorLocation>>::sse_encode(
+            self.monitor,
+            serializer,
+        );
         <bool>::sse_encode(self.is_full_screen, serializer);
         <String>::sse_encode(self.process_name, serializer);
         <String>::sse_encode(self.process_path, serializer);